@@ -0,0 +1,155 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Number of bytes in a kibibyte.
+const KIBIBYTE: f64 = 1024.0;
+
+/// Allocator of every program built with the `alloc-stats` feature.
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+thread_local! {
+    /// Number of times that memory was allocated or reallocated on this
+    /// thread.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    /// Number of bytes allocated on this thread that have not been freed,
+    /// which can become negative if memory allocated on another thread is
+    /// freed on this one.
+    static LIVE_BYTES: Cell<isize> = const { Cell::new(0) };
+    /// Most that [LIVE_BYTES] has been since it was last reset by [measure].
+    static PEAK_BYTES: Cell<isize> = const { Cell::new(0) };
+}
+
+/// How much a phase of solving a puzzle, such as parsing its input or
+/// solving one of its parts, asked of the heap.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AllocStats {
+    /// Number of times that memory was allocated or reallocated.
+    pub allocations: usize,
+    /// Most bytes that were allocated at once, beyond what was already
+    /// allocated when the phase began.
+    pub peak_bytes: usize,
+}
+
+impl AllocStats {
+    /// Returns [AllocStats::peak_bytes] in kibibytes, e.g. `"12.50 KiB"`.
+    pub fn peak_heap(&self) -> String {
+        format!("{:.2} KiB", self.peak_bytes as f64 / KIBIBYTE)
+    }
+}
+
+/// [GlobalAlloc] that hands every request to the [System] allocator, counting
+/// allocations and the bytes they hold on the thread that asked for them.
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            track_allocation(layout.size() as isize);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            track_allocation(layout.size() as isize);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        track_bytes(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            track_allocation(new_size as isize - layout.size() as isize);
+        }
+
+        new_ptr
+    }
+}
+
+/// Runs `phase`, returning whatever it returned along with the [AllocStats]
+/// of the allocations it made.
+///
+/// Only allocations made on the calling thread are counted, so `phase`
+/// should not hand its work to other threads.
+///
+/// ```
+/// use aoc_common::alloc_stats;
+///
+/// let (numbers, alloc_stats) = alloc_stats::measure(|| vec![0u64; 128]);
+///
+/// assert_eq!(numbers.len(), 128);
+/// assert_eq!(alloc_stats.allocations, 1);
+/// assert!(alloc_stats.peak_bytes >= 1024);
+/// ```
+pub fn measure<T>(phase: impl FnOnce() -> T) -> (T, AllocStats) {
+    let allocations_before = ALLOCATIONS.with(Cell::get);
+    let live_bytes_before = LIVE_BYTES.with(Cell::get);
+    let peak_bytes_before = PEAK_BYTES.with(|peak_bytes| peak_bytes.replace(live_bytes_before));
+
+    let output = phase();
+
+    let peak_bytes = PEAK_BYTES.with(|peak_bytes| {
+        let peak_bytes_during = peak_bytes.get();
+        // Phases measured within another phase count toward its peak too.
+        peak_bytes.set(peak_bytes_during.max(peak_bytes_before));
+
+        peak_bytes_during
+    });
+    let alloc_stats = AllocStats {
+        allocations: ALLOCATIONS.with(Cell::get) - allocations_before,
+        peak_bytes: (peak_bytes - live_bytes_before).max(0) as usize,
+    };
+
+    (output, alloc_stats)
+}
+
+/// Counts an allocation on the current thread that changed how many bytes
+/// it holds by `byte_delta`.
+fn track_allocation(byte_delta: isize) {
+    let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+
+    track_bytes(byte_delta);
+}
+
+/// Adds `byte_delta` to the bytes held on the current thread, raising its
+/// peak if they now exceed it.
+fn track_bytes(byte_delta: isize) {
+    // Threads that are being torn down no longer track anything.
+    let _ = LIVE_BYTES.try_with(|live_bytes| {
+        live_bytes.set(live_bytes.get() + byte_delta);
+
+        let _ = PEAK_BYTES
+            .try_with(|peak_bytes| peak_bytes.set(peak_bytes.get().max(live_bytes.get())));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_nested_phases() {
+        let (_, outer_alloc_stats) = measure(|| {
+            let (inner, inner_alloc_stats) = measure(|| vec![0u8; 4096]);
+            drop(inner);
+
+            assert_eq!(inner_alloc_stats.allocations, 1);
+            assert!(inner_alloc_stats.peak_bytes >= 4096);
+
+            vec![0u8; 16]
+        });
+
+        assert_eq!(outer_alloc_stats.allocations, 2);
+        assert!(outer_alloc_stats.peak_bytes >= 4096);
+    }
+}
@@ -0,0 +1,96 @@
+use num_bigint::BigUint;
+use std::fmt::{self, Display, Formatter};
+
+/// Answer to one part of a puzzle, wide enough to hold it without wrapping
+/// even when the input has been modified or stressed.
+///
+/// Any integer converts into the narrowest variant that holds every value of
+/// its type, so solvers can return whatever they computed with `.into()`:
+///
+/// ```
+/// use aoc_common::answer::Answer;
+/// use num_bigint::BigUint;
+///
+/// assert_eq!(Answer::from(7usize), Answer::U64(7));
+/// assert_eq!(Answer::from(-7i32), Answer::I64(-7));
+/// assert_eq!(Answer::from(u128::MAX).to_string(), "340282366920938463463374607431768211455");
+/// assert_eq!(Answer::from(BigUint::from(2u8).pow(128)).to_string(), "340282366920938463463374607431768211456");
+/// assert_eq!(Answer::from("Merry Christmas!").to_string(), "Merry Christmas!");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    /// Non-negative answer too large for a [u128].
+    BigUint(BigUint),
+    /// Answer that can be negative.
+    I64(i64),
+    /// Answer that is not a number, like letters traced out by dots.
+    String(String),
+    /// Non-negative answer too large for a [u64].
+    U128(u128),
+    /// Non-negative answer, which most are.
+    U64(u64),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Answer::BigUint(answer) => write!(f, "{}", answer),
+            Answer::I64(answer) => write!(f, "{}", answer),
+            Answer::String(answer) => write!(f, "{}", answer),
+            Answer::U128(answer) => write!(f, "{}", answer),
+            Answer::U64(answer) => write!(f, "{}", answer),
+        }
+    }
+}
+
+/// Compares the [Answer] as it is displayed, which is how answers are written
+/// down (e.g. in "answers.toml").
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::String(answer) => answer == other,
+            _ => self.to_string().as_str() == *other,
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(answer: BigUint) -> Self {
+        Answer::BigUint(answer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::String(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::String(answer.to_string())
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(answer: u128) -> Self {
+        Answer::U128(answer)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $wide:ty, $($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(answer: $integer) -> Self {
+                    Answer::$variant(answer as $wide)
+                }
+            }
+        )*
+    };
+}
+
+// Every supported platform has pointers of at most 64 bits, so no `usize` or
+// `isize` is truncated.
+impl_from_integer!(I64, i64, i8, i16, i32, i64, isize);
+impl_from_integer!(U64, u64, u8, u16, u32, u64, usize);
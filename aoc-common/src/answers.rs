@@ -0,0 +1,103 @@
+use crate::args::Part;
+use crate::error::WrongAnswer;
+use crate::output;
+use crate::table::Table;
+
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Path of the recorded answers that `--check` compares against when no
+/// other is specified, relative to the directory of a day's puzzle.
+///
+/// The file is ignored by git, so that the answers to personal puzzle inputs
+/// are never published, and has to be written by hand in each checkout.
+pub const DEFAULT_ANSWERS_PATH: &str = "../answers.toml";
+
+/// Panics unless the answers in `table` match the answers recorded for `day`
+/// in the workspace's [DEFAULT_ANSWERS_PATH], which is local to each checkout.
+///
+/// Meant for tests. Skips the check, printing a warning instead, if the file
+/// does not exist, so tests pass in checkouts that have not recorded answers.
+pub fn assert_matches_local_answers(day: u8, table: &Table) {
+    let answers_path = Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_ANSWERS_PATH);
+    if !answers_path.exists() {
+        output::print_warning(format!(
+            "Skipped checking the answers of day {}: \"{}\" does not exist",
+            day,
+            answers_path.display()
+        ));
+
+        return;
+    }
+
+    if let Err(error) = check(day, table, None, &answers_path) {
+        panic!("{:#}", error);
+    }
+}
+
+/// Returns [Err] unless the answers in `table`, one for each part selected by
+/// `part` in order, match the answers recorded for `day` in the TOML file at
+/// `answers_path`.
+///
+/// Answers are recorded as strings under a table per day, e.g.:
+///
+/// ```toml
+/// [day-1]
+/// part-one = "7"
+/// part-two = "5"
+/// ```
+pub fn check(day: u8, table: &Table, part: Option<Part>, answers_path: &Path) -> Result<()> {
+    let serialized_answers = fs::read_to_string(answers_path)
+        .with_context(|| format!("Failed to read \"{}\"", answers_path.display()))?;
+    let answers = serialized_answers
+        .parse::<toml::Table>()
+        .with_context(|| format!("\"{}\" is not valid TOML", answers_path.display()))?;
+    let day_answers = answers
+        .get(&format!("day-{}", day))
+        .and_then(|day_answers| day_answers.as_table())
+        .with_context(|| {
+            format!(
+                "\"{}\" has no answers for day {}",
+                answers_path.display(),
+                day
+            )
+        })?;
+
+    let selected_parts = [(Part::One, "part-one"), (Part::Two, "part-two")]
+        .into_iter()
+        .filter(|(each_part, _)| each_part.is_selected_by(part));
+    let mut mismatches = vec![];
+
+    for ((_, key), answer) in selected_parts.zip(table.answers()) {
+        let expected_answer = day_answers
+            .get(key)
+            .and_then(|expected_answer| expected_answer.as_str())
+            .with_context(|| {
+                format!(
+                    "\"{}\" has no {} answer for day {}",
+                    answers_path.display(),
+                    key,
+                    day
+                )
+            })?;
+
+        if answer != expected_answer {
+            mismatches.push(format!(
+                "{} is {:?}, not {:?}",
+                key, answer, expected_answer
+            ));
+        }
+    }
+
+    if !mismatches.is_empty() {
+        return Err(anyhow!(WrongAnswer::new(format!(
+            "Day {} disagrees with \"{}\": {}",
+            day,
+            answers_path.display(),
+            mismatches.join("; ")
+        ))));
+    }
+
+    Ok(())
+}
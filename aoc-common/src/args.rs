@@ -0,0 +1,618 @@
+use crate::answers::{self, DEFAULT_ANSWERS_PATH};
+use crate::baseline::{self, DEFAULT_MAX_SLOWDOWN};
+use crate::cancellation;
+use crate::error::{self, ErrorFormat, ErrorKind, TimedOut, USAGE_EXIT_CODE};
+use crate::flame::{FlameArgs, FlameGuard};
+use crate::input::{self, InputSource};
+#[cfg(feature = "otel")]
+use crate::otel::{OtelArgs, OtelGuard};
+use crate::output;
+#[cfg(feature = "profile")]
+use crate::profile::ProfileArgs;
+use crate::table::Table;
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::builder::FalseyValueParser;
+use clap::{ArgAction, Parser, ValueEnum};
+use clap_complete::Shell;
+use std::env;
+use std::ffi::OsString;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{self, ExitCode};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// Directory that the puzzle input is read from when neither `--input` nor
+/// `--input-dir` is specified.
+pub const DEFAULT_INPUT_DIR: &str = "files";
+
+/// Name of the file within the input directory that the puzzle input is read
+/// from when `--input` is not specified.
+pub const INPUT_FILE_NAME: &str = "input.txt";
+
+/// Name of the file within the input directory holding the example input
+/// from the puzzle description, which `--offline` falls back to.
+pub const SAMPLE_FILE_NAME: &str = "sample.txt";
+
+/// How long a solve may keep running after its `--timeout` has passed before
+/// the program exits without it.
+pub const TIMEOUT_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Command-line arguments accepted by every day's program.
+///
+/// Some of them can also be set through environment variables, which apply
+/// only when the matching flag is not specified:
+/// *   `AOC_FORMAT`\
+///     Stands in for `--format`.
+/// *   `AOC_INPUT_DIR`\
+///     Stands in for `--input-dir`.
+/// *   `AOC_OFFLINE`\
+///     Stands in for `--offline` unless it is empty, "0", "false", "no", or
+///     "off".
+/// *   `NO_COLOR`\
+///     Stands in for `--no-color` whenever it is set, as described at
+///     https://no-color.org.
+/// *   `RUST_LOG`\
+///     Chooses what is logged to standard error, in `tracing_subscriber`'s
+///     `EnvFilter` syntax (e.g. "day_4=debug"), in place of the level set by
+///     `--verbose`, and starts logging even without `--verbose`.
+#[derive(Debug, Parser)]
+pub struct CommonArgs {
+    /// Reads the puzzle input from the specified file, or from standard input
+    /// if it is "-"; gzipped files are decompressed automatically. Defaults to
+    /// "input.txt" within `--input-dir`.
+    #[clap(long, short, value_name = "FILE")]
+    pub input: Option<String>,
+    /// Reads the puzzle input from "input.txt" within the specified directory
+    /// unless `--input` is specified. Relative directories missing from the
+    /// working directory are looked for in the day's crate instead.
+    #[clap(long, value_name = "DIR", env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR)]
+    pub input_dir: PathBuf,
+    /// Solves the sample input from the puzzle description, "sample.txt"
+    /// within `--input-dir`, whenever the personal puzzle input is missing,
+    /// so that every day runs from a fresh clone.
+    #[clap(long, env = "AOC_OFFLINE", value_parser = FalseyValueParser::new())]
+    pub offline: bool,
+    /// Solves every file in the specified directory instead of a single
+    /// input, writing a CSV row of answers and timings for each.
+    #[clap(long, value_name = "DIR", conflicts_with = "visualize")]
+    pub inputs: Option<PathBuf>,
+    /// Solves the puzzle again every time the input file changes, printing
+    /// how the answers changed, until interrupted.
+    #[clap(long, conflicts_with_all = ["inputs", "visualize"])]
+    pub watch: bool,
+    /// Writes the CSV produced by `--inputs` to the specified file instead
+    /// of standard output.
+    #[clap(long, value_name = "FILE", requires = "inputs")]
+    pub out: Option<PathBuf>,
+    /// Writes the answers to the specified file instead of standard output.
+    #[clap(long, value_name = "FILE", conflicts_with_all = ["inputs", "watch"])]
+    pub output: Option<PathBuf>,
+    /// Appends the answers to the end of the `--output` file instead of
+    /// replacing its contents, accumulating a log of every run.
+    #[clap(long, requires = "output")]
+    pub append: bool,
+    /// Solves only the specified part of the puzzle instead of both.
+    #[clap(long, value_enum)]
+    pub part: Option<Part>,
+    /// Prints answers in the specified format.
+    #[clap(long, value_enum, env = "AOC_FORMAT", default_value_t = Format::Table)]
+    pub format: Format,
+    /// Prints everything without colors, even to a terminal.
+    #[clap(long)]
+    pub no_color: bool,
+    /// Logs how long every traced phase takes to standard error; repeat for
+    /// more detail (e.g. `-vv`).
+    #[clap(long, short, action = ArgAction::Count)]
+    pub verbose: u8,
+    /// Reports failures in the specified format; either way, the program
+    /// exits with 2 if the input cannot be interpreted, 3 if it has no
+    /// answer, 4 if it cannot be read, 5 if it times out, 6 if its answers
+    /// are wrong (e.g. `--check` fails), 7 if it is slower than
+    /// `--baseline`, 64 if its arguments are invalid, and 1 otherwise.
+    #[clap(long, value_enum, value_name = "FORMAT", default_value_t = ErrorFormat::Text)]
+    pub errors: ErrorFormat,
+    /// Visualizes the puzzle in the terminal instead of printing answers, if
+    /// the puzzle has a visualization.
+    #[clap(long)]
+    pub visualize: bool,
+    /// Gives up on solving once the specified amount of time (e.g. "30s" or
+    /// "1m 30s") has passed, reporting a timeout instead.
+    #[clap(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub timeout: Option<Duration>,
+    /// Compares the answers against those recorded for the personal puzzle
+    /// input in the specified TOML file (by default, the "answers.toml" that
+    /// git ignores at the root of the repository), failing if any disagree.
+    #[clap(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        default_missing_value = DEFAULT_ANSWERS_PATH
+    )]
+    pub check: Option<PathBuf>,
+    /// Prints a script that completes the arguments of this program in the
+    /// specified shell instead of solving the puzzle.
+    #[clap(long, value_enum, value_name = "SHELL", exclusive = true)]
+    pub completions: Option<Shell>,
+    /// Writes the answers, along with how long they took to reach, to the
+    /// specified JSON file for a later `--baseline` to compare against.
+    #[clap(long, value_name = "FILE", conflicts_with_all = ["inputs", "watch"])]
+    pub save_results: Option<PathBuf>,
+    /// Compares the answers and how long they took to reach against the
+    /// results in the specified file, written by `--save-results`, failing
+    /// if any answer changed or solving slowed down too much.
+    #[clap(long, value_name = "FILE", conflicts_with_all = ["inputs", "watch"])]
+    pub baseline: Option<PathBuf>,
+    /// Fails `--baseline` if solving took more than the specified percentage
+    /// longer than it did in the baseline results.
+    #[clap(long, value_name = "PERCENT", requires = "baseline", default_value_t = DEFAULT_MAX_SLOWDOWN)]
+    pub max_slowdown: f64,
+    #[clap(flatten)]
+    pub flame: FlameArgs,
+    #[cfg(feature = "otel")]
+    #[clap(flatten)]
+    pub otel: OtelArgs,
+    #[cfg(feature = "profile")]
+    #[clap(flatten)]
+    pub profile: ProfileArgs,
+    /// When the program started, which answers are timed from.
+    #[clap(skip = Instant::now())]
+    started_at: Instant,
+}
+
+impl CommonArgs {
+    /// Returns an error because `--visualize` was specified for a puzzle
+    /// without a visualization, or `Ok(())` if it was not specified at all.
+    pub fn forbid_visualize(&self, day: u8) -> Result<()> {
+        if self.visualize {
+            bail!("Day {} has no visualization", day);
+        }
+
+        Ok(())
+    }
+
+    /// Runs `solve` under the `--timeout` if there is one, returning the
+    /// [ExitCode] of a program that finished the way `solve` did and
+    /// reporting any error in the format specified by `--errors`.
+    ///
+    /// Long-running loops give up on their own once [cancellation] says so,
+    /// and whatever error `solve` then returns is reported as [TimedOut]. If
+    /// `solve` is still running [TIMEOUT_GRACE_PERIOD] after the deadline,
+    /// the timeout is reported and the program exits without waiting for it.
+    pub fn run(&self, solve: impl FnOnce() -> Result<()>) -> ExitCode {
+        if self.no_color {
+            output::disable_color();
+        }
+
+        if let Some(timeout) = self.timeout {
+            cancellation::cancel_after(timeout);
+
+            let errors = self.errors;
+            thread::spawn(move || {
+                thread::sleep(timeout + TIMEOUT_GRACE_PERIOD);
+
+                error::report(&anyhow!(TimedOut(timeout)), errors);
+                process::exit(ErrorKind::Timeout.exit_code().into());
+            });
+        }
+
+        #[cfg(feature = "profile")]
+        let solve = || -> Result<()> {
+            let profile = self.profile.start()?;
+            solve()?;
+
+            match profile {
+                Some(profile) => profile.finish(),
+                None => Ok(()),
+            }
+        };
+
+        match solve() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => match self.timeout {
+                Some(timeout) if cancellation::is_cancelled() => {
+                    error::report(&error.context(TimedOut(timeout)), self.errors)
+                }
+                _ => error::report(&error, self.errors),
+            },
+        }
+    }
+
+    /// Returns the [InputSource] that the puzzle input should be read from:
+    /// the one specified by `--input`, or else "input.txt" within
+    /// `--input-dir` (see [input::locate_dir]), unless that file does not
+    /// exist and an input was embedded in this program with [input::embed].
+    ///
+    /// Failing both, "sample.txt" within `--input-dir` is read instead if
+    /// `--offline` was specified, which is announced on standard error.
+    pub fn input_source(&self) -> InputSource {
+        let (input_source, warning) = self.input_source_with_warning();
+        if let Some(warning) = warning {
+            output::print_warning(warning);
+        }
+
+        input_source
+    }
+
+    /// Returns the [InputSource] that the puzzle input should be read from,
+    /// like [CommonArgs::input_source], along with the warning that it would
+    /// announce instead of announcing it.
+    pub fn input_source_with_warning(&self) -> (InputSource, Option<String>) {
+        if let Some(input) = &self.input {
+            return (InputSource::from(input), None);
+        }
+
+        let input_dir = input::locate_dir(&self.input_dir);
+        let input_path = input_dir.join(INPUT_FILE_NAME);
+        if input_path.exists() {
+            return (InputSource::from(input_path), None);
+        }
+
+        if let Some(embedded_input) = input::embedded() {
+            return (InputSource::Embedded(embedded_input), None);
+        }
+
+        if self.offline {
+            let sample_path = input_dir.join(SAMPLE_FILE_NAME);
+            let warning = format!(
+                "\"{}\" does not exist, so these answers are for the sample input in \"{}\"",
+                input_path.display(),
+                sample_path.display()
+            );
+
+            return (InputSource::from(sample_path), Some(warning));
+        }
+
+        (InputSource::from(input_path), None)
+    }
+
+    /// Prints `table` like [CommonArgs::print], traces each of its answers,
+    /// and then compares them against those recorded for `day` if `--check`
+    /// was specified.
+    ///
+    /// The answers, and how long they took to reach since the program
+    /// started, are also saved if `--save-results` was specified and
+    /// compared against the `--baseline` if there is one.
+    pub fn print_answers(&self, day: u8, table: &Table) -> Result<()> {
+        let elapsed = self.started_at.elapsed();
+        self.print(table)?;
+
+        for (label, answer) in table.labeled_answers() {
+            tracing::info!(day, label, answer, "answer");
+        }
+
+        if let Some(answers_path) = &self.check {
+            answers::check(day, table, self.part, answers_path)?;
+        }
+
+        if let Some(results_path) = &self.save_results {
+            baseline::save(day, table, elapsed, results_path)?;
+        }
+
+        match &self.baseline {
+            Some(baseline_path) => {
+                baseline::compare(day, table, elapsed, baseline_path, self.max_slowdown)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `table` in the [Format] specified by `--format`, colored only
+    /// if it would be printed to standard output and color is enabled.
+    pub fn format_table(&self, table: &Table) -> String {
+        let is_colored = self.output.is_none() && output::is_color_enabled();
+
+        match self.format {
+            Format::Json => format!("{}\n", table.answers_json()),
+            Format::Plain => table
+                .answers()
+                .map(|answer| format!("{}\n", answer))
+                .collect::<String>(),
+            Format::Table => table.render(is_colored),
+        }
+    }
+
+    /// Prints `table` in the [Format] specified by `--format`, to the
+    /// `--output` file if there is one and to standard output otherwise.
+    pub fn print(&self, table: &Table) -> Result<()> {
+        let formatted_table = self.format_table(table);
+
+        let output_path = match &self.output {
+            Some(output_path) => output_path,
+            None => {
+                print!("{}", formatted_table);
+
+                return Ok(());
+            }
+        };
+
+        OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(self.append)
+            .truncate(!self.append)
+            .open(output_path)
+            .and_then(|mut output_file| output_file.write_all(formatted_table.as_bytes()))
+            .with_context(|| format!("Failed to write answers to \"{}\"", output_path.display()))
+    }
+
+    /// Starts logging traced phases to standard error if `--verbose` was
+    /// specified or `RUST_LOG` is set, filtered by `RUST_LOG` if it is set
+    /// and by how many times `--verbose` was specified otherwise, recording
+    /// folded-stack timing data if `--flame` was specified, and exporting
+    /// spans if `--otel` was specified, returning a [TracingGuard] that must
+    /// be kept alive until the program finishes.
+    pub fn start_tracing(&self) -> Result<TracingGuard> {
+        let is_logging = self.verbose > 0 || env::var_os(EnvFilter::DEFAULT_ENV).is_some();
+        if !is_logging && self.flame.flame.is_none() && !self.is_exporting_otel() {
+            return Ok(TracingGuard::default());
+        }
+
+        let log_layer = match is_logging {
+            false => None,
+            true => Some(
+                tracing_subscriber::fmt::layer()
+                    .with_span_events(FmtSpan::CLOSE)
+                    .with_writer(std::io::stderr)
+                    .with_filter(self.log_filter()?),
+            ),
+        };
+        let (flame_layer, flame_guard) = match self.flame.layer()? {
+            Some((flame_layer, flame_guard)) => (Some(flame_layer), Some(flame_guard)),
+            None => (None, None),
+        };
+        #[cfg(feature = "otel")]
+        let (otel_layer, otel_guard) = match self.otel.layer()? {
+            Some((otel_layer, otel_guard)) => (Some(otel_layer), Some(otel_guard)),
+            None => (None, None),
+        };
+
+        let subscriber = tracing_subscriber::registry()
+            .with(log_layer)
+            .with(flame_layer);
+        #[cfg(feature = "otel")]
+        let subscriber = subscriber.with(otel_layer);
+
+        subscriber.try_init().context("Failed to start tracing")?;
+
+        Ok(TracingGuard {
+            // Exported spans only form a single trace per run if every phase
+            // shares a parent.
+            #[cfg(feature = "otel")]
+            _root_span: otel_guard
+                .is_some()
+                .then(|| tracing::info_span!("run").entered()),
+            _flame_guard: flame_guard,
+            #[cfg(feature = "otel")]
+            _otel_guard: otel_guard,
+        })
+    }
+
+    /// Returns the [EnvFilter] read from `RUST_LOG`, or one that lets through
+    /// whatever is at least as important as the level chosen by `--verbose`
+    /// if `RUST_LOG` is not set.
+    fn log_filter(&self) -> Result<EnvFilter> {
+        let verbose_level = match self.verbose {
+            0 | 1 => LevelFilter::INFO,
+            2 => LevelFilter::DEBUG,
+            _ => LevelFilter::TRACE,
+        };
+
+        EnvFilter::builder()
+            .with_default_directive(verbose_level.into())
+            .from_env()
+            .with_context(|| format!("{} is not a valid filter", EnvFilter::DEFAULT_ENV))
+    }
+
+    /// Returns `true` if traced phases are being exported as OpenTelemetry
+    /// spans, which is only ever the case with the `otel` feature.
+    fn is_exporting_otel(&self) -> bool {
+        #[cfg(feature = "otel")]
+        return self.otel.otel;
+        #[cfg(not(feature = "otel"))]
+        return false;
+    }
+}
+
+/// Keeps tracing started by [CommonArgs::start_tracing] going until dropped,
+/// at which point any buffered timing data and spans are written out.
+#[derive(Default)]
+pub struct TracingGuard {
+    /// Span that every other span exported while this guard is alive falls
+    /// under, closed before the exporter shuts down.
+    #[cfg(feature = "otel")]
+    _root_span: Option<tracing::span::EnteredSpan>,
+    /// Flushes folded-stack timing data to the `--flame` file when dropped.
+    _flame_guard: Option<FlameGuard>,
+    /// Exports any spans that are still buffered when dropped.
+    #[cfg(feature = "otel")]
+    _otel_guard: Option<OtelGuard>,
+}
+
+/// Enumerates the formats in which answers can be printed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// JSON object listing every answer along with its label, for use by
+    /// other tools.
+    Json,
+    /// Only the answers, one per line, for use by scripts.
+    Plain,
+    /// Aligned table of the answers along with any intermediate values.
+    Table,
+}
+
+/// Parses command-line arguments like [Parser] does, except that invalid
+/// arguments exit with [USAGE_EXIT_CODE] rather than the 2 that clap exits
+/// with, which [ErrorKind::Parse] already stands for.
+///
+/// Every [Parser] is a [ParseArgs].
+pub trait ParseArgs: Parser {
+    /// Returns the command-line arguments that this program was run with,
+    /// like [Parser::parse].
+    fn parse_args() -> Self {
+        Self::parse_args_from(env::args_os())
+    }
+
+    /// Returns the command-line arguments in `args`, which start with the
+    /// name of the program, like [Parser::parse_from].
+    fn parse_args_from<I, T>(args: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        Self::try_parse_from(args).unwrap_or_else(|error| {
+            let _ = error.print();
+
+            // Help and version requests are reported as errors too, but not
+            // to standard error.
+            process::exit(match error.use_stderr() {
+                true => USAGE_EXIT_CODE.into(),
+                false => 0,
+            })
+        })
+    }
+}
+
+impl<P: Parser> ParseArgs for P {}
+
+/// Enumerates the parts of a puzzle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Part {
+    /// First part of the puzzle.
+    #[clap(name = "1")]
+    One,
+    /// Second part of the puzzle, unlocked by solving the first.
+    #[clap(name = "2")]
+    Two,
+}
+
+impl Part {
+    /// Returns `true` if this [Part] should be solved when `selected_part` is
+    /// the only part requested, or when every part is requested because
+    /// `selected_part` is [Option::None].
+    pub fn is_selected_by(&self, selected_part: Option<Part>) -> bool {
+        selected_part.is_none_or(|selected_part| selected_part == *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+    use std::iter;
+    use std::sync::Mutex;
+
+    /// Guards the environment variables read by [CommonArgs], which every
+    /// test in this module shares.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Returns the [CommonArgs] parsed from `args` with `AOC_FORMAT` and
+    /// `AOC_INPUT_DIR` set to `format` and `input_dir` respectively, or unset
+    /// if they are [Option::None].
+    fn parse_with_env(args: &[&str], format: Option<&str>, input_dir: Option<&str>) -> CommonArgs {
+        let _env_guard = ENV_LOCK.lock().unwrap();
+        env::remove_var("AOC_OFFLINE");
+
+        for (name, value) in [("AOC_FORMAT", format), ("AOC_INPUT_DIR", input_dir)] {
+            match value {
+                Some(value) => env::set_var(name, value),
+                None => env::remove_var(name),
+            }
+        }
+
+        let common_args =
+            CommonArgs::try_parse_from(iter::once("day-0").chain(args.iter().copied())).unwrap();
+
+        env::remove_var("AOC_FORMAT");
+        env::remove_var("AOC_INPUT_DIR");
+
+        common_args
+    }
+
+    #[test]
+    fn falls_back_to_defaults_without_flags_or_environment() {
+        let common_args = parse_with_env(&[], None, None);
+
+        assert_eq!(common_args.format, Format::Table);
+        assert_eq!(
+            common_args.input_source(),
+            InputSource::from("files/input.txt")
+        );
+    }
+
+    #[test]
+    fn prefers_environment_to_defaults() {
+        let common_args = parse_with_env(&[], Some("plain"), Some("/tmp/aoc"));
+
+        assert_eq!(common_args.format, Format::Plain);
+        assert_eq!(
+            common_args.input_source(),
+            InputSource::from("/tmp/aoc/input.txt")
+        );
+    }
+
+    #[test]
+    fn prefers_flags_to_environment() {
+        let common_args = parse_with_env(
+            &["--format", "table", "--input-dir", "inputs"],
+            Some("plain"),
+            Some("/tmp/aoc"),
+        );
+
+        assert_eq!(common_args.format, Format::Table);
+        assert_eq!(
+            common_args.input_source(),
+            InputSource::from("inputs/input.txt")
+        );
+    }
+
+    #[test]
+    fn appends_answers_to_output_file() {
+        let output_path = env::temp_dir().join(format!("aoc-output-{}.txt", process::id()));
+        let output = output_path.to_str().unwrap();
+        let table = Table::new().answer("Answer", 42).clone();
+
+        let common_args = parse_with_env(&["--format", "plain", "--output", output], None, None);
+        common_args.print(&table).unwrap();
+        common_args.print(&table).unwrap();
+        assert_eq!(fs::read_to_string(&output_path).unwrap(), "42\n");
+
+        let common_args = parse_with_env(
+            &["--format", "json", "--output", output, "--append"],
+            None,
+            None,
+        );
+        common_args.print(&table).unwrap();
+        assert_eq!(
+            fs::read_to_string(&output_path).unwrap(),
+            "42\n{\"answers\":[{\"answer\":\"42\",\"label\":\"Answer\"}]}\n"
+        );
+
+        fs::remove_file(&output_path).unwrap();
+    }
+
+    #[test]
+    fn falls_back_to_sample_when_offline() {
+        let common_args = parse_with_env(&["--offline"], None, Some("/tmp/aoc-missing"));
+
+        assert_eq!(
+            common_args.input_source(),
+            InputSource::from("/tmp/aoc-missing/sample.txt")
+        );
+    }
+
+    #[test]
+    fn prefers_input_to_input_dir() {
+        let common_args = parse_with_env(&["--input", "-"], None, Some("/tmp/aoc"));
+
+        assert_eq!(common_args.input_source(), InputSource::Stdin);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::error::{SlowedDown, WrongAnswer};
+use crate::output;
+use crate::table::Table;
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Answers that a day's puzzle gave, and how long they took to reach, as
+/// [save]d by `--save-results`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SavedResults {
+    /// Label and value of every answer, in order.
+    pub answers: Vec<(String, String)>,
+    /// Day of December whose puzzle was solved.
+    pub day: u8,
+    /// How long the answers took to reach.
+    pub elapsed: Duration,
+}
+
+/// How many percent longer than in its baseline results a solve may take
+/// before `--baseline` fails, unless `--max-slowdown` says otherwise.
+pub const DEFAULT_MAX_SLOWDOWN: f64 = 10.0;
+
+/// Writes the answers in `table` that `day` gave, along with how long they
+/// took to reach (`elapsed`), to the JSON file at `results_path`, for later
+/// runs to [compare] against.
+///
+/// Results are recorded as a single object, e.g.:
+///
+/// ```json
+/// {"answers":[{"answer":"7","label":"Part one"}],"day":1,"elapsed_secs":0.0042}
+/// ```
+pub fn save(day: u8, table: &Table, elapsed: Duration, results_path: &Path) -> Result<()> {
+    let results = json!({
+        "answers": table
+            .labeled_answers()
+            .map(|(label, answer)| json!({ "label": label, "answer": answer }))
+            .collect::<Vec<Value>>(),
+        "day": day,
+        "elapsed_secs": elapsed.as_secs_f64(),
+    });
+
+    fs::write(results_path, format!("{}\n", results))
+        .with_context(|| format!("Failed to write results to \"{}\"", results_path.display()))
+}
+
+/// Returns [Err] unless the answers in `table`, in order, match the ones that
+/// `day` gave in the results [save]d to `baseline_path`, and took no more
+/// than `max_slowdown` percent longer to reach than they did then.
+///
+/// Either way, how `elapsed` compares to the saved timing is printed to
+/// standard error.
+pub fn compare(
+    day: u8,
+    table: &Table,
+    elapsed: Duration,
+    baseline_path: &Path,
+    max_slowdown: f64,
+) -> Result<()> {
+    let baseline = load(baseline_path)?;
+    if baseline.day != day {
+        return Err(anyhow!(
+            "\"{}\" holds the results of day {}, not day {}",
+            baseline_path.display(),
+            baseline.day,
+            day
+        ));
+    }
+
+    let answers = table.labeled_answers().collect::<Vec<(&str, &str)>>();
+    let mut mismatches = vec![];
+
+    if answers.len() != baseline.answers.len() {
+        mismatches.push(format!(
+            "there are {} answers, not {}",
+            answers.len(),
+            baseline.answers.len()
+        ));
+    }
+
+    for ((label, answer), (_, baseline_answer)) in answers.iter().zip(&baseline.answers) {
+        if answer != baseline_answer {
+            mismatches.push(format!(
+                "{} is {:?}, not {:?}",
+                label, answer, baseline_answer
+            ));
+        }
+    }
+
+    if !mismatches.is_empty() {
+        return Err(anyhow!(WrongAnswer::new(format!(
+            "Day {} disagrees with \"{}\": {}",
+            day,
+            baseline_path.display(),
+            mismatches.join("; ")
+        ))));
+    }
+
+    let slowdown = percent_slower(elapsed, baseline.elapsed);
+    output::print_timing(format!(
+        "Took {:.3?}, {:+.1}% relative to the {:.3?} in \"{}\"",
+        elapsed,
+        slowdown,
+        baseline.elapsed,
+        baseline_path.display()
+    ));
+
+    if slowdown > max_slowdown {
+        return Err(anyhow!(SlowedDown::new(format!(
+            "Day {} took {:.1}% longer than in \"{}\", more than the {}% allowed",
+            day,
+            slowdown,
+            baseline_path.display(),
+            max_slowdown
+        ))));
+    }
+
+    Ok(())
+}
+
+/// Reads the results [save]d to `results_path`.
+pub fn load(results_path: &Path) -> Result<SavedResults> {
+    let serialized_results = fs::read_to_string(results_path)
+        .with_context(|| format!("Failed to read \"{}\"", results_path.display()))?;
+    let results = serde_json::from_str::<Value>(&serialized_results)
+        .with_context(|| format!("\"{}\" is not valid JSON", results_path.display()))?;
+
+    let day = results["day"]
+        .as_u64()
+        .and_then(|day| u8::try_from(day).ok())
+        .with_context(|| {
+            format!(
+                "\"{}\" does not say which day it is for",
+                results_path.display()
+            )
+        })?;
+    let answers = results["answers"]
+        .as_array()
+        .with_context(|| format!("\"{}\" has no answers", results_path.display()))?
+        .iter()
+        .map(|answer| {
+            Some((
+                answer["label"].as_str()?.to_string(),
+                answer["answer"].as_str()?.to_string(),
+            ))
+        })
+        .collect::<Option<Vec<(String, String)>>>()
+        .with_context(|| {
+            format!(
+                "\"{}\" has an answer that is not a labeled string",
+                results_path.display()
+            )
+        })?;
+    let elapsed = results["elapsed_secs"]
+        .as_f64()
+        .and_then(|elapsed_secs| Duration::try_from_secs_f64(elapsed_secs).ok())
+        .with_context(|| format!("\"{}\" has no valid timing", results_path.display()))?;
+
+    Ok(SavedResults {
+        answers,
+        day,
+        elapsed,
+    })
+}
+
+/// Returns how many percent longer `elapsed` is than `baseline_elapsed`, which
+/// is negative if it is shorter.
+fn percent_slower(elapsed: Duration, baseline_elapsed: Duration) -> f64 {
+    if baseline_elapsed.is_zero() {
+        return 0.0;
+    }
+
+    (elapsed.as_secs_f64() / baseline_elapsed.as_secs_f64() - 1.0) * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use std::env;
+    use std::process;
+
+    /// Returns a [Table] with a single answer, `answer`.
+    fn table_of(answer: &str) -> Table {
+        Table::new().answer("Part one", answer).clone()
+    }
+
+    #[test]
+    fn compares_answers_and_timings_to_saved_results() {
+        let baseline_path = env::temp_dir().join(format!("aoc-baseline-{}.json", process::id()));
+        save(
+            1,
+            &table_of("7"),
+            Duration::from_millis(100),
+            &baseline_path,
+        )
+        .unwrap();
+
+        assert_eq!(
+            load(&baseline_path).unwrap(),
+            SavedResults {
+                answers: vec![("Part one".to_string(), "7".to_string())],
+                day: 1,
+                elapsed: Duration::from_millis(100),
+            }
+        );
+
+        assert!(compare(
+            1,
+            &table_of("7"),
+            Duration::from_millis(105),
+            &baseline_path,
+            10.0
+        )
+        .is_ok());
+
+        let slower = compare(
+            1,
+            &table_of("7"),
+            Duration::from_millis(150),
+            &baseline_path,
+            10.0,
+        )
+        .unwrap_err();
+        assert!(slower.downcast_ref::<SlowedDown>().is_some());
+        assert_eq!(ErrorKind::of(&slower), ErrorKind::Slowdown);
+
+        let wrong = compare(
+            1,
+            &table_of("8"),
+            Duration::from_millis(50),
+            &baseline_path,
+            10.0,
+        )
+        .unwrap_err();
+        assert_eq!(ErrorKind::of(&wrong), ErrorKind::WrongAnswer);
+
+        assert!(compare(
+            2,
+            &table_of("7"),
+            Duration::from_millis(100),
+            &baseline_path,
+            10.0
+        )
+        .is_err());
+
+        fs::remove_file(&baseline_path).unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+use crate::args::Part;
+use crate::input;
+use crate::table::Table;
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Headings of the columns of every CSV written by [write_answers_csv].
+pub const CSV_HEADINGS: [&str; 6] = [
+    "input",
+    "part_one",
+    "part_one_ms",
+    "part_two",
+    "part_two_ms",
+    "error",
+];
+
+/// Solves the puzzle described by every file in the `inputs` directory with
+/// `solve`, writing one CSV row per file with the answers to each part and
+/// how long each took to `out`, or to standard output if there is no `out`.
+///
+/// `solve` returns a [Table] of the answers to a single [Part] of the puzzle
+/// described by an input. Parts that are not selected by `part` are left
+/// blank, as are parts that fail; the failures of a row are written to its
+/// last column instead of stopping the whole batch.
+pub fn write_answers_csv<F>(
+    inputs: &Path,
+    out: Option<&Path>,
+    part: Option<Part>,
+    mut solve: F,
+) -> Result<()>
+where
+    F: FnMut(&str, Part) -> Result<Table>,
+{
+    let writer: Box<dyn Write> = match out {
+        Some(out) => Box::new(
+            fs::File::create(out)
+                .with_context(|| format!("Failed to create \"{}\"", out.display()))?,
+        ),
+        None => Box::new(io::stdout()),
+    };
+    let mut csv_writer = csv::Writer::from_writer(writer);
+
+    csv_writer
+        .write_record(CSV_HEADINGS)
+        .context("Failed to write CSV headings")?;
+
+    for input_path in input_paths_in(inputs)? {
+        let input = input::load(input_path.as_path())?;
+        let mut record = vec![input_path.display().to_string()];
+        let mut errors = vec![];
+
+        for (label, each_part) in [("Part one", Part::One), ("Part two", Part::Two)] {
+            if !each_part.is_selected_by(part) {
+                record.extend([String::new(), String::new()]);
+                continue;
+            }
+
+            let start = Instant::now();
+            let answers = solve(&input, each_part);
+            let elapsed = start.elapsed();
+
+            match answers {
+                Ok(table) => record.push(table.answers().collect::<Vec<&str>>().join(" ")),
+                Err(error) => {
+                    record.push(String::new());
+                    errors.push(format!("{}: {:#}", label, error));
+                }
+            }
+            record.push(format!("{:.3}", elapsed.as_secs_f64() * 1000.0));
+        }
+
+        record.push(errors.join("; "));
+
+        csv_writer
+            .write_record(&record)
+            .with_context(|| format!("Failed to write answers to \"{}\"", input_path.display()))?;
+    }
+
+    csv_writer.flush().context("Failed to write answers CSV")?;
+
+    Ok(())
+}
+
+/// Returns the path of every file in the `inputs` directory, sorted so that
+/// batches are written in the same order every time.
+fn input_paths_in(inputs: &Path) -> Result<Vec<PathBuf>> {
+    let mut input_paths = fs::read_dir(inputs)
+        .with_context(|| format!("Failed to list \"{}\"", inputs.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<PathBuf>>>()
+        .with_context(|| format!("Failed to list \"{}\"", inputs.display()))?;
+
+    input_paths.retain(|input_path| input_path.is_file());
+    input_paths.sort();
+
+    Ok(input_paths)
+}
@@ -0,0 +1,85 @@
+use crate::args::{Part, INPUT_FILE_NAME, SAMPLE_FILE_NAME};
+use crate::input;
+use crate::registry::Registration;
+use crate::solver::Solver;
+
+use criterion::Criterion;
+use std::path::Path;
+
+/// Benchmarks each step of solving the puzzle input in `files_dir` with
+/// `solver` separately: parsing it, and then solving each part of the puzzle
+/// from the parsed input.
+///
+/// The personal puzzle input, "input.txt", is used if it exists, and the
+/// sample input, "sample.txt", otherwise.
+///
+/// ```no_run
+/// use aoc_common::bench;
+/// use criterion::{criterion_group, criterion_main, Criterion};
+/// # use anyhow::Result;
+/// # use aoc_common::answer::Answer;
+/// # use aoc_common::solver::Solver;
+/// # struct LanternfishSolver;
+/// # impl Solver for LanternfishSolver {
+/// #     type Parsed = ();
+/// #     fn day(&self) -> u8 { 6 }
+/// #     fn parse(&self, _: &str) -> Result<()> { Ok(()) }
+/// #     fn part_one(&self, _: &()) -> Result<Answer> { Ok(0u8.into()) }
+/// #     fn part_two(&self, _: &()) -> Result<Answer> { Ok(0u8.into()) }
+/// # }
+///
+/// fn bench_lanternfish_solver(c: &mut Criterion) {
+///     bench::bench_solver(c, &LanternfishSolver, concat!(env!("CARGO_MANIFEST_DIR"), "/files"));
+/// }
+///
+/// criterion_group!(benches, bench_lanternfish_solver);
+/// criterion_main!(benches);
+/// ```
+pub fn bench_solver<S: Solver>(c: &mut Criterion, solver: &S, files_dir: impl AsRef<Path>) {
+    let input = input_in(files_dir.as_ref());
+    let parsed = solver.parse(&input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day_{}", solver.day()));
+
+    group.bench_function("parse", |b| b.iter(|| solver.parse(&input).unwrap()));
+    group.bench_function("part_one", |b| b.iter(|| solver.part_one(&parsed).unwrap()));
+    group.bench_function("part_two", |b| b.iter(|| solver.part_two(&parsed).unwrap()));
+
+    group.finish();
+}
+
+/// Benchmarks solving each part of the puzzle input in `files_dir` with the
+/// solver of `registration`, parsing included, since a
+/// [Registry](crate::registry::Registry) only exposes whole solves.
+///
+/// The puzzle input is chosen the same way as by [bench_solver].
+pub fn bench_registration(
+    c: &mut Criterion,
+    registration: &Registration,
+    files_dir: impl AsRef<Path>,
+) {
+    let input = input_in(files_dir.as_ref());
+    let solver = &registration.solver;
+
+    let mut group = c.benchmark_group(format!("{}_day_{}", registration.year, solver.day()));
+
+    for (name, part) in [("part_one", Part::One), ("part_two", Part::Two)] {
+        group.bench_function(name, |b| {
+            b.iter(|| solver.answers_table(&input, Some(part)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+/// Returns the personal puzzle input in `files_dir` if there is one, and the
+/// sample input otherwise.
+fn input_in(files_dir: &Path) -> String {
+    let input_path = [INPUT_FILE_NAME, SAMPLE_FILE_NAME]
+        .iter()
+        .map(|file_name| files_dir.join(file_name))
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| panic!("\"{}\" holds no puzzle input", files_dir.display()));
+
+    input::read(input_path.as_path()).unwrap()
+}
@@ -0,0 +1,42 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Character representing bitwise one.
+const ONE: char = '1';
+
+/// Character representing bitwise zero.
+const ZERO: char = '0';
+
+/// Enumerates both possible values for a bit.
+///
+/// Each [Bit] is stored as the byte of its value, so casting it with `as`
+/// yields that value, and slices of them can be loaded as bytes at no cost.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[repr(u8)]
+pub enum Bit {
+    /// Enum representation of a bitwise one.
+    One = 1,
+    /// Enum representation of a bitwise zero.
+    Zero = 0,
+}
+
+impl Bit {
+    /// Attempts to interpret the given [char] as a [Bit], returning
+    /// [Option::None] if interpretation fails.
+    pub fn from(bit_char: char) -> Option<Bit> {
+        match bit_char {
+            ONE => Some(Bit::One),
+            ZERO => Some(Bit::Zero),
+            _ => None,
+        }
+    }
+
+    /// Returns `1` if this [Bit] is [Bit::One], or `0` otherwise.
+    pub fn value(&self) -> usize {
+        match self {
+            Bit::One => 1,
+            Bit::Zero => 0,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// Instant after which long-running solves should give up, if any.
+static DEADLINE: OnceLock<Instant> = OnceLock::new();
+
+/// Asks every long-running solve to give up once `timeout` has passed.
+///
+/// Only the first call has any effect; the deadline cannot be moved once set.
+pub fn cancel_after(timeout: Duration) {
+    let _ = DEADLINE.set(Instant::now() + timeout);
+}
+
+/// Returns `true` if the deadline set by [cancel_after] has passed, in which
+/// case long-running loops should stop and return whatever signals failure.
+pub fn is_cancelled() -> bool {
+    DEADLINE
+        .get()
+        .is_some_and(|deadline| Instant::now() >= *deadline)
+}
@@ -0,0 +1,23 @@
+use anyhow::{Context, Result};
+use clap::CommandFactory;
+use clap_complete::Shell;
+use std::io::{self, Write};
+
+/// Prints a script to standard output that completes the command-line
+/// arguments of the program described by `A` in `shell`.
+///
+/// Sourcing the script (e.g. `source <(day-4 --completions bash)`) makes
+/// every flag of the program discoverable by pressing tab.
+pub fn print<A: CommandFactory>(shell: Shell) -> Result<()> {
+    let mut command = A::command();
+    let program_name = command.get_name().to_string();
+
+    // Generating straight into standard output panics if it is closed early,
+    // so the script is generated in memory first.
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut command, program_name, &mut script);
+
+    io::stdout()
+        .write_all(&script)
+        .context("Failed to print completions")
+}
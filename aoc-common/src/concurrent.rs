@@ -0,0 +1,77 @@
+use crate::args::Part;
+use crate::table::Table;
+use crate::timing::Timings;
+
+use anyhow::Result;
+use std::panic;
+use std::thread;
+use tracing::{info_span, Span};
+
+/// Every part of a puzzle, in order, along with how it is labeled and the
+/// name of the thread that solves it.
+const PARTS: [(Part, &str, &str); 2] = [
+    (Part::One, "Part one", "part-one"),
+    (Part::Two, "Part two", "part-two"),
+];
+
+/// Solves every part of the puzzle selected by `part` at the same time, each
+/// on its own thread, returning a [Table] of the answers to each part in
+/// order followed by how long each earlier phase in `timings` (such as
+/// parsing) and then each part took.
+///
+/// `solve` returns a [Table] of the answers to a single [Part] of the puzzle.
+/// Both parts share it, so it should only read the input that was parsed
+/// for them once. If both parts fail, the error of the first is returned.
+pub fn solve_parts<F>(part: Option<Part>, mut timings: Timings, solve: F) -> Result<Table>
+where
+    F: Fn(Part) -> Result<Table> + Sync,
+{
+    let solve = &solve;
+    let parent_span = Span::current();
+    let solved_parts = thread::scope(|scope| {
+        let handles = PARTS
+            .into_iter()
+            .filter(|(each_part, _, _)| each_part.is_selected_by(part))
+            .map(|(each_part, label, thread_name)| {
+                let parent_span = parent_span.clone();
+
+                // Naming the thread tells the samples of each part apart in
+                // the flamegraphs of `--profile`.
+                thread::Builder::new()
+                    .name(thread_name.to_string())
+                    .spawn_scoped(scope, move || {
+                        // Spans entered on other threads have no parent unless
+                        // given one explicitly.
+                        let _part_span =
+                            info_span!(parent: &parent_span, "part", ?each_part).entered();
+                        let mut part_timings = Timings::new();
+                        let part_table = part_timings.time(label, || solve(each_part));
+
+                        (part_table, part_timings)
+                    })
+                    .expect("Failed to spawn the thread of a part")
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| match handle.join() {
+                Ok(solved_part) => solved_part,
+                Err(panic) => panic::resume_unwind(panic),
+            })
+            .collect::<Vec<(Result<Table>, Timings)>>()
+    });
+
+    let mut table = Table::new();
+    for (part_table, part_timings) in solved_parts {
+        if !table.is_empty() {
+            table.separator();
+        }
+        table.append(part_table?);
+        timings.append(part_timings);
+    }
+
+    table.separator().append(timings.table());
+
+    Ok(table)
+}
@@ -0,0 +1,229 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::AddAssign;
+
+/// Numeric type able to count the items in a [Counter].
+pub trait Count: Clone + Ord + for<'a> AddAssign<&'a Self> + From<u8> {}
+
+impl<C> Count for C where C: Clone + Ord + for<'a> AddAssign<&'a Self> + From<u8> {}
+
+/// Multiset that tracks how many times each distinct item has been added to
+/// it, counting with values of type `C`.
+#[derive(Clone, Debug)]
+pub struct Counter<T, C = usize> {
+    /// Number of times each distinct item has been added.
+    counts: HashMap<T, C>,
+}
+
+impl<T, C> Counter<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    /// Creates a new, empty [Counter].
+    pub fn new() -> Counter<T, C> {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Adds a single copy of `item` to this [Counter].
+    pub fn add(&mut self, item: T) {
+        self.add_count(item, &C::from(1));
+    }
+
+    /// Adds `count` copies of `item` to this [Counter].
+    ///
+    /// Adding zero copies of an item still makes it a distinct item of this
+    /// [Counter], just one with a count of zero, which
+    /// [Counter::least_common] then takes into account.
+    pub fn add_count(&mut self, item: T, count: &C) {
+        match self.counts.get_mut(&item) {
+            Some(item_count) => *item_count += count,
+            None => {
+                self.counts.insert(item, count.clone());
+            }
+        }
+    }
+
+    /// Returns how many copies of `item` this [Counter] holds.
+    pub fn count_of(&self, item: &T) -> C {
+        self.counts.get(item).cloned().unwrap_or_else(|| C::from(0))
+    }
+
+    /// Returns the relation between each distinct item of this [Counter] and
+    /// its count.
+    pub fn into_counts(self) -> HashMap<T, C> {
+        self.counts
+    }
+
+    /// Returns `true` if nothing has been added to this [Counter].
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns an [Iterator] over every distinct item of this [Counter] along
+    /// with its count, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, &C)> {
+        self.counts.iter()
+    }
+
+    /// Returns the least common item of this [Counter] along with its count,
+    /// or [Option::None] if it is empty or if several items tie for least
+    /// common.
+    pub fn least_common(&self) -> Option<(&T, &C)> {
+        self.extreme(Ordering::Less)
+    }
+
+    /// Returns how many distinct items this [Counter] holds.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns the most common item of this [Counter] along with its count,
+    /// or [Option::None] if it is empty or if several items tie for most
+    /// common.
+    pub fn most_common(&self) -> Option<(&T, &C)> {
+        self.extreme(Ordering::Greater)
+    }
+
+    /// Returns the `n` most common items of this [Counter] along with their
+    /// counts, from most to least common, or every item if it holds fewer
+    /// than `n`.
+    ///
+    /// Items with equal counts are returned in no particular order.
+    pub fn most_common_n(&self, n: usize) -> Vec<(&T, &C)> {
+        let mut counts = self.counts.iter().collect::<Vec<(&T, &C)>>();
+        counts.sort_by(|(_, count), (_, other_count)| other_count.cmp(count));
+        counts.truncate(n);
+
+        counts
+    }
+
+    /// Adds every copy of every item in `other` to this [Counter].
+    pub fn merge(&mut self, other: Counter<T, C>) {
+        for (item, count) in other.counts {
+            self.add_count(item, &count);
+        }
+    }
+
+    /// Returns the total count of every item in this [Counter].
+    pub fn total(&self) -> C {
+        let mut total = C::from(0);
+        for count in self.counts.values() {
+            total += count;
+        }
+
+        total
+    }
+
+    /// Returns the only item whose count compares as `ordering` to the count
+    /// of every other item, or [Option::None] if there is no such item.
+    fn extreme(&self, ordering: Ordering) -> Option<(&T, &C)> {
+        let mut extreme: Option<(&T, &C)> = None;
+        let mut is_tied = false;
+
+        for (item, count) in self.counts.iter() {
+            match extreme.map(|(_, extreme_count)| count.cmp(extreme_count)) {
+                Some(Ordering::Equal) => is_tied = true,
+                Some(comparison) if comparison != ordering => {}
+                _ => {
+                    extreme = Some((item, count));
+                    is_tied = false;
+                }
+            }
+        }
+
+        if is_tied {
+            return None;
+        }
+
+        extreme
+    }
+}
+
+impl<T, C> Default for Counter<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    fn default() -> Self {
+        Counter::new()
+    }
+}
+
+impl<T, C> Extend<T> for Counter<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T, C> FromIterator<T> for Counter<T, C>
+where
+    T: Eq + Hash,
+    C: Count,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+
+        counter
+    }
+}
+
+impl<T, C> PartialEq for Counter<T, C>
+where
+    T: Eq + Hash,
+    C: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_most_common_items_from_most_to_least_common() {
+        let counter = "mississippi".chars().collect::<Counter<char>>();
+        let most_common = counter.most_common_n(3);
+
+        assert_eq!(
+            most_common
+                .iter()
+                .map(|(_, count)| **count)
+                .collect::<Vec<usize>>(),
+            [4, 4, 2]
+        );
+        assert_eq!(most_common[2], (&'p', &2));
+        assert_eq!(counter.most_common_n(10).len(), 4);
+    }
+
+    #[test]
+    fn finds_extremes_unless_tied() {
+        let counter = "mississippi".chars().collect::<Counter<char>>();
+
+        assert_eq!(counter.least_common(), Some((&'m', &1)));
+        assert_eq!(counter.most_common(), None);
+    }
+
+    #[test]
+    fn merges_counts_of_shared_and_distinct_items() {
+        let mut counter = "aab".chars().collect::<Counter<char>>();
+        counter.merge("bc".chars().collect());
+
+        assert_eq!(counter.count_of(&'a'), 2);
+        assert_eq!(counter.count_of(&'b'), 2);
+        assert_eq!(counter.count_of(&'c'), 1);
+        assert_eq!(counter.total(), 5);
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::Error;
+use miette::{
+    Diagnostic, GraphicalReportHandler, LabeledSpan, NamedSource, SourceCode, SourceSpan,
+};
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+
+/// Error raised while interpreting a puzzle input that points at exactly
+/// where in the input things went wrong, so that it can be reported with the
+/// offending line, a highlight under the offending text, and a hint about
+/// what was expected instead.
+///
+/// Parsers that work a line at a time raise an [InputDiagnostic] against the
+/// line, and whatever splits the input into lines moves it
+/// [within](InputDiagnostic::within) the whole input:
+///
+/// ```
+/// use aoc_common::diagnostic::{self, InputDiagnostic};
+///
+/// let input = "0,9 -> 5,9\n8,0 => 0,8";
+/// let line = input.lines().nth(1).unwrap();
+///
+/// let diagnostic = InputDiagnostic::new("Missing arrow", line, 4..6)
+///     .with_help("Points are separated by \"->\"")
+///     .within(input, diagnostic::span_of(line, input).start);
+///
+/// assert_eq!(diagnostic.span(), 15..17);
+/// assert_eq!(&input[diagnostic.span()], "=>");
+/// ```
+#[derive(Clone, Debug)]
+pub struct InputDiagnostic {
+    /// Hint about what was expected instead, if there is one.
+    help: Option<String>,
+    /// Short description of what is wrong.
+    message: String,
+    /// Text that `span` points into.
+    source_code: NamedSource<String>,
+    /// Byte offsets of the offending text within `source_code`.
+    span: Range<usize>,
+}
+
+impl InputDiagnostic {
+    /// Creates an [InputDiagnostic] that describes what is wrong with the
+    /// text at the `span` of byte offsets within `source` with `message`.
+    pub fn new(message: impl Display, source: &str, span: Range<usize>) -> InputDiagnostic {
+        InputDiagnostic {
+            help: None,
+            message: message.to_string(),
+            source_code: NamedSource::new("input", source.to_string()),
+            span,
+        }
+    }
+
+    /// Returns this [InputDiagnostic] with a `help` message that says what was
+    /// expected instead.
+    pub fn with_help(self, help: impl Display) -> InputDiagnostic {
+        InputDiagnostic {
+            help: Some(help.to_string()),
+            ..self
+        }
+    }
+
+    /// Returns this [InputDiagnostic], raised against text that starts
+    /// `offset` bytes into `input`, pointing into `input` instead.
+    pub fn within(self, input: &str, offset: usize) -> InputDiagnostic {
+        InputDiagnostic {
+            source_code: NamedSource::new("input", input.to_string()),
+            span: (self.span.start + offset)..(self.span.end + offset),
+            ..self
+        }
+    }
+
+    /// Returns the byte offsets of the offending text within the source that
+    /// this [InputDiagnostic] points into.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns this [InputDiagnostic] drawn as it would be by a compiler: the
+    /// offending lines of input, the offending text underlined, and the help
+    /// message, if there is one.
+    pub fn render(&self) -> String {
+        let mut rendered = String::new();
+        GraphicalReportHandler::new()
+            .render_report(&mut rendered, self)
+            .expect("Rendering a diagnostic into a String cannot fail");
+
+        rendered
+    }
+}
+
+impl Diagnostic for InputDiagnostic {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display + 'a>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(std::iter::once(LabeledSpan::new_with_span(
+            Some("here".to_string()),
+            SourceSpan::from(self.span.clone()),
+        ))))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source_code)
+    }
+}
+
+impl Display for InputDiagnostic {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for InputDiagnostic {}
+
+/// Returns the first [InputDiagnostic], boxed or not, in the chain of errors
+/// that caused `error`, if there is one.
+pub fn find(error: &Error) -> Option<&InputDiagnostic> {
+    error.chain().find_map(|cause| {
+        cause.downcast_ref::<InputDiagnostic>().or_else(|| {
+            cause
+                .downcast_ref::<Box<InputDiagnostic>>()
+                .map(|input_diagnostic| &**input_diagnostic)
+        })
+    })
+}
+
+/// Returns `error` with any [InputDiagnostic] that it is made of, raised
+/// against text that starts `offset` bytes into `input`, pointing into
+/// `input` instead.
+///
+/// Errors of any other type are returned unchanged.
+pub fn relocate(error: Error, input: &str, offset: usize) -> Error {
+    match error.downcast::<InputDiagnostic>() {
+        Ok(diagnostic) => Error::new(diagnostic.within(input, offset)),
+        Err(error) => error,
+    }
+}
+
+/// Returns the byte offsets of `part` within `whole`, which `part` must have
+/// been sliced from, e.g. by [str::lines] or [str::split].
+///
+/// ```
+/// use aoc_common::diagnostic;
+///
+/// let input = "00100\n11110";
+///
+/// assert_eq!(diagnostic::span_of(input.lines().nth(1).unwrap(), input), 6..11);
+/// ```
+pub fn span_of(part: &str, whole: &str) -> Range<usize> {
+    let start = (part.as_ptr() as usize)
+        .checked_sub(whole.as_ptr() as usize)
+        .filter(|start| start + part.len() <= whole.len())
+        .expect("part was not sliced from whole");
+
+    start..(start + part.len())
+}
@@ -0,0 +1,251 @@
+use crate::diagnostic::{self, InputDiagnostic};
+use crate::output;
+
+use anyhow::Error;
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::num::{ParseFloatError, ParseIntError};
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Code that a program exits with when its command-line arguments are
+/// invalid, as `EX_USAGE` from `sysexits.h`, which no [ErrorKind] shares; see
+/// [ParseArgs](crate::args::ParseArgs).
+pub const USAGE_EXIT_CODE: u8 = 64;
+
+/// Enumerates the categories of failure that a program can report, each of
+/// which exits with its own code so that wrapping scripts can tell them apart.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// Puzzle input could not be read.
+    Io,
+    /// Puzzle input was understood, but has no answer.
+    NoSolution,
+    /// Failure that fits none of the other categories.
+    Other,
+    /// Puzzle input could not be interpreted.
+    Parse,
+    /// Solving took longer than it did in the results that it was compared
+    /// against by `--baseline`, by more than `--max-slowdown` allowed.
+    Slowdown,
+    /// Solving took longer than `--timeout` allowed.
+    Timeout,
+    /// Answers disagree with the ones that they were compared against, such
+    /// as by `--check`.
+    WrongAnswer,
+}
+
+impl ErrorKind {
+    /// Returns the [ErrorKind] of `error`, judged by the [TimedOut],
+    /// [WrongAnswer], [SlowedDown], [NoSolution], and [InvalidInput] contexts
+    /// and the I/O, [InputDiagnostic], and number parsing errors within it.
+    ///
+    /// ```
+    /// use anyhow::{anyhow, Context};
+    /// use aoc_common::error::{ErrorKind, InvalidInput, NoSolution, TimedOut};
+    /// use std::time::Duration;
+    ///
+    /// let invalid: anyhow::Result<u8> = Err(anyhow!("Oops")).context(InvalidInput);
+    /// let unsolvable = None::<u8>.context(NoSolution::new("There was no winner!"));
+    /// let unparseable = "x".parse::<u8>().context("\"x\" is not a valid number");
+    ///
+    /// assert_eq!(ErrorKind::of(&invalid.unwrap_err()), ErrorKind::Parse);
+    /// assert_eq!(ErrorKind::of(&unsolvable.unwrap_err()), ErrorKind::NoSolution);
+    /// assert_eq!(ErrorKind::of(&unparseable.unwrap_err()), ErrorKind::Parse);
+    /// assert_eq!(ErrorKind::of(&anyhow!(TimedOut(Duration::from_secs(30)))), ErrorKind::Timeout);
+    /// assert_eq!(ErrorKind::of(&anyhow!("Oops")), ErrorKind::Other);
+    /// ```
+    pub fn of(error: &Error) -> ErrorKind {
+        if error.downcast_ref::<TimedOut>().is_some() {
+            return ErrorKind::Timeout;
+        }
+
+        if error.downcast_ref::<WrongAnswer>().is_some() {
+            return ErrorKind::WrongAnswer;
+        }
+
+        if error.downcast_ref::<SlowedDown>().is_some() {
+            return ErrorKind::Slowdown;
+        }
+
+        if error.downcast_ref::<NoSolution>().is_some() {
+            return ErrorKind::NoSolution;
+        }
+
+        if error.downcast_ref::<InvalidInput>().is_some()
+            || error.chain().any(|cause| {
+                cause.is::<InputDiagnostic>()
+                    || cause.is::<ParseIntError>()
+                    || cause.is::<ParseFloatError>()
+            })
+        {
+            return ErrorKind::Parse;
+        }
+
+        if error.chain().any(|cause| cause.is::<io::Error>()) {
+            return ErrorKind::Io;
+        }
+
+        ErrorKind::Other
+    }
+
+    /// Returns the code that a program exits with after failing in this
+    /// way, which is never [USAGE_EXIT_CODE].
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            ErrorKind::Other => 1,
+            ErrorKind::Parse => 2,
+            ErrorKind::NoSolution => 3,
+            ErrorKind::Io => 4,
+            ErrorKind::Timeout => 5,
+            ErrorKind::WrongAnswer => 6,
+            ErrorKind::Slowdown => 7,
+        }
+    }
+
+    /// Returns the name of this [ErrorKind] in machine-readable error
+    /// reports.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorKind::Io => "io",
+            ErrorKind::NoSolution => "no_solution",
+            ErrorKind::Other => "other",
+            ErrorKind::Parse => "parse",
+            ErrorKind::Slowdown => "slowdown",
+            ErrorKind::Timeout => "timeout",
+            ErrorKind::WrongAnswer => "wrong_answer",
+        }
+    }
+}
+
+/// Enumerates the formats in which a failure can be reported.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ErrorFormat {
+    /// Single-line JSON object with the kind of failure, its exit code, its
+    /// message, and the chain of errors that caused it.
+    Json,
+    /// Human-readable message followed by the chain of errors that caused it,
+    /// or by the offending line of input if an [InputDiagnostic] caused it.
+    Text,
+}
+
+/// Context attached to errors raised while interpreting a puzzle input, which
+/// categorizes them as [ErrorKind::Parse] failures.
+#[derive(Clone, Copy, Debug)]
+pub struct InvalidInput;
+
+impl Display for InvalidInput {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Failed to interpret the puzzle input")
+    }
+}
+
+/// Context attached to errors raised when a well-formed puzzle input has no
+/// answer (e.g. when no board ever wins a game of bingo), which categorizes
+/// them as [ErrorKind::NoSolution] failures.
+#[derive(Clone, Debug)]
+pub struct NoSolution(String);
+
+impl NoSolution {
+    /// Creates a [NoSolution] that explains why there is no answer with
+    /// `message`.
+    pub fn new(message: impl Display) -> NoSolution {
+        NoSolution(message.to_string())
+    }
+}
+
+impl Display for NoSolution {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Error raised when computed answers disagree with the ones that they were
+/// compared against, such as by `--check` or `--shuffle`, which categorizes
+/// it as an [ErrorKind::WrongAnswer] failure.
+#[derive(Clone, Debug)]
+pub struct WrongAnswer(String);
+
+impl WrongAnswer {
+    /// Creates a [WrongAnswer] that describes the disagreement with
+    /// `message`.
+    pub fn new(message: impl Display) -> WrongAnswer {
+        WrongAnswer(message.to_string())
+    }
+}
+
+impl Display for WrongAnswer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Error raised when solving took too much longer than it did in the results
+/// that it was compared against by `--baseline`, which categorizes it as an
+/// [ErrorKind::Slowdown] failure.
+#[derive(Clone, Debug)]
+pub struct SlowedDown(String);
+
+impl SlowedDown {
+    /// Creates a [SlowedDown] that describes the slowdown with `message`.
+    pub fn new(message: impl Display) -> SlowedDown {
+        SlowedDown(message.to_string())
+    }
+}
+
+impl Display for SlowedDown {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Error raised in place of whatever a solve failed with after running for
+/// longer than `--timeout` allowed, which categorizes it as an
+/// [ErrorKind::Timeout] failure.
+#[derive(Clone, Copy, Debug)]
+pub struct TimedOut(pub Duration);
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Timed out after {}", humantime::format_duration(self.0))
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Returns `error` as the single-line JSON object that [ErrorFormat::Json]
+/// reports it with.
+pub fn json_of(error: &Error) -> Value {
+    let error_kind = ErrorKind::of(error);
+
+    json!({
+        "kind": error_kind.name(),
+        "exit_code": error_kind.exit_code(),
+        "message": error.to_string(),
+        "causes": error
+            .chain()
+            .skip(1)
+            .map(|cause| cause.to_string())
+            .collect::<Vec<String>>(),
+    })
+}
+
+/// Prints `error` to standard error in the specified `error_format`,
+/// returning the [ExitCode] matching its [ErrorKind].
+pub fn report(error: &Error, error_format: ErrorFormat) -> ExitCode {
+    let error_kind = ErrorKind::of(error);
+
+    match error_format {
+        ErrorFormat::Json => eprintln!("{}", json_of(error)),
+        ErrorFormat::Text => match diagnostic::find(error) {
+            Some(input_diagnostic) => {
+                output::print_error(format!("{}\n\n{}", error, input_diagnostic.render()))
+            }
+            None => output::print_error(format!("{:?}", error)),
+        },
+    }
+
+    ExitCode::from(error_kind.exit_code())
+}
@@ -0,0 +1,52 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use tracing::Subscriber;
+use tracing_flame::{FlameLayer, FlushGuard};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Keeps folded-stack timing data flowing to its file until dropped, at which
+/// point any buffered data is flushed.
+pub type FlameGuard = FlushGuard<BufWriter<File>>;
+
+/// Records folded-stack timing data for every traced phase of a program to a
+/// file.
+pub type FileFlameLayer<S> = FlameLayer<S, BufWriter<File>>;
+
+/// Command-line arguments that control the recording of folded-stack timing
+/// data for the traced phases of a program.
+#[derive(Debug, Parser)]
+pub struct FlameArgs {
+    /// Writes folded-stack timing data for every traced phase (e.g. parsing
+    /// and solving) to the specified file, for use with flamegraph tools like
+    /// inferno.
+    #[clap(long, value_name = "FILE")]
+    pub flame: Option<PathBuf>,
+}
+
+impl FlameArgs {
+    /// Returns a [FileFlameLayer] that records folded-stack timing data to the
+    /// `--flame` file, along with the [FlameGuard] that must be kept alive
+    /// until the program finishes, returning [Option::None] if no `--flame`
+    /// file was specified.
+    pub fn layer<S>(&self) -> Result<Option<(FileFlameLayer<S>, FlameGuard)>>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        let flame_file_path = match &self.flame {
+            Some(flame_file_path) => flame_file_path,
+            None => return Ok(None),
+        };
+
+        let flame_layer_and_guard = FlameLayer::with_file(flame_file_path).with_context(|| {
+            format!(
+                "Failed to create file at path \"{}\"",
+                flame_file_path.display()
+            )
+        })?;
+
+        Ok(Some(flame_layer_and_guard))
+    }
+}
@@ -0,0 +1,416 @@
+use core::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a point in space.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Coordinate {
+    /// X-component of this [Coordinate].
+    pub x: i32,
+    /// Y-component of this [Coordinate].
+    pub y: i32,
+}
+
+/// Represents a displacement in space, such as the difference between two
+/// instances of [Coordinate].
+pub type Vector = Coordinate;
+
+impl Coordinate {
+    /// Returns a [Vector] whose components are the signs (`-1`, `0`, or `1`)
+    /// of the respective components of this [Coordinate].
+    pub fn signum(&self) -> Vector {
+        Vector {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+}
+
+/// Displacement between two instances of [Coordinate], widened to 64 bits so
+/// that it cannot overflow no matter how far apart they are.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WideVector {
+    /// X-component of this [WideVector].
+    pub x: i64,
+    /// Y-component of this [WideVector].
+    pub y: i64,
+}
+
+impl WideVector {
+    /// Returns the [WideVector] pointing from `from` to `to`.
+    pub fn between(from: Coordinate, to: Coordinate) -> WideVector {
+        WideVector {
+            x: to.x as i64 - from.x as i64,
+            y: to.y as i64 - from.y as i64,
+        }
+    }
+
+    /// Returns the Z-component of the cross product of this [WideVector] and
+    /// `other`, widened so that it cannot overflow.
+    pub fn cross(&self, other: &WideVector) -> i128 {
+        self.x as i128 * other.y as i128 - self.y as i128 * other.x as i128
+    }
+
+    /// Returns the dot product of this [WideVector] and `other`, widened so
+    /// that it cannot overflow.
+    pub fn dot(&self, other: &WideVector) -> i128 {
+        self.x as i128 * other.x as i128 + self.y as i128 * other.y as i128
+    }
+
+    /// Returns the Euclidean length of this [WideVector].
+    #[cfg(feature = "std")]
+    pub fn length(&self) -> f64 {
+        (self.x as f64).hypot(self.y as f64)
+    }
+
+    /// Returns a [Vector] whose components are the signs (`-1`, `0`, or `1`)
+    /// of the respective components of this [WideVector].
+    pub fn signum(&self) -> Vector {
+        Vector {
+            x: self.x.signum() as i32,
+            y: self.y.signum() as i32,
+        }
+    }
+}
+
+impl From<Vector> for WideVector {
+    fn from(vector: Vector) -> Self {
+        WideVector {
+            x: vector.x as i64,
+            y: vector.y as i64,
+        }
+    }
+}
+
+impl Add<Vector> for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, vector: Vector) -> Self::Output {
+        Coordinate {
+            x: self.x + vector.x,
+            y: self.y + vector.y,
+        }
+    }
+}
+
+impl Mul<i32> for Coordinate {
+    type Output = Coordinate;
+
+    fn mul(self, scalar: i32) -> Self::Output {
+        Coordinate {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+impl Neg for Coordinate {
+    type Output = Coordinate;
+
+    fn neg(self) -> Self::Output {
+        Coordinate {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Sub for Coordinate {
+    type Output = Vector;
+
+    fn sub(self, other: Coordinate) -> Self::Output {
+        Vector {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl fmt::Debug for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self, f)
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Returns the Euclidean distance between `a` and `b`.
+#[cfg(feature = "std")]
+pub fn distance_between(a: Coordinate, b: Coordinate) -> f64 {
+    WideVector::between(a, b).length()
+}
+
+/// Returns the Euclidean distance between `point` and the closest point on
+/// the line segment going from `segment_start` to `segment_end`.
+#[cfg(feature = "std")]
+pub fn distance_to_segment(
+    point: Coordinate,
+    segment_start: Coordinate,
+    segment_end: Coordinate,
+) -> f64 {
+    let segment = WideVector::between(segment_start, segment_end);
+    let offset = WideVector::between(segment_start, point);
+
+    // Project `point` onto the segment to find out whether its closest point
+    // is one of the ends or somewhere in between.
+    let projection = offset.dot(&segment);
+    if projection <= 0 {
+        return distance_between(point, segment_start);
+    }
+    if projection >= segment.dot(&segment) {
+        return distance_between(point, segment_end);
+    }
+
+    (offset.cross(&segment) as f64).abs() / segment.length()
+}
+
+/// Returns the Manhattan (taxicab) distance between `a` and `b`.
+pub fn manhattan_distance_between(a: Coordinate, b: Coordinate) -> u64 {
+    let delta = WideVector::between(a, b);
+
+    delta.x.unsigned_abs() + delta.y.unsigned_abs()
+}
+
+/// Axis-aligned rectangle covering every [Coordinate] between two corners,
+/// inclusive.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Rectangle {
+    /// Corner of this [Rectangle] with the largest components.
+    pub max: Coordinate,
+    /// Corner of this [Rectangle] with the smallest components.
+    pub min: Coordinate,
+}
+
+impl Rectangle {
+    /// Creates the smallest [Rectangle] that covers both `a` and `b`.
+    pub fn spanning(a: Coordinate, b: Coordinate) -> Rectangle {
+        Rectangle {
+            max: Coordinate {
+                x: a.x.max(b.x),
+                y: a.y.max(b.y),
+            },
+            min: Coordinate {
+                x: a.x.min(b.x),
+                y: a.y.min(b.y),
+            },
+        }
+    }
+
+    /// Returns the portion of the line segment going from `start` to `end`
+    /// that lies within this [Rectangle], or [Option::None] if no part of it
+    /// does.
+    ///
+    /// Uses Cohen–Sutherland clipping, which yields whole-numbered endpoints
+    /// for horizontal, vertical, and 45° diagonal segments; other segments are
+    /// returned unclipped if they are not entirely inside or outside of this
+    /// [Rectangle].
+    pub fn clip(&self, start: Coordinate, end: Coordinate) -> Option<(Coordinate, Coordinate)> {
+        let delta = WideVector::between(start, end);
+        let is_lattice_stepped = delta.x == 0 || delta.y == 0 || delta.x.abs() == delta.y.abs();
+        let step = delta.signum();
+        let (mut start, mut end) = (start, end);
+
+        loop {
+            let (start_outcode, end_outcode) = (self.outcode_of(start), self.outcode_of(end));
+
+            if start_outcode | end_outcode == outcode::INSIDE {
+                return Some((start, end));
+            }
+            if start_outcode & end_outcode != outcode::INSIDE {
+                return None;
+            }
+            if !is_lattice_stepped {
+                return Some((start, end));
+            }
+
+            // Slide whichever end is outside towards the other end until it
+            // reaches the edge it is beyond; the opposite end is never beyond
+            // that same edge, so the segment always steps along that axis.
+            if start_outcode != outcode::INSIDE {
+                start = self.slide_onto_edge(start, step, start_outcode);
+            } else {
+                end = self.slide_onto_edge(end, -step, end_outcode);
+            }
+        }
+    }
+
+    /// Returns `true` if this [Rectangle] covers `coordinate`.
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        self.outcode_of(coordinate) == outcode::INSIDE
+    }
+
+    /// Returns the Cohen–Sutherland outcode of `coordinate`, a bit set of
+    /// every edge of this [Rectangle] that `coordinate` lies beyond.
+    fn outcode_of(&self, coordinate: Coordinate) -> u8 {
+        let mut code = outcode::INSIDE;
+
+        if coordinate.x < self.min.x {
+            code |= outcode::BEYOND_MIN_X;
+        } else if coordinate.x > self.max.x {
+            code |= outcode::BEYOND_MAX_X;
+        }
+
+        if coordinate.y < self.min.y {
+            code |= outcode::BEYOND_MIN_Y;
+        } else if coordinate.y > self.max.y {
+            code |= outcode::BEYOND_MAX_Y;
+        }
+
+        code
+    }
+
+    /// Moves `coordinate` along the unit `step` until it reaches one of the
+    /// edges of this [Rectangle] flagged in its `code`.
+    fn slide_onto_edge(&self, coordinate: Coordinate, step: Vector, code: u8) -> Coordinate {
+        let steps = if code & outcode::BEYOND_MIN_X != 0 {
+            self.min.x as i64 - coordinate.x as i64
+        } else if code & outcode::BEYOND_MAX_X != 0 {
+            coordinate.x as i64 - self.max.x as i64
+        } else if code & outcode::BEYOND_MIN_Y != 0 {
+            self.min.y as i64 - coordinate.y as i64
+        } else {
+            coordinate.y as i64 - self.max.y as i64
+        };
+
+        Coordinate {
+            x: (coordinate.x as i64 + steps * step.x as i64) as i32,
+            y: (coordinate.y as i64 + steps * step.y as i64) as i32,
+        }
+    }
+}
+
+/// Module used to namespace the bits of Cohen–Sutherland outcodes.
+mod outcode {
+    /// Outcode of a coordinate within a [super::Rectangle].
+    pub const INSIDE: u8 = 0;
+
+    /// Bit set when a coordinate lies beyond the minimum X edge.
+    pub const BEYOND_MIN_X: u8 = 0b0001;
+
+    /// Bit set when a coordinate lies beyond the maximum X edge.
+    pub const BEYOND_MAX_X: u8 = 0b0010;
+
+    /// Bit set when a coordinate lies beyond the minimum Y edge.
+    pub const BEYOND_MIN_Y: u8 = 0b0100;
+
+    /// Bit set when a coordinate lies beyond the maximum Y edge.
+    pub const BEYOND_MAX_Y: u8 = 0b1000;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shorthand for creating a [Coordinate] at `(x, y)`.
+    fn at(x: i32, y: i32) -> Coordinate {
+        Coordinate { x, y }
+    }
+
+    #[test]
+    fn clips_segments_to_rectangles() {
+        let rectangle = Rectangle::spanning(at(0, 0), at(4, 4));
+
+        // Fully inside.
+        assert_eq!(
+            rectangle.clip(at(1, 1), at(3, 2)),
+            Some((at(1, 1), at(3, 2)))
+        );
+        // Fully outside, beyond a single edge.
+        assert_eq!(rectangle.clip(at(5, 0), at(9, 4)), None);
+        // Fully outside, beyond different edges, passing by a corner.
+        assert_eq!(rectangle.clip(at(-2, 3), at(3, 8)), None);
+        // Crossing one edge.
+        assert_eq!(
+            rectangle.clip(at(2, 2), at(8, 2)),
+            Some((at(2, 2), at(4, 2)))
+        );
+        assert_eq!(
+            rectangle.clip(at(2, -3), at(2, 1)),
+            Some((at(2, 0), at(2, 1)))
+        );
+        // Crossing two edges.
+        assert_eq!(
+            rectangle.clip(at(-3, 1), at(9, 1)),
+            Some((at(0, 1), at(4, 1)))
+        );
+        assert_eq!(
+            rectangle.clip(at(-1, -1), at(6, 6)),
+            Some((at(0, 0), at(4, 4)))
+        );
+        assert_eq!(
+            rectangle.clip(at(6, -1), at(-1, 6)),
+            Some((at(4, 1), at(1, 4)))
+        );
+        // Only touching a corner.
+        assert_eq!(
+            rectangle.clip(at(-2, 2), at(2, 6)),
+            Some((at(0, 4), at(0, 4)))
+        );
+    }
+
+    #[test]
+    fn leaves_segments_that_would_be_clipped_off_the_lattice_unclipped() {
+        let rectangle = Rectangle::spanning(at(0, 0), at(4, 4));
+
+        // Clipping would end this segment at (0, 1.5) and (4, 3.5).
+        assert_eq!(
+            rectangle.clip(at(-1, 1), at(5, 4)),
+            Some((at(-1, 1), at(5, 4)))
+        );
+        assert_eq!(
+            rectangle.clip(at(1, 1), at(3, 2)),
+            Some((at(1, 1), at(3, 2)))
+        );
+        assert_eq!(rectangle.clip(at(5, 0), at(7, 3)), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn measures_distance_to_the_closest_point_of_segments() {
+        let (start, end) = (Coordinate { x: 0, y: 0 }, Coordinate { x: 4, y: 0 });
+
+        // Projects before the start of the segment.
+        assert_eq!(
+            distance_to_segment(Coordinate { x: -3, y: 4 }, start, end),
+            5.0
+        );
+        // Projects onto the segment.
+        assert_eq!(
+            distance_to_segment(Coordinate { x: 2, y: 3 }, start, end),
+            3.0
+        );
+        assert_eq!(
+            distance_to_segment(Coordinate { x: 3, y: 0 }, start, end),
+            0.0
+        );
+        // Projects past the end of the segment.
+        assert_eq!(
+            distance_to_segment(Coordinate { x: 7, y: -4 }, start, end),
+            5.0
+        );
+        // Projects onto a diagonal segment.
+        let diagonal_distance =
+            distance_to_segment(Coordinate { x: 0, y: 4 }, start, Coordinate { x: 4, y: 4 });
+        assert!((diagonal_distance - 8f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn measures_distance_to_zero_length_segments_as_to_a_point() {
+        let point = Coordinate { x: 1, y: 1 };
+
+        assert_eq!(
+            distance_to_segment(Coordinate { x: 4, y: 5 }, point, point),
+            5.0
+        );
+        assert_eq!(distance_to_segment(point, point, point), 0.0);
+    }
+}
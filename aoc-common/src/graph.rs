@@ -0,0 +1,265 @@
+use crate::pathfinding;
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Graph whose nodes are labeled by values of type `N` and whose edges are
+/// stored as adjacency lists of node indices.
+///
+/// Nodes are numbered in the order that they are added, starting from `0`.
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    /// Indices of the nodes adjacent to each node, indexed by node.
+    adjacency_lists: Vec<Vec<usize>>,
+    /// Index of the node with each label.
+    indices: HashMap<N, usize>,
+    /// Label of each node, indexed by node.
+    labels: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    /// Creates a new [Graph] without any nodes.
+    pub fn new() -> Graph<N> {
+        Graph {
+            adjacency_lists: vec![],
+            indices: HashMap::new(),
+            labels: vec![],
+        }
+    }
+
+    /// Adds a one-way edge going from the node labeled `from` to the node
+    /// labeled `to`, adding either node if it is not already in this [Graph].
+    pub fn add_directed_edge(&mut self, from: N, to: N) {
+        let from_index = self.add_node(from);
+        let to_index = self.add_node(to);
+
+        if !self.adjacency_lists[from_index].contains(&to_index) {
+            self.adjacency_lists[from_index].push(to_index);
+        }
+    }
+
+    /// Adds a two-way edge between the nodes labeled `a` and `b`, adding
+    /// either node if it is not already in this [Graph].
+    pub fn add_edge(&mut self, a: N, b: N) {
+        self.add_directed_edge(a.clone(), b.clone());
+        self.add_directed_edge(b, a);
+    }
+
+    /// Returns the index of the node labeled `label`, adding it to this
+    /// [Graph] first if necessary.
+    pub fn add_node(&mut self, label: N) -> usize {
+        if let Some(index) = self.indices.get(&label) {
+            return *index;
+        }
+
+        let index = self.labels.len();
+        self.adjacency_lists.push(vec![]);
+        self.indices.insert(label.clone(), index);
+        self.labels.push(label);
+
+        index
+    }
+
+    /// Returns the index of the node labeled `label`, or [Option::None] if
+    /// there is no such node.
+    pub fn index_of(&self, label: &N) -> Option<usize> {
+        self.indices.get(label).copied()
+    }
+}
+
+impl<N> Graph<N> {
+    /// Returns how many distinct paths lead from the node at index `start` to
+    /// the node at index `goal`, where the rules about which nodes a path may
+    /// step into are expressed as a state threaded along each path.
+    ///
+    /// `transition` receives the state of a path and the index of a node
+    /// adjacent to its last node, returning the state of the path extended
+    /// into that node, or [Option::None] if the path may not go there. Paths
+    /// stop as soon as they reach `goal`.
+    ///
+    /// Counts are memoized by node and state, so paths that reach the same
+    /// node in the same state are only explored once. Returns [Err] if the
+    /// rules allow a path to loop forever, since there would then be
+    /// infinitely many paths.
+    pub fn count_paths<S, F>(
+        &self,
+        start: usize,
+        goal: usize,
+        initial_state: S,
+        transition: F,
+    ) -> Result<u64>
+    where
+        S: Clone + Eq + Hash,
+        F: Fn(&S, usize) -> Option<S>,
+    {
+        let mut path_counts = HashMap::<(usize, S), Option<u64>>::new();
+
+        self.count_paths_from(start, goal, initial_state, &transition, &mut path_counts)
+    }
+
+    /// Returns the label of the node at index `index`, or [Option::None] if
+    /// there is no such node.
+    pub fn label_of(&self, index: usize) -> Option<&N> {
+        self.labels.get(index)
+    }
+
+    /// Returns `true` if this [Graph] has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Returns how many nodes this [Graph] has.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns the indices of every node that the node at index `index` has
+    /// an edge to, in the order that those edges were added.
+    pub fn neighbors_of(&self, index: usize) -> &[usize] {
+        self.adjacency_lists
+            .get(index)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Returns the indices of every node along a path with as few edges as
+    /// possible from the node at index `start` to the node at index `goal`,
+    /// or [Option::None] if `goal` cannot be reached.
+    pub fn shortest_path(&self, start: usize, goal: usize) -> Option<Vec<usize>> {
+        pathfinding::bfs(
+            start,
+            |index| self.neighbors_of(*index).to_vec(),
+            |index| *index == goal,
+        )
+    }
+
+    /// Depth-first helper of [Graph::count_paths] where `path_counts` holds
+    /// the count of every finished `(node, state)` and [Option::None] for
+    /// those still being explored.
+    fn count_paths_from<S, F>(
+        &self,
+        node: usize,
+        goal: usize,
+        state: S,
+        transition: &F,
+        path_counts: &mut HashMap<(usize, S), Option<u64>>,
+    ) -> Result<u64>
+    where
+        S: Clone + Eq + Hash,
+        F: Fn(&S, usize) -> Option<S>,
+    {
+        if node == goal {
+            return Ok(1);
+        }
+
+        let key = (node, state);
+        match path_counts.get(&key) {
+            Some(Some(path_count)) => return Ok(*path_count),
+            Some(None) => return Err(anyhow!("Paths can loop back to node {} forever", node)),
+            None => {}
+        }
+        path_counts.insert(key.clone(), None);
+
+        let mut path_count = 0;
+        for neighbor in self.neighbors_of(node) {
+            if let Some(next_state) = transition(&key.1, *neighbor) {
+                path_count +=
+                    self.count_paths_from(*neighbor, goal, next_state, transition, path_counts)?;
+            }
+        }
+
+        path_counts.insert(key, Some(path_count));
+
+        Ok(path_count)
+    }
+}
+
+impl<N: PartialEq> PartialEq for Graph<N> {
+    fn eq(&self, other: &Self) -> bool {
+        // Indices are derived from labels, so they need not be compared.
+        self.labels == other.labels && self.adjacency_lists == other.adjacency_lists
+    }
+}
+
+impl<N: Clone + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_nodes_in_the_order_they_are_added() {
+        let graph = caves();
+
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.index_of(&"start"), Some(0));
+        assert_eq!(graph.index_of(&"end"), Some(3));
+        assert_eq!(graph.index_of(&"nowhere"), None);
+        assert_eq!(graph.label_of(4), Some(&"d"));
+        assert_eq!(graph.label_of(5), None);
+    }
+
+    #[test]
+    fn has_no_nodes_or_neighbors_when_empty() {
+        let graph = Graph::<&str>::new();
+
+        assert!(graph.is_empty());
+        assert_eq!(graph.neighbors_of(0), &[] as &[usize]);
+        assert_eq!(graph.shortest_path(0, 1), None);
+    }
+
+    #[test]
+    fn finds_shortest_path_between_connected_nodes() {
+        let graph = caves();
+
+        assert_eq!(graph.shortest_path(0, 3), Some(vec![0, 1, 3]));
+        assert_eq!(graph.shortest_path(0, 4), None);
+    }
+
+    #[test]
+    fn counts_paths_that_follow_the_rules() {
+        let graph = caves();
+        let start = graph.index_of(&"start").unwrap();
+        let end = graph.index_of(&"end").unwrap();
+
+        // Lowercase caves may only be visited once along each path.
+        let path_count = graph.count_paths(start, end, vec![start], |visited, node| {
+            let label = graph.label_of(node)?;
+            if label.chars().all(char::is_lowercase) && visited.contains(&node) {
+                return None;
+            }
+
+            let mut visited = visited.clone();
+            visited.push(node);
+
+            Some(visited)
+        });
+
+        assert_eq!(path_count.unwrap(), 5);
+        assert_eq!(
+            graph
+                .count_paths(start, graph.index_of(&"d").unwrap(), (), |_, _| Some(()))
+                .ok(),
+            None
+        );
+    }
+
+    /// Returns a small cave system with five ways from `start` to `end` that
+    /// visit `b` at most once, where `d` cannot be reached from any cave.
+    fn caves() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        graph.add_edge("start", "A");
+        graph.add_edge("start", "b");
+        graph.add_edge("A", "b");
+        graph.add_edge("A", "end");
+        graph.add_edge("b", "end");
+        graph.add_node("d");
+
+        graph
+    }
+}
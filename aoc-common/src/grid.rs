@@ -0,0 +1,371 @@
+#[cfg(feature = "std")]
+use crate::pathfinding;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use anyhow::{anyhow, Result};
+use core::ops::{Index, IndexMut};
+
+/// `(row, column)` offsets of the positions directly up, down, left, and right
+/// of a position.
+pub const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// `(row, column)` offsets of the eight positions surrounding a position,
+/// diagonals included, row by row.
+pub const SURROUNDING_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Dense, rectangular 2D grid of values stored row by row.
+///
+/// Cells are addressed by `(row, column)` tuples, with `(0, 0)` at the top
+/// left of the grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid2D<T> {
+    /// Every value in this grid, stored row by row.
+    cells: Vec<T>,
+    /// Number of rows in this grid.
+    height: usize,
+    /// Number of columns in this grid.
+    width: usize,
+}
+
+impl<T: Clone> Grid2D<T> {
+    /// Creates a new [Grid2D] with the specified dimensions, setting every
+    /// cell to a copy of `value`.
+    pub fn filled_with(value: T, width: usize, height: usize) -> Grid2D<T> {
+        Grid2D {
+            cells: vec![value; width * height],
+            height,
+            width,
+        }
+    }
+}
+
+impl<T> Grid2D<T> {
+    /// Interprets the given rows of values as a [Grid2D], returning [Err] if
+    /// the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid2D<T>> {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+
+        if let Some((row_index, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width)
+        {
+            return Err(anyhow!(
+                "Row {} has {} columns (not {})",
+                row_index,
+                row.len(),
+                width
+            ));
+        }
+
+        Ok(Grid2D {
+            cells: rows.into_iter().flatten().collect(),
+            height,
+            width,
+        })
+    }
+
+    /// Returns every position reachable from `start` by stepping up, down,
+    /// left, or right through cells that satisfy `predicate`, in the order
+    /// that a breadth-first search visits them.
+    ///
+    /// Returns an empty [Vec] if `start` is out of bounds or does not satisfy
+    /// `predicate` itself.
+    #[cfg(feature = "std")]
+    pub fn flood_fill<P>(&self, start: (usize, usize), predicate: P) -> Vec<(usize, usize)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        match self.get(start.0, start.1) {
+            Some(value) if predicate(value) => {}
+            _ => return vec![],
+        }
+
+        pathfinding::bfs_reachable(start, |(row, column)| {
+            self.neighbors4(*row, *column)
+                .filter(|neighbor| predicate(&self[*neighbor]))
+                .collect::<Vec<(usize, usize)>>()
+        })
+    }
+
+    /// Returns the value at the specified position, returning [Option::None]
+    /// if the position is out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.index_of(row, column).map(|index| &self.cells[index])
+    }
+
+    /// Returns a mutable reference to the value at the specified position,
+    /// returning [Option::None] if the position is out of bounds.
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.index_of(row, column)
+            .map(move |index| &mut self.cells[index])
+    }
+
+    /// Returns an [Iterator] over every position within this [Grid2D]
+    /// directly up, down, left, or right of the specified position.
+    ///
+    /// Yields nothing if the position itself is out of bounds.
+    pub fn neighbors4(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors_at(row, column, &ORTHOGONAL_OFFSETS)
+    }
+
+    /// Returns an [Iterator] over every position within this [Grid2D] among
+    /// the eight surrounding the specified position, diagonals included.
+    ///
+    /// Yields nothing if the position itself is out of bounds.
+    pub fn neighbors8(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors_at(row, column, &SURROUNDING_OFFSETS)
+    }
+
+    /// Returns the position `offset` away from `position`, given as a
+    /// `(row, column)` offset, or [Option::None] if either is out of bounds.
+    pub fn offset(
+        &self,
+        position: (usize, usize),
+        offset: (isize, isize),
+    ) -> Option<(usize, usize)> {
+        self.index_of(position.0, position.1)?;
+
+        let row = position.0.checked_add_signed(offset.0)?;
+        let column = position.1.checked_add_signed(offset.1)?;
+        self.index_of(row, column)?;
+
+        Some((row, column))
+    }
+
+    /// Returns an [Iterator] over the positions directly up, down, left, and
+    /// right of the specified position, wrapping around to the opposite edge
+    /// of this [Grid2D] instead of stepping beyond it.
+    ///
+    /// Yields nothing if the position itself is out of bounds. On grids only
+    /// one or two cells wide or tall, the same position can be yielded more
+    /// than once.
+    pub fn wrapping_neighbors(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let is_in_bounds = self.index_of(row, column).is_some();
+
+        ORTHOGONAL_OFFSETS
+            .into_iter()
+            .filter(move |_| is_in_bounds)
+            .map(move |offset| self.wrapping_offset((row, column), offset))
+    }
+
+    /// Returns the position `offset` away from `position`, given as a
+    /// `(row, column)` offset, wrapping around to the opposite edge of this
+    /// [Grid2D] instead of stepping beyond it.
+    ///
+    /// Panics if this [Grid2D] is empty.
+    pub fn wrapping_offset(
+        &self,
+        position: (usize, usize),
+        offset: (isize, isize),
+    ) -> (usize, usize) {
+        (
+            wrap(position.0, offset.0, self.height),
+            wrap(position.1, offset.1, self.width),
+        )
+    }
+
+    /// Returns every value in this [Grid2D], row by row, for loops that scan
+    /// the whole grid at once.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns how many rows this [Grid2D] has.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns an [Iterator] over the position and value of every cell in this
+    /// [Grid2D], row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, value)| ((index / width, index % width), value))
+    }
+
+    /// Partitions the cells satisfying `predicate` into regions connected by
+    /// steps up, down, left, or right, returning the positions within each
+    /// region.
+    ///
+    /// Regions are ordered by the first of their cells to appear when reading
+    /// this [Grid2D] row by row.
+    #[cfg(feature = "std")]
+    pub fn regions<P>(&self, predicate: P) -> Vec<Vec<(usize, usize)>>
+    where
+        P: Fn(&T) -> bool,
+    {
+        let mut is_visited = Grid2D::filled_with(false, self.width, self.height);
+        let mut regions = vec![];
+
+        for row in 0..self.height {
+            for column in 0..self.width {
+                if is_visited[(row, column)] || !predicate(&self[(row, column)]) {
+                    continue;
+                }
+
+                let region = self.flood_fill((row, column), &predicate);
+                for position in region.iter() {
+                    is_visited[*position] = true;
+                }
+
+                regions.push(region);
+            }
+        }
+
+        regions
+    }
+
+    /// Returns how many columns this [Grid2D] has.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the index within [Grid2D::cells] of the specified position,
+    /// returning [Option::None] if the position is out of bounds.
+    fn index_of(&self, row: usize, column: usize) -> Option<usize> {
+        if row >= self.height || column >= self.width {
+            return None;
+        }
+
+        Some(row * self.width + column)
+    }
+
+    /// Returns an [Iterator] over every position within this [Grid2D] that
+    /// is one of `offsets` away from the specified position.
+    fn neighbors_at<'a>(
+        &'a self,
+        row: usize,
+        column: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |offset| self.offset((row, column), *offset))
+    }
+}
+
+/// Returns `index` moved by `offset` within `0..length`, wrapping around at
+/// either end.
+fn wrap(index: usize, offset: isize, length: usize) -> usize {
+    ((index as isize + offset).rem_euclid(length as isize)) as usize
+}
+
+impl<T> Index<(usize, usize)> for Grid2D<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        self.get(row, column).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is out of bounds of a {}x{} grid",
+                row, column, self.height, self.width
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid2D<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        let (height, width) = (self.height, self.width);
+
+        self.get_mut(row, column).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is out of bounds of a {}x{} grid",
+                row, column, height, width
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Returns a [Strategy] for a non-empty grid's dimensions, along with a
+    /// position that may lie somewhat beyond its edges.
+    fn grid_and_position() -> impl Strategy<Value = (Grid2D<()>, (usize, usize))> {
+        (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+            (
+                Just(Grid2D::filled_with((), width, height)),
+                (0..height + 2, 0..width + 2),
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn neighbors4_are_symmetric((grid, (row, column)) in grid_and_position()) {
+            for neighbor in grid.neighbors4(row, column) {
+                prop_assert!(grid.neighbors4(neighbor.0, neighbor.1).any(|other| other == (row, column)));
+            }
+        }
+
+        #[test]
+        fn neighbors8_are_symmetric((grid, (row, column)) in grid_and_position()) {
+            for neighbor in grid.neighbors8(row, column) {
+                prop_assert!(grid.neighbors8(neighbor.0, neighbor.1).any(|other| other == (row, column)));
+            }
+        }
+
+        #[test]
+        fn wrapping_neighbors_are_symmetric((grid, (row, column)) in grid_and_position()) {
+            for neighbor in grid.wrapping_neighbors(row, column) {
+                prop_assert!(grid
+                    .wrapping_neighbors(neighbor.0, neighbor.1)
+                    .any(|other| other == (row, column)));
+            }
+        }
+
+        #[test]
+        fn neighbors_stay_in_bounds((grid, (row, column)) in grid_and_position()) {
+            let neighbors = grid
+                .neighbors8(row, column)
+                .chain(grid.wrapping_neighbors(row, column))
+                .collect::<Vec<(usize, usize)>>();
+
+            for neighbor in neighbors {
+                prop_assert!(grid.get(neighbor.0, neighbor.1).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn counts_neighbors_by_position() {
+        let grid = Grid2D::filled_with((), 3, 3);
+
+        assert_eq!(grid.neighbors4(0, 0).count(), 2);
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(0, 1).count(), 5);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.neighbors8(3, 3).count(), 0);
+        assert_eq!(
+            grid.wrapping_neighbors(0, 0)
+                .collect::<Vec<(usize, usize)>>(),
+            vec![(2, 0), (1, 0), (0, 2), (0, 1)]
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use crate::error::InvalidInput;
+use crate::timing::Timings;
+
+use anyhow::{Context, Result};
+use flate2::bufread::MultiGzDecoder;
+use std::env::current_dir;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines, Read};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tracing::instrument;
+
+#[cfg(feature = "mmap")]
+use memmap2::Mmap;
+
+#[cfg(feature = "async")]
+pub mod nonblocking;
+
+/// Path that stands for standard input instead of a file.
+pub const STDIN_PATH: &str = "-";
+
+/// Directory of the crate that built this program, if any, registered by
+/// [set_crate_dir].
+static CRATE_DIR: OnceLock<&'static Path> = OnceLock::new();
+
+/// Puzzle input baked into this program, if any, registered by [embed].
+static EMBEDDED_INPUT: OnceLock<&'static str> = OnceLock::new();
+
+/// Leading bytes of every gzip stream.
+const GZIP_MAGIC_BYTES: [u8; 2] = [0x1f, 0x8b];
+
+/// Buffered reader over the decompressed contents of an [InputSource].
+pub type InputReader = Box<dyn BufRead + Send>;
+
+/// Type that a day's puzzle input can be interpreted as one line at a time,
+/// so that, with the `async` feature, `nonblocking::deserialize_lines` can
+/// interpret the input as it streams in instead of reading all of it first.
+pub trait DeserializeLines: Sized {
+    /// What every line of the puzzle input is interpreted as.
+    type Line;
+
+    /// Interprets a single `line` of the puzzle input, without its trailing
+    /// newline.
+    fn deserialize_line(line: &str) -> Result<Self::Line>;
+
+    /// Assembles a value of this type out of every interpreted line of the
+    /// puzzle input, in order.
+    fn from_lines(lines: Vec<Self::Line>) -> Self;
+}
+
+/// Type that a day's puzzle input can be interpreted as, so that
+/// [load_parsed] can load the input of any day.
+pub trait FromInput: Sized {
+    /// Interprets the entire puzzle `input` as a value of this type.
+    fn from_input(input: &str) -> Result<Self>;
+}
+
+/// Entire (decompressed) contents of an [InputSource], dereferencing to a
+/// [str].
+///
+/// With the `mmap` feature enabled, uncompressed files are memory-mapped
+/// instead of being copied into a [String].
+#[derive(Debug)]
+pub struct Input(InputContents);
+
+/// Storage backing an [Input].
+#[derive(Debug)]
+enum InputContents {
+    /// Contents copied into memory owned by the [Input].
+    Owned(String),
+    /// Contents of a memory-mapped file, already validated as UTF-8.
+    #[cfg(feature = "mmap")]
+    Mapped(Mmap),
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        match &self.0 {
+            InputContents::Owned(contents) => contents,
+            // Safety: mapped contents are only kept once they have been
+            // validated as UTF-8 by [map].
+            #[cfg(feature = "mmap")]
+            InputContents::Mapped(mapping) => unsafe { std::str::from_utf8_unchecked(mapping) },
+        }
+    }
+}
+
+/// Place that puzzle input can be read from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    /// File at the specified path, relative to the current working
+    /// directory.
+    File(PathBuf),
+    /// Puzzle input baked into this program with [embed].
+    Embedded(&'static str),
+    /// Standard input of this process.
+    Stdin,
+}
+
+impl InputSource {
+    /// Returns `true` if this [InputSource] is named like a gzip file.
+    fn has_gzip_extension(&self) -> bool {
+        match self {
+            InputSource::File(path) => path.extension().is_some_and(|extension| extension == "gz"),
+            InputSource::Embedded(_) | InputSource::Stdin => false,
+        }
+    }
+
+    /// Returns this [InputSource] with any file path resolved against the
+    /// current working directory.
+    fn resolved(self) -> Result<InputSource> {
+        match self {
+            InputSource::File(path) => {
+                let pwd = current_dir().context("Failed to read current working directory")?;
+
+                Ok(InputSource::File(pwd.join(path)))
+            }
+            source => Ok(source),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "file at path \"{}\"", path.display()),
+            InputSource::Embedded(_) => write!(f, "input embedded in this program"),
+            InputSource::Stdin => write!(f, "standard input"),
+        }
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(path: &str) -> Self {
+        if path == STDIN_PATH {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(path))
+        }
+    }
+}
+
+impl From<&String> for InputSource {
+    fn from(path: &String) -> Self {
+        InputSource::from(path.as_str())
+    }
+}
+
+impl From<&Path> for InputSource {
+    fn from(path: &Path) -> Self {
+        InputSource::File(path.to_path_buf())
+    }
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        InputSource::File(path)
+    }
+}
+
+/// Returns the directory registered by [set_crate_dir], if any.
+pub fn crate_dir() -> Option<&'static Path> {
+    CRATE_DIR.get().copied()
+}
+
+/// Registers `contents` as the puzzle input baked into this program, which
+/// is read in place of "input.txt" when that file cannot be found.
+///
+/// Only the first call has any effect.
+pub fn embed(contents: &'static str) {
+    let _ = EMBEDDED_INPUT.set(contents);
+}
+
+/// Returns the puzzle input registered by [embed], if any.
+pub fn embedded() -> Option<&'static str> {
+    EMBEDDED_INPUT.get().copied()
+}
+
+/// Registers `crate_dir`, typically `env!("CARGO_MANIFEST_DIR")`, as the
+/// directory of the crate that built this program, which [locate_dir] falls
+/// back to for relative directories missing from the current working
+/// directory.
+///
+/// Only the first call has any effect.
+pub fn set_crate_dir(crate_dir: &'static str) {
+    let _ = CRATE_DIR.set(Path::new(crate_dir));
+}
+
+/// Returns `dir` as is if it is absolute or exists relative to the current
+/// working directory, or else `dir` within the directory registered by
+/// [set_crate_dir] if it exists there.
+///
+/// This lets a program find its "files" directory when it is run from
+/// somewhere other than its crate, like the root of the workspace.
+pub fn locate_dir(dir: &Path) -> PathBuf {
+    if dir.is_absolute() || dir.exists() {
+        return dir.to_path_buf();
+    }
+
+    crate_dir()
+        .map(|crate_dir| crate_dir.join(dir))
+        .filter(|crate_relative_dir| crate_relative_dir.exists())
+        .unwrap_or_else(|| dir.to_path_buf())
+}
+
+/// Opens `source` for reading, transparently decompressing it if it is named
+/// like a gzip file or starts with the gzip magic bytes.
+///
+/// File paths are resolved relative to the current working directory, and
+/// [STDIN_PATH] reads from standard input.
+pub fn open(source: impl Into<InputSource>) -> Result<InputReader> {
+    let source = source.into().resolved()?;
+
+    let mut reader: InputReader = match &source {
+        InputSource::File(path) => Box::new(BufReader::new(
+            File::open(path).with_context(|| format!("Failed to open {}", source))?,
+        )),
+        InputSource::Embedded(contents) => Box::new(contents.as_bytes()),
+        InputSource::Stdin => Box::new(BufReader::new(io::stdin())),
+    };
+
+    let is_gzipped = source.has_gzip_extension()
+        || reader
+            .fill_buf()
+            .with_context(|| format!("Failed to read {}", source))?
+            .starts_with(&GZIP_MAGIC_BYTES);
+
+    if !is_gzipped {
+        return Ok(reader);
+    }
+
+    Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+}
+
+/// Reads the entire (decompressed) contents of `source` as a [String],
+/// replacing any invalid UTF-8 sequences.
+#[instrument(skip_all)]
+pub fn read(source: impl Into<InputSource>) -> Result<String> {
+    let source = source.into();
+    let mut raw_contents = vec![];
+
+    open(source.clone())?
+        .read_to_end(&mut raw_contents)
+        .with_context(|| format!("Failed to read {}", source))?;
+
+    Ok(String::from_utf8_lossy(&raw_contents).into_owned())
+}
+
+/// Loads the entire (decompressed) contents of `source` as an [Input],
+/// memory-mapping uncompressed files when the `mmap` feature is enabled.
+///
+/// Falls back to [read] for standard input, gzip files, and files that are
+/// not valid UTF-8.
+#[instrument(skip_all)]
+pub fn load(source: impl Into<InputSource>) -> Result<Input> {
+    let source = source.into();
+
+    #[cfg(feature = "mmap")]
+    if let Some(mapping) = map(&source)? {
+        return Ok(Input(InputContents::Mapped(mapping)));
+    }
+
+    Ok(Input(InputContents::Owned(read(source)?)))
+}
+
+/// Loads the entire (decompressed) contents of `source` like [load], and then
+/// interprets them as a `T`, recording how long interpreting them took in
+/// `timings`.
+///
+/// Failing to interpret the contents is reported as [InvalidInput].
+#[instrument(skip_all)]
+pub fn load_parsed<T: FromInput>(
+    source: impl Into<InputSource>,
+    timings: &mut Timings,
+) -> Result<T> {
+    let contents = load(source)?;
+
+    timings
+        .time("Parse", || T::from_input(&contents))
+        .context(InvalidInput)
+}
+
+/// Memory-maps `source`, returning [Option::None] if it is not an
+/// uncompressed, UTF-8 encoded file.
+#[cfg(feature = "mmap")]
+fn map(source: &InputSource) -> Result<Option<Mmap>> {
+    let source = source.clone().resolved()?;
+    let path = match &source {
+        InputSource::File(path) if !source.has_gzip_extension() => path,
+        _ => return Ok(None),
+    };
+
+    let file = File::open(path).with_context(|| format!("Failed to open {}", source))?;
+
+    // Safety: puzzle inputs are not expected to change while they are being
+    // solved; if one does, the worst case is a garbled answer.
+    let mapping =
+        unsafe { Mmap::map(&file) }.with_context(|| format!("Failed to map {}", source))?;
+
+    if mapping.starts_with(&GZIP_MAGIC_BYTES) || std::str::from_utf8(&mapping).is_err() {
+        return Ok(None);
+    }
+
+    Ok(Some(mapping))
+}
+
+/// Returns an [Iterator] over the (decompressed) lines of `source`, without
+/// their trailing newlines.
+#[instrument(skip_all)]
+pub fn read_lines(source: impl Into<InputSource>) -> Result<Lines<InputReader>> {
+    Ok(open(source)?.lines())
+}
@@ -0,0 +1,164 @@
+//! Asynchronous counterparts of the functions in [super], for callers that
+//! already run inside a tokio runtime.
+
+use super::{DeserializeLines, FromInput, InputSource, GZIP_MAGIC_BYTES};
+use crate::error::InvalidInput;
+use crate::timing::Timings;
+
+use anyhow::{Context, Result};
+use async_compression::tokio::bufread::GzipDecoder;
+use std::pin::Pin;
+use std::time::Instant;
+use tokio::fs::File;
+use tokio::io::{self, AsyncBufRead, AsyncBufReadExt, AsyncReadExt, BufReader, Lines};
+
+/// Buffered reader over the decompressed contents of an [InputSource].
+pub type InputReader = Pin<Box<dyn AsyncBufRead + Send>>;
+
+/// Interprets every line that `reader` streams as a `T`, one line at a time,
+/// so that only a single line is ever held in memory before it has been
+/// interpreted.
+///
+/// Failing to interpret a line is reported as [InvalidInput], along with the
+/// number of the line.
+pub async fn deserialize_lines<T: DeserializeLines>(
+    reader: impl AsyncBufRead + Unpin,
+) -> Result<T> {
+    let mut lines = reader.lines();
+    let mut deserialized_lines = vec![];
+
+    while let Some(line) = lines
+        .next_line()
+        .await
+        .context("Failed to read the next line")?
+    {
+        let deserialized_line = T::deserialize_line(&line)
+            .with_context(|| format!("Line {} is invalid", deserialized_lines.len() + 1))
+            .context(InvalidInput)?;
+
+        deserialized_lines.push(deserialized_line);
+    }
+
+    Ok(T::from_lines(deserialized_lines))
+}
+
+/// Interprets the (decompressed) lines of `source` as a `T` as they stream
+/// in, like [deserialize_lines], recording how long reading and interpreting
+/// them took in `timings`.
+pub async fn load_lines<T: DeserializeLines>(
+    source: impl Into<InputSource>,
+    timings: &mut Timings,
+) -> Result<T> {
+    let start = Instant::now();
+    let deserialized = deserialize_lines(open(source).await?).await?;
+    timings.record("Parse", start.elapsed());
+
+    Ok(deserialized)
+}
+
+/// Reads the entire (decompressed) contents of `source` like [read], and then
+/// interprets them as a `T`, recording how long interpreting them took in
+/// `timings`, like [super::load_parsed].
+pub async fn load_parsed<T: FromInput>(
+    source: impl Into<InputSource>,
+    timings: &mut Timings,
+) -> Result<T> {
+    let contents = read(source).await?;
+
+    timings
+        .time("Parse", || T::from_input(&contents))
+        .context(InvalidInput)
+}
+
+/// Opens `source` for reading, transparently decompressing it if it is named
+/// like a gzip file or starts with the gzip magic bytes.
+pub async fn open(source: impl Into<InputSource>) -> Result<InputReader> {
+    let source = source.into().resolved()?;
+
+    let mut reader: InputReader = match &source {
+        InputSource::File(path) => Box::pin(BufReader::new(
+            File::open(path)
+                .await
+                .with_context(|| format!("Failed to open {}", source))?,
+        )),
+        InputSource::Embedded(contents) => Box::pin(contents.as_bytes()),
+        InputSource::Stdin => Box::pin(BufReader::new(io::stdin())),
+    };
+
+    let is_gzipped = source.has_gzip_extension()
+        || reader
+            .fill_buf()
+            .await
+            .with_context(|| format!("Failed to read {}", source))?
+            .starts_with(&GZIP_MAGIC_BYTES);
+
+    if !is_gzipped {
+        return Ok(reader);
+    }
+
+    let mut decoder = GzipDecoder::new(reader);
+    decoder.multiple_members(true);
+
+    Ok(Box::pin(BufReader::new(decoder)))
+}
+
+/// Reads the entire (decompressed) contents of `source` as a [String],
+/// replacing any invalid UTF-8 sequences.
+pub async fn read(source: impl Into<InputSource>) -> Result<String> {
+    let source = source.into();
+    let mut raw_contents = vec![];
+
+    open(source.clone())
+        .await?
+        .read_to_end(&mut raw_contents)
+        .await
+        .with_context(|| format!("Failed to read {}", source))?;
+
+    Ok(String::from_utf8_lossy(&raw_contents).into_owned())
+}
+
+/// Returns a stream of the (decompressed) lines of `source`, without their
+/// trailing newlines.
+pub async fn read_lines(source: impl Into<InputSource>) -> Result<Lines<InputReader>> {
+    Ok(open(source).await?.lines())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::parse;
+
+    /// Newline-separated list of numbers.
+    #[derive(Debug, PartialEq)]
+    struct Numbers(Vec<u32>);
+
+    impl DeserializeLines for Numbers {
+        type Line = u32;
+
+        fn deserialize_line(line: &str) -> Result<u32> {
+            parse::parse_number::<u32>(line, line)
+        }
+
+        fn from_lines(lines: Vec<u32>) -> Numbers {
+            Numbers(lines)
+        }
+    }
+
+    #[tokio::test]
+    async fn deserializes_one_line_at_a_time() {
+        let numbers = deserialize_lines::<Numbers>("199\n200\n208\n".as_bytes()).await;
+
+        assert_eq!(numbers.unwrap(), Numbers(vec![199, 200, 208]));
+    }
+
+    #[tokio::test]
+    async fn reports_which_line_is_invalid() {
+        let error = deserialize_lines::<Numbers>("199\n2x0\n208".as_bytes())
+            .await
+            .unwrap_err();
+
+        assert_eq!(ErrorKind::of(&error), ErrorKind::Parse);
+        assert!(format!("{:#}", error).contains("Line 2 is invalid"));
+    }
+}
@@ -0,0 +1,77 @@
+//! Code shared by every day's puzzle.
+//!
+//! Without the default `std` feature, only the pure [bit], [geometry], [grid],
+//! and [render] modules are built, and they only need `alloc`, so that they
+//! can run on targets without an operating system.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+extern crate anyhow;
+#[cfg(feature = "serde")]
+extern crate serde;
+
+#[cfg(feature = "alloc-stats")]
+pub mod alloc_stats;
+#[cfg(feature = "std")]
+pub mod answer;
+#[cfg(feature = "std")]
+pub mod answers;
+#[cfg(feature = "std")]
+pub mod args;
+#[cfg(feature = "std")]
+pub mod baseline;
+#[cfg(feature = "std")]
+pub mod batch;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod bit;
+#[cfg(feature = "std")]
+pub mod cancellation;
+#[cfg(feature = "std")]
+pub mod completions;
+#[cfg(feature = "std")]
+pub mod concurrent;
+#[cfg(feature = "std")]
+pub mod counter;
+#[cfg(feature = "std")]
+pub mod diagnostic;
+#[cfg(feature = "std")]
+pub mod error;
+#[cfg(feature = "std")]
+pub mod flame;
+pub mod geometry;
+#[cfg(feature = "std")]
+pub mod graph;
+pub mod grid;
+#[cfg(feature = "std")]
+pub mod input;
+#[cfg(feature = "std")]
+pub mod memo;
+#[cfg(feature = "otel")]
+pub mod otel;
+#[cfg(feature = "std")]
+pub mod output;
+#[cfg(feature = "std")]
+pub mod parse;
+#[cfg(feature = "std")]
+pub mod pathfinding;
+#[cfg(feature = "profile")]
+pub mod profile;
+#[cfg(feature = "record")]
+pub mod recording;
+#[cfg(feature = "std")]
+pub mod registry;
+pub mod render;
+#[cfg(feature = "std")]
+pub mod shuffle;
+#[cfg(feature = "std")]
+pub mod solver;
+#[cfg(feature = "std")]
+pub mod svg;
+#[cfg(feature = "std")]
+pub mod table;
+#[cfg(feature = "std")]
+pub mod timing;
+#[cfg(feature = "std")]
+pub mod watch;
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cache of the values that a recursive computation has produced for each of
+/// its inputs, so that every input only has to be computed once.
+///
+/// Recursive solvers thread a `&mut Memo` through their recursion, wrapping
+/// each step in [Memo::get_or_insert_with]:
+///
+/// ```
+/// use aoc_common::memo::Memo;
+///
+/// fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+///     memo.get_or_insert_with(n, |memo| match n {
+///         0 | 1 => n,
+///         _ => fibonacci(n - 1, memo) + fibonacci(n - 2, memo),
+///     })
+/// }
+///
+/// assert_eq!(fibonacci(90, &mut Memo::new()), 2880067194370816120);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    /// Relates every input computed so far to its value.
+    values: HashMap<K, V>,
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    /// Creates a new, empty [Memo].
+    pub fn new() -> Memo<K, V> {
+        Memo {
+            values: HashMap::new(),
+        }
+    }
+
+    /// Returns the value cached for `key`, or [Option::None] if it has not
+    /// been computed yet.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    /// Returns the value cached for `key`, computing and caching it with
+    /// `compute` first if it has not been computed yet.
+    ///
+    /// `compute` receives this [Memo] so that it can recurse into other keys.
+    pub fn get_or_insert_with<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Memo<K, V>) -> V,
+    {
+        if let Some(value) = self.values.get(&key) {
+            return value.clone();
+        }
+
+        let value = compute(self);
+        self.values.insert(key, value.clone());
+
+        value
+    }
+
+    /// Returns `true` if no value has been cached yet.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns how many values have been cached.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<K, V> Default for Memo<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_empty() {
+        let memo = Memo::<u8, u8>::new();
+
+        assert!(memo.is_empty());
+        assert_eq!(memo.len(), 0);
+        assert_eq!(memo.get(&1), None);
+    }
+
+    #[test]
+    fn computes_each_key_only_once() {
+        let mut memo = Memo::new();
+        let mut computations = 0;
+
+        for _ in 0..3 {
+            let value = memo.get_or_insert_with("answer", |_| {
+                computations += 1;
+
+                42
+            });
+
+            assert_eq!(value, 42);
+        }
+
+        assert_eq!(computations, 1);
+        assert_eq!(memo.get(&"answer"), Some(&42));
+        assert_eq!(memo.len(), 1);
+    }
+
+    #[test]
+    fn caches_every_key_computed_while_recursing() {
+        /// Returns the number of ways to climb `steps` stairs one or two at a
+        /// time.
+        fn ways_to_climb(steps: u32, memo: &mut Memo<u32, u64>) -> u64 {
+            memo.get_or_insert_with(steps, |memo| match steps {
+                0 | 1 => 1,
+                _ => ways_to_climb(steps - 1, memo) + ways_to_climb(steps - 2, memo),
+            })
+        }
+
+        let mut memo = Memo::new();
+
+        assert_eq!(ways_to_climb(10, &mut memo), 89);
+        assert_eq!(memo.len(), 11);
+        assert_eq!(memo.get(&5), Some(&8));
+    }
+}
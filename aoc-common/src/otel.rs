@@ -0,0 +1,85 @@
+use crate::output;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::SpanExporter;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use opentelemetry_sdk::Resource;
+use std::env;
+use std::path::Path;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Exports the traced phases of a program as OpenTelemetry spans.
+pub type OtelLayer<S> = OpenTelemetryLayer<S, SdkTracer>;
+
+/// Command-line arguments that control the export of a program's traced
+/// phases to an OpenTelemetry collector.
+#[derive(Debug, Parser)]
+pub struct OtelArgs {
+    /// Exports every traced phase (e.g. parsing and solving), along with the
+    /// answers, as OTLP spans to the collector at the standard
+    /// `OTEL_EXPORTER_OTLP_ENDPOINT` (by default, http://localhost:4318).
+    #[clap(long)]
+    pub otel: bool,
+}
+
+impl OtelArgs {
+    /// Returns an [OtelLayer] that exports spans named after this program if
+    /// `--otel` was specified, along with the [OtelGuard] that must be kept
+    /// alive until the program finishes, returning [Option::None] otherwise.
+    pub fn layer<S>(&self) -> Result<Option<(OtelLayer<S>, OtelGuard)>>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        if !self.otel {
+            return Ok(None);
+        }
+
+        let span_exporter = SpanExporter::builder()
+            .with_http()
+            .build()
+            .context("Failed to create OTLP span exporter")?;
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_batch_exporter(span_exporter)
+            .with_resource(
+                Resource::builder()
+                    .with_service_name(program_name())
+                    .build(),
+            )
+            .build();
+
+        let otel_layer =
+            tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer("aoc-common"));
+
+        Ok(Some((otel_layer, OtelGuard { tracer_provider })))
+    }
+}
+
+/// Keeps spans flowing to the OpenTelemetry collector until dropped, at which
+/// point any that are still buffered are exported.
+pub struct OtelGuard {
+    /// Provides the tracer that every exported span is recorded with.
+    tracer_provider: SdkTracerProvider,
+}
+
+impl Drop for OtelGuard {
+    fn drop(&mut self) {
+        if let Err(error) = self.tracer_provider.shutdown() {
+            output::print_warning(format!("Failed to export OTLP spans: {}", error));
+        }
+    }
+}
+
+/// Returns the name that this program was run with (e.g. "day-4"), which
+/// tells the days apart once their spans reach the collector.
+fn program_name() -> String {
+    env::args()
+        .next()
+        .as_deref()
+        .and_then(|program_path| Path::new(program_path).file_stem())
+        .map(|file_stem| file_stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "aoc".to_string())
+}
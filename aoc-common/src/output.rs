@@ -0,0 +1,114 @@
+use std::env;
+use std::fmt::Display;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Environment variable that disables colored output when set, as described
+/// at https://no-color.org.
+const NO_COLOR_VAR: &str = "NO_COLOR";
+
+/// ANSI escape sequence that resets all styling.
+const RESET: &str = "\x1b[0m";
+
+/// Is `true` once colored output has been turned off by [disable_color].
+static IS_COLOR_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enumerates the kinds of text that every day's output styles the same way.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Emphasis {
+    /// Answer to the puzzle, which stands out from everything else.
+    Answer,
+    /// Failure that kept the puzzle from being solved.
+    Error,
+    /// Heading that labels whatever follows it.
+    Header,
+    /// How long something took, or when it happened, which recedes behind
+    /// everything else.
+    Timing,
+    /// Something unexpected that did not keep the puzzle from being solved.
+    Warning,
+}
+
+impl Emphasis {
+    /// Returns the ANSI escape sequence that starts text with this
+    /// [Emphasis].
+    fn escape(&self) -> &'static str {
+        match self {
+            Emphasis::Answer => "\x1b[1;32m",
+            Emphasis::Error => "\x1b[1;31m",
+            Emphasis::Header => "\x1b[1m",
+            Emphasis::Timing => "\x1b[2m",
+            Emphasis::Warning => "\x1b[33m",
+        }
+    }
+}
+
+/// Turns off colored output for the rest of the program, as `--no-color`
+/// does, even where a terminal would otherwise be colored.
+pub fn disable_color() {
+    IS_COLOR_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Returns `true` if output written to standard output should be colored.
+pub fn is_color_enabled() -> bool {
+    io::stdout().is_terminal() && is_color_allowed()
+}
+
+/// Returns `true` if output written to standard error should be colored.
+pub fn is_stderr_color_enabled() -> bool {
+    io::stderr().is_terminal() && is_color_allowed()
+}
+
+/// Returns `text` styled with `emphasis` as ANSI escape sequences if
+/// `is_colored` is `true`, and unchanged otherwise.
+///
+/// ```
+/// use aoc_common::output::{self, Emphasis};
+///
+/// assert_eq!(output::paint(42, Emphasis::Answer, false), "42");
+/// assert_eq!(
+///     output::paint(42, Emphasis::Answer, true),
+///     "\x1b[1;32m42\x1b[0m"
+/// );
+/// ```
+pub fn paint(text: impl Display, emphasis: Emphasis, is_colored: bool) -> String {
+    match is_colored {
+        false => text.to_string(),
+        true => format!("{}{}{}", emphasis.escape(), text, RESET),
+    }
+}
+
+/// Prints `message` to standard error, prefixed by a label styled as an
+/// [Emphasis::Error].
+pub fn print_error(message: impl Display) {
+    eprintln!(
+        "{} {}",
+        paint("Error:", Emphasis::Error, is_stderr_color_enabled()),
+        message
+    );
+}
+
+/// Prints `message` about how long something took to standard error, styled
+/// as [Emphasis::Timing].
+pub fn print_timing(message: impl Display) {
+    eprintln!(
+        "{}",
+        paint(message, Emphasis::Timing, is_stderr_color_enabled())
+    );
+}
+
+/// Prints `message` to standard error, prefixed by a label styled as an
+/// [Emphasis::Warning].
+pub fn print_warning(message: impl Display) {
+    eprintln!(
+        "{} {}",
+        paint("Warning:", Emphasis::Warning, is_stderr_color_enabled()),
+        message
+    );
+}
+
+/// Returns `true` unless colored output was turned off, by [disable_color]
+/// or by setting `NO_COLOR`.
+fn is_color_allowed() -> bool {
+    !IS_COLOR_DISABLED.load(Ordering::Relaxed) && env::var_os(NO_COLOR_VAR).is_none()
+}
@@ -0,0 +1,311 @@
+use anyhow::{anyhow, Context, Result};
+use std::fmt::Display;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+/// Integer type that numbers in puzzle inputs can be parsed into with
+/// [parse_number].
+pub trait Number: FromStr<Err = ParseIntError> + Display {
+    /// Smallest value of this type.
+    const MIN: Self;
+    /// Largest value of this type.
+    const MAX: Self;
+}
+
+macro_rules! impl_number {
+    ($($number:ty),*) => {
+        $(
+            impl Number for $number {
+                const MIN: Self = <$number>::MIN;
+                const MAX: Self = <$number>::MAX;
+            }
+        )*
+    };
+}
+
+impl_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Interprets `token`, give or take surrounding whitespace, as a number of
+/// type `T` found in `line`.
+///
+/// Surrounding whitespace is logged at debug level, since it usually means
+/// that `line` was split on the wrong separator.
+///
+/// Unlike a bare [str::parse], the returned error names the offending token,
+/// the line it came from, and `T`, and says which way it is out of range if
+/// it does not fit in `T`.
+///
+/// ```
+/// use aoc_common::parse;
+///
+/// assert_eq!(parse::parse_number::<u8>(" 30", "7,30,4").unwrap(), 30);
+/// assert_eq!(
+///     parse::parse_number::<u8>("300", "7,300,4").unwrap_err().to_string(),
+///     "\"300\" in \"7,300,4\" is too large for a u8 (at most 255)",
+/// );
+/// assert_eq!(
+///     parse::parse_number::<u8>("-1", "-1").unwrap_err().to_string(),
+///     "\"-1\" is too small for a u8 (at least 0)",
+/// );
+/// ```
+pub fn parse_number<T: Number>(token: &str, line: &str) -> Result<T> {
+    if token.trim().len() != token.len() {
+        tracing::debug!(token, line, "ignoring whitespace around number");
+    }
+
+    let token = token.trim();
+    let line = line.trim();
+
+    let error = match token.parse::<T>() {
+        Ok(number) => return Ok(number),
+        Err(error) => error,
+    };
+
+    let type_name = std::any::type_name::<T>();
+    let is_negative = token.starts_with('-');
+    let description = if token == line {
+        format!("\"{}\"", token)
+    } else {
+        format!("\"{}\" in \"{}\"", token, line)
+    };
+
+    match error.kind() {
+        IntErrorKind::PosOverflow => Err(anyhow!(
+            "{} is too large for a {} (at most {})",
+            description,
+            type_name,
+            T::MAX
+        )),
+        IntErrorKind::NegOverflow => Err(anyhow!(
+            "{} is too small for a {} (at least {})",
+            description,
+            type_name,
+            T::MIN
+        )),
+        // Unsigned types reject every minus sign as an invalid digit, even
+        // when the rest of the token is a perfectly good number.
+        IntErrorKind::InvalidDigit if is_negative && token[1..].parse::<u128>().is_ok() => {
+            Err(anyhow!(
+                "{} is too small for a {} (at least {})",
+                description,
+                type_name,
+                T::MIN
+            ))
+        }
+        _ => Err(anyhow!(
+            "{} is not a valid {} ({})",
+            description,
+            type_name,
+            error
+        )),
+    }
+}
+
+/// Interprets `token`, give or take surrounding whitespace, as an integer of
+/// type `T` with an optional leading `+` or `-`, like [parse_number].
+///
+/// ```
+/// use aoc_common::parse;
+///
+/// assert_eq!(parse::signed_int::<i32>(" -12 ").unwrap(), -12);
+/// assert_eq!(parse::signed_int::<i32>("+7").unwrap(), 7);
+/// assert!(parse::signed_int::<i32>("7a").is_err());
+/// ```
+pub fn signed_int<T: Number>(token: &str) -> Result<T> {
+    parse_number(token, token)
+}
+
+/// Interprets every non-empty piece of `input` between occurrences of
+/// `separator` with `item`, returning the results in order.
+///
+/// Pieces are trimmed before being handed to `item`, and the first one that
+/// `item` rejects is named in the returned error along with its position.
+pub fn separated<T, F>(input: &str, separator: char, item: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    pieces(input.split(separator), item)
+}
+
+/// Interprets every whitespace-separated piece of `input` with `item`, like
+/// [separated], returning the results in order.
+pub fn whitespace_separated<T, F>(input: &str, item: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    pieces(input.split_whitespace(), item)
+}
+
+/// Interprets `input` as a pair of integers separated by a comma, like `3,4`.
+///
+/// ```
+/// use aoc_common::parse;
+///
+/// assert_eq!(parse::coordinate_pair::<i32>("3, 4").unwrap(), (3, 4));
+/// assert!(parse::coordinate_pair::<i32>("3,4,5").is_err());
+/// ```
+pub fn coordinate_pair<T: Number>(input: &str) -> Result<(T, T)> {
+    let (raw_x, raw_y) = input
+        .split_once(',')
+        .with_context(|| format!("\"{}\" is not a valid coordinate (no comma)", input.trim()))?;
+
+    let x = parse_number(raw_x, input)
+        .with_context(|| format!("\"{}\" lacks a valid x", input.trim()))?;
+    let y = parse_number(raw_y, input)
+        .with_context(|| format!("\"{}\" lacks a valid y", input.trim()))?;
+
+    Ok((x, y))
+}
+
+/// Splits `input` into a label and the value that follows it, like
+/// `forward 5`, trimming both.
+///
+/// ```
+/// use aoc_common::parse;
+///
+/// assert_eq!(parse::labeled(" forward  5 ").unwrap(), ("forward", "5"));
+/// assert!(parse::labeled("forward").is_err());
+/// ```
+pub fn labeled(input: &str) -> Result<(&str, &str)> {
+    let input = input.trim();
+
+    input
+        .split_once(char::is_whitespace)
+        .map(|(label, value)| (label, value.trim()))
+        .with_context(|| format!("\"{}\" is not a label followed by a value", input))
+}
+
+/// Returns an [Iterator] over the groups of consecutive non-blank lines in
+/// `input`, such as the boards of a bingo game.
+///
+/// ```
+/// use aoc_common::parse;
+///
+/// let groups = parse::paragraphs("a\nb\r\n\r\n\nc\n").collect::<Vec<&str>>();
+///
+/// assert_eq!(groups, ["a\nb", "c"]);
+/// ```
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+
+    std::iter::from_fn(move || {
+        rest = &rest[offset_of_first_line_in(rest, false)..];
+        if rest.is_empty() {
+            return None;
+        }
+
+        let (paragraph, remainder) = rest.split_at(offset_of_first_line_in(rest, true));
+        rest = remainder;
+
+        Some(paragraph.trim_end_matches(['\r', '\n']))
+    })
+}
+
+/// Returns the byte offset of the first line in `input` that is blank if
+/// `is_blank` is `true` and not blank otherwise, or the length of `input` if
+/// there is no such line.
+fn offset_of_first_line_in(input: &str, is_blank: bool) -> usize {
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() == is_blank {
+            return offset;
+        }
+
+        offset += line.len();
+    }
+
+    offset
+}
+
+/// Interprets every non-empty piece in `pieces` with `item`, naming the first
+/// one that `item` rejects in the returned error.
+fn pieces<'a, T, F>(pieces: impl Iterator<Item = &'a str>, mut item: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    pieces
+        .map(str::trim)
+        .filter(|piece| !piece.is_empty())
+        .enumerate()
+        .map(|(i, piece)| {
+            item(piece).with_context(|| format!("Item #{} (\"{}\") is not valid", i + 1, piece))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separates_non_empty_pieces() {
+        assert_eq!(
+            separated("1, 2,,3,", ',', signed_int::<u8>).unwrap(),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            whitespace_separated(" 4  5\n6 ", signed_int::<u8>).unwrap(),
+            [4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn separates_empty_input_into_nothing() {
+        assert!(separated("", ',', signed_int::<u8>).unwrap().is_empty());
+        assert!(whitespace_separated(" \n ", signed_int::<u8>)
+            .unwrap()
+            .is_empty());
+        assert_eq!(paragraphs("").count(), 0);
+        assert_eq!(paragraphs("\n\n \n").count(), 0);
+    }
+
+    #[test]
+    fn names_the_first_malformed_piece() {
+        let error = separated("1,x,y", ',', signed_int::<u8>).unwrap_err();
+
+        assert_eq!(error.to_string(), "Item #2 (\"x\") is not valid");
+    }
+
+    #[test]
+    fn rejects_malformed_coordinates_and_labels() {
+        assert_eq!(
+            coordinate_pair::<i32>("3 4").unwrap_err().to_string(),
+            "\"3 4\" is not a valid coordinate (no comma)"
+        );
+        assert_eq!(
+            coordinate_pair::<i32>("3,").unwrap_err().to_string(),
+            "\"3,\" lacks a valid y"
+        );
+        assert!(coordinate_pair::<u8>("-3,4").is_err());
+        assert!(labeled("").is_err());
+    }
+
+    #[test]
+    fn says_which_way_numbers_are_out_of_range() {
+        assert_eq!(
+            parse_number::<i8>("-129", "1,-129")
+                .unwrap_err()
+                .to_string(),
+            "\"-129\" in \"1,-129\" is too small for a i8 (at least -128)"
+        );
+        assert_eq!(
+            parse_number::<i16>("40000", "40000")
+                .unwrap_err()
+                .to_string(),
+            "\"40000\" is too large for a i16 (at most 32767)"
+        );
+    }
+
+    #[test]
+    fn rejects_tokens_that_are_not_numbers() {
+        assert_eq!(
+            parse_number::<u8>("", "").unwrap_err().to_string(),
+            "\"\" is not a valid u8 (cannot parse integer from empty string)"
+        );
+        assert_eq!(
+            parse_number::<u8>("--1", "--1").unwrap_err().to_string(),
+            "\"--1\" is not a valid u8 (invalid digit found in string)"
+        );
+    }
+}
@@ -0,0 +1,394 @@
+use crate::cancellation;
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+pub use crate::graph::Graph;
+
+/// Cheapest way found to get from one node to another.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<N> {
+    /// Total cost of every step along this [Path].
+    pub cost: u64,
+    /// Every node along this [Path], from the start to the goal inclusive.
+    pub nodes: Vec<N>,
+}
+
+/// Returns the cheapest [Path] from `start` to any node satisfying `is_goal`,
+/// using A* search guided by `heuristic`, or [Option::None] if no goal can be
+/// reached.
+///
+/// `neighbors` returns every node one step away from a node, along with the
+/// cost of that step. `heuristic` estimates the cost of getting from a node
+/// to the nearest goal; the returned [Path] is only guaranteed to be the
+/// cheapest if that estimate never exceeds the true cost.
+///
+/// Also returns [Option::None] if the search is cancelled through
+/// [cancellation] before reaching a goal.
+pub fn a_star<N, FN, IN, FH, FG>(
+    start: N,
+    mut neighbors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, u64)>,
+    FH: FnMut(&N) -> u64,
+    FG: FnMut(&N) -> bool,
+{
+    // Nodes are numbered as they are discovered so that the frontier only
+    // needs to hold plain indices.
+    let mut nodes = vec![start.clone()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut best_costs = vec![0];
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = frontier.pop() {
+        if cancellation::is_cancelled() {
+            return None;
+        }
+
+        if cost > best_costs[index] {
+            // A cheaper way to this node was found after this one was queued.
+            continue;
+        }
+
+        if is_goal(&nodes[index]) {
+            return Some(Path {
+                cost,
+                nodes: path_to(index, &nodes, &parents),
+            });
+        }
+
+        for (neighbor, step_cost) in neighbors(&nodes[index]) {
+            let neighbor_cost = cost + step_cost;
+
+            let neighbor_index = match indices.entry(neighbor) {
+                Entry::Occupied(entry) => {
+                    let neighbor_index = *entry.get();
+                    if neighbor_cost >= best_costs[neighbor_index] {
+                        continue;
+                    }
+
+                    best_costs[neighbor_index] = neighbor_cost;
+                    parents[neighbor_index] = Some(index);
+
+                    neighbor_index
+                }
+                Entry::Vacant(entry) => {
+                    let neighbor_index = nodes.len();
+                    nodes.push(entry.key().clone());
+                    entry.insert(neighbor_index);
+                    best_costs.push(neighbor_cost);
+                    parents.push(Some(index));
+
+                    neighbor_index
+                }
+            };
+
+            let estimate = neighbor_cost + heuristic(&nodes[neighbor_index]);
+            frontier.push(Reverse((estimate, neighbor_cost, neighbor_index)));
+        }
+    }
+
+    None
+}
+
+/// Returns every node along a path from `start` to the nearest node satisfying
+/// `is_goal`, using breadth-first search, or [Option::None] if no goal can be
+/// reached.
+///
+/// `neighbors` returns every node one step away from a node. Every step
+/// counts the same, so the returned path has as few steps as possible.
+pub fn bfs<N, FN, IN, FG>(start: N, neighbors: FN, is_goal: FG) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let (nodes, parents, goal_index) = breadth_first(start, neighbors, is_goal);
+
+    goal_index.map(|goal_index| path_to(goal_index, &nodes, &parents))
+}
+
+/// Returns every node reachable from `start`, including `start` itself, in
+/// the order that breadth-first search visits them.
+///
+/// `neighbors` returns every node one step away from a node.
+pub fn bfs_reachable<N, FN, IN>(start: N, neighbors: FN) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    breadth_first(start, neighbors, |_| false).0
+}
+
+/// Returns every node along the first path from `start` to a node satisfying
+/// `is_goal` found by depth-first search, or [Option::None] if no goal can be
+/// reached.
+///
+/// `neighbors` returns every node one step away from a node, which are
+/// explored in the order they are returned. The returned path is not
+/// necessarily the shortest; use [bfs] for that.
+pub fn dfs<N, FN, IN, FG>(start: N, neighbors: FN, is_goal: FG) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let (nodes, parents, goal_index) = depth_first(start, neighbors, is_goal);
+
+    goal_index.map(|goal_index| path_to(goal_index, &nodes, &parents))
+}
+
+/// Returns every node reachable from `start`, including `start` itself, in
+/// the order that depth-first search visits them.
+///
+/// `neighbors` returns every node one step away from a node, which are
+/// explored in the order they are returned.
+pub fn dfs_reachable<N, FN, IN>(start: N, neighbors: FN) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    depth_first(start, neighbors, |_| false).0
+}
+
+/// Returns the cheapest [Path] from `start` to any node satisfying `is_goal`,
+/// using Dijkstra's algorithm, or [Option::None] if no goal can be reached.
+///
+/// `neighbors` returns every node one step away from a node, along with the
+/// cost of that step.
+pub fn dijkstra<N, FN, IN, FG>(start: N, neighbors: FN, is_goal: FG) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, u64)>,
+    FG: FnMut(&N) -> bool,
+{
+    a_star(start, neighbors, |_| 0, is_goal)
+}
+
+/// Breadth-first search from `start` that stops at the first node satisfying
+/// `is_goal`, returning every node visited in order, the index of the node
+/// each was discovered from, and the index of the goal if one was reached
+/// before the search was cancelled through [cancellation].
+fn breadth_first<N, FN, IN, FG>(
+    start: N,
+    mut neighbors: FN,
+    mut is_goal: FG,
+) -> (Vec<N>, Vec<Option<usize>>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![start.clone()];
+    let mut parents = vec![None];
+    let mut is_discovered = HashSet::from([start]);
+    let mut frontier = VecDeque::from([0]);
+
+    while let Some(index) = frontier.pop_front() {
+        if cancellation::is_cancelled() {
+            break;
+        }
+
+        if is_goal(&nodes[index]) {
+            return (nodes, parents, Some(index));
+        }
+
+        for neighbor in neighbors(&nodes[index]) {
+            if !is_discovered.insert(neighbor.clone()) {
+                continue;
+            }
+
+            frontier.push_back(nodes.len());
+            nodes.push(neighbor);
+            parents.push(Some(index));
+        }
+    }
+
+    (nodes, parents, None)
+}
+
+/// Depth-first search from `start` that stops at the first node satisfying
+/// `is_goal`, returning every node visited in order, the index of the node
+/// each was reached from, and the index of the goal if one was reached
+/// before the search was cancelled through [cancellation].
+fn depth_first<N, FN, IN, FG>(
+    start: N,
+    mut neighbors: FN,
+    mut is_goal: FG,
+) -> (Vec<N>, Vec<Option<usize>>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![];
+    let mut parents = vec![];
+    let mut is_visited = HashSet::new();
+    let mut frontier = vec![(start, None)];
+
+    while let Some((node, parent)) = frontier.pop() {
+        if cancellation::is_cancelled() {
+            break;
+        }
+
+        if !is_visited.insert(node.clone()) {
+            continue;
+        }
+
+        let index = nodes.len();
+        nodes.push(node);
+        parents.push(parent);
+
+        if is_goal(&nodes[index]) {
+            return (nodes, parents, Some(index));
+        }
+
+        // The frontier is a stack, so neighbors are pushed in reverse for the
+        // first of them to be explored first.
+        let unvisited_neighbors = neighbors(&nodes[index])
+            .into_iter()
+            .filter(|neighbor| !is_visited.contains(neighbor))
+            .collect::<Vec<N>>();
+        frontier.extend(
+            unvisited_neighbors
+                .into_iter()
+                .rev()
+                .map(|neighbor| (neighbor, Some(index))),
+        );
+    }
+
+    (nodes, parents, None)
+}
+
+/// Returns every node along the path that leads to the node at index `index`,
+/// following `parents` back to the start.
+fn path_to<N: Clone>(index: usize, nodes: &[N], parents: &[Option<usize>]) -> Vec<N> {
+    let mut path = vec![];
+    let mut current = Some(index);
+
+    while let Some(current_index) = current {
+        path.push(nodes[current_index].clone());
+        current = parents[current_index];
+    }
+
+    path.reverse();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_fewest_steps_with_bfs() {
+        let path = bfs(0, |node| neighbors_of(*node), |node| *node == 4);
+
+        assert_eq!(path, Some(vec![0, 2, 4]));
+    }
+
+    #[test]
+    fn finds_first_path_explored_with_dfs() {
+        let path = dfs(0, |node| neighbors_of(*node), |node| *node == 4);
+
+        assert_eq!(path, Some(vec![0, 1, 3, 4]));
+    }
+
+    #[test]
+    fn finds_cheapest_path_with_dijkstra_and_a_star() {
+        let expected = Some(Path {
+            cost: 4,
+            nodes: vec![0, 1, 3, 4],
+        });
+
+        assert_eq!(
+            dijkstra(0, |node| weighted_neighbors_of(*node), |node| *node == 4),
+            expected
+        );
+        assert_eq!(
+            a_star(
+                0,
+                |node| weighted_neighbors_of(*node),
+                |node| 4u64.saturating_sub(*node),
+                |node| *node == 4
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn finds_no_path_to_unreachable_nodes() {
+        assert_eq!(bfs(0, |node| neighbors_of(*node), |node| *node == 5), None);
+        assert_eq!(dfs(0, |node| neighbors_of(*node), |node| *node == 5), None);
+        assert_eq!(
+            dijkstra(0, |node| weighted_neighbors_of(*node), |node| *node == 5),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_start_as_its_own_goal() {
+        assert_eq!(
+            bfs(5, |node| neighbors_of(*node), |node| *node == 5),
+            Some(vec![5])
+        );
+        assert_eq!(
+            dijkstra(5, |node| weighted_neighbors_of(*node), |node| *node == 5),
+            Some(Path {
+                cost: 0,
+                nodes: vec![5],
+            })
+        );
+    }
+
+    #[test]
+    fn reaches_every_connected_node_once() {
+        assert_eq!(
+            bfs_reachable(0, |node| neighbors_of(*node)),
+            [0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            dfs_reachable(0, |node| neighbors_of(*node)),
+            [0, 1, 3, 4, 2]
+        );
+        assert_eq!(bfs_reachable(5, |node| neighbors_of(*node)), [5]);
+    }
+
+    /// Returns the neighbors of `node` in a small graph where `0` reaches `4`
+    /// in two steps through `2` or three through `1` and `3`, and `5` is
+    /// disconnected from the rest.
+    fn neighbors_of(node: u64) -> Vec<u64> {
+        weighted_neighbors_of(node)
+            .into_iter()
+            .map(|(neighbor, _)| neighbor)
+            .collect()
+    }
+
+    /// Returns the neighbors of `node` in the graph of [neighbors_of], along
+    /// with step costs that make the longer way from `0` to `4` the cheaper.
+    fn weighted_neighbors_of(node: u64) -> Vec<(u64, u64)> {
+        match node {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(0, 1), (3, 1)],
+            2 => vec![(0, 5), (4, 5)],
+            3 => vec![(1, 1), (4, 2)],
+            4 => vec![(2, 5), (3, 2)],
+            _ => vec![],
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use pprof::protos::Message;
+use pprof::{ProfilerGuard, ProfilerGuardBuilder, Report};
+use std::fs::File;
+use std::io::Write;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::SystemTime;
+
+/// Name of the thread that runs `main`, which the operating system knows by
+/// the name of the program instead.
+const MAIN_THREAD_NAME: &str = "main";
+
+/// How many times per second the call stack is sampled while profiling.
+const SAMPLING_FREQUENCY: i32 = 997;
+
+/// Is `true` while a [Profile] is being sampled.
+static IS_SAMPLING: AtomicBool = AtomicBool::new(false);
+
+/// Every phase that [time_phase] ran while a [Profile] was being sampled, in
+/// the order that they finished.
+static PHASE_WINDOWS: Mutex<Vec<PhaseWindow>> = Mutex::new(Vec::new());
+
+/// Command-line arguments that control CPU profiling of a program.
+#[derive(Debug, Parser)]
+pub struct ProfileArgs {
+    /// Samples where the CPU spends its time while the puzzle is read and
+    /// solved, writing the profile to the specified file: as a flamegraph if
+    /// it ends with ".svg", and in pprof's protobuf format otherwise.
+    ///
+    /// Flamegraphs of each timed phase, such as parsing and solving each
+    /// part, are written next to it too, named after the phase (e.g.
+    /// "profile-part-one.svg" beside "profile.svg").
+    #[clap(long, value_name = "FILE")]
+    pub profile: Option<PathBuf>,
+}
+
+impl ProfileArgs {
+    /// Starts sampling the call stack if a `--profile` file was specified,
+    /// returning the [Profile] to [Profile::finish] once solving is done, or
+    /// [Option::None] if no `--profile` file was specified.
+    pub fn start(&self) -> Result<Option<Profile>> {
+        let profile_file_path = match &self.profile {
+            Some(profile_file_path) => profile_file_path.clone(),
+            None => return Ok(None),
+        };
+
+        let guard = ProfilerGuardBuilder::default()
+            .frequency(SAMPLING_FREQUENCY)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .context("Failed to start profiling")?;
+        IS_SAMPLING.store(true, Ordering::Relaxed);
+
+        Ok(Some(Profile {
+            guard,
+            profile_file_path,
+        }))
+    }
+}
+
+/// CPU profile that is being sampled until it is finished.
+pub struct Profile {
+    /// Keeps sampling the call stack until dropped.
+    guard: ProfilerGuard<'static>,
+    /// Path of the file that the finished profile is written to.
+    profile_file_path: PathBuf,
+}
+
+impl Profile {
+    /// Stops sampling the call stack and writes what was sampled to the
+    /// `--profile` file, followed by a flamegraph of every phase that was
+    /// sampled if that file is a flamegraph too.
+    pub fn finish(self) -> Result<()> {
+        IS_SAMPLING.store(false, Ordering::Relaxed);
+        let report = self
+            .guard
+            .report()
+            .build()
+            .context("Failed to build CPU profile")?;
+        let phase_windows = mem::take(&mut *PHASE_WINDOWS.lock().unwrap());
+        let mut profile_file = File::create(&self.profile_file_path).with_context(|| {
+            format!(
+                "Failed to create file at path \"{}\"",
+                self.profile_file_path.display()
+            )
+        })?;
+
+        if self
+            .profile_file_path
+            .extension()
+            .is_some_and(|extension| extension == "svg")
+        {
+            report
+                .flamegraph(&mut profile_file)
+                .context("Failed to write flamegraph")?;
+
+            for phase_window in phase_windows.iter() {
+                write_phase_flamegraph(&report, phase_window, &self.profile_file_path)?;
+            }
+        } else {
+            let profile = report.pprof().context("Failed to encode CPU profile")?;
+            let mut encoded_profile = vec![];
+            profile
+                .encode(&mut encoded_profile)
+                .context("Failed to encode CPU profile")?;
+
+            profile_file
+                .write_all(&encoded_profile)
+                .context("Failed to write CPU profile")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// When a phase of solving a puzzle ran, and on which thread, so that its
+/// samples can be told apart from the rest of a [Profile].
+struct PhaseWindow {
+    /// When the phase finished.
+    finished_at: SystemTime,
+    /// Label of the phase, such as "Parse" or "Part one".
+    label: String,
+    /// When the phase started.
+    started_at: SystemTime,
+    /// Name of the thread that ran the phase, or [Option::None] if it ran on
+    /// the main thread, whose samples are told apart by time alone.
+    thread_name: Option<String>,
+}
+
+impl PhaseWindow {
+    /// Returns `true` if the call stack sampled in `frames` belongs to this
+    /// phase.
+    fn contains(&self, frames: &pprof::Frames) -> bool {
+        (self.started_at..=self.finished_at).contains(&frames.sample_timestamp)
+            && self
+                .thread_name
+                .as_ref()
+                .is_none_or(|thread_name| frames.thread_name == *thread_name)
+    }
+}
+
+/// Returns the path of the flamegraph of the phase labeled `label` that is
+/// written next to the `--profile` file at `profile_file_path`.
+pub fn phase_profile_path_of(profile_file_path: &Path, label: &str) -> PathBuf {
+    let stem = profile_file_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+
+    profile_file_path.with_file_name(format!(
+        "{}-{}.svg",
+        stem,
+        label.to_lowercase().replace(' ', "-")
+    ))
+}
+
+/// Runs `phase`, remembering when it ran as the phase labeled `label` if a
+/// [Profile] is being sampled, and returns whatever it returned.
+pub fn time_phase<T>(label: &str, phase: impl FnOnce() -> T) -> T {
+    if !IS_SAMPLING.load(Ordering::Relaxed) {
+        return phase();
+    }
+
+    let started_at = SystemTime::now();
+    let output = phase();
+    let thread_name = thread::current()
+        .name()
+        .filter(|thread_name| *thread_name != MAIN_THREAD_NAME)
+        .map(str::to_string);
+
+    PHASE_WINDOWS.lock().unwrap().push(PhaseWindow {
+        finished_at: SystemTime::now(),
+        label: label.to_string(),
+        started_at,
+        thread_name,
+    });
+
+    output
+}
+
+/// Writes a flamegraph of the samples in `report` that belong to
+/// `phase_window` next to the `--profile` file at `profile_file_path`,
+/// unless none of them do.
+fn write_phase_flamegraph(
+    report: &Report,
+    phase_window: &PhaseWindow,
+    profile_file_path: &Path,
+) -> Result<()> {
+    let phase_report = Report {
+        data: report
+            .data
+            .iter()
+            .filter(|(frames, _)| phase_window.contains(frames))
+            .map(|(frames, count)| (frames.clone(), *count))
+            .collect(),
+        timing: report.timing.clone(),
+    };
+    if phase_report.data.is_empty() {
+        return Ok(());
+    }
+
+    let phase_profile_path = phase_profile_path_of(profile_file_path, &phase_window.label);
+    let phase_profile_file = File::create(&phase_profile_path).with_context(|| {
+        format!(
+            "Failed to create file at path \"{}\"",
+            phase_profile_path.display()
+        )
+    })?;
+
+    phase_report
+        .flamegraph(phase_profile_file)
+        .with_context(|| format!("Failed to write flamegraph of {}", phase_window.label))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_phase_profiles_after_their_phases() {
+        assert_eq!(
+            phase_profile_path_of(Path::new("profiles/day-4.svg"), "Part one"),
+            Path::new("profiles/day-4-part-one.svg")
+        );
+        assert_eq!(
+            phase_profile_path_of(Path::new("profile.svg"), "Parse"),
+            Path::new("profile-parse.svg")
+        );
+    }
+}
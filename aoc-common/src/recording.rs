@@ -0,0 +1,205 @@
+use crate::error::WrongAnswer;
+use crate::table::Table;
+
+use anyhow::{anyhow, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+/// Everything that a day's program decided while solving its puzzle, from
+/// the input that it parsed to the answers that it printed, as [record]ed by
+/// `--record`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Recording<I, D> {
+    /// Answers that were printed, in order.
+    pub answers: Vec<String>,
+    /// Day of December whose puzzle was solved.
+    pub day: u8,
+    /// Intermediate decisions that led to the answers, in the order that
+    /// they were made.
+    pub decisions: Vec<D>,
+    /// Parsed puzzle input.
+    pub input: I,
+}
+
+impl<I, D> Recording<I, D> {
+    /// Reads the [Recording] written to `recording_path`.
+    pub fn load(recording_path: &Path) -> Result<Recording<I, D>>
+    where
+        I: DeserializeOwned,
+        D: DeserializeOwned,
+    {
+        let serialized_recording = fs::read_to_string(recording_path)
+            .with_context(|| format!("Failed to read \"{}\"", recording_path.display()))?;
+
+        serde_json::from_str(&serialized_recording).with_context(|| {
+            format!(
+                "\"{}\" is not a recording of this program",
+                recording_path.display()
+            )
+        })
+    }
+
+    /// Writes this [Recording] to the JSON file at `recording_path`.
+    pub fn save(&self, recording_path: &Path) -> Result<()>
+    where
+        I: Serialize,
+        D: Serialize,
+    {
+        let serialized_recording = serde_json::to_string(self)?;
+
+        fs::write(recording_path, format!("{}\n", serialized_recording)).with_context(|| {
+            format!(
+                "Failed to write recording to \"{}\"",
+                recording_path.display()
+            )
+        })
+    }
+}
+
+/// Solves `input` with `solve`, which returns the decisions that it made
+/// along with its [Table] of answers, writing all of them to
+/// `recording_path` for a later [replay].
+///
+/// Returns the [Table] of answers, noting how many decisions were recorded.
+pub fn record<I, D, F>(day: u8, input: I, recording_path: &Path, solve: F) -> Result<Table>
+where
+    I: Serialize,
+    D: Serialize,
+    F: FnOnce(&I) -> Result<(Vec<D>, Table)>,
+{
+    let (decisions, mut table) = solve(&input)?;
+    let recording = Recording {
+        answers: table.answers().map(str::to_string).collect(),
+        day,
+        decisions,
+        input,
+    };
+    recording.save(recording_path)?;
+
+    table
+        .separator()
+        .entry("Recorded decisions", recording.decisions.len());
+
+    Ok(table)
+}
+
+/// Solves the input recorded to `recording_path` again with `solve`,
+/// returning its [Table] of answers if it makes exactly the decisions and
+/// reaches exactly the answers that were recorded.
+///
+/// Otherwise, the first decision that differs is reported as a
+/// [WrongAnswer], so that a change in behavior can be traced back to the
+/// step where it began.
+pub fn replay<I, D, F>(day: u8, recording_path: &Path, solve: F) -> Result<Table>
+where
+    I: DeserializeOwned,
+    D: Debug + DeserializeOwned + PartialEq,
+    F: FnOnce(&I) -> Result<(Vec<D>, Table)>,
+{
+    let recording = Recording::<I, D>::load(recording_path)?;
+    if recording.day != day {
+        return Err(anyhow!(
+            "\"{}\" holds a recording of day {}, not day {}",
+            recording_path.display(),
+            recording.day,
+            day
+        ));
+    }
+
+    let (decisions, mut table) = solve(&recording.input)?;
+
+    let divergence = decisions
+        .iter()
+        .zip(&recording.decisions)
+        .position(|(decision, recorded_decision)| decision != recorded_decision);
+    if let Some(index) = divergence {
+        return Err(anyhow!(WrongAnswer::new(format!(
+            "Decision {} differs from \"{}\": {:?} was decided instead of {:?}",
+            index + 1,
+            recording_path.display(),
+            decisions[index],
+            recording.decisions[index]
+        ))));
+    }
+    if decisions.len() != recording.decisions.len() {
+        return Err(anyhow!(WrongAnswer::new(format!(
+            "{} decisions were made instead of the {} in \"{}\"",
+            decisions.len(),
+            recording.decisions.len(),
+            recording_path.display()
+        ))));
+    }
+
+    let answers = table.answers().collect::<Vec<&str>>();
+    if answers != recording.answers {
+        return Err(anyhow!(WrongAnswer::new(format!(
+            "The answers changed from {} to {} despite the same decisions in \"{}\"",
+            recording.answers.join(", "),
+            answers.join(", "),
+            recording_path.display()
+        ))));
+    }
+
+    table
+        .separator()
+        .entry("Replayed decisions", decisions.len());
+
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use std::env;
+    use std::process;
+
+    /// Returns the running sums of `numbers` as decisions, along with a
+    /// [Table] whose only answer is their total.
+    fn sum(numbers: &[u32; 3]) -> Result<(Vec<u32>, Table)> {
+        let running_sums = numbers
+            .iter()
+            .scan(0, |total, number| {
+                *total += number;
+                Some(*total)
+            })
+            .collect::<Vec<u32>>();
+        let total = running_sums.last().copied().unwrap_or_default();
+
+        Ok((running_sums, Table::new().answer("Total", total).clone()))
+    }
+
+    #[test]
+    fn replays_recorded_decisions() {
+        let recording_path = env::temp_dir().join(format!("aoc-recording-{}.json", process::id()));
+        record(1, [1, 2, 3], &recording_path, sum).unwrap();
+
+        assert_eq!(
+            Recording::<[u32; 3], u32>::load(&recording_path).unwrap(),
+            Recording {
+                answers: vec!["6".to_string()],
+                day: 1,
+                decisions: vec![1, 3, 6],
+                input: [1, 2, 3],
+            }
+        );
+        assert!(replay(1, &recording_path, sum).is_ok());
+
+        let diverged = replay(1, &recording_path, |numbers: &[u32; 3]| {
+            let (mut running_sums, table) = sum(numbers)?;
+            running_sums[1] += 1;
+
+            Ok((running_sums, table))
+        })
+        .unwrap_err();
+        assert_eq!(ErrorKind::of(&diverged), ErrorKind::WrongAnswer);
+        assert!(diverged.to_string().starts_with("Decision 2 differs"));
+
+        assert!(replay(2, &recording_path, sum).is_err());
+
+        fs::remove_file(&recording_path).unwrap();
+    }
+}
@@ -0,0 +1,346 @@
+use crate::args::{CommonArgs, ParseArgs, Part};
+use crate::solver::{self, Solver};
+use crate::table::Table;
+use crate::{batch, completions, input, shuffle, watch};
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use std::env;
+use std::ffi::OsString;
+use std::process::ExitCode;
+
+/// [Solver] of any [Solver::Parsed] type, so that solvers of different
+/// puzzles can be kept side by side in a [Registry].
+///
+/// Every [Solver] that can be shared across threads is an [AnySolver].
+pub trait AnySolver: Send + Sync {
+    /// Returns the day of December whose puzzle this [AnySolver] solves.
+    fn day(&self) -> u8;
+
+    /// Returns a [Table] of the answers to the puzzle described by `input`,
+    /// including only the specified `part` if there is one and both parts
+    /// otherwise.
+    fn answers_table(&self, input: &str, part: Option<Part>) -> Result<Table>;
+
+    /// Returns a [Table] of the answers to the puzzle described by `input`,
+    /// like [AnySolver::answers_table], followed by how long parsing `input`
+    /// and solving each part took.
+    fn answers_table_with_timings(&self, input: &str, part: Option<Part>) -> Result<Table>;
+
+    /// Returns `input` rearranged by `seed` like [Solver::shuffle], if the
+    /// puzzle allows it.
+    fn shuffle(&self, input: &str, seed: u64) -> Option<String>;
+}
+
+impl<S: Solver + Send + Sync> AnySolver for S {
+    fn day(&self) -> u8 {
+        Solver::day(self)
+    }
+
+    fn answers_table(&self, input: &str, part: Option<Part>) -> Result<Table> {
+        solver::answers_table(self, input, part)
+    }
+
+    fn answers_table_with_timings(&self, input: &str, part: Option<Part>) -> Result<Table> {
+        solver::answers_table_with_timings(self, input, part)
+    }
+
+    fn shuffle(&self, input: &str, seed: u64) -> Option<String> {
+        Solver::shuffle(self, input, seed)
+    }
+}
+
+/// [Solver] added to a [Registry], along with what tells it apart from the
+/// other solvers of the same puzzle.
+pub struct Registration {
+    /// Solves the puzzle.
+    pub solver: Box<dyn AnySolver>,
+    /// Name that tells this [Registration] apart from others for the same
+    /// puzzle, e.g. the algorithm that it uses.
+    pub variant: String,
+    /// Year whose Advent of Code the puzzle is from.
+    pub year: u16,
+}
+
+/// Collection of [Solver]s, possibly for other years or with alternative
+/// algorithms, that a crate outside of this workspace can solve puzzles with
+/// through the same command-line interface as every day here.
+///
+/// [Registry::run] solves puzzles with only the solvers registered here. To
+/// solve them with the `aoc` program instead, along with every day of this
+/// workspace, register them after `aoc_solvers::register_all` and pass the
+/// [Registry] to `aoc::main_with`.
+///
+/// ```no_run
+/// use anyhow::Result;
+/// use aoc_common::answer::Answer;
+/// use aoc_common::registry::Registry;
+/// use aoc_common::solver::Solver;
+/// use std::process::ExitCode;
+///
+/// struct ReportRepairSolver;
+///
+/// impl Solver for ReportRepairSolver {
+///     type Parsed = Vec<u32>;
+///
+///     fn day(&self) -> u8 {
+///         1
+///     }
+///
+///     fn parse(&self, input: &str) -> Result<Vec<u32>> {
+///         Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+///     }
+///
+///     fn part_one(&self, entries: &Vec<u32>) -> Result<Answer> {
+///         Ok(entries.iter().sum::<u32>().into())
+///     }
+///
+///     fn part_two(&self, entries: &Vec<u32>) -> Result<Answer> {
+///         Ok(entries.iter().product::<u32>().into())
+///     }
+/// }
+///
+/// fn main() -> ExitCode {
+///     // e.g. `report-repair --year 2020 --day 1 --input expenses.txt`
+///     Registry::new()
+///         .register(2020, "naive", ReportRepairSolver)
+///         .run()
+/// }
+/// ```
+#[derive(Default)]
+pub struct Registry {
+    /// Every [Registration], in the order that they were registered.
+    registrations: Vec<Registration>,
+}
+
+/// Command-line arguments accepted by [Registry::run].
+#[derive(Debug, Parser)]
+#[clap(about = "Solves the registered Advent of Code puzzles")]
+pub struct RegistryArgs {
+    /// Year whose Advent of Code the puzzle to solve is from.
+    #[clap(long, required_unless_present_any = ["completions", "list"])]
+    pub year: Option<u16>,
+
+    /// Day of December whose puzzle to solve.
+    #[clap(long, required_unless_present_any = ["completions", "list"])]
+    pub day: Option<u8>,
+
+    /// Solves the puzzle with the registered solver of the specified variant
+    /// instead of the first one registered for it.
+    #[clap(long)]
+    pub variant: Option<String>,
+
+    /// Lists every registered solver instead of solving anything.
+    #[clap(long, conflicts_with_all = ["year", "day", "variant"])]
+    pub list: bool,
+
+    /// Solves the puzzle, and then solves it again with its input shuffled
+    /// by the specified seed, failing if the answers change.
+    #[clap(long, value_name = "SEED")]
+    pub shuffle: Option<u64>,
+
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
+
+impl Registry {
+    /// Creates an empty [Registry].
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Adds `solver`, which solves a puzzle from the Advent of Code of
+    /// `year`, to this [Registry] under the specified `variant`.
+    pub fn register(
+        &mut self,
+        year: u16,
+        variant: impl Into<String>,
+        solver: impl AnySolver + 'static,
+    ) -> &mut Registry {
+        self.registrations.push(Registration {
+            solver: Box::new(solver),
+            variant: variant.into(),
+            year,
+        });
+
+        self
+    }
+
+    /// Returns the [Registration] of the specified `variant` for the puzzle
+    /// of `day` in `year`, or the first one registered for that puzzle if no
+    /// `variant` is specified.
+    pub fn get(&self, year: u16, day: u8, variant: Option<&str>) -> Option<&Registration> {
+        self.registrations.iter().find(|registration| {
+            registration.year == year
+                && registration.solver.day() == day
+                && variant.is_none_or(|variant| registration.variant == variant)
+        })
+    }
+
+    /// Returns every [Registration] in this [Registry], in the order that
+    /// they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = &Registration> {
+        self.registrations.iter()
+    }
+
+    /// Solves the puzzle chosen by the command-line arguments with the
+    /// solver registered for it, or does whatever else they ask for instead,
+    /// returning the [ExitCode] that the program should exit with.
+    pub fn run(&self) -> ExitCode {
+        self.run_from(env::args_os())
+    }
+
+    /// Solves the puzzle chosen by `args`, command-line arguments starting
+    /// with the name of the program, like [Registry::run] does with the ones
+    /// that this program was run with.
+    pub fn run_from<I, T>(&self, args: I) -> ExitCode
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let args = RegistryArgs::parse_args_from(args);
+
+        args.common.run(|| self.run_with(&args))
+    }
+
+    /// Does whatever `args` asks for with the solvers in this [Registry].
+    fn run_with(&self, args: &RegistryArgs) -> Result<()> {
+        if let Some(shell) = args.common.completions {
+            return completions::print::<RegistryArgs>(shell);
+        }
+
+        if args.list {
+            for registration in self.iter() {
+                println!(
+                    "{} day {} ({})",
+                    registration.year,
+                    registration.solver.day(),
+                    registration.variant
+                );
+            }
+
+            return Ok(());
+        }
+
+        // Both are required unless `--list` or `--completions` is specified.
+        let (year, day) = args.year.zip(args.day).unwrap_or_default();
+        let variant = args.variant.as_deref();
+        let registration = self.get(year, day, variant).ok_or_else(|| match variant {
+            Some(variant) => anyhow!(
+                "No solver of variant \"{}\" is registered for {} day {}",
+                variant,
+                year,
+                day
+            ),
+            None => anyhow!("No solver is registered for {} day {}", year, day),
+        })?;
+        let solver = &registration.solver;
+
+        let _tracing_guard = args.common.start_tracing()?;
+
+        if let Some(inputs) = &args.common.inputs {
+            return batch::write_answers_csv(
+                inputs,
+                args.common.out.as_deref(),
+                args.common.part,
+                |input, part| solver.answers_table(input, Some(part)),
+            );
+        }
+
+        if args.common.watch {
+            return watch::watch_answers(&args.common, |input| {
+                solver.answers_table_with_timings(input, args.common.part)
+            });
+        }
+
+        args.common.forbid_visualize(day)?;
+
+        let input = input::load(args.common.input_source())?;
+
+        if let Some(seed) = args.shuffle {
+            let shuffled_input = solver
+                .shuffle(&input, seed)
+                .ok_or_else(|| anyhow!("The input of {} day {} cannot be shuffled", year, day))?;
+
+            return args.common.print(&shuffle::check_answers_unchanged(
+                &input,
+                &shuffled_input,
+                seed,
+                |input| solver.answers_table(input, args.common.part),
+            )?);
+        }
+
+        args.common.print_answers(
+            day,
+            &solver.answers_table_with_timings(&input, args.common.part)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    /// [Solver] that answers with the sum or product of the numbers on every
+    /// line of its input.
+    struct ArithmeticSolver(u8);
+
+    impl Solver for ArithmeticSolver {
+        type Parsed = Vec<u64>;
+
+        fn day(&self) -> u8 {
+            self.0
+        }
+
+        fn parse(&self, input: &str) -> Result<Vec<u64>> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(&self, numbers: &Vec<u64>) -> Result<Answer> {
+            Ok(numbers.iter().sum::<u64>().into())
+        }
+
+        fn part_two(&self, numbers: &Vec<u64>) -> Result<Answer> {
+            Ok(numbers.iter().product::<u64>().into())
+        }
+    }
+
+    #[test]
+    fn finds_registered_solvers_by_year_day_and_variant() {
+        let mut registry = Registry::new();
+        registry
+            .register(2020, "first", ArithmeticSolver(1))
+            .register(2020, "second", ArithmeticSolver(1))
+            .register(2021, "first", ArithmeticSolver(2));
+
+        let registration = registry.get(2020, 1, None).unwrap();
+        assert_eq!(registration.variant, "first");
+        assert_eq!(
+            registration
+                .solver
+                .answers_table("2\n3\n4", None)
+                .unwrap()
+                .answers()
+                .collect::<Vec<&str>>(),
+            ["9", "24"]
+        );
+
+        assert_eq!(
+            registry.get(2020, 1, Some("second")).unwrap().variant,
+            "second"
+        );
+        assert!(registry.get(2020, 1, Some("third")).is_none());
+        assert!(registry.get(2020, 2, None).is_none());
+        assert_eq!(registry.get(2021, 2, None).unwrap().year, 2021);
+        assert_eq!(registry.iter().count(), 3);
+    }
+
+    #[test]
+    fn requires_a_puzzle_unless_listing_solvers() {
+        assert!(RegistryArgs::try_parse_from(["aoc", "--list"]).is_ok());
+        assert!(RegistryArgs::try_parse_from(["aoc", "--year", "2020", "--day", "1"]).is_ok());
+        assert!(RegistryArgs::try_parse_from(["aoc", "--year", "2020"]).is_err());
+        assert!(RegistryArgs::try_parse_from(["aoc", "--list", "--day", "1"]).is_err());
+    }
+}
@@ -0,0 +1,230 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// ANSI escape sequence that resets all styling.
+const RESET: &str = "\x1b[0m";
+
+/// Enumerates the colors that a [Cell] can be rendered in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Color {
+    Blue,
+    Cyan,
+    Green,
+    Magenta,
+    Red,
+    Yellow,
+}
+
+impl Color {
+    /// Returns the number that selects this [Color] within the ANSI escape
+    /// sequences of foreground (`3x`) and background (`4x`) colors.
+    fn ansi_index(&self) -> u8 {
+        match self {
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+        }
+    }
+}
+
+/// How the text of a [Cell] is rendered in a terminal.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Style {
+    /// Color behind the text, if it has one.
+    pub background: Option<Color>,
+    /// Color of the text itself, if it has one.
+    pub foreground: Option<Color>,
+    /// Is `true` if the text is emboldened.
+    pub is_bold: bool,
+    /// Is `true` if the text is faint, so that it recedes behind the rest.
+    pub is_dim: bool,
+}
+
+impl Style {
+    /// [Style] of text without any styling.
+    pub const PLAIN: Style = Style {
+        background: None,
+        foreground: None,
+        is_bold: false,
+        is_dim: false,
+    };
+
+    /// Returns this [Style] with the text emboldened.
+    pub fn bold(self) -> Style {
+        Style {
+            is_bold: true,
+            ..self
+        }
+    }
+
+    /// Returns this [Style] with the text faint.
+    pub fn dim(self) -> Style {
+        Style {
+            is_dim: true,
+            ..self
+        }
+    }
+
+    /// Returns this [Style] with the text colored `color`.
+    pub fn fg(self, color: Color) -> Style {
+        Style {
+            foreground: Some(color),
+            ..self
+        }
+    }
+
+    /// Returns this [Style] with `color` behind the text.
+    pub fn on(self, color: Color) -> Style {
+        Style {
+            background: Some(color),
+            ..self
+        }
+    }
+
+    /// Returns the ANSI escape sequence that starts this [Style], or
+    /// [Option::None] if it has no styling.
+    fn escape(&self) -> Option<String> {
+        let mut parameters = Vec::new();
+
+        if self.is_bold {
+            parameters.push(String::from("1"));
+        }
+        if self.is_dim {
+            parameters.push(String::from("2"));
+        }
+        if let Some(foreground) = self.foreground {
+            parameters.push(alloc::format!("3{}", foreground.ansi_index()));
+        }
+        if let Some(background) = self.background {
+            parameters.push(alloc::format!("4{}", background.ansi_index()));
+        }
+
+        if parameters.is_empty() {
+            return None;
+        }
+
+        Some(alloc::format!("\x1b[{}m", parameters.join(";")))
+    }
+}
+
+/// Text rendered in a single [Style] at one position of a [Renderable].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cell {
+    /// How [Cell::text] is rendered.
+    pub style: Style,
+    /// What this [Cell] shows.
+    pub text: String,
+}
+
+impl Cell {
+    /// Creates a [Cell] that shows `text` in the specified `style`.
+    pub fn new(text: impl Into<String>, style: Style) -> Cell {
+        Cell {
+            style,
+            text: text.into(),
+        }
+    }
+}
+
+/// Grid-shaped value that can be drawn in a terminal, row by row, with each
+/// of its cells styled on its own.
+///
+/// ```
+/// use aoc_common::render::{Cell, Color, Renderable, Style};
+///
+/// struct Checkerboard;
+///
+/// impl Renderable for Checkerboard {
+///     fn cells(&self) -> Vec<Vec<Cell>> {
+///         (0..2)
+///             .map(|row| {
+///                 (0..2)
+///                     .map(|column| match (row + column) % 2 {
+///                         0 => Cell::new("#", Style::PLAIN.fg(Color::Red)),
+///                         _ => Cell::new(".", Style::PLAIN),
+///                     })
+///                     .collect()
+///             })
+///             .collect()
+///     }
+/// }
+///
+/// assert_eq!(Checkerboard.render(false), "#.\n.#\n");
+/// assert_eq!(Checkerboard.render(true), "\x1b[31m#\x1b[0m.\n.\x1b[31m#\x1b[0m\n");
+/// ```
+pub trait Renderable {
+    /// Returns every row of this [Renderable], from top to bottom, as its
+    /// [Cell]s from left to right.
+    fn cells(&self) -> Vec<Vec<Cell>>;
+
+    /// Returns the number of spaces between adjacent [Cell]s of a row.
+    fn column_gap(&self) -> usize {
+        0
+    }
+
+    /// Returns every row of this [Renderable] on its own line, with the
+    /// styles of its [Cell]s rendered as ANSI escape sequences if
+    /// `is_colored` is `true`.
+    fn render(&self, is_colored: bool) -> String {
+        let gap = " ".repeat(self.column_gap());
+        let mut rendering = String::new();
+
+        for row in self.cells() {
+            for (column_index, cell) in row.iter().enumerate() {
+                if column_index > 0 {
+                    rendering.push_str(&gap);
+                }
+
+                match cell.style.escape().filter(|_| is_colored) {
+                    Some(escape) => {
+                        rendering.push_str(&escape);
+                        rendering.push_str(&cell.text);
+                        rendering.push_str(RESET);
+                    }
+                    None => rendering.push_str(&cell.text),
+                }
+            }
+
+            rendering.push('\n');
+        }
+
+        rendering
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    /// [Renderable] of a single row of spaced out cells.
+    struct Row(Vec<Cell>);
+
+    impl Renderable for Row {
+        fn cells(&self) -> Vec<Vec<Cell>> {
+            vec![self.0.clone()]
+        }
+
+        fn column_gap(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn combines_styles_into_one_escape() {
+        let row = Row(vec![
+            Cell::new("a", Style::PLAIN.bold().fg(Color::Green).on(Color::Blue)),
+            Cell::new("b", Style::PLAIN.dim()),
+            Cell::new("c", Style::PLAIN),
+        ]);
+
+        assert_eq!(
+            row.render(true),
+            "\x1b[1;32;44ma\x1b[0m \x1b[2mb\x1b[0m c\n"
+        );
+        assert_eq!(row.render(false), "a b c\n");
+    }
+}
@@ -0,0 +1,100 @@
+use crate::error::WrongAnswer;
+use crate::parse;
+use crate::table::Table;
+
+use anyhow::{anyhow, Context, Result};
+
+/// Rearranges `items` into a pseudorandom order determined entirely by
+/// `seed`, so that the same seed always produces the same order.
+///
+/// ```
+/// use aoc_common::shuffle;
+///
+/// let mut items = [1, 2, 3, 4, 5];
+/// shuffle::shuffle(&mut items, 7);
+///
+/// let mut same_items = [1, 2, 3, 4, 5];
+/// shuffle::shuffle(&mut same_items, 7);
+///
+/// assert_eq!(items, same_items);
+/// assert_ne!(items, [1, 2, 3, 4, 5]);
+/// ```
+pub fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut state = seed;
+
+    // Fisher-Yates, drawing from SplitMix64 instead of an external random
+    // number generator whose output could change between versions.
+    for i in (1..items.len()).rev() {
+        let j = (split_mix_64(&mut state) % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+/// Returns the lines of `input` rearranged by [shuffle].
+pub fn shuffle_lines(input: &str, seed: u64) -> String {
+    let mut lines = input.lines().collect::<Vec<&str>>();
+    shuffle(&mut lines, seed);
+
+    lines.join("\n")
+}
+
+/// Returns the paragraphs of `input` (as split by [parse::paragraphs]) after
+/// the first `fixed_paragraphs` of them rearranged by [shuffle], keeping the
+/// first `fixed_paragraphs` where they are.
+pub fn shuffle_paragraphs(input: &str, fixed_paragraphs: usize, seed: u64) -> String {
+    let mut paragraphs = parse::paragraphs(input).collect::<Vec<&str>>();
+    let first_shuffled_paragraph = fixed_paragraphs.min(paragraphs.len());
+    shuffle(&mut paragraphs[first_shuffled_paragraph..], seed);
+
+    paragraphs.join("\n\n")
+}
+
+/// Solves both `input` and `shuffled_input`, its rearrangement by `seed`,
+/// with `solve`, returning the [Table] of answers to `input` if the answers
+/// to both agree.
+///
+/// The puzzle does not care how its input is ordered wherever it was
+/// rearranged, so the answers disagreeing means the solution depends on an
+/// order that it should not; this is reported as a [WrongAnswer].
+pub fn check_answers_unchanged<F>(
+    input: &str,
+    shuffled_input: &str,
+    seed: u64,
+    mut solve: F,
+) -> Result<Table>
+where
+    F: FnMut(&str) -> Result<Table>,
+{
+    let mut table = solve(input)?;
+    let shuffled_table = solve(shuffled_input)
+        .with_context(|| format!("Failed to solve the input shuffled with seed {}", seed))?;
+
+    let answers = table.answers().collect::<Vec<&str>>();
+    let shuffled_answers = shuffled_table.answers().collect::<Vec<&str>>();
+    if answers != shuffled_answers {
+        return Err(anyhow!(WrongAnswer::new(format!(
+            "Shuffling the input with seed {} changed the answers from {} to {}",
+            seed,
+            answers.join(", "),
+            shuffled_answers.join(", ")
+        ))));
+    }
+
+    table
+        .separator()
+        .entry("Unchanged by shuffling with seed", seed);
+
+    Ok(table)
+}
+
+/// Advances `state` and returns the next number in the SplitMix64 sequence
+/// that it produces.
+fn split_mix_64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    z ^ (z >> 31)
+}
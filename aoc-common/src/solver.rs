@@ -0,0 +1,130 @@
+use crate::answer::Answer;
+use crate::args::{CommonArgs, Part};
+use crate::error::InvalidInput;
+use crate::table::Table;
+use crate::timing::Timings;
+use crate::{batch, watch};
+
+use anyhow::{Context, Result};
+use std::path::Path;
+use tracing::info_span;
+
+/// Solves both parts of a single day's Advent of Code puzzle from the raw text
+/// of its input, which is parsed only once for both.
+pub trait Solver {
+    /// Representation of the puzzle input that both parts are solved from,
+    /// which neither part can modify.
+    type Parsed;
+
+    /// Returns the day of December whose puzzle this [Solver] solves.
+    fn day(&self) -> u8;
+
+    /// Interprets `input` as the representation that both parts of the
+    /// puzzle are solved from.
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+
+    /// Returns the [Answer] to the first part of the puzzle described by
+    /// `parsed`.
+    fn part_one(&self, parsed: &Self::Parsed) -> Result<Answer>;
+
+    /// Returns the [Answer] to the second part of the puzzle described by
+    /// `parsed`.
+    fn part_two(&self, parsed: &Self::Parsed) -> Result<Answer>;
+
+    /// Returns `input` rearranged by `seed` wherever the puzzle does not care
+    /// how it is ordered, or [Option::None] if it cares about every part of
+    /// it, which is the default.
+    fn shuffle(&self, _input: &str, _seed: u64) -> Option<String> {
+        None
+    }
+}
+
+/// Returns a [Table] of the answers that `solver` gives for the puzzle
+/// described by `input`, including only the specified `part` if there is one
+/// and both parts otherwise.
+///
+/// `input` is parsed once, and both parts are solved from the result.
+pub fn answers_table<S: Solver>(solver: &S, input: &str, part: Option<Part>) -> Result<Table> {
+    timed_answers_table(solver, input, part, &mut Timings::new())
+}
+
+/// Returns a [Table] of the answers that `solver` gives for the puzzle
+/// described by `input`, like [answers_table], followed by how long parsing
+/// `input` and solving each part took.
+pub fn answers_table_with_timings<S: Solver>(
+    solver: &S,
+    input: &str,
+    part: Option<Part>,
+) -> Result<Table> {
+    let mut timings = Timings::new();
+    let mut table = timed_answers_table(solver, input, part, &mut timings)?;
+    table.separator().append(timings.table());
+
+    Ok(table)
+}
+
+/// Prints the answers that `solver` gives for the puzzle described by `input`,
+/// as specified by `args`.
+pub fn print_answers<S: Solver>(solver: &S, input: &str, args: &CommonArgs) -> Result<()> {
+    args.forbid_visualize(solver.day())?;
+    args.print_answers(
+        solver.day(),
+        &answers_table_with_timings(solver, input, args.part)?,
+    )
+}
+
+/// Writes a CSV of the answers that `solver` gives for every input in the
+/// `inputs` directory, along with how long each part took, as specified by
+/// `args`.
+pub fn write_answers_csv<S: Solver>(solver: &S, inputs: &Path, args: &CommonArgs) -> Result<()> {
+    batch::write_answers_csv(inputs, args.out.as_deref(), args.part, |input, part| {
+        answers_table(solver, input, Some(part))
+    })
+}
+
+/// Prints the answers that `solver` gives for the input file specified by
+/// `args`, and then how they change every time that file does.
+pub fn watch_answers<S: Solver>(solver: &S, args: &CommonArgs) -> Result<()> {
+    watch::watch_answers(args, |input| {
+        answers_table_with_timings(solver, input, args.part)
+    })
+}
+
+/// Returns a [Table] of the answers that `solver` gives for the puzzle
+/// described by `input`, like [answers_table], recording how long parsing
+/// `input` and solving each part took in `timings`.
+fn timed_answers_table<S: Solver>(
+    solver: &S,
+    input: &str,
+    part: Option<Part>,
+    timings: &mut Timings,
+) -> Result<Table> {
+    let parsed = timings
+        .time("Parse", || {
+            info_span!("parse", day = solver.day()).in_scope(|| solver.parse(input))
+        })
+        .context(InvalidInput)?;
+    let mut table = Table::new();
+
+    if Part::One.is_selected_by(part) {
+        let part_one_answer = timings
+            .time("Part one", || {
+                info_span!("part_one", day = solver.day()).in_scope(|| solver.part_one(&parsed))
+            })
+            .with_context(|| format!("Failed to solve part one of day {}", solver.day()))?;
+
+        table.answer("Part one", part_one_answer);
+    }
+
+    if Part::Two.is_selected_by(part) {
+        let part_two_answer = timings
+            .time("Part two", || {
+                info_span!("part_two", day = solver.day()).in_scope(|| solver.part_two(&parsed))
+            })
+            .with_context(|| format!("Failed to solve part two of day {}", solver.day()))?;
+
+        table.answer("Part two", part_two_answer);
+    }
+
+    Ok(table)
+}
@@ -0,0 +1,166 @@
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Color behind everything drawn in an [Svg], matching the Advent of Code
+/// website.
+const BACKGROUND: &str = "#0f0f23";
+
+/// Enumerates the gradients that the intensity of a cell, such as how many
+/// times it is covered, can be colored along.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum ColorRamp {
+    /// From dark to light gray.
+    Grayscale,
+    /// From yellow through orange to red.
+    #[default]
+    Heat,
+    /// From purple through teal to yellow, which stays distinguishable to
+    /// colorblind readers.
+    Viridis,
+}
+
+impl ColorRamp {
+    /// Returns the hexadecimal RGB color (e.g. `"#ff9900"`) at `fraction` of
+    /// the way along this [ColorRamp], where `fraction` is clamped to be
+    /// between `0.0` and `1.0`.
+    pub fn color_at(&self, fraction: f64) -> String {
+        let stops = self.stops();
+        let position = fraction.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+        let stop_index = (position.floor() as usize).min(stops.len() - 2);
+        let (from, to) = (stops[stop_index], stops[stop_index + 1]);
+        let progress = position - stop_index as f64;
+
+        let channel = |shift: u32| {
+            let from = ((from >> shift) & 0xff) as f64;
+            let to = ((to >> shift) & 0xff) as f64;
+
+            (from + (to - from) * progress).round() as u32
+        };
+
+        format!("#{:02x}{:02x}{:02x}", channel(16), channel(8), channel(0))
+    }
+
+    /// Returns the RGB colors that this [ColorRamp] passes through evenly, in
+    /// order.
+    fn stops(&self) -> &'static [u32] {
+        match self {
+            ColorRamp::Grayscale => &[0x555555, 0xffffff],
+            ColorRamp::Heat => &[0xffff66, 0xff9900, 0xcc0000],
+            ColorRamp::Viridis => &[0x440154, 0x21918c, 0xfde725],
+        }
+    }
+}
+
+/// Scalable vector drawing of a grid of cells, each `scale` pixels wide and
+/// tall, that lines and filled cells are drawn onto in order.
+///
+/// ```
+/// use aoc_common::svg::Svg;
+///
+/// let mut svg = Svg::new(2, 1, 10);
+/// svg.square(1, 0, "#ff0000");
+///
+/// assert!(svg.render().contains(r##"<rect x="10" y="0" width="10" height="10" fill="#ff0000"/>"##));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Svg {
+    /// Markup of everything drawn so far, in order.
+    elements: Vec<String>,
+    /// Number of rows of cells.
+    height: usize,
+    /// Width and height of every cell, in pixels.
+    scale: usize,
+    /// Number of columns of cells.
+    width: usize,
+}
+
+impl Svg {
+    /// Creates an empty [Svg] of `width` columns and `height` rows of cells,
+    /// each `scale` pixels wide and tall.
+    pub fn new(width: usize, height: usize, scale: usize) -> Svg {
+        Svg {
+            elements: Vec::new(),
+            height,
+            scale,
+            width,
+        }
+    }
+
+    /// Draws a line in the `stroke` color from the center of the cell at
+    /// `from` to the center of the cell at `to`, both `(column, row)`.
+    pub fn line(&mut self, from: (usize, usize), to: (usize, usize), stroke: &str) -> &mut Svg {
+        let center_of = |cell: usize| (cell * self.scale) as f64 + self.scale as f64 / 2.0;
+
+        self.elements.push(format!(
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}" stroke-linecap="round"/>"#,
+            center_of(from.0),
+            center_of(from.1),
+            center_of(to.0),
+            center_of(to.1),
+            stroke,
+            self.scale as f64 / 4.0
+        ));
+
+        self
+    }
+
+    /// Returns the markup of this [Svg] as a standalone SVG document.
+    pub fn render(&self) -> String {
+        let (width, height) = (self.width * self.scale, self.height * self.scale);
+        let mut rendering = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            width, height
+        );
+        rendering.push('\n');
+
+        // Writing to a String cannot fail.
+        let _ = writeln!(
+            rendering,
+            r#"<rect width="{}" height="{}" fill="{}"/>"#,
+            width, height, BACKGROUND
+        );
+        for element in &self.elements {
+            let _ = writeln!(rendering, "{}", element);
+        }
+
+        rendering.push_str("</svg>\n");
+
+        rendering
+    }
+
+    /// Writes this [Svg] to the file at `path` as a standalone SVG document.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.render())
+            .with_context(|| format!("Failed to write SVG to \"{}\"", path.display()))
+    }
+
+    /// Fills the cell at `column` and `row` with the `fill` color.
+    pub fn square(&mut self, column: usize, row: usize, fill: &str) -> &mut Svg {
+        self.elements.push(format!(
+            r#"<rect x="{}" y="{}" width="{2}" height="{2}" fill="{3}"/>"#,
+            column * self.scale,
+            row * self.scale,
+            self.scale,
+            fill
+        ));
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolates_between_stops() {
+        assert_eq!(ColorRamp::Heat.color_at(0.0), "#ffff66");
+        assert_eq!(ColorRamp::Heat.color_at(0.5), "#ff9900");
+        assert_eq!(ColorRamp::Heat.color_at(1.0), "#cc0000");
+        assert_eq!(ColorRamp::Heat.color_at(7.0), "#cc0000");
+        assert_eq!(ColorRamp::Grayscale.color_at(0.5), "#aaaaaa");
+    }
+}
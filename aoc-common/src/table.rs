@@ -0,0 +1,343 @@
+use crate::output::{self, Emphasis};
+
+use serde_json::{json, Value};
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+/// Number of spaces between adjacent columns.
+const COLUMN_GAP: usize = 2;
+
+/// Enumerates the ways that a row of a [Table] can be styled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Style {
+    /// Final value of its row is an answer, and stands out from the rest.
+    Answer,
+    /// Row labels the columns of the rows that follow it.
+    Header,
+    /// Row has no special styling.
+    Plain,
+    /// Final value of its row is how long something took, and recedes behind
+    /// the rest.
+    Timing,
+    /// Row warns about something unexpected.
+    Warning,
+}
+
+impl Style {
+    /// Returns the [Emphasis] of a cell with this [Style], given whether it
+    /// is the last cell in its row, returning [Option::None] if the cell is
+    /// unstyled.
+    fn emphasis_for(&self, is_last_cell: bool) -> Option<Emphasis> {
+        match self {
+            Style::Answer if is_last_cell => Some(Emphasis::Answer),
+            Style::Timing if is_last_cell => Some(Emphasis::Timing),
+            Style::Answer | Style::Plain | Style::Timing => None,
+            Style::Header => Some(Emphasis::Header),
+            Style::Warning => Some(Emphasis::Warning),
+        }
+    }
+}
+
+/// Row of cells within a [Table].
+#[derive(Clone, Debug, PartialEq)]
+struct Row {
+    /// Text of every cell in this row, from left to right.
+    cells: Vec<String>,
+    /// How this row is styled.
+    style: Style,
+}
+
+/// Rows of text printed with every column aligned, in place of tab-separated
+/// `println!` calls that misalign whenever a value outgrows its tab stop.
+///
+/// The last cell of each row is never padded, so single-cell rows (such as
+/// titles and warnings) do not widen any column.
+///
+/// ```
+/// use aoc_common::table::Table;
+///
+/// let table = Table::new()
+///     .entry("Gamma rate", 22)
+///     .entry("Epsilon rate", 9)
+///     .answer("Product", 198)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "Gamma rate:    22\nEpsilon rate:  9\nProduct:       198\n"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Table {
+    /// Every row in this table, from top to bottom.
+    rows: Vec<Row>,
+}
+
+impl Table {
+    /// Creates a new, empty [Table].
+    pub fn new() -> Table {
+        Table::default()
+    }
+
+    /// Appends a row labeling `label` with `value`, styling `value` as an
+    /// answer.
+    pub fn answer(&mut self, label: &str, value: impl Display) -> &mut Table {
+        self.push(
+            vec![format!("{}:", label), value.to_string()],
+            Style::Answer,
+        )
+    }
+
+    /// Appends every row of `other`, keeping their styles.
+    pub fn append(&mut self, other: Table) -> &mut Table {
+        self.rows.extend(other.rows);
+
+        self
+    }
+
+    /// Returns the label and value of every row appended by [Table::answer]
+    /// as JSON, the way that `--format json` prints them.
+    pub fn answers_json(&self) -> Value {
+        json!({
+            "answers": self
+                .labeled_answers()
+                .map(|(label, answer)| json!({ "label": label, "answer": answer }))
+                .collect::<Vec<Value>>(),
+        })
+    }
+
+    /// Returns the value of every row appended by [Table::answer], from top
+    /// to bottom.
+    pub fn answers(&self) -> impl Iterator<Item = &str> {
+        self.rows
+            .iter()
+            .filter(|row| row.style == Style::Answer)
+            .filter_map(|row| row.cells.last())
+            .map(|cell| cell.as_str())
+    }
+
+    /// Returns the label and value of every row appended by [Table::answer],
+    /// from top to bottom.
+    pub fn labeled_answers(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.rows
+            .iter()
+            .filter(|row| row.style == Style::Answer)
+            .filter_map(|row| match row.cells.as_slice() {
+                [label, value] => Some((label.strip_suffix(':').unwrap_or(label), value.as_str())),
+                _ => None,
+            })
+    }
+
+    /// Appends a row labeling `label` with `value`.
+    pub fn entry(&mut self, label: &str, value: impl Display) -> &mut Table {
+        self.push(vec![format!("{}:", label), value.to_string()], Style::Plain)
+    }
+
+    /// Appends a row of column headings.
+    pub fn header<I>(&mut self, headings: I) -> &mut Table
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        self.push(
+            headings
+                .into_iter()
+                .map(|heading| heading.to_string())
+                .collect(),
+            Style::Header,
+        )
+    }
+
+    /// Returns `true` if this [Table] has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Prints this [Table] to standard output, colored if standard output is
+    /// a terminal and colors have not been disabled.
+    pub fn print(&self) {
+        print!("{}", self.render(output::is_color_enabled()));
+    }
+
+    /// Returns every line of this [Table], terminated by newlines, with
+    /// styles rendered as ANSI escape sequences if `is_colored` is `true`.
+    pub fn render(&self, is_colored: bool) -> String {
+        let column_widths = self.column_widths();
+        let mut rendered = String::new();
+
+        for row in self.rows.iter() {
+            let mut column_start = 0;
+            let mut line = String::new();
+
+            for (cell_index, cell) in row.cells.iter().enumerate() {
+                let is_last_cell = cell_index == row.cells.len() - 1;
+                let emphasis = row.style.emphasis_for(is_last_cell).filter(|_| is_colored);
+
+                // Continuation lines of a multi-line last cell (like an answer
+                // drawn as ASCII art) start in the same column as its first.
+                let text = if is_last_cell {
+                    cell.replace('\n', &format!("\n{}", " ".repeat(column_start)))
+                } else {
+                    cell.clone()
+                };
+
+                match emphasis {
+                    Some(emphasis) => line.push_str(&output::paint(text, emphasis, true)),
+                    None => line.push_str(&text),
+                }
+
+                if !is_last_cell {
+                    let width = column_widths[cell_index] + COLUMN_GAP;
+
+                    line.push_str(&" ".repeat(width - cell.chars().count()));
+                    column_start += width;
+                }
+            }
+
+            rendered.push_str(line.trim_end());
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+
+    /// Appends a row of cells.
+    pub fn row<I>(&mut self, cells: I) -> &mut Table
+    where
+        I: IntoIterator,
+        I::Item: Display,
+    {
+        self.push(
+            cells.into_iter().map(|cell| cell.to_string()).collect(),
+            Style::Plain,
+        )
+    }
+
+    /// Appends an empty row, visually separating the rows before it from the
+    /// rows after it.
+    pub fn separator(&mut self) -> &mut Table {
+        self.push(vec![], Style::Plain)
+    }
+
+    /// Appends a row labeling `label` with how long it took, `elapsed`,
+    /// styling `elapsed` as a timing.
+    pub fn timing(&mut self, label: &str, elapsed: Duration) -> &mut Table {
+        self.push(
+            vec![format!("{}:", label), format!("{:.3?}", elapsed)],
+            Style::Timing,
+        )
+    }
+
+    /// Appends a single-cell row of text that spans every column.
+    pub fn title(&mut self, title: impl Display) -> &mut Table {
+        self.push(vec![title.to_string()], Style::Plain)
+    }
+
+    /// Appends a single-cell row warning about `message`.
+    pub fn warning(&mut self, message: impl Display) -> &mut Table {
+        self.push(vec![message.to_string()], Style::Warning)
+    }
+
+    /// Returns the width of the widest padded cell in each column.
+    fn column_widths(&self) -> Vec<usize> {
+        let mut column_widths = vec![];
+
+        for row in self.rows.iter() {
+            let padded_cells = &row.cells[..row.cells.len().saturating_sub(1)];
+
+            for (cell_index, cell) in padded_cells.iter().enumerate() {
+                if cell_index >= column_widths.len() {
+                    column_widths.push(0);
+                }
+
+                column_widths[cell_index] = column_widths[cell_index].max(cell.chars().count());
+            }
+        }
+
+        column_widths
+    }
+
+    /// Appends a row of `cells` styled with `style`.
+    fn push(&mut self, cells: Vec<String>, style: Style) -> &mut Table {
+        self.rows.push(Row { cells, style });
+
+        self
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_nothing_when_empty() {
+        let table = Table::new();
+
+        assert!(table.is_empty());
+        assert_eq!(table.render(true), "");
+        assert_eq!(table.answers_json(), json!({ "answers": [] }));
+    }
+
+    #[test]
+    fn aligns_every_column_except_the_last() {
+        let table = Table::new()
+            .title("Day 5")
+            .header(["x", "y", "count"])
+            .row([10, 2, 3])
+            .row([1, 200, 4])
+            .to_string();
+
+        assert_eq!(table, "Day 5\nx   y    count\n10  2    3\n1   200  4\n");
+    }
+
+    #[test]
+    fn indents_continuation_lines_of_the_last_cell() {
+        let table = Table::new().answer("Code", "#..#\n####").to_string();
+
+        assert_eq!(table, "Code:  #..#\n       ####\n");
+    }
+
+    #[test]
+    fn lists_only_labeled_answers() {
+        let table = Table::new()
+            .entry("Gamma rate", 22)
+            .answer("Product", 198)
+            .timing("Part 1", Duration::from_millis(1))
+            .answer("Life support rating", 230)
+            .clone();
+
+        assert_eq!(table.answers().collect::<Vec<&str>>(), ["198", "230"]);
+        assert_eq!(
+            table.answers_json(),
+            json!({
+                "answers": [
+                    { "label": "Product", "answer": "198" },
+                    { "label": "Life support rating", "answer": "230" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn colors_only_emphasized_cells() {
+        let table = Table::new()
+            .entry("Gamma rate", 22)
+            .answer("Product", 198)
+            .clone();
+
+        assert_eq!(
+            table.render(true),
+            format!(
+                "Gamma rate:  22\nProduct:     {}\n",
+                output::paint("198", Emphasis::Answer, true)
+            )
+        );
+        assert_eq!(table.render(false), table.to_string());
+    }
+}
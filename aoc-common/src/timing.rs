@@ -0,0 +1,105 @@
+#[cfg(feature = "alloc-stats")]
+use crate::alloc_stats::{self, AllocStats};
+#[cfg(feature = "profile")]
+use crate::profile;
+use crate::table::Table;
+
+use std::time::{Duration, Instant};
+
+/// Wall-clock time taken by each phase of solving a puzzle, such as parsing
+/// its input or solving one of its parts, in the order that they were timed.
+///
+/// With the `alloc-stats` feature, how many allocations every phase run by
+/// [Timings::time] made, and its peak heap usage, are recorded too.
+///
+/// ```
+/// use aoc_common::timing::Timings;
+/// use std::time::Duration;
+///
+/// let mut timings = Timings::new();
+/// let depths = timings.time("Parse", || vec![199, 200, 208]);
+/// timings.record("Part one", Duration::from_micros(312));
+///
+/// assert_eq!(depths.len(), 3);
+/// let table = timings.table().to_string();
+/// assert!(table.lines().last().unwrap().starts_with("Part one time:"));
+/// assert!(table.ends_with("312.000µs\n"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Timings {
+    /// Label and [AllocStats] of every phase run by [Timings::time], in the
+    /// order that they were timed.
+    #[cfg(feature = "alloc-stats")]
+    alloc_stats: Vec<(String, AllocStats)>,
+    /// Label and duration of every timed phase, in the order that they were
+    /// timed.
+    phases: Vec<(String, Duration)>,
+}
+
+impl Timings {
+    /// Creates a new [Timings] without any phases.
+    pub fn new() -> Timings {
+        Timings::default()
+    }
+
+    /// Adds every phase timed in `other` after the phases of this [Timings].
+    pub fn append(&mut self, other: Timings) -> &mut Timings {
+        #[cfg(feature = "alloc-stats")]
+        self.alloc_stats.extend(other.alloc_stats);
+        self.phases.extend(other.phases);
+
+        self
+    }
+
+    /// Records that the phase labeled `label` took `elapsed`.
+    pub fn record(&mut self, label: &str, elapsed: Duration) -> &mut Timings {
+        self.phases.push((label.to_string(), elapsed));
+
+        self
+    }
+
+    /// Returns a [Table] with a row for how long every phase took, e.g.
+    /// `Parse time: 312.000µs`, each followed by rows for its allocations and
+    /// peak heap usage if they were recorded.
+    pub fn table(&self) -> Table {
+        let mut table = Table::new();
+
+        for (label, elapsed) in self.phases.iter() {
+            table.timing(&format!("{} time", label), *elapsed);
+
+            #[cfg(feature = "alloc-stats")]
+            if let Some((_, alloc_stats)) = self
+                .alloc_stats
+                .iter()
+                .find(|(alloc_stats_label, _)| alloc_stats_label == label)
+            {
+                table
+                    .entry(&format!("{} allocations", label), alloc_stats.allocations)
+                    .entry(&format!("{} peak heap", label), alloc_stats.peak_heap());
+            }
+        }
+
+        table
+    }
+
+    /// Runs `phase`, recording how long it took under `label`, and returns
+    /// whatever it returned.
+    ///
+    /// With the `profile` feature, `phase` also gets a flamegraph of its own
+    /// from `--profile`.
+    pub fn time<T>(&mut self, label: &str, phase: impl FnOnce() -> T) -> T {
+        #[cfg(feature = "profile")]
+        let phase = || profile::time_phase(label, phase);
+        let start = Instant::now();
+        #[cfg(feature = "alloc-stats")]
+        let (output, alloc_stats) = alloc_stats::measure(phase);
+        #[cfg(not(feature = "alloc-stats"))]
+        let output = phase();
+        self.record(label, start.elapsed());
+
+        #[cfg(feature = "alloc-stats")]
+        self.alloc_stats.push((label.to_string(), alloc_stats));
+
+        output
+    }
+}
@@ -0,0 +1,160 @@
+use crate::args::{CommonArgs, Part};
+use crate::input::{self, InputSource};
+use crate::output::{self, Emphasis};
+use crate::table::Table;
+
+use anyhow::{bail, Context, Result};
+use notify::{RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How long to wait after a change for any that follow it, so that an editor
+/// saving a file in several steps only triggers a single run.
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(100);
+
+/// Solves the puzzle described by the input file specified by `args` with
+/// `solve`, and then again every time that file changes, until the program
+/// is interrupted.
+///
+/// The answers are printed in full the first time, and every time after that
+/// as a timestamped diff against the answers of the run before. Failures are
+/// printed instead of stopping the watch, since inputs are often briefly
+/// invalid while being edited.
+pub fn watch_answers<F>(args: &CommonArgs, mut solve: F) -> Result<()>
+where
+    F: FnMut(&str) -> Result<Table>,
+{
+    let input_path = match args.input_source() {
+        InputSource::File(input_path) => input_path,
+        InputSource::Embedded(_) => bail!("Embedded input cannot be watched for changes"),
+        InputSource::Stdin => bail!("Standard input cannot be watched for changes"),
+    };
+    let watched_directory = match input_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    // Editors often replace files instead of writing to them, so the
+    // directory is watched rather than the file itself.
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(sender).context("Failed to start watching for changes")?;
+    watcher
+        .watch(watched_directory, RecursiveMode::NonRecursive)
+        .with_context(|| format!("Failed to watch \"{}\"", watched_directory.display()))?;
+
+    println!(
+        "{} Watching \"{}\" for changes",
+        timestamp(),
+        input_path.display()
+    );
+
+    let mut previous_answers: Option<Vec<(&str, String)>> = None;
+    loop {
+        let answers = input::load(input_path.as_path())
+            .and_then(|input| solve(&input))
+            .map(|table| labeled_answers_of(&table, args.part));
+
+        match (&previous_answers, &answers) {
+            (_, Err(error)) => println!(
+                "{} {} {:#}",
+                timestamp(),
+                output::paint("Error:", Emphasis::Error, output::is_color_enabled()),
+                error
+            ),
+            (None, Ok(answers)) => {
+                for (label, answer) in answers.iter() {
+                    println!("{} {}: {}", timestamp(), label, painted_answer(answer));
+                }
+            }
+            (Some(previous_answers), Ok(answers)) => print_diff(previous_answers, answers),
+        }
+        if let Ok(answers) = answers {
+            previous_answers = Some(answers);
+        }
+
+        wait_for_change(&receiver, &input_path)?;
+    }
+}
+
+/// Returns the answers in `table`, one for each part selected by `part` in
+/// order, labeled with the part that they answer.
+fn labeled_answers_of(table: &Table, part: Option<Part>) -> Vec<(&'static str, String)> {
+    [(Part::One, "Part one"), (Part::Two, "Part two")]
+        .into_iter()
+        .filter(|(each_part, _)| each_part.is_selected_by(part))
+        .zip(table.answers())
+        .map(|((_, label), answer)| (label, answer.to_string()))
+        .collect()
+}
+
+/// Returns `answer` styled as an [Emphasis::Answer] if standard output is
+/// colored.
+fn painted_answer(answer: &str) -> String {
+    output::paint(answer, Emphasis::Answer, output::is_color_enabled())
+}
+
+/// Prints a timestamped line for every answer in `answers`, showing what it
+/// was in `previous_answers` if it changed.
+fn print_diff(previous_answers: &[(&str, String)], answers: &[(&str, String)]) {
+    let timestamp = timestamp();
+
+    for (label, answer) in answers.iter() {
+        match previous_answers
+            .iter()
+            .find(|(previous_label, _)| previous_label == label)
+        {
+            Some((_, previous_answer)) if previous_answer != answer => println!(
+                "{} {}: {} -> {}",
+                timestamp,
+                label,
+                previous_answer,
+                painted_answer(answer)
+            ),
+            _ => println!(
+                "{} {}: {} (unchanged)",
+                timestamp,
+                label,
+                painted_answer(answer)
+            ),
+        }
+    }
+}
+
+/// Returns the current time, formatted for the start of a printed line.
+fn timestamp() -> String {
+    output::paint(
+        format!("[{}]", humantime::format_rfc3339_seconds(SystemTime::now())),
+        Emphasis::Timing,
+        output::is_color_enabled(),
+    )
+}
+
+/// Blocks until `receiver` reports a change to the file at `input_path`,
+/// and then until changes stop arriving for [DEBOUNCE_DELAY].
+fn wait_for_change(
+    receiver: &mpsc::Receiver<notify::Result<notify::Event>>,
+    input_path: &Path,
+) -> Result<()> {
+    loop {
+        let event = receiver
+            .recv()
+            .context("Stopped receiving changes")?
+            .context("Failed to watch for changes")?;
+
+        let is_input_changed = event.paths.iter().any(|path| {
+            path.file_name() == input_path.file_name()
+                && (event.kind.is_create() || event.kind.is_modify())
+        });
+        if is_input_changed {
+            break;
+        }
+    }
+
+    thread::sleep(DEBOUNCE_DELAY);
+    receiver.try_iter().for_each(drop);
+
+    Ok(())
+}
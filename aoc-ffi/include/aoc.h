@@ -0,0 +1,65 @@
+/*
+ * C interface to every day's solver, built as a shared library by
+ * `cargo build -p aoc-ffi --release` (e.g. target/release/libaoc_ffi.so).
+ */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Returned by aoc_solve if it wrote the answer to its output buffer. */
+#define AOC_OK 0
+
+/*
+ * Returned by aoc_solve if the answer, or the error message that it would
+ * have written instead, does not fit in its output buffer.
+ */
+#define AOC_BUFFER_TOO_SMALL (-1)
+
+/* Returned by aoc_solve if it was passed a null pointer. */
+#define AOC_NULL_POINTER (-2)
+
+/*
+ * Buffer, owned by the caller, that aoc_solve writes a nul-terminated answer
+ * or error message to.
+ */
+typedef struct AocBuffer {
+    /* First byte of the buffer. */
+    char *data;
+    /* How many bytes, including the nul terminator, fit in the buffer. */
+    size_t capacity;
+    /*
+     * Length of what was written, or would have been written if it had fit,
+     * not counting the nul terminator.
+     */
+    size_t len;
+} AocBuffer;
+
+/*
+ * Solves the specified part (1 or 2) of the puzzle of day described by the
+ * len bytes of UTF-8 at input_ptr, writing the answer to out_buf.
+ *
+ * Returns AOC_OK on success. Otherwise, returns the exit code that the day's
+ * program would have exited with (2 if the input cannot be interpreted, 3 if
+ * it has no answer, and 1 otherwise) and writes the error message to out_buf
+ * instead, or returns AOC_BUFFER_TOO_SMALL or AOC_NULL_POINTER.
+ *
+ *     char answer[64];
+ *     AocBuffer out_buf = { .data = answer, .capacity = sizeof(answer) };
+ *     aoc_solve(1, 1, (const uint8_t *)"199\n200\n208\n210", 15, &out_buf);
+ *     // returns AOC_OK, answer == "3", out_buf.len == 1
+ */
+int32_t aoc_solve(uint8_t day, uint8_t part, const uint8_t *input_ptr, size_t len,
+                  AocBuffer *out_buf);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
@@ -0,0 +1,198 @@
+//! C interface to every day's solver, so that the solutions can be called
+//! from other languages, declared for C by `include/aoc.h`.
+//!
+//! ```c
+//! #include "aoc.h"
+//!
+//! char answer[64];
+//! AocBuffer out_buf = { .data = answer, .capacity = sizeof(answer) };
+//! int32_t status = aoc_solve(1, 1, (const uint8_t *)"199\n200\n208\n210", 15, &out_buf);
+//! // status == AOC_OK, answer == "3", out_buf.len == 1
+//! status = aoc_solve(6, 1, (const uint8_t *)"3,4,3,1,2", 9, &out_buf);
+//! // status == AOC_OK, answer == "5934", out_buf.len == 4
+//! ```
+
+extern crate anyhow;
+extern crate aoc_common;
+extern crate aoc_solvers;
+
+use anyhow::{anyhow, Context, Error};
+use aoc_common::error::{ErrorKind, InvalidInput};
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::{ptr, slice, str};
+
+/// Returned by [aoc_solve] if it wrote the answer to its output buffer.
+pub const AOC_OK: i32 = 0;
+
+/// Returned by [aoc_solve] if the answer, or the error message that it would
+/// have written instead, does not fit in its output buffer.
+pub const AOC_BUFFER_TOO_SMALL: i32 = -1;
+
+/// Returned by [aoc_solve] if it was passed a null pointer.
+pub const AOC_NULL_POINTER: i32 = -2;
+
+/// Buffer, owned by the caller, that [aoc_solve] writes a nul-terminated
+/// answer or error message to.
+#[repr(C)]
+pub struct AocBuffer {
+    /// First byte of the buffer.
+    pub data: *mut c_char,
+    /// How many bytes, including the nul terminator, fit in the buffer.
+    pub capacity: usize,
+    /// Length of what was written, or would have been written if it had
+    /// fit, not counting the nul terminator.
+    pub len: usize,
+}
+
+/// Solves the specified `part` (1 or 2) of the puzzle of `day` described by
+/// the `len` bytes of UTF-8 at `input_ptr`, writing the answer to `out_buf`.
+///
+/// Returns [AOC_OK] on success. Otherwise, returns the exit code that the
+/// day's program would have exited with (2 if the input cannot be
+/// interpreted, 3 if it has no answer, and 1 otherwise) and writes the error
+/// message to `out_buf` instead, or returns [AOC_BUFFER_TOO_SMALL] or
+/// [AOC_NULL_POINTER].
+///
+/// Either way, `out_buf.len` is set to the length of what was written, so
+/// that a buffer that was too small can be retried with one that fits.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `len` readable bytes, and `out_buf` must point
+/// to an [AocBuffer] whose `data` points to `capacity` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input_ptr: *const u8,
+    len: usize,
+    out_buf: *mut AocBuffer,
+) -> i32 {
+    if input_ptr.is_null() || out_buf.is_null() || (*out_buf).data.is_null() {
+        return AOC_NULL_POINTER;
+    }
+
+    let input = slice::from_raw_parts(input_ptr, len);
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        let input = str::from_utf8(input)
+            .context("Puzzle input is not valid UTF-8")
+            .context(InvalidInput)?;
+
+        aoc_solvers::answer(day, part, input)
+    }))
+    .unwrap_or_else(|_| Err(anyhow!("Day {} panicked", day)));
+
+    let (status, text) = match solved {
+        Ok(answer) => (AOC_OK, answer),
+        Err(error) => (status_of(&error), format!("{:#}", error)),
+    };
+
+    if !write_to(&mut *out_buf, &text) {
+        return AOC_BUFFER_TOO_SMALL;
+    }
+
+    status
+}
+
+/// Returns the status that [aoc_solve] reports `error` with.
+fn status_of(error: &Error) -> i32 {
+    i32::from(ErrorKind::of(error).exit_code())
+}
+
+/// Writes `text` followed by a nul terminator to `out_buf`, truncating it to
+/// fit, and returns `true` unless it had to be truncated.
+///
+/// # Safety
+///
+/// `out_buf.data` must point to `out_buf.capacity` writable bytes.
+unsafe fn write_to(out_buf: &mut AocBuffer, text: &str) -> bool {
+    out_buf.len = text.len();
+
+    if out_buf.capacity == 0 {
+        return false;
+    }
+
+    let written_len = text.len().min(out_buf.capacity - 1);
+    ptr::copy_nonoverlapping(text.as_ptr(), out_buf.data.cast::<u8>(), written_len);
+    *out_buf.data.add(written_len) = 0;
+
+    written_len == text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    /// Calls [aoc_solve] with an output buffer of `capacity` bytes, returning
+    /// what it returned, what it wrote, and the length it reported.
+    fn solve(day: u8, part: u8, input: &str, capacity: usize) -> (i32, String, usize) {
+        let mut data = vec![1 as c_char; capacity.max(1)];
+        let mut out_buf = AocBuffer {
+            data: data.as_mut_ptr(),
+            capacity,
+            len: 0,
+        };
+
+        let status = unsafe { aoc_solve(day, part, input.as_ptr(), input.len(), &mut out_buf) };
+        let written = match capacity {
+            0 => String::new(),
+            _ => unsafe { CStr::from_ptr(data.as_ptr()) }
+                .to_string_lossy()
+                .into_owned(),
+        };
+
+        (status, written, out_buf.len)
+    }
+
+    #[test]
+    fn writes_answer_to_buffer() {
+        assert_eq!(
+            solve(6, 2, "3,4,3,1,2", 64),
+            (AOC_OK, "26984457539".to_string(), 11)
+        );
+    }
+
+    #[test]
+    fn solves_sonar_sweep_sample() {
+        let sonar_sweep_sample = include_str!("../../day-1/files/sample.txt");
+
+        assert_eq!(
+            solve(1, 1, sonar_sweep_sample, 64),
+            (AOC_OK, "7".to_string(), 1)
+        );
+        assert_eq!(
+            solve(1, 2, sonar_sweep_sample, 64),
+            (AOC_OK, "5".to_string(), 1)
+        );
+    }
+
+    #[test]
+    fn reports_length_of_answer_that_does_not_fit() {
+        assert_eq!(
+            solve(6, 2, "3,4,3,1,2", 5),
+            (AOC_BUFFER_TOO_SMALL, "2698".to_string(), 11)
+        );
+        assert_eq!(
+            solve(6, 2, "3,4,3,1,2", 0),
+            (AOC_BUFFER_TOO_SMALL, String::new(), 11)
+        );
+    }
+
+    #[test]
+    fn returns_exit_code_of_errors() {
+        let (status, message, _) = solve(6, 1, "3,4,x", 256);
+
+        assert_eq!(status, 2);
+        assert!(message.contains("\"x\""), "{}", message);
+        assert_eq!(solve(16, 1, "", 256).0, 1);
+    }
+
+    #[test]
+    fn rejects_null_pointers() {
+        let status = unsafe { aoc_solve(6, 1, ptr::null(), 0, ptr::null_mut()) };
+
+        assert_eq!(status, AOC_NULL_POINTER);
+    }
+}
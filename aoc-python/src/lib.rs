@@ -0,0 +1,66 @@
+//! Python extension module, `aoc2021`, that solves every day's puzzle and
+//! exposes the data structures of days 3 and 4 for analysis, e.g. from
+//! Jupyter.
+//!
+//! Install it into the active virtual environment with `maturin develop` from
+//! this directory, and then:
+//!
+//! ```python
+//! import aoc2021
+//!
+//! aoc2021.solve(1, 1, "199\n200\n208\n210")  # "3"
+//! grid = aoc2021.BinaryGrid.deserialize(open("day-3/files/input.txt").read())
+//! grid.most_common_bits()
+//! ```
+
+extern crate aoc_solvers;
+extern crate day_3;
+extern crate day_4;
+extern crate pyo3;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Returns the answer to the specified `part` (1 or 2) of the puzzle of
+/// `day` described by `input`, raising `ValueError` if it cannot be solved.
+#[pyfunction]
+fn solve(day: u8, part: u8, input: &str) -> PyResult<String> {
+    aoc_solvers::answer(day, part, input)
+        .map_err(|error| PyValueError::new_err(format!("{:#}", error)))
+}
+
+/// Returns every day of December whose puzzle `solve` can solve, in order.
+#[pyfunction]
+fn days() -> Vec<u32> {
+    // Python would otherwise receive a `Vec<u8>` as `bytes` rather than as a
+    // list of numbers.
+    aoc_solvers::days().into_iter().map(u32::from).collect()
+}
+
+#[pymodule]
+fn aoc2021(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(days, module)?)?;
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    day_3::python::register(module)?;
+    day_4::python::register(module)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_sonar_sweep_sample() {
+        let sonar_sweep_sample = include_str!("../../day-1/files/sample.txt");
+
+        assert_eq!(solve(1, 1, sonar_sweep_sample).unwrap(), "7");
+        assert_eq!(solve(1, 2, sonar_sweep_sample).unwrap(), "5");
+    }
+
+    #[test]
+    fn lists_every_solved_day() {
+        assert_eq!(days()[..5], [1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_registered_solvers(c: &mut Criterion) {
+    for day in aoc_solvers::days() {
+        let registration = aoc_solvers::registry()
+            .get(aoc_solvers::YEAR, day, None)
+            .unwrap();
+
+        bench::bench_registration(
+            c,
+            registration,
+            format!("{}/../day-{}/files", env!("CARGO_MANIFEST_DIR"), day),
+        );
+    }
+}
+
+criterion_group!(benches, bench_registered_solvers);
+criterion_main!(benches);
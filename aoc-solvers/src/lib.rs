@@ -0,0 +1,150 @@
+//! Every day's [Solver](aoc_common::solver::Solver), registered in the one
+//! [Registry] that the `aoc` program, its server, the dashboard, and the
+//! bindings for other languages all solve puzzles with.
+
+extern crate anyhow;
+extern crate aoc_common;
+
+use anyhow::{anyhow, Result};
+use aoc_common::args::Part;
+use aoc_common::registry::Registry;
+use std::sync::OnceLock;
+
+/// Year whose Advent of Code every registered solver is from.
+pub const YEAR: u16 = 2021;
+
+/// Returns the answer to the specified `part` (1 or 2) of the puzzle of
+/// `day` described by `input`.
+pub fn answer(day: u8, part: u8, input: &str) -> Result<String> {
+    let selected_part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(anyhow!("Puzzles have no part {}", part)),
+    };
+
+    let registration = registry()
+        .get(YEAR, day, None)
+        .ok_or_else(|| anyhow!("Day {} cannot be solved here", day))?;
+
+    registration
+        .solver
+        .answers_table(input, Some(selected_part))?
+        .answers()
+        .next()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("Day {} gave no answer to part {}", day, part))
+}
+
+/// Returns every day of December whose puzzle [answer] can solve, in order.
+pub fn days() -> Vec<u8> {
+    registry()
+        .iter()
+        .map(|registration| registration.solver.day())
+        .collect()
+}
+
+/// Returns the personal puzzle input of `day` if this crate was built with
+/// `embedded-inputs`, so that it can be solved without a checkout.
+pub fn embedded_input(day: u8) -> Option<&'static str> {
+    if !cfg!(feature = "embedded-inputs") {
+        return None;
+    }
+
+    match day {
+        1 => Some(day_1::fixtures::INPUT),
+        2 => Some(day_2::fixtures::INPUT),
+        3 => Some(day_3::fixtures::INPUT),
+        4 => Some(day_4::fixtures::INPUT),
+        5 => Some(day_5::fixtures::INPUT),
+        _ => None,
+    }
+}
+
+/// Adds every day's solver to `registry`, so that a [Registry] can hold them
+/// along with solvers of its own.
+pub fn register_all(registry: &mut Registry) -> &mut Registry {
+    registry
+        .register(YEAR, "", day_1::solver::SonarSweepSolver)
+        .register(YEAR, "", day_2::solver::DiveSolver)
+        .register(YEAR, "", day_3::solver::BinaryDiagnosticSolver)
+        .register(YEAR, "", day_4::solver::GiantSquidSolver)
+        .register(YEAR, "", day_5::solver::HydrothermalVentureSolver)
+        .register(YEAR, "", day_6::solver::LanternfishSolver)
+        .register(YEAR, "", day_7::solver::CrabAlignmentSolver)
+        .register(YEAR, "", day_8::solver::SegmentSearchSolver)
+        .register(YEAR, "", day_9::solver::SmokeBasinSolver)
+        .register(YEAR, "", day_10::solver::SyntaxScoringSolver)
+        .register(YEAR, "", day_11::solver::DumboOctopusSolver)
+        .register(YEAR, "", day_12::solver::PassagePathingSolver)
+        .register(
+            YEAR,
+            "",
+            day_13::solver::TransparentOrigamiSolver::default(),
+        )
+        .register(YEAR, "", day_14::solver::PolymerizationSolver)
+        .register(YEAR, "", day_15::solver::ChitonSolver::default())
+        .register(YEAR, "", day_17::solver::TrickShotSolver)
+        .register(YEAR, "", day_18::solver::SnailfishSolver)
+        .register(YEAR, "", day_20::solver::TrenchMapSolver)
+        .register(YEAR, "", day_21::solver::DiracDiceSolver)
+        .register(YEAR, "", day_23::solver::AmphipodSolver::default())
+        .register(YEAR, "", day_24::solver::MonadSolver::default())
+        .register(YEAR, "", day_25::solver::SeaCucumberSolver)
+}
+
+/// Returns the [Registry] of every day's solver, which is only put together
+/// once.
+pub fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+
+    REGISTRY.get_or_init(|| {
+        let mut registry = Registry::new();
+        register_all(&mut registry);
+
+        registry
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_lanternfish_sample() {
+        let lanternfish_sample = include_str!("../../day-6/files/sample.txt");
+
+        assert_eq!(answer(6, 1, lanternfish_sample).unwrap(), "5934");
+        assert_eq!(answer(6, 2, lanternfish_sample).unwrap(), "26984457539");
+    }
+
+    #[test]
+    fn lists_every_registered_day() {
+        assert_eq!(
+            days(),
+            (1..=25)
+                .filter(|day| ![16, 19, 22].contains(day))
+                .collect::<Vec<u8>>()
+        );
+    }
+
+    #[test]
+    fn embeds_inputs_only_with_embedded_inputs() {
+        assert_eq!(
+            embedded_input(1).is_some(),
+            cfg!(feature = "embedded-inputs")
+        );
+        assert!(embedded_input(6).is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_days_and_parts() {
+        assert_eq!(
+            answer(16, 1, "").unwrap_err().to_string(),
+            "Day 16 cannot be solved here"
+        );
+        assert_eq!(
+            answer(6, 3, "").unwrap_err().to_string(),
+            "Puzzles have no part 3"
+        );
+    }
+}
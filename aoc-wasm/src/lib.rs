@@ -0,0 +1,58 @@
+//! Every day's solver registered in [aoc_solvers], exported to JavaScript
+//! through `wasm-bindgen` so that puzzles can be solved in a web page.
+//!
+//! Solvers only ever see the text of a puzzle input, so nothing here reads
+//! files or needs a runtime. Build it with e.g.
+//! `cargo build -p aoc-wasm --target wasm32-unknown-unknown --release`, and
+//! then generate its JavaScript glue with `wasm-bindgen`.
+//!
+//! ```js
+//! import init, { days, solve } from "./aoc_wasm.js";
+//!
+//! await init();
+//! console.log(days()); // Uint8Array [1, 2, 3, ...]
+//! console.log(solve(1, 1, "199\n200\n208\n210")); // "3"
+//! ```
+
+extern crate aoc_solvers;
+extern crate wasm_bindgen;
+
+use aoc_solvers::answer;
+use wasm_bindgen::prelude::*;
+
+/// Returns the answer to the specified `part` (1 or 2) of the puzzle of
+/// `day` described by `input`, throwing if it cannot be solved.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, JsError> {
+    answer(day, part, input).map_err(|error| JsError::new(&format!("{:#}", error)))
+}
+
+/// Returns every day of December whose puzzle [solve] can solve, in order.
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    aoc_solvers::days()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test(unsupported = test)]
+    fn solves_sonar_sweep_sample() {
+        let sonar_sweep_sample = include_str!("../../day-1/files/sample.txt");
+
+        assert_eq!(solve(1, 1, sonar_sweep_sample).unwrap(), "7");
+        assert_eq!(solve(1, 2, sonar_sweep_sample).unwrap(), "5");
+    }
+
+    #[test]
+    fn lists_every_exported_day() {
+        assert_eq!(
+            days(),
+            (1..=25)
+                .filter(|day| ![16, 19, 22].contains(day))
+                .collect::<Vec<u8>>()
+        );
+    }
+}
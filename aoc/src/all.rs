@@ -0,0 +1,153 @@
+use crate::registered;
+
+use aoc_common::args::Part;
+use aoc_common::output::{self, Emphasis};
+
+use anyhow::{anyhow, Result};
+use std::ffi::OsString;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Outcome of solving the puzzle of a single day.
+struct DayRun {
+    /// Day of December whose puzzle was solved.
+    day: u8,
+    /// How long solving the puzzle of [DayRun::day] took.
+    elapsed: Duration,
+    /// Answers and timings that solving the puzzle of [DayRun::day] gave,
+    /// already formatted.
+    output: Result<String>,
+}
+
+/// Solves the puzzle of every day of `year` with a registered solver, from
+/// its own puzzle input in the workspace at `root`, formatted as `day_args`
+/// specify, and only the specified `part` if there is one, printing every
+/// day's answers as soon as it finishes followed by how long it took to solve
+/// them all.
+///
+/// If `is_parallel`, days are solved at the same time on as many threads as
+/// there are cores, and so finish in no particular order; otherwise, they
+/// are solved one after another, in order.
+///
+/// Returns [ExitCode::FAILURE] if any day fails.
+pub fn run_all(
+    root: &Path,
+    year: u16,
+    part: Option<Part>,
+    day_args: &[OsString],
+    is_parallel: bool,
+) -> Result<ExitCode> {
+    let days = registered::days_of(year);
+    if days.is_empty() {
+        return Err(anyhow!("No day of {} has been solved yet", year));
+    }
+
+    let started_at = Instant::now();
+
+    let thread_count = match is_parallel {
+        true => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        false => 1,
+    };
+    let next_day_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel::<DayRun>();
+    let mut failed_days = vec![];
+
+    thread::scope(|scope| {
+        for _ in 0..thread_count.min(days.len()) {
+            let (days, next_day_index) = (&days, &next_day_index);
+            let sender = sender.clone();
+
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next_day_index.fetch_add(1, Ordering::Relaxed)) {
+                    let started_at = Instant::now();
+                    let output = registered::registration_of(year, day)
+                        .ok_or_else(|| anyhow!("No solver is registered for {} day {}", year, day))
+                        .and_then(|registration| {
+                            registered::solve(root, registration, part, day_args)
+                        });
+                    let day_run = DayRun {
+                        day,
+                        elapsed: started_at.elapsed(),
+                        output,
+                    };
+
+                    if sender.send(day_run).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        // Only the threads hold senders now, so the receiver runs dry once
+        // they have all finished.
+        drop(sender);
+
+        for day_run in receiver {
+            if !print_day_run(&day_run) {
+                failed_days.push(day_run.day);
+            }
+        }
+    });
+
+    println!(
+        "Total wall time: {}",
+        output::paint(
+            format!("{:.3?}", started_at.elapsed()),
+            Emphasis::Timing,
+            output::is_color_enabled()
+        )
+    );
+
+    if !failed_days.is_empty() {
+        failed_days.sort_unstable();
+        output::print_error(format!(
+            "Failed days: {}",
+            failed_days
+                .iter()
+                .map(u8::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ));
+
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Prints the heading of `day_run` followed by its answers, or by why its day
+/// failed, returning `true` if the day succeeded.
+fn print_day_run(day_run: &DayRun) -> bool {
+    let is_colored = output::is_color_enabled();
+    println!(
+        "{} {}",
+        output::paint(format!("Day {}", day_run.day), Emphasis::Header, is_colored),
+        output::paint(
+            format!("({:.3?})", day_run.elapsed),
+            Emphasis::Timing,
+            is_colored
+        )
+    );
+
+    let is_success = match &day_run.output {
+        Ok(formatted_answers) => {
+            print!("{}", formatted_answers);
+
+            true
+        }
+        Err(error) => {
+            output::print_error(format!("{:#}", error));
+
+            false
+        }
+    };
+
+    println!();
+
+    is_success
+}
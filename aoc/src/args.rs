@@ -0,0 +1,171 @@
+use crate::layout::DEFAULT_YEAR;
+
+use aoc_common::args::Part;
+use aoc_common::baseline::DEFAULT_MAX_SLOWDOWN;
+use clap::{Parser, Subcommand};
+use clap_complete::Shell;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+/// Address that `serve` listens on unless `--address` says otherwise.
+const DEFAULT_ADDRESS: &str = "127.0.0.1:2021";
+
+/// Root of the workspace that this program was built from, whose days solve
+/// puzzles unless `--root` says otherwise.
+///
+/// Built with `embedded-inputs`, this program solves the days whose puzzle
+/// inputs it embeds without one.
+const DEFAULT_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Solves any day's puzzle from anywhere in the workspace")]
+pub struct Args {
+    #[clap(subcommand)]
+    pub command: Command,
+
+    /// Directory of the workspace whose days solve puzzles, and whose puzzle
+    /// inputs they solve unless `--input` says otherwise.
+    #[clap(long, global = true, value_name = "DIR", default_value = DEFAULT_ROOT)]
+    pub root: PathBuf,
+
+    /// Year of Advent of Code whose puzzles to solve; days of years other
+    /// than 2021 live in e.g. "2020/day-1" within the workspace.
+    #[clap(long, global = true, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR)]
+    pub year: u16,
+}
+
+/// Enumerates what this program can be asked to do.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Prints a script that completes the arguments of this program in the
+    /// specified shell, e.g. `source <(aoc completions bash)`.
+    Completions {
+        /// Shell to complete the arguments in.
+        #[clap(value_enum)]
+        shell: Shell,
+    },
+    /// Solves the puzzle of the specified day, like `run`, comparing the
+    /// answers and how long they took to reach against the results that the
+    /// day saved earlier with `-- --save-results FILE`.
+    Diff {
+        /// Day of December whose puzzle to solve.
+        #[clap(long)]
+        day: u8,
+
+        /// Path to the results to compare against.
+        #[clap(long, value_name = "FILE")]
+        baseline: PathBuf,
+
+        /// Fails if solving took more than the specified percentage longer
+        /// than it did in the baseline results.
+        #[clap(long, value_name = "PERCENT", default_value_t = DEFAULT_MAX_SLOWDOWN)]
+        max_slowdown: f64,
+
+        /// Solves only the specified part of the puzzle instead of both.
+        #[clap(long, value_enum)]
+        part: Option<Part>,
+
+        /// Path to the puzzle input to solve instead of the day's own.
+        #[clap(long, value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Arguments passed along to the day that solves the puzzle.
+        #[clap(last = true, value_name = "DAY_ARGS")]
+        day_args: Vec<OsString>,
+    },
+    /// Solves the puzzle of the specified day, from its own puzzle input
+    /// unless `--input` says otherwise, or of every solved day with `--all`.
+    Run {
+        /// Day of December whose puzzle to solve.
+        #[clap(long, required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Solves the puzzle of every solved day, from its own puzzle input,
+        /// followed by how long solving them all took.
+        #[clap(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+
+        /// Solves the puzzles of `--all` at the same time, one per core,
+        /// printing each day's answers as soon as it finishes.
+        #[clap(long, requires = "all")]
+        parallel: bool,
+
+        /// Solves only the specified part of the puzzle instead of both.
+        #[clap(long, value_enum)]
+        part: Option<Part>,
+
+        /// Path to the puzzle input to solve instead of the day's own.
+        #[clap(long, value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Arguments passed along to the day that solves the puzzle, e.g.
+        /// `-- --format json`.
+        #[clap(last = true, value_name = "DAY_ARGS")]
+        day_args: Vec<OsString>,
+    },
+    /// Answers requests like `POST /2021/day/5/part/2`, whose body is the
+    /// puzzle input, with the JSON that the day prints its answer in.
+    Serve {
+        /// Address to listen for requests on.
+        #[clap(long, value_name = "ADDRESS", default_value = DEFAULT_ADDRESS)]
+        address: String,
+    },
+    /// Solves the puzzle of the specified day, like `run`, and then again
+    /// with its input rearranged by the specified seed, failing if the
+    /// answers change; only days whose answers should not depend on the
+    /// order of their input can be shuffled.
+    Shuffle {
+        /// Day of December whose puzzle to solve.
+        #[clap(long)]
+        day: u8,
+
+        /// Seed that decides how the input is rearranged.
+        #[clap(long)]
+        seed: u64,
+
+        /// Path to the puzzle input to solve instead of the day's own.
+        #[clap(long, value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Arguments passed along to the day that solves the puzzle.
+        #[clap(last = true, value_name = "DAY_ARGS")]
+        day_args: Vec<OsString>,
+    },
+    /// Solves the puzzle described by a puzzle input, working out which day
+    /// it is from by its shape unless `--day` says otherwise.
+    Solve {
+        /// Path to the puzzle input to solve.
+        #[clap(value_name = "FILE")]
+        input: PathBuf,
+
+        /// Day of December whose puzzle the input is for.
+        #[clap(long)]
+        day: Option<u8>,
+
+        /// Arguments passed along to the day that solves the puzzle, e.g.
+        /// `-- --part 2`.
+        #[clap(last = true, value_name = "DAY_ARGS")]
+        day_args: Vec<OsString>,
+    },
+    /// Solves the puzzle of the specified day, like `run`, and then again
+    /// every time its input file changes, printing how the answers changed,
+    /// until interrupted.
+    Watch {
+        /// Day of December whose puzzle to solve.
+        #[clap(long)]
+        day: u8,
+
+        /// Solves only the specified part of the puzzle instead of both.
+        #[clap(long, value_enum)]
+        part: Option<Part>,
+
+        /// Path to the puzzle input to watch instead of the day's own.
+        #[clap(long, value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Arguments passed along to the day that solves the puzzle.
+        #[clap(last = true, value_name = "DAY_ARGS")]
+        day_args: Vec<OsString>,
+    },
+}
@@ -0,0 +1,469 @@
+use anyhow::{anyhow, Result};
+
+/// Most digits that a sonar sweep depth is written with, beyond which a
+/// rectangle of digits is taken to be a grid instead.
+const MAX_DEPTH_DIGITS: usize = 5;
+
+/// Shape that the puzzle input of a single day takes, recognized by looking
+/// at the input alone.
+struct InputShape {
+    /// Day of December whose puzzle input takes this shape.
+    day: u8,
+    /// Returns `true` if the trimmed input passed to it takes this shape.
+    matches: fn(&str) -> bool,
+}
+
+/// Shape of the puzzle input of every day with a solution in this workspace.
+const INPUT_SHAPES: [InputShape; 22] = [
+    InputShape {
+        day: 1,
+        matches: is_depth_report,
+    },
+    InputShape {
+        day: 2,
+        matches: is_course,
+    },
+    InputShape {
+        day: 3,
+        matches: is_binary_grid,
+    },
+    InputShape {
+        day: 4,
+        matches: is_bingo_game,
+    },
+    InputShape {
+        day: 5,
+        matches: is_vent_lines,
+    },
+    InputShape {
+        day: 6,
+        matches: is_lanternfish_timers,
+    },
+    InputShape {
+        day: 7,
+        matches: is_crab_positions,
+    },
+    InputShape {
+        day: 8,
+        matches: is_display_entries,
+    },
+    InputShape {
+        day: 9,
+        matches: is_digit_grid,
+    },
+    InputShape {
+        day: 10,
+        matches: is_chunk_lines,
+    },
+    InputShape {
+        day: 11,
+        matches: is_octopus_grid,
+    },
+    InputShape {
+        day: 12,
+        matches: is_cave_connections,
+    },
+    InputShape {
+        day: 13,
+        matches: is_transparent_paper,
+    },
+    InputShape {
+        day: 14,
+        matches: is_polymer_instructions,
+    },
+    InputShape {
+        day: 15,
+        matches: is_digit_grid,
+    },
+    InputShape {
+        day: 17,
+        matches: is_target_area,
+    },
+    InputShape {
+        day: 18,
+        matches: is_snailfish_numbers,
+    },
+    InputShape {
+        day: 20,
+        matches: is_trench_map,
+    },
+    InputShape {
+        day: 21,
+        matches: is_starting_positions,
+    },
+    InputShape {
+        day: 23,
+        matches: is_burrow,
+    },
+    InputShape {
+        day: 24,
+        matches: is_alu_program,
+    },
+    InputShape {
+        day: 25,
+        matches: is_sea_floor,
+    },
+];
+
+/// Returns every day whose puzzle input is shaped like `input`, in order.
+pub fn days_matching(input: &str) -> Vec<u8> {
+    let input = input.trim();
+
+    INPUT_SHAPES
+        .iter()
+        .filter(|input_shape| (input_shape.matches)(input))
+        .map(|input_shape| input_shape.day)
+        .collect()
+}
+
+/// Returns the day whose puzzle input is shaped like `input`, or [Err] if no
+/// day's is or if several days' are.
+pub fn day_of(input: &str) -> Result<u8> {
+    match days_matching(input)[..] {
+        [] => Err(anyhow!(
+            "Input does not look like the puzzle input of any day; pass --day to choose one"
+        )),
+        [day] => Ok(day),
+        ref days => Err(anyhow!(
+            "Input looks like the puzzle input of days {}; pass --day to choose one",
+            days.iter()
+                .map(|day| day.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Returns `true` if every line of `input` is an integer, too short for the
+/// lines to be rows of a grid of digits, e.g. `199`.
+fn is_depth_report(input: &str) -> bool {
+    input.lines().all(|line| line.parse::<u64>().is_ok())
+        && !is_binary_grid(input)
+        && !is_digit_grid(input)
+}
+
+/// Returns `true` if every line of `input` is a direction and a distance, e.g.
+/// `forward 5`.
+fn is_course(input: &str) -> bool {
+    input.lines().all(|line| match line.split_once(' ') {
+        Some((direction, distance)) => {
+            ["down", "forward", "up"].contains(&direction) && distance.parse::<u64>().is_ok()
+        }
+        None => false,
+    })
+}
+
+/// Returns `true` if `input` is a rectangle of at least two columns of
+/// binary digits, e.g. `00100`.
+fn is_binary_grid(input: &str) -> bool {
+    is_grid_of(input, |c| c == '0' || c == '1') && input.lines().all(|line| line.len() > 1)
+}
+
+/// Returns `true` if `input` is a line of comma-separated numbers followed by
+/// boards of five rows of five numbers each.
+fn is_bingo_game(input: &str) -> bool {
+    let mut paragraphs = paragraphs_of(input);
+
+    let is_draw_line = paragraphs
+        .next()
+        .map(|draws| !draws.contains('\n') && is_comma_separated_integers(draws))
+        .unwrap_or(false);
+    let boards = paragraphs.collect::<Vec<&str>>();
+
+    is_draw_line
+        && !boards.is_empty()
+        && boards.iter().all(|board| {
+            board.lines().count() == 5
+                && board.lines().all(|row| {
+                    let numbers = row.split_whitespace().collect::<Vec<&str>>();
+
+                    numbers.len() == 5 && numbers.iter().all(|number| number.parse::<u32>().is_ok())
+                })
+        })
+}
+
+/// Returns `true` if every line of `input` is a chain of points joined by
+/// arrows, e.g. `0,9 -> 5,9`.
+fn is_vent_lines(input: &str) -> bool {
+    input.lines().all(|line| {
+        let points = line.split(" -> ").collect::<Vec<&str>>();
+
+        points.len() > 1 && points.iter().all(|point| is_coordinate(point))
+    })
+}
+
+/// Returns `true` if `input` is a single line of comma-separated timers from
+/// 0 to 8, e.g. `3,4,3,1,2`.
+fn is_lanternfish_timers(input: &str) -> bool {
+    !input.contains('\n')
+        && input
+            .split(',')
+            .all(|timer| matches!(timer.trim().parse::<u8>(), Ok(0..=8)))
+}
+
+/// Returns `true` if `input` is a single line of comma-separated positions,
+/// at least one of which is beyond any lanternfish timer, e.g. `16,1,2,0`.
+fn is_crab_positions(input: &str) -> bool {
+    !input.contains('\n') && is_comma_separated_integers(input) && !is_lanternfish_timers(input)
+}
+
+/// Returns `true` if every line of `input` is ten signal patterns and four
+/// output digits, separated by `|`.
+fn is_display_entries(input: &str) -> bool {
+    input.lines().all(|line| match line.split_once('|') {
+        Some((patterns, digits)) => {
+            let is_segments = |word: &str| word.chars().all(|c| ('a'..='g').contains(&c));
+
+            patterns
+                .split_whitespace()
+                .filter(|word| is_segments(word))
+                .count()
+                == 10
+                && digits
+                    .split_whitespace()
+                    .filter(|word| is_segments(word))
+                    .count()
+                    == 4
+        }
+        None => false,
+    })
+}
+
+/// Returns `true` if `input` is a rectangle of decimal digits wider than any
+/// sonar sweep depth, e.g. a heightmap or a map of risk levels.
+fn is_digit_grid(input: &str) -> bool {
+    is_grid_of(input, |c| c.is_ascii_digit())
+        && !is_binary_grid(input)
+        && input.lines().all(|line| line.len() > MAX_DEPTH_DIGITS)
+}
+
+/// Returns `true` if every line of `input` is made of nothing but opening
+/// and closing brackets, e.g. `[({(<(())[]>`.
+fn is_chunk_lines(input: &str) -> bool {
+    input
+        .lines()
+        .all(|line| !line.is_empty() && line.chars().all(|c| "()[]{}<>".contains(c)))
+}
+
+/// Returns `true` if `input` is a 10 by 10 grid of energy levels.
+fn is_octopus_grid(input: &str) -> bool {
+    is_digit_grid(input)
+        && input.lines().count() == 10
+        && input.lines().all(|line| line.len() == 10)
+}
+
+/// Returns `true` if every line of `input` connects two named caves, e.g.
+/// `start-A`.
+fn is_cave_connections(input: &str) -> bool {
+    input.lines().all(|line| match line.split_once('-') {
+        Some((from, to)) => [from, to]
+            .iter()
+            .all(|cave| !cave.is_empty() && cave.chars().all(|c| c.is_ascii_alphabetic())),
+        None => false,
+    })
+}
+
+/// Returns `true` if `input` is a paragraph of dots, e.g. `6,10`, followed
+/// by a paragraph of folds, e.g. `fold along y=7`.
+fn is_transparent_paper(input: &str) -> bool {
+    match paragraphs_of(input).collect::<Vec<&str>>()[..] {
+        [dots, folds] => {
+            dots.lines().all(is_coordinate)
+                && folds.lines().all(|fold| {
+                    fold.strip_prefix("fold along x=")
+                        .or_else(|| fold.strip_prefix("fold along y="))
+                        .map(|line| line.parse::<u32>().is_ok())
+                        .unwrap_or(false)
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `input` is a polymer template followed by a paragraph of
+/// pair insertion rules, e.g. `CH -> B`.
+fn is_polymer_instructions(input: &str) -> bool {
+    let is_elements = |elements: &str, count: Option<usize>| {
+        count.map_or(!elements.is_empty(), |count| elements.len() == count)
+            && elements.chars().all(|c| c.is_ascii_uppercase())
+    };
+
+    match paragraphs_of(input).collect::<Vec<&str>>()[..] {
+        [template, rules] => {
+            is_elements(template, None)
+                && rules.lines().all(|rule| match rule.split_once(" -> ") {
+                    Some((pair, element)) => {
+                        is_elements(pair, Some(2)) && is_elements(element, Some(1))
+                    }
+                    None => false,
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `input` describes a target area, e.g.
+/// `target area: x=20..30, y=-10..-5`.
+fn is_target_area(input: &str) -> bool {
+    input.starts_with("target area: x=") && !input.contains('\n')
+}
+
+/// Returns `true` if every line of `input` is a nested pair of numbers, e.g.
+/// `[[1,2],3]`.
+fn is_snailfish_numbers(input: &str) -> bool {
+    input.lines().all(|line| {
+        line.starts_with('[')
+            && line.chars().any(|c| c.is_ascii_digit())
+            && line
+                .chars()
+                .all(|c| c.is_ascii_digit() || "[],".contains(c))
+    })
+}
+
+/// Returns `true` if `input` is an image enhancement algorithm of light and
+/// dark pixels followed by an image of them.
+fn is_trench_map(input: &str) -> bool {
+    let is_pixels = |c: char| c == '#' || c == '.';
+
+    match paragraphs_of(input).collect::<Vec<&str>>()[..] {
+        [algorithm, image] => {
+            algorithm.chars().all(|c| is_pixels(c) || c == '\n') && is_grid_of(image, is_pixels)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `input` gives the starting position of each player, e.g.
+/// `Player 1 starting position: 4`.
+fn is_starting_positions(input: &str) -> bool {
+    input.lines().all(|line| {
+        line.starts_with("Player ")
+            && line
+                .split_once(" starting position: ")
+                .map(|(_, position)| position.parse::<u8>().is_ok())
+                .unwrap_or(false)
+    })
+}
+
+/// Returns `true` if `input` is a burrow of amphipods walled in by `#`.
+fn is_burrow(input: &str) -> bool {
+    input.starts_with("#############")
+        && input.chars().all(|c| "#.ABCD \n".contains(c) || c == '\r')
+}
+
+/// Returns `true` if every line of `input` is an ALU instruction, e.g.
+/// `inp w` or `mul x 0`.
+fn is_alu_program(input: &str) -> bool {
+    input.lines().all(|line| {
+        ["add ", "div ", "eql ", "inp ", "mod ", "mul "]
+            .iter()
+            .any(|operation| line.starts_with(operation))
+    })
+}
+
+/// Returns `true` if `input` is a rectangle of east-facing and south-facing
+/// sea cucumbers and empty space.
+fn is_sea_floor(input: &str) -> bool {
+    is_grid_of(input, |c| ">v.".contains(c))
+}
+
+/// Returns `true` if `input` is a comma-separated list of integers.
+fn is_comma_separated_integers(input: &str) -> bool {
+    input
+        .split(',')
+        .all(|number| number.trim().parse::<i64>().is_ok())
+}
+
+/// Returns `true` if `input` is an `x,y` pair of integers, e.g. `0,9`.
+fn is_coordinate(input: &str) -> bool {
+    match input.split_once(',') {
+        Some((x, y)) => x.parse::<i64>().is_ok() && y.parse::<i64>().is_ok(),
+        None => false,
+    }
+}
+
+/// Returns `true` if `input` is a rectangle, more than one line tall, of
+/// characters that all satisfy `is_cell`.
+fn is_grid_of(input: &str, is_cell: impl Fn(char) -> bool) -> bool {
+    let width = input.lines().next().map(str::len).unwrap_or(0);
+
+    input.lines().count() > 1
+        && input
+            .lines()
+            .all(|line| line.len() == width && line.chars().all(&is_cell))
+}
+
+/// Returns the paragraphs of `input`, which are separated by blank lines.
+fn paragraphs_of(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every day with a solution in this workspace, and its sample input.
+    const SAMPLES: [(u8, &str); 22] = [
+        (1, include_str!("../../day-1/files/sample.txt")),
+        (2, include_str!("../../day-2/files/sample.txt")),
+        (3, include_str!("../../day-3/files/sample.txt")),
+        (4, include_str!("../../day-4/files/sample.txt")),
+        (5, include_str!("../../day-5/files/sample.txt")),
+        (6, include_str!("../../day-6/files/sample.txt")),
+        (7, include_str!("../../day-7/files/sample.txt")),
+        (8, include_str!("../../day-8/files/sample.txt")),
+        (9, include_str!("../../day-9/files/sample.txt")),
+        (10, include_str!("../../day-10/files/sample.txt")),
+        (11, include_str!("../../day-11/files/sample.txt")),
+        (12, include_str!("../../day-12/files/sample.txt")),
+        (13, include_str!("../../day-13/files/sample.txt")),
+        (14, include_str!("../../day-14/files/sample.txt")),
+        (15, include_str!("../../day-15/files/sample.txt")),
+        (17, include_str!("../../day-17/files/sample.txt")),
+        (18, include_str!("../../day-18/files/sample.txt")),
+        (20, include_str!("../../day-20/files/sample.txt")),
+        (21, include_str!("../../day-21/files/sample.txt")),
+        (23, include_str!("../../day-23/files/sample.txt")),
+        (24, include_str!("../../day-24/files/sample.txt")),
+        (25, include_str!("../../day-25/files/sample.txt")),
+    ];
+
+    #[test]
+    fn detects_the_day_of_every_sample() {
+        for (day, sample) in SAMPLES {
+            let days = days_matching(sample);
+
+            assert!(days.contains(&day), "day {} matched {:?}", day, days);
+
+            // Digit grids cannot be told apart by shape alone.
+            if ![9, 11, 15].contains(&day) {
+                assert_eq!(day_of(sample).unwrap(), day);
+            }
+        }
+    }
+
+    #[test]
+    fn detects_the_day_of_every_input() {
+        for (day, input) in [
+            (1, include_str!("../../day-1/files/input.txt")),
+            (2, include_str!("../../day-2/files/input.txt")),
+            (3, include_str!("../../day-3/files/input.txt")),
+            (4, include_str!("../../day-4/files/input.txt")),
+            (5, include_str!("../../day-5/files/input.txt")),
+        ] {
+            assert_eq!(day_of(input).unwrap(), day);
+        }
+    }
+
+    #[test]
+    fn reports_ambiguous_and_unrecognized_inputs() {
+        assert_eq!(
+            day_of(SAMPLES[10].1).unwrap_err().to_string(),
+            "Input looks like the puzzle input of days 9, 11, 15; pass --day to choose one"
+        );
+        assert!(day_of("Hello, world!").is_err());
+    }
+}
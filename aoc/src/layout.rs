@@ -0,0 +1,27 @@
+use std::path::{Path, PathBuf};
+
+/// Year whose days live directly in the root of the workspace.
+pub const DEFAULT_YEAR: u16 = 2021;
+
+/// Returns the directory of the crate that solves the puzzle of `day` in
+/// `year` within the workspace at `root`: `day-N` for [DEFAULT_YEAR], and
+/// `YEAR/day-N` for every other year.
+pub fn crate_dir_of(root: &Path, year: u16, day: u8) -> PathBuf {
+    match year {
+        DEFAULT_YEAR => root.join(format!("day-{}", day)),
+        _ => root.join(year.to_string()).join(format!("day-{}", day)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nests_days_of_other_years_in_their_own_directory() {
+        let root = Path::new("aoc2021");
+
+        assert_eq!(crate_dir_of(root, 2021, 5), root.join("day-5"));
+        assert_eq!(crate_dir_of(root, 2020, 5), root.join("2020").join("day-5"));
+    }
+}
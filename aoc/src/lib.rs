@@ -0,0 +1,208 @@
+//! The `aoc` program, which solves any registered day's puzzle from anywhere,
+//! as a library, so that a crate outside of this workspace can build its own
+//! `aoc` that also solves puzzles with solvers of its own.
+//!
+//! ```no_run
+//! use aoc_common::registry::Registry;
+//! # use aoc_common::solver::Solver;
+//! use std::process::ExitCode;
+//!
+//! # struct ReportRepairSolver;
+//! # impl Solver for ReportRepairSolver {
+//! #     type Parsed = ();
+//! #     fn day(&self) -> u8 { 1 }
+//! #     fn parse(&self, _: &str) -> anyhow::Result<()> { Ok(()) }
+//! #     fn part_one(&self, _: &()) -> anyhow::Result<aoc_common::answer::Answer> { Ok(0.into()) }
+//! #     fn part_two(&self, _: &()) -> anyhow::Result<aoc_common::answer::Answer> { Ok(0.into()) }
+//! # }
+//! fn main() -> ExitCode {
+//!     let mut registry = Registry::new();
+//!     aoc_solvers::register_all(&mut registry).register(2020, "naive", ReportRepairSolver);
+//!
+//!     // e.g. `aoc run --year 2020 --day 1 --input expenses.txt`
+//!     aoc::main_with(registry)
+//! }
+//! ```
+
+extern crate anyhow;
+extern crate aoc_common;
+extern crate aoc_solvers;
+extern crate axum;
+extern crate clap;
+extern crate clap_complete;
+extern crate serde_json;
+extern crate tokio;
+
+mod all;
+mod args;
+mod detect;
+mod layout;
+mod registered;
+mod serve;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::args::{ParseArgs, Part};
+use aoc_common::completions;
+use aoc_common::error::{self, ErrorFormat, InvalidInput};
+use aoc_common::registry::Registry;
+use args::{Args, Command};
+use std::ffi::OsString;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Does whatever the command-line arguments ask for, solving every puzzle
+/// with its solver in `registry`, returning the [ExitCode] that the program
+/// should exit with.
+pub fn main_with(registry: Registry) -> ExitCode {
+    registered::set_registry(registry);
+    let args = Args::parse_args();
+
+    let result = match &args.command {
+        Command::Completions { shell } => {
+            completions::print::<Args>(*shell).map(|()| ExitCode::SUCCESS)
+        }
+        Command::Diff {
+            day,
+            baseline,
+            max_slowdown,
+            part,
+            input,
+            day_args,
+        } => run(
+            &args.root,
+            args.year,
+            *day,
+            input.as_deref(),
+            *part,
+            &with_day_args(
+                [
+                    "--baseline".into(),
+                    baseline.into(),
+                    "--max-slowdown".into(),
+                    max_slowdown.to_string().into(),
+                ],
+                day_args,
+            ),
+        ),
+        Command::Run {
+            all: true,
+            parallel,
+            part,
+            day_args,
+            ..
+        } => all::run_all(&args.root, args.year, *part, day_args, *parallel),
+        Command::Run {
+            day,
+            part,
+            input,
+            day_args,
+            ..
+        } => day
+            .context("--day is required without --all")
+            .and_then(|day| {
+                run(
+                    &args.root,
+                    args.year,
+                    day,
+                    input.as_deref(),
+                    *part,
+                    day_args,
+                )
+            }),
+        Command::Serve { address } => serve::serve(args.year, address),
+        Command::Shuffle {
+            day,
+            seed,
+            input,
+            day_args,
+        } => run(
+            &args.root,
+            args.year,
+            *day,
+            input.as_deref(),
+            None,
+            &with_day_args(["--shuffle".into(), seed.to_string().into()], day_args),
+        ),
+        Command::Solve {
+            input,
+            day,
+            day_args,
+        } => solve(&args.root, args.year, input, *day, day_args),
+        Command::Watch {
+            day,
+            part,
+            input,
+            day_args,
+        } => run(
+            &args.root,
+            args.year,
+            *day,
+            input.as_deref(),
+            *part,
+            &with_day_args(["--watch".into()], day_args),
+        ),
+    };
+
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(error) => error::report(&error, ErrorFormat::Text),
+    }
+}
+
+/// Solves the puzzle of `day` of `year` with its registered solver, from the
+/// puzzle input at `input_path` if there is one and its own in the workspace
+/// at `root` otherwise, and only the specified `part` if there is one,
+/// followed by `day_args`, returning the [ExitCode] that the day's own
+/// program would exit with.
+fn run(
+    root: &Path,
+    year: u16,
+    day: u8,
+    input_path: Option<&Path>,
+    part: Option<Part>,
+    day_args: &[OsString],
+) -> Result<ExitCode> {
+    if registered::registration_of(year, day).is_none() {
+        return Err(anyhow!("Day {} of {} has not been solved yet", day, year));
+    }
+
+    Ok(registered::run(root, year, day, input_path, part, day_args))
+}
+
+/// Solves the puzzle input at `input_path` with `day` of `year`, or with
+/// whichever day of 2021 its shape says it is for if there is no `day`, like
+/// [run] does with `day_args`, returning the [ExitCode] that the day's own
+/// program would exit with.
+fn solve(
+    root: &Path,
+    year: u16,
+    input_path: &Path,
+    day: Option<u8>,
+    day_args: &[OsString],
+) -> Result<ExitCode> {
+    let day = match day {
+        Some(day) => day,
+        None if year != layout::DEFAULT_YEAR => {
+            return Err(anyhow!(
+                "Only puzzle inputs of {} can be recognized by their shape, so --day is required for {}",
+                layout::DEFAULT_YEAR,
+                year
+            ));
+        }
+        None => {
+            let input = fs::read_to_string(input_path)
+                .with_context(|| format!("Failed to read \"{}\"", input_path.display()))?;
+
+            detect::day_of(&input).context(InvalidInput)?
+        }
+    };
+
+    run(root, year, day, Some(input_path), None, day_args)
+}
+
+/// Returns `flags` for the solver of a day, followed by the `day_args` passed
+/// along to it.
+fn with_day_args<const N: usize>(flags: [OsString; N], day_args: &[OsString]) -> Vec<OsString> {
+    flags.into_iter().chain(day_args.iter().cloned()).collect()
+}
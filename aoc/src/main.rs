@@ -0,0 +1,13 @@
+extern crate aoc;
+extern crate aoc_common;
+extern crate aoc_solvers;
+
+use aoc_common::registry::Registry;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut registry = Registry::new();
+    aoc_solvers::register_all(&mut registry);
+
+    aoc::main_with(registry)
+}
@@ -0,0 +1,166 @@
+use crate::layout;
+
+use anyhow::Result;
+use aoc_common::args::{CommonArgs, Part, INPUT_FILE_NAME};
+use aoc_common::input::{self, InputSource};
+use aoc_common::registry::{Registration, Registry};
+use clap::Parser;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::OnceLock;
+
+/// [Registry] set by [set_registry] that every puzzle is solved with.
+static REGISTRY: OnceLock<Registry> = OnceLock::new();
+
+/// Returns every day of December whose puzzle in `year` has a registered
+/// solver, in order.
+pub fn days_of(year: u16) -> Vec<u8> {
+    let mut days = registry()
+        .iter()
+        .filter(|registration| registration.year == year)
+        .map(|registration| registration.solver.day())
+        .collect::<Vec<u8>>();
+    days.sort_unstable();
+    days.dedup();
+
+    days
+}
+
+/// Returns the [Registration] of the solver of `day` in `year` that is
+/// registered with this program, if there is one.
+///
+/// Puzzles are only ever solved by their [Registration], although their
+/// puzzle inputs are still read from the workspace.
+pub fn registration_of(year: u16, day: u8) -> Option<&'static Registration> {
+    registry().get(year, day, None)
+}
+
+/// Solves the puzzle of `day` of `year` with its registered solver, from the
+/// puzzle input at `input_path` if there is one and its own in the workspace
+/// at `root` otherwise, and only the specified `part` if there is one,
+/// followed by `day_args`, printing its answers the way that the day's own
+/// program would.
+///
+/// Returns the [ExitCode] that the day's own program would exit with.
+pub fn run(
+    root: &Path,
+    year: u16,
+    day: u8,
+    input_path: Option<&Path>,
+    part: Option<Part>,
+    day_args: &[OsString],
+) -> ExitCode {
+    let mut args: Vec<OsString> = vec![
+        "aoc".into(),
+        "--year".into(),
+        year.to_string().into(),
+        "--day".into(),
+        day.to_string().into(),
+        "--input-dir".into(),
+        files_dir_of(root, year, day).into(),
+    ];
+
+    if let Some(input_path) = input_path {
+        args.extend(["--input".into(), input_path.into()]);
+    }
+
+    match part {
+        Some(Part::One) => args.extend(["--part".into(), "1".into()]),
+        Some(Part::Two) => args.extend(["--part".into(), "2".into()]),
+        None => {}
+    }
+
+    args.extend(day_args.iter().cloned());
+
+    if let Some(embedded_input) = embedded_input_of(year, day) {
+        input::embed(embedded_input);
+    }
+
+    registry().run_from(args)
+}
+
+/// Sets the [Registry] that every puzzle is solved with to `registry`.
+///
+/// Only the first call has any effect.
+pub fn set_registry(registry: Registry) {
+    let _ = REGISTRY.set(registry);
+}
+
+/// Returns the answers that `registration` gives to its own puzzle input in
+/// the workspace at `root`, including only the specified `part` if there is
+/// one, followed by how long reaching them took, formatted as `day_args`
+/// specify.
+///
+/// Nothing is printed, so a warning about which input was read is appended to
+/// the answers instead.
+pub fn solve(
+    root: &Path,
+    registration: &Registration,
+    part: Option<Part>,
+    day_args: &[OsString],
+) -> Result<String> {
+    let day = registration.solver.day();
+    let files_dir = files_dir_of(root, registration.year, day);
+    let common_args = CommonArgs::try_parse_from(
+        [
+            OsString::from("aoc"),
+            "--input-dir".into(),
+            files_dir.clone().into(),
+        ]
+        .into_iter()
+        .chain(day_args.iter().cloned()),
+    )?;
+
+    // Every day is solved by this one program, which can only ever embed a
+    // single input with [input::embed], so the day's own is picked here.
+    let (input_source, warning) = match embedded_input_of(registration.year, day) {
+        Some(embedded_input)
+            if common_args.input.is_none() && !files_dir.join(INPUT_FILE_NAME).exists() =>
+        {
+            (InputSource::Embedded(embedded_input), None)
+        }
+        _ => common_args.input_source_with_warning(),
+    };
+    let mut table = registration
+        .solver
+        .answers_table_with_timings(&input::load(input_source)?, part)?;
+
+    if let Some(warning) = warning {
+        table.separator().warning(warning);
+    }
+
+    Ok(common_args.format_table(&table))
+}
+
+/// Returns the personal puzzle input of `day` of `year` that is embedded in
+/// this program, if it was built with `embedded-inputs`.
+fn embedded_input_of(year: u16, day: u8) -> Option<&'static str> {
+    match year {
+        aoc_solvers::YEAR => aoc_solvers::embedded_input(day),
+        _ => None,
+    }
+}
+
+/// Returns the directory holding the puzzle inputs of `day` of `year` in the
+/// workspace at `root`.
+fn files_dir_of(root: &Path, year: u16, day: u8) -> PathBuf {
+    layout::crate_dir_of(root, year, day).join("files")
+}
+
+/// Returns the [Registry] set by [set_registry], or the one of every day in
+/// this workspace if there is none.
+fn registry() -> &'static Registry {
+    REGISTRY.get().unwrap_or_else(aoc_solvers::registry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_registered_days_of_each_year() {
+        assert_eq!(days_of(layout::DEFAULT_YEAR)[..3], [1, 2, 3]);
+        assert!(days_of(2015).is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+use crate::registered;
+
+use anyhow::{Context, Result};
+use aoc_common::args::Part;
+use aoc_common::error::{self, ErrorKind};
+use aoc_common::registry::Registration;
+use axum::extract::{Path as RoutePath, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Router;
+use serde_json::json;
+use std::process::ExitCode;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::task;
+
+/// What every request to the server shares.
+struct Server {
+    /// Year of Advent of Code whose puzzles are solved.
+    year: u16,
+}
+
+/// Answers requests like `POST /2021/day/5/part/2`, for `year`, on `address`
+/// until interrupted, solving every puzzle with its registered solver.
+///
+/// The body of every request is the puzzle input to solve, and the response
+/// is the JSON that the day prints with `--format json`, or that it reports
+/// failures with if it fails, along with:
+/// *   `422 Unprocessable Entity`\
+///     If the puzzle input cannot be interpreted or has no answer.
+/// *   `404 Not Found`\
+///     If the year, day, or part has not been solved.
+/// *   `500 Internal Server Error`\
+///     If the day fails in any other way.
+pub fn serve(year: u16, address: &str) -> Result<ExitCode> {
+    let server = Arc::new(Server { year });
+    let router = Router::new()
+        .route("/{year}/day/{day}/part/{part}", post(solve))
+        .with_state(server);
+
+    let runtime = tokio::runtime::Runtime::new().context("Failed to start tokio")?;
+    runtime.block_on(async {
+        let listener = TcpListener::bind(address)
+            .await
+            .with_context(|| format!("Failed to listen on {}", address))?;
+
+        eprintln!("Solving puzzles at http://{}", address);
+
+        axum::serve(listener, router)
+            .await
+            .context("Failed to serve requests")
+    })?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Returns a JSON response with `status_code` whose message is `message`.
+fn error_response(status_code: StatusCode, message: &str) -> Response {
+    (
+        status_code,
+        [(header::CONTENT_TYPE, "application/json")],
+        json!({ "message": message }).to_string(),
+    )
+        .into_response()
+}
+
+/// Answers a request to solve `part` of the puzzle of `day` in `year`, whose
+/// body is the puzzle `input`.
+async fn solve(
+    State(server): State<Arc<Server>>,
+    RoutePath((year, day, part)): RoutePath<(u16, u8, u8)>,
+    input: String,
+) -> Response {
+    let registration = match registered::registration_of(year, day) {
+        Some(registration) if year == server.year => registration,
+        _ => return error_response(StatusCode::NOT_FOUND, "Puzzle has not been solved yet"),
+    };
+    if part != 1 && part != 2 {
+        return error_response(StatusCode::NOT_FOUND, "Puzzles only have parts 1 and 2");
+    }
+
+    match solve_with_registration(registration, part, input).await {
+        Ok((status_code, json)) => (
+            status_code,
+            [(header::CONTENT_TYPE, "application/json")],
+            json,
+        )
+            .into_response(),
+        Err(error) => error_response(StatusCode::INTERNAL_SERVER_ERROR, &format!("{:#}", error)),
+    }
+}
+
+/// Solves `part` of the puzzle described by `input` with the solver of
+/// `registration`, returning the JSON that the day's own program would have
+/// written and the [StatusCode] that its exit code would correspond to.
+async fn solve_with_registration(
+    registration: &'static Registration,
+    part: u8,
+    input: String,
+) -> Result<(StatusCode, Vec<u8>)> {
+    let selected_part = if part == 1 { Part::One } else { Part::Two };
+    let answers_table = task::spawn_blocking(move || {
+        registration
+            .solver
+            .answers_table(&input, Some(selected_part))
+    })
+    .await
+    .with_context(|| format!("Failed to wait for day {}", registration.solver.day()))?;
+
+    Ok(match answers_table {
+        Ok(answers_table) => (
+            StatusCode::OK,
+            format!("{}\n", answers_table.answers_json()).into_bytes(),
+        ),
+        Err(error) => (
+            status_code_of(ErrorKind::of(&error).exit_code()),
+            format!("{}\n", error::json_of(&error)).into_bytes(),
+        ),
+    })
+}
+
+/// Returns the [StatusCode] that a day failing with `exit_code` corresponds
+/// to.
+fn status_code_of(exit_code: u8) -> StatusCode {
+    match exit_code {
+        // The input could not be interpreted or has no answer.
+        2 | 3 => StatusCode::UNPROCESSABLE_ENTITY,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
@@ -0,0 +1,49 @@
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+/// Root of the workspace that this program was built from, whose days'
+/// files are archived unless `--root` says otherwise.
+const DEFAULT_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Archives every day's files directory, or restores them from an archive")]
+pub struct Args {
+    #[clap(subcommand)]
+    pub command: Command,
+
+    /// Directory of the workspace whose days' files are archived or
+    /// restored.
+    #[clap(long, global = true, value_name = "DIR", default_value = DEFAULT_ROOT)]
+    pub root: PathBuf,
+
+    /// Encrypts the archive with AES-256 under the specified passphrase when
+    /// packing, or decrypts it when unpacking.
+    #[clap(
+        long,
+        global = true,
+        env = "AOC_ARCHIVE_PASSPHRASE",
+        hide_env_values = true
+    )]
+    pub passphrase: Option<String>,
+}
+
+/// Enumerates what this program can do with an archive.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Writes the files directory of every day to a zip archive.
+    Pack {
+        /// Path of the zip archive to write.
+        archive: PathBuf,
+    },
+    /// Restores the files directory of every day from a zip archive.
+    Unpack {
+        /// Path of the zip archive to read.
+        archive: PathBuf,
+
+        /// Overwrites files that differ from their archived copies instead
+        /// of failing.
+        #[clap(long)]
+        force: bool,
+    },
+}
@@ -0,0 +1,288 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use zip::result::ZipError;
+use zip::write::SimpleFileOptions;
+use zip::{AesMode, CompressionMethod, ZipArchive, ZipWriter};
+
+/// Name of the directory within each day's directory that holds its puzzle
+/// input and sample, which is what gets archived.
+const FILES_DIR_NAME: &str = "files";
+
+/// Writes every file in the files directory of every day in the workspace at
+/// `root` to a zip archive at `archive_path`, encrypted under `passphrase`
+/// if there is one, returning how many files were written.
+///
+/// Each file is stored at its path relative to `root`, e.g.
+/// `day-1/files/input.txt`.
+pub fn pack(root: &Path, archive_path: &Path, passphrase: Option<&str>) -> Result<usize> {
+    let day_file_paths = day_file_paths(root)?;
+
+    let archive_file = File::create(archive_path)
+        .with_context(|| format!("Failed to create \"{}\"", archive_path.display()))?;
+    let mut zip_writer = ZipWriter::new(archive_file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let options = match passphrase {
+        Some(passphrase) => options.with_aes_encryption(AesMode::Aes256, passphrase),
+        None => options,
+    };
+
+    for day_file_path in day_file_paths.iter() {
+        let contents = fs::read(root.join(day_file_path))
+            .with_context(|| format!("Failed to read \"{}\"", day_file_path.display()))?;
+
+        zip_writer.start_file(archive_name_of(day_file_path), options)?;
+        zip_writer.write_all(&contents)?;
+    }
+
+    zip_writer
+        .finish()
+        .with_context(|| format!("Failed to write \"{}\"", archive_path.display()))?;
+
+    Ok(day_file_paths.len())
+}
+
+/// Restores the files directory of every day in the workspace at `root` from
+/// the zip archive at `archive_path`, decrypting it with `passphrase` if it
+/// is encrypted, returning how many files were restored.
+///
+/// Nothing is written unless every file in the archive belongs in a day's
+/// files directory and, unless `is_forced`, matches any copy that is already
+/// there.
+pub fn unpack(
+    root: &Path,
+    archive_path: &Path,
+    passphrase: Option<&str>,
+    is_forced: bool,
+) -> Result<usize> {
+    let archive_file = File::open(archive_path)
+        .with_context(|| format!("Failed to open \"{}\"", archive_path.display()))?;
+    let mut zip_archive = ZipArchive::new(archive_file)
+        .with_context(|| format!("\"{}\" is not a zip archive", archive_path.display()))?;
+
+    let mut day_files = vec![];
+    for index in 0..zip_archive.len() {
+        let (day_file_path, contents) = read_day_file(&mut zip_archive, index, passphrase)?;
+        let path = root.join(&day_file_path);
+
+        if !is_forced && path.exists() && fs::read(&path)? != contents {
+            bail!(
+                "\"{}\" differs from its archived copy; pass --force to overwrite it",
+                path.display()
+            );
+        }
+
+        day_files.push((path, contents));
+    }
+
+    for (path, contents) in day_files.iter() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create \"{}\"", dir.display()))?;
+        }
+
+        fs::write(path, contents)
+            .with_context(|| format!("Failed to write \"{}\"", path.display()))?;
+    }
+
+    Ok(day_files.len())
+}
+
+/// Returns the name that the file at `day_file_path` is stored under in an
+/// archive, which separates directories with `/` on every platform.
+fn archive_name_of(day_file_path: &Path) -> String {
+    day_file_path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Returns the path, relative to `root`, of every file in the files directory
+/// of every day in the workspace at `root`, in order.
+fn day_file_paths(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut day_file_paths = vec![];
+
+    for entry in
+        fs::read_dir(root).with_context(|| format!("Failed to read \"{}\"", root.display()))?
+    {
+        let day_dir_name = entry?.file_name();
+        let files_dir = Path::new(&day_dir_name).join(FILES_DIR_NAME);
+
+        if is_day_dir_name(&day_dir_name.to_string_lossy()) && root.join(&files_dir).is_dir() {
+            collect_file_paths(root, &files_dir, &mut day_file_paths)?;
+        }
+    }
+
+    day_file_paths.sort();
+
+    Ok(day_file_paths)
+}
+
+/// Adds the path, relative to `root`, of every file within `dir` (itself
+/// relative to `root`) and its subdirectories to `file_paths`.
+fn collect_file_paths(root: &Path, dir: &Path, file_paths: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(root.join(dir))
+        .with_context(|| format!("Failed to read \"{}\"", dir.display()))?
+    {
+        let entry = entry?;
+        let path = dir.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            collect_file_paths(root, &path, file_paths)?;
+        } else {
+            file_paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if `path` lies within the files directory of a day, e.g.
+/// `day-1/files/input.txt`.
+fn is_day_file_path(path: &Path) -> bool {
+    let mut components = path.components();
+
+    matches!(
+        (components.next(), components.next(), components.next()),
+        (Some(Component::Normal(day_dir_name)), Some(Component::Normal(files_dir_name)), Some(_))
+            if is_day_dir_name(&day_dir_name.to_string_lossy())
+                && files_dir_name == FILES_DIR_NAME
+    )
+}
+
+/// Returns `true` if `name` is that of a day's directory, e.g. `day-1`.
+fn is_day_dir_name(name: &str) -> bool {
+    name.strip_prefix("day-")
+        .and_then(|day| day.parse::<u8>().ok())
+        .is_some()
+}
+
+/// Returns the path relative to the root of the workspace, and the contents,
+/// of the file at `index` in `zip_archive`, decrypting it with `passphrase`
+/// if it is encrypted.
+fn read_day_file(
+    zip_archive: &mut ZipArchive<File>,
+    index: usize,
+    passphrase: Option<&str>,
+) -> Result<(PathBuf, Vec<u8>)> {
+    let is_encrypted = zip_archive.by_index_raw(index)?.encrypted();
+    let mut zip_file = match (is_encrypted, passphrase) {
+        (true, None) => bail!("The archive is encrypted; pass --passphrase to decrypt it"),
+        (true, Some(passphrase)) => zip_archive
+            .by_index_decrypt(index, passphrase.as_bytes())
+            .map_err(|error| match error {
+                ZipError::InvalidPassword => anyhow!("The passphrase is wrong"),
+                error => error.into(),
+            })?,
+        (false, _) => zip_archive.by_index(index)?,
+    };
+
+    let day_file_path = zip_file
+        .enclosed_name()
+        .filter(|path| is_day_file_path(path))
+        .with_context(|| {
+            format!(
+                "\"{}\" does not belong in a day's files directory",
+                zip_file.name()
+            )
+        })?;
+
+    let mut contents = vec![];
+    zip_file
+        .read_to_end(&mut contents)
+        .with_context(|| format!("Failed to extract \"{}\"", zip_file.name()))?;
+
+    Ok((day_file_path, contents))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    /// Returns an empty directory for `test` to work in.
+    fn scratch_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-archive-{}-{}", test, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    /// Creates a workspace at `root` with day 1's input and sample, and a
+    /// file outside of any files directory.
+    fn create_workspace(root: &Path) {
+        fs::create_dir_all(root.join("day-1/files")).unwrap();
+        fs::create_dir_all(root.join("day-1/src")).unwrap();
+        fs::write(root.join("day-1/files/input.txt"), "199\n200\n").unwrap();
+        fs::write(root.join("day-1/files/sample.txt"), "1\n2\n").unwrap();
+        fs::write(root.join("day-1/src/main.rs"), "fn main() {}\n").unwrap();
+    }
+
+    #[test]
+    fn restores_the_files_that_it_packed() {
+        let dir = scratch_dir("round-trip");
+        let (packed_root, unpacked_root) = (dir.join("packed"), dir.join("unpacked"));
+        let archive_path = dir.join("inputs.zip");
+        create_workspace(&packed_root);
+
+        assert_eq!(pack(&packed_root, &archive_path, None).unwrap(), 2);
+        assert_eq!(
+            unpack(&unpacked_root, &archive_path, None, false).unwrap(),
+            2
+        );
+
+        assert_eq!(
+            fs::read_to_string(unpacked_root.join("day-1/files/input.txt")).unwrap(),
+            "199\n200\n"
+        );
+        assert!(!unpacked_root.join("day-1/src/main.rs").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn only_unpacks_encrypted_archives_with_the_right_passphrase() {
+        let dir = scratch_dir("encrypted");
+        let archive_path = dir.join("inputs.zip");
+        create_workspace(&dir);
+
+        pack(&dir, &archive_path, Some("hunter2")).unwrap();
+
+        assert!(unpack(&dir, &archive_path, None, false).is_err());
+        assert!(unpack(&dir, &archive_path, Some("hunter3"), false).is_err());
+        assert_eq!(
+            unpack(&dir, &archive_path, Some("hunter2"), false).unwrap(),
+            2
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn only_overwrites_changed_files_when_forced() {
+        let dir = scratch_dir("overwrite");
+        let archive_path = dir.join("inputs.zip");
+        create_workspace(&dir);
+
+        pack(&dir, &archive_path, None).unwrap();
+        fs::write(dir.join("day-1/files/input.txt"), "7\n").unwrap();
+
+        assert!(unpack(&dir, &archive_path, None, false).is_err());
+        assert_eq!(
+            fs::read_to_string(dir.join("day-1/files/input.txt")).unwrap(),
+            "7\n"
+        );
+
+        unpack(&dir, &archive_path, None, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("day-1/files/input.txt")).unwrap(),
+            "199\n200\n"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+extern crate anyhow;
+extern crate clap;
+extern crate zip;
+
+mod args;
+mod day_files;
+
+use anyhow::Result;
+use args::{Args, Command};
+use clap::Parser;
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let passphrase = args.passphrase.as_deref();
+
+    match &args.command {
+        Command::Pack { archive } => {
+            let file_count = day_files::pack(&args.root, archive, passphrase)?;
+
+            println!("Packed {} files into \"{}\"", file_count, archive.display());
+        }
+        Command::Unpack { archive, force } => {
+            let file_count = day_files::unpack(&args.root, archive, passphrase, *force)?;
+
+            println!(
+                "Unpacked {} files from \"{}\"",
+                file_count,
+                archive.display()
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use crate::day_status::DayStatus;
+
+use ratatui::crossterm::event::KeyCode;
+
+/// Enumerates what the dashboard can be asked to do with a key press.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    /// Nothing beyond updating what is shown.
+    None,
+    /// Closes the dashboard.
+    Quit,
+    /// Solves the puzzle of the specified day in the background.
+    Run(u8),
+    /// Solves the sample puzzle of the specified day with its registered
+    /// solver, without running the day.
+    SolveSample(u8),
+    /// Shows the visualization of the puzzle of the specified day.
+    Visualize(u8),
+}
+
+/// State of the dashboard: every day's status, which of them is selected, and
+/// the message describing whatever happened last.
+#[derive(Clone, Debug)]
+pub struct App {
+    /// Status of every day, in order.
+    pub day_statuses: Vec<DayStatus>,
+    /// Message describing whatever happened last.
+    pub message: String,
+    /// Day whose puzzle is being solved in the background, if there is one.
+    pub running_day: Option<u8>,
+    /// Index of the selected day within `day_statuses`.
+    pub selected: usize,
+}
+
+impl App {
+    /// Creates an [App] that shows `day_statuses` with the first day
+    /// selected.
+    pub fn new(day_statuses: Vec<DayStatus>) -> App {
+        App {
+            day_statuses,
+            message: String::new(),
+            running_day: None,
+            selected: 0,
+        }
+    }
+
+    /// Returns the [Action] that pressing the key with the specified
+    /// `key_code` asks for, moving the selection if that is what it asks for
+    /// instead.
+    pub fn handle_key(&mut self, key_code: KeyCode) -> Action {
+        match key_code {
+            KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.day_statuses.len() - 1);
+
+                Action::None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+
+                Action::None
+            }
+            KeyCode::Enter | KeyCode::Char('r') => self.act_on_selected_day(Action::Run),
+            KeyCode::Char('s') => {
+                let day_status = self.selected_day_status();
+                if !day_status.has_solver {
+                    self.message = format!("Day {} has no registered solver", day_status.day);
+
+                    return Action::None;
+                }
+
+                self.act_on_selected_day(Action::SolveSample)
+            }
+            KeyCode::Char('v') => self.act_on_selected_day(Action::Visualize),
+            _ => Action::None,
+        }
+    }
+
+    /// Returns the [DayStatus] of the selected day.
+    pub fn selected_day_status(&self) -> &DayStatus {
+        &self.day_statuses[self.selected]
+    }
+
+    /// Returns the [Action] that `action` creates for the selected day, or
+    /// [Action::None] with an explanation in the message if the selected day
+    /// cannot be acted upon.
+    fn act_on_selected_day(&mut self, action: impl FnOnce(u8) -> Action) -> Action {
+        let day_status = self.selected_day_status();
+
+        if !day_status.is_implemented {
+            self.message = format!("Day {} has not been implemented", day_status.day);
+
+            return Action::None;
+        }
+
+        if let Some(running_day) = self.running_day {
+            self.message = format!("Wait for day {} to finish first", running_day);
+
+            return Action::None;
+        }
+
+        action(day_status.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_status::Verification;
+
+    /// Returns an [App] showing days 1 to 3, of which only the first two
+    /// have been implemented.
+    fn app() -> App {
+        App::new(
+            (1..=3)
+                .map(|day| DayStatus {
+                    day,
+                    has_input: false,
+                    has_solver: day == 2,
+                    is_implemented: day < 3,
+                    last_results: None,
+                    last_run_at: None,
+                    verification: Verification::Unverified,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn moves_the_selection_within_bounds() {
+        let mut app = app();
+
+        assert_eq!(app.handle_key(KeyCode::Up), Action::None);
+        assert_eq!(app.selected, 0);
+
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Char('j'));
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.selected, 2);
+
+        app.handle_key(KeyCode::Char('k'));
+        assert_eq!(app.selected_day_status().day, 2);
+    }
+
+    #[test]
+    fn only_acts_on_implemented_days_one_at_a_time() {
+        let mut app = app();
+
+        assert_eq!(app.handle_key(KeyCode::Enter), Action::Run(1));
+        assert_eq!(app.handle_key(KeyCode::Char('v')), Action::Visualize(1));
+
+        app.running_day = Some(1);
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.handle_key(KeyCode::Char('r')), Action::None);
+        assert_eq!(app.message, "Wait for day 1 to finish first");
+
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.handle_key(KeyCode::Char('r')), Action::None);
+        assert_eq!(app.message, "Day 3 has not been implemented");
+
+        assert_eq!(app.handle_key(KeyCode::Char('q')), Action::Quit);
+    }
+
+    #[test]
+    fn only_solves_samples_of_days_with_registered_solvers() {
+        let mut app = app();
+
+        assert_eq!(app.handle_key(KeyCode::Char('s')), Action::None);
+        assert_eq!(app.message, "Day 1 has no registered solver");
+
+        app.handle_key(KeyCode::Down);
+        assert_eq!(app.handle_key(KeyCode::Char('s')), Action::SolveSample(2));
+    }
+}
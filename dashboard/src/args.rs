@@ -0,0 +1,25 @@
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Root of the workspace that this program was built from, whose days are
+/// shown unless `--root` says otherwise.
+const DEFAULT_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Shows every day's puzzle at a glance, and runs or visualizes them")]
+pub struct Args {
+    /// Directory of the workspace whose days are shown.
+    #[clap(long, value_name = "DIR", default_value = DEFAULT_ROOT)]
+    pub root: PathBuf,
+
+    /// Directory, relative to `--root`, that the results of every run are
+    /// saved to and read back from.
+    #[clap(long, value_name = "DIR", default_value = "target/dashboard")]
+    pub results_dir: PathBuf,
+
+    /// Builds days without optimizations before running them, which is
+    /// quicker to build but slower to run.
+    #[clap(long)]
+    pub debug: bool,
+}
@@ -0,0 +1,223 @@
+use anyhow::{Context, Result};
+use aoc_common::baseline::{self, SavedResults};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Path of the recorded answers that the last results of every day are
+/// verified against, relative to the root of the workspace, which git
+/// ignores.
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+/// Number of days of December with an Advent of Code puzzle.
+pub const DAY_COUNT: u8 = 25;
+
+/// Everything the dashboard shows about a single day's puzzle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayStatus {
+    /// Day of December whose puzzle this is.
+    pub day: u8,
+    /// Is `true` if the day has a personal puzzle input in its
+    /// `files/input.txt`.
+    pub has_input: bool,
+    /// Is `true` if the day's [Solver](aoc_common::solver::Solver) is
+    /// registered, so that it can solve puzzles without being run.
+    pub has_solver: bool,
+    /// Is `true` if the day is a member of the workspace.
+    pub is_implemented: bool,
+    /// Answers that the day gave, and how long they took to reach, the last
+    /// time that the dashboard ran it.
+    pub last_results: Option<SavedResults>,
+    /// When the dashboard last ran the day.
+    pub last_run_at: Option<SystemTime>,
+    /// How the answers that the day last gave compare to its recorded
+    /// answers.
+    pub verification: Verification,
+}
+
+/// Enumerates how the answers that a day last gave compare to the ones
+/// recorded in [ANSWERS_PATH].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verification {
+    /// Every answer matches its recorded answer.
+    Correct,
+    /// The day has no last answers, or no recorded answers to compare them
+    /// to.
+    Unverified,
+    /// At least one answer differs from its recorded answer.
+    Wrong,
+}
+
+impl DayStatus {
+    /// Returns the [DayStatus] of every day for the workspace at `root`,
+    /// whose runs saved their results to `results_dir`.
+    pub fn load_all(root: &Path, results_dir: &Path) -> Result<Vec<DayStatus>> {
+        let members = workspace_members(root)?;
+        let answers = recorded_answers(root)?;
+        let solver_days = aoc_solvers::days();
+
+        (1..=DAY_COUNT)
+            .map(|day| {
+                let results_path = results_path(results_dir, day);
+                let last_results = if results_path.exists() {
+                    Some(baseline::load(&results_path)?)
+                } else {
+                    None
+                };
+
+                let verification = match (&last_results, answers.get(&format!("day-{}", day))) {
+                    (Some(last_results), Some(day_answers)) => verify(last_results, day_answers),
+                    _ => Verification::Unverified,
+                };
+
+                Ok(DayStatus {
+                    day,
+                    has_input: day_dir(root, day).join("files/input.txt").exists(),
+                    has_solver: solver_days.contains(&day),
+                    is_implemented: members.contains(&format!("day-{}", day)),
+                    last_results,
+                    last_run_at: fs::metadata(&results_path)
+                        .and_then(|metadata| metadata.modified())
+                        .ok(),
+                    verification,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the directory of `day`'s crate in the workspace at `root`.
+pub fn day_dir(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day-{}", day))
+}
+
+/// Returns the path of the file in `results_dir` that the results of running
+/// `day` are saved to.
+pub fn results_path(results_dir: &Path, day: u8) -> PathBuf {
+    results_dir.join(format!("day-{}.json", day))
+}
+
+/// Returns the answers recorded in [ANSWERS_PATH] in the workspace at
+/// `root`, or none if it has not recorded any.
+fn recorded_answers(root: &Path) -> Result<toml::Table> {
+    let answers_path = root.join(ANSWERS_PATH);
+    if !answers_path.exists() {
+        return Ok(toml::Table::new());
+    }
+
+    let serialized_answers = fs::read_to_string(&answers_path)
+        .with_context(|| format!("Failed to read \"{}\"", answers_path.display()))?;
+
+    serialized_answers
+        .parse::<toml::Table>()
+        .with_context(|| format!("\"{}\" is not valid TOML", answers_path.display()))
+}
+
+/// Returns how the answers in `last_results` compare to `day_answers`, the
+/// recorded answers of the same day, which are keyed by part.
+fn verify(last_results: &SavedResults, day_answers: &toml::Value) -> Verification {
+    let expected_answers = ["part-one", "part-two"]
+        .iter()
+        .map(|key| day_answers.get(key).and_then(|answer| answer.as_str()));
+    let mut verification = Verification::Unverified;
+
+    for ((_, answer), expected_answer) in last_results.answers.iter().zip(expected_answers) {
+        match expected_answer {
+            Some(expected_answer) if expected_answer == answer => {
+                verification = Verification::Correct;
+            }
+            Some(_) => return Verification::Wrong,
+            None => return Verification::Unverified,
+        }
+    }
+
+    verification
+}
+
+/// Returns the name of every member of the workspace at `root`.
+fn workspace_members(root: &Path) -> Result<Vec<String>> {
+    let manifest_path = root.join("Cargo.toml");
+    let serialized_manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Failed to read \"{}\"", manifest_path.display()))?;
+    let manifest = serialized_manifest
+        .parse::<toml::Table>()
+        .with_context(|| format!("\"{}\" is not valid TOML", manifest_path.display()))?;
+
+    manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(|members| members.as_array())
+        .and_then(|members| {
+            members
+                .iter()
+                .map(|member| member.as_str().map(str::to_string))
+                .collect::<Option<Vec<String>>>()
+        })
+        .with_context(|| {
+            format!(
+                "\"{}\" does not list its workspace members",
+                manifest_path.display()
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::table::Table;
+    use std::env;
+    use std::process;
+    use std::time::Duration;
+
+    #[test]
+    fn loads_the_status_of_every_day() {
+        let root = env::temp_dir().join(format!("aoc-dashboard-{}", process::id()));
+        let results_dir = root.join("results");
+        fs::create_dir_all(day_dir(&root, 2).join("files")).unwrap();
+        fs::create_dir_all(&results_dir).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc-common\", \"day-1\", \"day-2\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join(ANSWERS_PATH),
+            "[day-1]\npart-one = \"7\"\n\n[day-3]\npart-one = \"9\"\n",
+        )
+        .unwrap();
+        fs::write(day_dir(&root, 2).join("files/input.txt"), "1\n").unwrap();
+        baseline::save(
+            1,
+            Table::new().answer("Part one", "7"),
+            Duration::from_millis(3),
+            &results_path(&results_dir, 1),
+        )
+        .unwrap();
+        baseline::save(
+            3,
+            Table::new().answer("Part one", "8"),
+            Duration::from_millis(3),
+            &results_path(&results_dir, 3),
+        )
+        .unwrap();
+
+        let day_statuses = DayStatus::load_all(&root, &results_dir).unwrap();
+
+        assert_eq!(day_statuses.len(), 25);
+        assert!(day_statuses[0].is_implemented && !day_statuses[0].has_input);
+        assert_eq!(
+            day_statuses[0].last_results.as_ref().unwrap().answers,
+            [("Part one".to_string(), "7".to_string())]
+        );
+        assert!(day_statuses[0].last_run_at.is_some());
+        assert_eq!(day_statuses[0].verification, Verification::Correct);
+        assert!(day_statuses[1].is_implemented && day_statuses[1].has_input);
+        assert!(day_statuses[1].last_results.is_none());
+        assert_eq!(day_statuses[1].verification, Verification::Unverified);
+        assert!(!day_statuses[2].is_implemented);
+        assert_eq!(day_statuses[2].verification, Verification::Wrong);
+        assert!(day_statuses[0].has_solver && !day_statuses[15].has_solver);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
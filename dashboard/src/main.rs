@@ -0,0 +1,102 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate aoc_solvers;
+extern crate clap;
+extern crate ratatui;
+
+mod app;
+mod args;
+mod day_status;
+mod runner;
+mod ui;
+
+use anyhow::{Context, Result};
+use app::{Action, App};
+use args::Args;
+use clap::Parser;
+use day_status::DayStatus;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use ratatui::DefaultTerminal;
+use runner::{Run, Runner};
+use std::io;
+use std::time::Duration;
+
+/// How long to wait for a key press before checking on a running day again.
+const TICK: Duration = Duration::from_millis(100);
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    // Days run from their own directories, so every path handed to them has
+    // to be absolute.
+    let root = args
+        .root
+        .canonicalize()
+        .with_context(|| format!("\"{}\" does not exist", args.root.display()))?;
+    let runner = Runner::new(&root, &args.results_dir, args.debug);
+    let app = App::new(DayStatus::load_all(runner.root(), runner.results_dir())?);
+
+    let terminal = ratatui::init();
+    let result = run(terminal, app, &runner);
+    ratatui::restore();
+
+    result
+}
+
+/// Shows the dashboard described by `app` on `terminal` until it is asked to
+/// quit, running days with `runner` as it is asked to.
+fn run(mut terminal: DefaultTerminal, mut app: App, runner: &Runner) -> Result<()> {
+    let mut run: Option<Run> = None;
+
+    loop {
+        let finished_run = run.as_ref().and_then(|run| Some((run.day, run.outcome()?)));
+        if let Some((day, outcome)) = finished_run {
+            app.message = match outcome {
+                Ok(()) => format!("Solved day {}", day),
+                Err(error) => error.to_string(),
+            };
+            app.day_statuses = DayStatus::load_all(runner.root(), runner.results_dir())?;
+            app.running_day = None;
+            run = None;
+        }
+
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+
+        if !event::poll(TICK)? {
+            continue;
+        }
+
+        let key_code = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key.code,
+            _ => continue,
+        };
+
+        match app.handle_key(key_code) {
+            Action::None => {}
+            Action::Quit => return Ok(()),
+            Action::Run(day) => {
+                run = Some(runner.run(day)?);
+                app.running_day = Some(day);
+                app.message = format!("Solving day {}…", day);
+            }
+            Action::SolveSample(day) => {
+                app.message = match runner.solve_sample(day) {
+                    Ok(answers) => format!("Day {}'s sample: {}", day, answers),
+                    Err(error) => format!("{:#}", error),
+                };
+            }
+            Action::Visualize(day) => {
+                ratatui::restore();
+                let visualized = runner.visualize(day);
+                println!("\nPress enter to return to the dashboard");
+                io::stdin().read_line(&mut String::new())?;
+                terminal = ratatui::init();
+
+                app.message = match visualized {
+                    Ok(()) => format!("Visualized day {}", day),
+                    Err(error) => error.to_string(),
+                };
+            }
+        }
+    }
+}
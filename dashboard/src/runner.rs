@@ -0,0 +1,161 @@
+use crate::day_status;
+
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Instant;
+
+/// Runs days of the workspace with `cargo run`, from their own directories
+/// so that they find their inputs and answers.
+#[derive(Clone, Debug)]
+pub struct Runner {
+    /// Is `true` if days are built without optimizations.
+    is_debug: bool,
+    /// Directory that the results of every run are saved to.
+    results_dir: PathBuf,
+    /// Root of the workspace.
+    root: PathBuf,
+}
+
+/// Run of a single day in the background, started by [Runner::run].
+#[derive(Debug)]
+pub struct Run {
+    /// Day of December whose puzzle is being solved.
+    pub day: u8,
+    /// Receives the output of `cargo run` once it finishes.
+    output: Receiver<Result<Output>>,
+}
+
+impl Runner {
+    /// Creates a [Runner] for the workspace at `root` that saves results to
+    /// `results_dir` (relative to `root`), and builds days without
+    /// optimizations if `is_debug` is `true`.
+    pub fn new(root: &Path, results_dir: &Path, is_debug: bool) -> Runner {
+        Runner {
+            is_debug,
+            results_dir: root.join(results_dir),
+            root: root.to_path_buf(),
+        }
+    }
+
+    /// Returns the directory that the results of every run are saved to.
+    pub fn results_dir(&self) -> &Path {
+        &self.results_dir
+    }
+
+    /// Returns the root of the workspace.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Starts solving `day` in the background, saving its results with
+    /// `--save-results` for the dashboard to read back.
+    pub fn run(&self, day: u8) -> Result<Run> {
+        fs::create_dir_all(&self.results_dir)
+            .with_context(|| format!("Failed to create \"{}\"", self.results_dir.display()))?;
+
+        let mut command = self.cargo_run(day);
+        command
+            .arg("--save-results")
+            .arg(day_status::results_path(&self.results_dir, day))
+            .stdin(Stdio::null());
+
+        let (sender, output) = mpsc::channel();
+        thread::spawn(move || {
+            let output = command
+                .output()
+                .with_context(|| format!("Failed to start day {}", day));
+
+            // The dashboard may have quit while the day was running.
+            let _ = sender.send(output);
+        });
+
+        Ok(Run { day, output })
+    }
+
+    /// Returns the answers to the sample puzzle input of `day`, followed by
+    /// how long they took to reach, as solved right here by the day's
+    /// registered [Solver](aoc_common::solver::Solver) instead of by running
+    /// the day.
+    pub fn solve_sample(&self, day: u8) -> Result<String> {
+        let registration = aoc_solvers::registry()
+            .get(aoc_solvers::YEAR, day, None)
+            .ok_or_else(|| anyhow!("Day {} has no registered solver", day))?;
+
+        let sample_path = day_status::day_dir(&self.root, day).join("files/sample.txt");
+        let sample = fs::read_to_string(&sample_path)
+            .with_context(|| format!("Failed to read \"{}\"", sample_path.display()))?;
+
+        let started_at = Instant::now();
+        let table = registration.solver.answers_table(&sample, None)?;
+        let elapsed = started_at.elapsed();
+
+        Ok(format!(
+            "{} ({:.1?})",
+            table.answers().collect::<Vec<&str>>().join(", "),
+            elapsed
+        ))
+    }
+
+    /// Shows the visualization of `day` in the terminal, returning once it
+    /// finishes.
+    pub fn visualize(&self, day: u8) -> Result<()> {
+        let status = self
+            .cargo_run(day)
+            .arg("--visualize")
+            .status()
+            .with_context(|| format!("Failed to start day {}", day))?;
+
+        if !status.success() {
+            return Err(anyhow!("Day {} could not be visualized", day));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a [Command] that builds and runs `day`, to which arguments for
+    /// the day itself can be appended.
+    fn cargo_run(&self, day: u8) -> Command {
+        let mut command = Command::new("cargo");
+        command
+            .current_dir(day_status::day_dir(&self.root, day))
+            .args(["run", "--quiet", "--package", &format!("day-{}", day)]);
+
+        if !self.is_debug {
+            command.arg("--release");
+        }
+
+        command.arg("--");
+
+        command
+    }
+}
+
+impl Run {
+    /// Returns how the run ended if it has, or [Option::None] if it is still
+    /// going.
+    pub fn outcome(&self) -> Option<Result<()>> {
+        let output = self.output.try_recv().ok()?;
+
+        Some(output.and_then(|output| {
+            if output.status.success() {
+                return Ok(());
+            }
+
+            // Days report failures on standard error starting with "Error:",
+            // after whatever cargo printed while building them.
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr
+                .lines()
+                .rev()
+                .find(|line| line.starts_with("Error:"))
+                .or_else(|| stderr.lines().last())
+                .unwrap_or("it exited unsuccessfully");
+
+            Err(anyhow!("Day {} failed: {}", self.day, reason))
+        }))
+    }
+}
@@ -0,0 +1,126 @@
+use crate::app::App;
+use crate::day_status::{DayStatus, Verification};
+
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Row, Table, TableState};
+use ratatui::Frame;
+use std::time::{Duration, SystemTime};
+
+/// Keys that the dashboard responds to, and what they do.
+const KEYBINDINGS: &str = "↑/↓ select · enter/r run · s solve sample · v visualize · q quit";
+
+/// Draws the dashboard described by `app` onto `frame`: a table of every
+/// day's status, followed by the latest message and the keybindings.
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [table_area, message_area, keybindings_area] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let header = Row::new([
+        "Day", "Status", "Input", "Part one", "Part two", "Verified", "Took", "Last run",
+    ])
+    .bold();
+    let rows = app
+        .day_statuses
+        .iter()
+        .map(|day_status| row_of(day_status, app.running_day == Some(day_status.day)));
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(3),
+            Constraint::Length(11),
+            Constraint::Length(5),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .block(Block::bordered().title(" Advent of Code 2021 "))
+    .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+    frame.render_stateful_widget(
+        table,
+        table_area,
+        &mut TableState::default().with_selected(Some(app.selected)),
+    );
+    frame.render_widget(Line::raw(app.message.as_str()), message_area);
+    frame.render_widget(Line::raw(KEYBINDINGS).dim(), keybindings_area);
+}
+
+/// Returns a [Row] describing `day_status`, which says that the day is being
+/// solved if `is_running`.
+fn row_of(day_status: &DayStatus, is_running: bool) -> Row<'static> {
+    let status = if is_running {
+        "running…"
+    } else if day_status.is_implemented {
+        "implemented"
+    } else {
+        "—"
+    };
+    let input = if day_status.has_input { "yes" } else { "no" };
+
+    let answer = |index: usize| {
+        day_status
+            .last_results
+            .as_ref()
+            .and_then(|results| results.answers.get(index))
+            .map(|(_, answer)| summary_of(answer))
+            .unwrap_or_default()
+    };
+    let verified = match day_status.verification {
+        Verification::Correct => "✓",
+        Verification::Unverified => "",
+        Verification::Wrong => "✗",
+    };
+    let took = day_status
+        .last_results
+        .as_ref()
+        .map(|results| format!("{:.1?}", results.elapsed))
+        .unwrap_or_default();
+    let last_run = day_status
+        .last_run_at
+        .and_then(|last_run_at| SystemTime::now().duration_since(last_run_at).ok())
+        .map(age_of)
+        .unwrap_or_default();
+
+    Row::new([
+        day_status.day.to_string(),
+        status.to_string(),
+        input.to_string(),
+        answer(0),
+        answer(1),
+        verified.to_string(),
+        took,
+        last_run,
+    ])
+}
+
+/// Returns `answer` as it fits on a single line, which is just how many lines
+/// it has if it has several (e.g. letters drawn in ASCII art).
+fn summary_of(answer: &str) -> String {
+    match answer.trim().lines().count() {
+        0 | 1 => answer.to_string(),
+        line_count => format!("({} lines)", line_count),
+    }
+}
+
+/// Returns how long ago something happened `elapsed` ago, in its largest
+/// whole unit, e.g. "3m ago".
+fn age_of(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3_599 => format!("{}m ago", secs / 60),
+        3_600..=86_399 => format!("{}h ago", secs / 3_600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
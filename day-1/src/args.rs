@@ -0,0 +1,15 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Counts how often the depths of a sonar sweep increase")]
+pub struct Args {
+    /// Narrates how the answers are found, step by step, before printing
+    /// them.
+    #[clap(long)]
+    pub explain: bool,
+
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
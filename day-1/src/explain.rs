@@ -0,0 +1,39 @@
+use aoc_common::table::Table;
+use day_1::sonar_sweep::pairwise;
+use std::fmt::Display;
+
+/// Prints every value in `sequence` alongside how it compares to the value
+/// before it, the way the puzzle narrates its example, followed by how many
+/// of the values are larger than the value before them.
+///
+/// Parameters:
+/// *   `noun`\
+///     Names each value in `sequence` (e.g. "measurement").
+pub fn narrate_increases<T: Display + PartialOrd>(noun: &str, sequence: &[T]) {
+    let mut increases = 0;
+    let mut table = Table::new();
+
+    for (maybe_previous, next) in pairwise(sequence) {
+        let comparison = match maybe_previous {
+            None => format!("(N/A - no previous {})", noun),
+            Some(previous) if next > previous => {
+                increases += 1;
+
+                "(increased)".to_string()
+            }
+            Some(previous) if next < previous => "(decreased)".to_string(),
+            Some(_) => "(no change)".to_string(),
+        };
+
+        table.row([next.to_string(), comparison]);
+    }
+
+    table
+        .separator()
+        .title(format!(
+            "{} {}s are larger than the previous {}.",
+            increases, noun, noun
+        ))
+        .separator()
+        .print();
+}
@@ -0,0 +1,10 @@
+//! Solves the "Sonar Sweep" puzzle.
+
+extern crate anyhow;
+extern crate aoc_common;
+extern crate itertools;
+extern crate tracing;
+
+pub mod fixtures;
+pub mod solver;
+pub mod sonar_sweep;
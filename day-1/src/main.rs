@@ -1,112 +1,131 @@
 extern crate anyhow;
-extern crate itertools;
-extern crate tokio;
-
-use anyhow::{Context, Error, Result};
-use itertools::izip;
-use std::env::current_dir;
-use std::iter;
-use tokio::fs::File;
-use tokio::io::AsyncReadExt;
-
-#[tokio::main]
-async fn main() -> Result<()> {
-    let sonar_sweep_depths = read_sonar_sweep_depths("files/input.txt").await?;
-
-    let number_of_depth_increases = number_of_increases_in(&sonar_sweep_depths);
-    println!("Number of depth increases: {}", number_of_depth_increases);
-
-    let three_measurement_sums = triplewise(sonar_sweep_depths)
-        .filter(|(maybe_first, maybe_second, _)| !maybe_first.is_none() && !maybe_second.is_none())
-        .map(|(maybe_first, maybe_second, third)| {
-            maybe_first.unwrap_or(0) + maybe_second.unwrap_or(0) + third
-        })
-        .collect::<Vec<i32>>();
-
-    let three_measurement_sum_increases = number_of_increases_in(&three_measurement_sums);
-    println!(
-        "Number of three-measurement sum increases: {}",
-        three_measurement_sum_increases
-    );
-
-    Ok(())
+extern crate aoc_common;
+extern crate clap;
+extern crate day_1;
+
+mod args;
+mod explain;
+
+use anyhow::Result;
+use aoc_common::args::ParseArgs;
+use aoc_common::args::Part;
+use aoc_common::batch;
+use aoc_common::completions;
+use aoc_common::concurrent;
+use aoc_common::input;
+use aoc_common::table::Table;
+use aoc_common::timing::Timings;
+use aoc_common::watch;
+use args::Args;
+use day_1::sonar_sweep::{self, SonarSweepDepths};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    #[cfg(feature = "embedded-inputs")]
+    input::embed(include_str!("../files/input.txt"));
+
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
 }
 
-/// Returns the number of increases in the given `sequence` of integers.
-fn number_of_increases_in<'a, I>(sequence: I) -> usize
-where
-    I: IntoIterator<Item = &'a i32> + Clone,
-{
-    pairwise(sequence)
-        .filter(|(maybe_prev, next)| match maybe_prev {
-            Some(prev) => next > prev,
-            _ => false,
-        })
-        .map(|(_, next)| next)
-        .count()
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return batch::write_answers_csv(
+            inputs,
+            args.common.out.as_deref(),
+            args.common.part,
+            |input, part| {
+                Ok(answers_table(
+                    &sonar_sweep::parse_sonar_sweep_depths(input)?,
+                    Some(part),
+                ))
+            },
+        );
+    }
+
+    if args.common.watch {
+        return watch::watch_answers(&args.common, |input| {
+            Ok(answers_table(
+                &sonar_sweep::parse_sonar_sweep_depths(input)?,
+                args.common.part,
+            ))
+        });
+    }
+
+    args.common.forbid_visualize(1)?;
+    let mut timings = Timings::new();
+    let sonar_sweep_depths: SonarSweepDepths =
+        input::load_parsed(args.common.input_source(), &mut timings)?;
+
+    if args.explain {
+        explain::narrate_increases("measurement", &sonar_sweep_depths);
+        explain::narrate_increases(
+            "sum",
+            &sonar_sweep::three_measurement_sums_of(&sonar_sweep_depths),
+        );
+    }
+
+    args.common.print_answers(
+        1,
+        &concurrent::solve_parts(args.common.part, timings, |part| {
+            Ok(answers_table(&sonar_sweep_depths, Some(part)))
+        })?,
+    )
 }
 
-/// Returns a new [Iterator] that places each element of the given iterator on
-/// the right side of a tuple, placing the element before to its left
-/// (e.g. `(prev, next)`).
-fn pairwise<I>(right: I) -> impl Iterator<Item = (Option<I::Item>, I::Item)>
-where
-    I: IntoIterator + Clone,
-{
-    let left = iter::once(None).chain(right.clone().into_iter().map(Some));
-    left.zip(right)
+/// Returns a [Table] of the answers to the puzzle for the specified
+/// `sonar_sweep_depths`, including only the specified `part` if there is one
+/// and both parts otherwise.
+fn answers_table(sonar_sweep_depths: &[i32], part: Option<Part>) -> Table {
+    let mut table = Table::new();
+
+    if Part::One.is_selected_by(part) {
+        table.answer(
+            "Number of depth increases",
+            sonar_sweep::number_of_increases_in(sonar_sweep_depths),
+        );
+    }
+
+    if Part::Two.is_selected_by(part) {
+        table.answer(
+            "Number of three-measurement sum increases",
+            sonar_sweep::number_of_increases_in(&sonar_sweep::three_measurement_sums_of(
+                sonar_sweep_depths,
+            )),
+        );
+    }
+
+    table
 }
 
-/// Reads the contents of the "sonar sweep" input file as a newline-separated
-/// list of integer depths.
-async fn read_sonar_sweep_depths(sonar_sweep_file_path: &str) -> Result<Vec<i32>, Error> {
-    let pwd = current_dir().context("Failed to read current working directory")?;
-    let sonar_sweep_file_path_buf = pwd.join(sonar_sweep_file_path);
-
-    let mut sonar_sweep_file = File::open(&sonar_sweep_file_path_buf)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to open file at path \"{}\"",
-                sonar_sweep_file_path_buf.display()
-            )
-        })?;
-    let mut raw_sonar_sweep_file_contents = vec![];
-
-    sonar_sweep_file
-        .read_to_end(&mut raw_sonar_sweep_file_contents)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to read file at path \"{}\"",
-                sonar_sweep_file_path_buf.display()
-            )
-        })?;
-
-    let sonar_sweep_file_contents = String::from_utf8_lossy(&raw_sonar_sweep_file_contents);
-
-    let sonar_sweep_depths = sonar_sweep_file_contents
-        .lines()
-        .map(|raw_depth| {
-            raw_depth
-                .parse::<i32>()
-                .with_context(|| format!("\"{}\" is not a valid integer", raw_depth))
-        })
-        .collect::<Result<Vec<i32>>>()
-        .context("Failed to parse sonar sweep depths")?;
-
-    Ok(sonar_sweep_depths)
-}
-
-/// Returns a new [Iterator] that places each element of the given iterator on
-/// the right side of a tuple, placing the two elements before to its left
-/// (e.g. `(2 before, 1 before, element)`).
-fn triplewise<I>(right: I) -> impl Iterator<Item = (Option<I::Item>, Option<I::Item>, I::Item)>
-where
-    I: IntoIterator + Clone,
-{
-    let middle = iter::once(None).chain(right.clone().into_iter().map(Some));
-    let left = iter::once(None).chain(iter::once(None).chain(right.clone().into_iter().map(Some)));
-
-    izip!(left, middle, right)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers;
+    use day_1::fixtures::{INPUT, SAMPLE};
+    use insta::assert_snapshot;
+
+    /// Returns the depths listed in `serialized_depths`.
+    fn depths_in(serialized_depths: &str) -> Vec<i32> {
+        sonar_sweep::parse_sonar_sweep_depths(serialized_depths).unwrap()
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&depths_in(SAMPLE), None).to_string());
+    }
+
+    #[test]
+    fn agrees_with_local_answers_to_input() {
+        answers::assert_matches_local_answers(1, &answers_table(&depths_in(INPUT), None));
+    }
 }
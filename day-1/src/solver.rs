@@ -0,0 +1,52 @@
+use crate::sonar_sweep::{self, SonarSweepDepths};
+
+use anyhow::Result;
+use aoc_common::answer::Answer;
+use aoc_common::input::FromInput;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Sonar Sweep" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SonarSweepSolver;
+
+impl Solver for SonarSweepSolver {
+    type Parsed = SonarSweepDepths;
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input: &str) -> Result<SonarSweepDepths> {
+        SonarSweepDepths::from_input(input)
+    }
+
+    fn part_one(&self, sonar_sweep_depths: &SonarSweepDepths) -> Result<Answer> {
+        Ok(sonar_sweep::number_of_increases_in(sonar_sweep_depths.iter()).into())
+    }
+
+    fn part_two(&self, sonar_sweep_depths: &SonarSweepDepths) -> Result<Answer> {
+        let three_measurement_sums = sonar_sweep::three_measurement_sums_of(sonar_sweep_depths);
+
+        Ok(sonar_sweep::number_of_increases_in(&three_measurement_sums).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = SonarSweepSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SonarSweepSolver.part_one(&parsed).unwrap(), "7");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = SonarSweepSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SonarSweepSolver.part_two(&parsed).unwrap(), "5");
+    }
+}
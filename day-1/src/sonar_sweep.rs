@@ -0,0 +1,130 @@
+use anyhow::{Context, Error, Result};
+use aoc_common::input::{DeserializeLines, FromInput};
+use aoc_common::parse;
+use itertools::izip;
+use std::iter;
+use std::ops::Deref;
+use tracing::instrument;
+
+/// Integer depths measured by the sonar sweep, in the order that the puzzle
+/// input lists them.
+pub struct SonarSweepDepths(Vec<i32>);
+
+impl Deref for SonarSweepDepths {
+    type Target = [i32];
+
+    fn deref(&self) -> &[i32] {
+        &self.0
+    }
+}
+
+impl DeserializeLines for SonarSweepDepths {
+    type Line = i32;
+
+    fn deserialize_line(line: &str) -> Result<i32> {
+        parse::signed_int::<i32>(line)
+    }
+
+    fn from_lines(depths: Vec<i32>) -> SonarSweepDepths {
+        SonarSweepDepths(depths)
+    }
+}
+
+impl FromInput for SonarSweepDepths {
+    fn from_input(input: &str) -> Result<SonarSweepDepths> {
+        parse_sonar_sweep_depths(input).map(SonarSweepDepths)
+    }
+}
+
+/// Returns the number of increases in the given `sequence` of integers.
+#[instrument(skip_all)]
+pub fn number_of_increases_in<'a, T, I>(sequence: I) -> usize
+where
+    T: PartialOrd + 'a,
+    I: IntoIterator<Item = &'a T> + Clone,
+{
+    pairwise(sequence)
+        .filter(|(maybe_prev, next)| match maybe_prev {
+            Some(prev) => next > prev,
+            _ => false,
+        })
+        .count()
+}
+
+/// Returns a new [Iterator] that places each element of the given iterator on
+/// the right side of a tuple, placing the element before to its left
+/// (e.g. `(prev, next)`).
+pub fn pairwise<I>(right: I) -> impl Iterator<Item = (Option<I::Item>, I::Item)>
+where
+    I: IntoIterator + Clone,
+{
+    let left = iter::once(None).chain(right.clone().into_iter().map(Some));
+    left.zip(right)
+}
+
+/// Interprets `serialized_depths` as a newline-separated list of integer
+/// depths.
+pub fn parse_sonar_sweep_depths(serialized_depths: &str) -> Result<Vec<i32>, Error> {
+    serialized_depths
+        .lines()
+        .map(parse::signed_int::<i32>)
+        .collect::<Result<Vec<i32>>>()
+        .context("Failed to parse sonar sweep depths")
+}
+
+/// Returns the sum of every window of three consecutive depths in
+/// `sonar_sweep_depths`, widened so that no sum of three depths overflows.
+pub fn three_measurement_sums_of(sonar_sweep_depths: &[i32]) -> Vec<i64> {
+    triplewise(sonar_sweep_depths)
+        .filter(|(maybe_first, maybe_second, _)| !maybe_first.is_none() && !maybe_second.is_none())
+        .map(|(maybe_first, maybe_second, third)| {
+            i64::from(*maybe_first.unwrap_or(&0))
+                + i64::from(*maybe_second.unwrap_or(&0))
+                + i64::from(*third)
+        })
+        .collect::<Vec<i64>>()
+}
+
+/// Returns a new [Iterator] that places each element of the given iterator on
+/// the right side of a tuple, placing the two elements before to its left
+/// (e.g. `(2 before, 1 before, element)`).
+fn triplewise<I>(right: I) -> impl Iterator<Item = (Option<I::Item>, Option<I::Item>, I::Item)>
+where
+    I: IntoIterator + Clone,
+{
+    let middle = iter::once(None).chain(right.clone().into_iter().map(Some));
+    let left = iter::once(None).chain(iter::once(None).chain(right.clone().into_iter().map(Some)));
+
+    izip!(left, middle, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn counts_depth_increases_in_sample() {
+        let sonar_sweep_depths = parse_sonar_sweep_depths(SAMPLE).unwrap();
+
+        assert_eq!(number_of_increases_in(&sonar_sweep_depths), 7);
+    }
+
+    #[test]
+    fn counts_three_measurement_sum_increases_in_sample() {
+        let sonar_sweep_depths = parse_sonar_sweep_depths(SAMPLE).unwrap();
+        let three_measurement_sums = three_measurement_sums_of(&sonar_sweep_depths);
+
+        assert_eq!(number_of_increases_in(&three_measurement_sums), 5);
+    }
+
+    #[test]
+    fn sums_measurements_near_the_i32_limit_without_overflowing() {
+        let sonar_sweep_depths = [i32::MAX, i32::MAX, i32::MAX, i32::MAX - 1];
+
+        assert_eq!(
+            three_measurement_sums_of(&sonar_sweep_depths),
+            [3 * i32::MAX as i64, 3 * i32::MAX as i64 - 1]
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_10::solver::SyntaxScoringSolver;
+
+fn bench_syntax_scoring_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &SyntaxScoringSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_syntax_scoring_solver);
+criterion_main!(benches);
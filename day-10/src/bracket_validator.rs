@@ -0,0 +1,140 @@
+use anyhow::{anyhow, Result};
+
+/// Pairs of opening and closing characters recognized by the navigation
+/// subsystem.
+pub const NAVIGATION_BRACKETS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Describes where, and how, a line of chunks is corrupted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Corruption {
+    /// Closing character that was expected instead of
+    /// [Corruption::found], or [Option::None] if no chunk was open.
+    pub expected: Option<char>,
+    /// Closing character that does not match the chunk it closes.
+    pub found: char,
+    /// Zero-based index of the character where the chunk being closed was
+    /// opened, or [Option::None] if no chunk was open.
+    pub opened_at: Option<usize>,
+    /// Zero-based index of [Corruption::found] within its line.
+    pub position: usize,
+}
+
+/// Outcome of checking a single line of chunks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Validation {
+    /// Every chunk in the line is opened and closed correctly.
+    Complete,
+    /// A chunk in the line is closed with the wrong character.
+    Corrupted(Corruption),
+    /// The line ends while chunks are still open; the completion is the
+    /// sequence of closing characters that would close all of them.
+    Incomplete(String),
+}
+
+/// Checks lines of chunks delimited by matched pairs of opening and closing
+/// characters, like `[<>({}){}[([])<>]]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BracketValidator {
+    /// Every pair of opening and closing characters delimiting chunks.
+    brackets: Vec<(char, char)>,
+}
+
+impl BracketValidator {
+    /// Creates a [BracketValidator] that recognizes the given pairs of opening
+    /// and closing characters, returning [Err] if any character is used more
+    /// than once.
+    pub fn with_brackets(brackets: &[(char, char)]) -> Result<BracketValidator> {
+        let mut characters = brackets
+            .iter()
+            .flat_map(|(opening, closing)| [*opening, *closing])
+            .collect::<Vec<char>>();
+        let character_count = characters.len();
+
+        characters.sort_unstable();
+        characters.dedup();
+        if characters.len() != character_count {
+            return Err(anyhow!(
+                "{:?} uses the same character for multiple brackets",
+                brackets
+            ));
+        }
+
+        Ok(BracketValidator {
+            brackets: brackets.to_vec(),
+        })
+    }
+
+    /// Checks whether the chunks in `line` are complete, corrupted, or
+    /// incomplete, returning [Err] at the first character that is not one of
+    /// the recognized brackets.
+    pub fn validate(&self, line: &str) -> Result<Validation> {
+        // Each open chunk is remembered by the closing character it expects
+        // and where it was opened.
+        let mut open_chunks: Vec<(char, usize)> = vec![];
+
+        for (position, character) in line.chars().enumerate() {
+            if let Some(closing) = self.closing_for(character) {
+                open_chunks.push((closing, position));
+
+                continue;
+            }
+
+            if !self.is_closing(character) {
+                return Err(anyhow!(
+                    "\"{}\" at position {} of \"{}\" is not a bracket",
+                    character,
+                    position,
+                    line
+                ));
+            }
+
+            match open_chunks.pop() {
+                Some((expected, _)) if expected == character => {}
+                open_chunk => {
+                    return Ok(Validation::Corrupted(Corruption {
+                        expected: open_chunk.map(|(expected, _)| expected),
+                        found: character,
+                        opened_at: open_chunk.map(|(_, opened_at)| opened_at),
+                        position,
+                    }));
+                }
+            }
+        }
+
+        if open_chunks.is_empty() {
+            return Ok(Validation::Complete);
+        }
+
+        Ok(Validation::Incomplete(
+            open_chunks
+                .iter()
+                .rev()
+                .map(|(closing, _)| *closing)
+                .collect(),
+        ))
+    }
+
+    /// Returns the closing character matching the specified `opening`
+    /// character, or [Option::None] if it does not open a chunk.
+    fn closing_for(&self, opening: char) -> Option<char> {
+        self.brackets
+            .iter()
+            .find(|(candidate, _)| *candidate == opening)
+            .map(|(_, closing)| *closing)
+    }
+
+    /// Returns `true` if `character` closes a chunk.
+    fn is_closing(&self, character: char) -> bool {
+        self.brackets
+            .iter()
+            .any(|(_, closing)| *closing == character)
+    }
+}
+
+impl Default for BracketValidator {
+    fn default() -> Self {
+        BracketValidator {
+            brackets: NAVIGATION_BRACKETS.to_vec(),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod bracket_validator;
+pub mod fixtures;
+pub mod solver;
+pub mod syntax_score;
@@ -0,0 +1,103 @@
+use crate::bracket_validator::{BracketValidator, Validation};
+use crate::syntax_score::{completion_score_of, error_score_of};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+use tracing::instrument;
+
+/// [Solver] for the "Syntax Scoring" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SyntaxScoringSolver;
+
+impl SyntaxScoringSolver {
+    /// Returns the [Validation] of every non-empty line in `input`.
+    #[instrument(skip_all)]
+    fn validate_lines(input: &str) -> Result<Vec<Validation>> {
+        let bracket_validator = BracketValidator::default();
+
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .enumerate()
+            .map(|(line_index, line)| {
+                bracket_validator.validate(line).with_context(|| {
+                    format!("Line {} is not a valid line of chunks", line_index + 1)
+                })
+            })
+            .collect()
+    }
+}
+
+impl Solver for SyntaxScoringSolver {
+    type Parsed = Vec<Validation>;
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<Validation>> {
+        SyntaxScoringSolver::validate_lines(input)
+    }
+
+    fn part_one(&self, validations: &Vec<Validation>) -> Result<Answer> {
+        let total_error_score = validations
+            .iter()
+            .filter_map(|validation| match validation {
+                Validation::Corrupted(corruption) => Some(error_score_of(corruption)),
+                _ => None,
+            })
+            .sum::<Result<u64>>()?;
+
+        Ok(total_error_score.into())
+    }
+
+    fn part_two(&self, validations: &Vec<Validation>) -> Result<Answer> {
+        let mut completion_scores = validations
+            .iter()
+            .filter_map(|validation| match validation {
+                Validation::Incomplete(completion) => Some(completion_score_of(completion)),
+                _ => None,
+            })
+            .collect::<Result<Vec<u64>>>()?;
+
+        if completion_scores.is_empty() {
+            return Err(anyhow!(NoSolution::new("There are no incomplete lines")));
+        }
+
+        completion_scores.sort_unstable();
+
+        Ok(completion_scores[completion_scores.len() / 2].into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = SyntaxScoringSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SyntaxScoringSolver.part_one(&parsed).unwrap(), "26397");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = SyntaxScoringSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SyntaxScoringSolver.part_two(&parsed).unwrap(), "288957");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&SyntaxScoringSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::bracket_validator::Corruption;
+
+use anyhow::{anyhow, Result};
+
+/// Returns the syntax error score of the given [Corruption], which depends on
+/// the illegal closing character that was found.
+pub fn error_score_of(corruption: &Corruption) -> Result<u64> {
+    match corruption.found {
+        ')' => Ok(3),
+        ']' => Ok(57),
+        '}' => Ok(1197),
+        '>' => Ok(25137),
+        found => Err(anyhow!("\"{}\" has no syntax error score", found)),
+    }
+}
+
+/// Returns the autocomplete score of the given `completion`, which multiplies
+/// the running score by five before adding the value of each character.
+pub fn completion_score_of(completion: &str) -> Result<u64> {
+    completion.chars().try_fold(0, |score, character| {
+        let value = match character {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            _ => return Err(anyhow!("\"{}\" has no autocomplete score", character)),
+        };
+
+        Ok(score * 5 + value)
+    })
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_11::solver::DumboOctopusSolver;
+
+fn bench_dumbo_octopus_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &DumboOctopusSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_dumbo_octopus_solver);
+criterion_main!(benches);
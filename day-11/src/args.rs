@@ -0,0 +1,19 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Simulates the flashes of a grid of dumbo octopuses")]
+pub struct Args {
+    /// Milliseconds to wait between frames of the animation.
+    #[clap(long, value_name = "MS", default_value_t = 100, requires = "visualize")]
+    pub frame_delay: u64,
+
+    /// Number of steps to animate, stopping once every octopus flashes at
+    /// once if unspecified.
+    #[clap(long, value_name = "N", requires = "visualize")]
+    pub steps: Option<usize>,
+
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
@@ -0,0 +1,57 @@
+use crate::octopus_grid::OctopusGrid;
+
+use std::fmt::Write;
+
+/// ANSI escape sequence that clears the terminal and moves the cursor to its
+/// top left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// ANSI escape sequence that resets all text styling.
+const RESET: &str = "\x1b[0m";
+
+/// ANSI escape sequence styling octopuses that flashed during the last step.
+const FLASH_STYLE: &str = "\x1b[1;97;43m";
+
+/// ANSI escape sequences styling octopuses by energy level, from dimmest to
+/// brightest, indexed by energy level minus one.
+const ENERGY_STYLES: [&str; 9] = [
+    "\x1b[38;5;236m",
+    "\x1b[38;5;238m",
+    "\x1b[38;5;240m",
+    "\x1b[38;5;242m",
+    "\x1b[38;5;244m",
+    "\x1b[38;5;247m",
+    "\x1b[38;5;250m",
+    "\x1b[38;5;253m",
+    "\x1b[38;5;255m",
+];
+
+/// Returns a single frame of an animation of `octopus_grid` for display in a
+/// terminal, replacing whatever was on screen, captioned with the number of
+/// the step it shows and how many octopuses flashed during that step.
+///
+/// Octopuses that just flashed (and so have no energy) are highlighted, and
+/// all others get brighter as their energy level rises.
+pub fn render_frame(octopus_grid: &OctopusGrid, step: usize, flash_count: usize) -> String {
+    let energy_levels = octopus_grid.energy_levels();
+    let mut frame = String::from(CLEAR_SCREEN);
+
+    for row in 0..energy_levels.height() {
+        for column in 0..energy_levels.width() {
+            let energy_level = energy_levels[(row, column)];
+            let style = match energy_level {
+                0 => FLASH_STYLE,
+                _ => ENERGY_STYLES[(energy_level as usize - 1).min(ENERGY_STYLES.len() - 1)],
+            };
+
+            // Writing to a String cannot fail.
+            let _ = write!(frame, "{}{}{}", style, energy_level, RESET);
+        }
+
+        frame.push('\n');
+    }
+
+    let _ = writeln!(frame, "\nStep:\t\t{}\nFlashes:\t{}", step, flash_count);
+
+    frame
+}
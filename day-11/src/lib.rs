@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod fixtures;
+pub mod flash_animation;
+pub mod octopus_grid;
+pub mod solver;
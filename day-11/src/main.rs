@@ -0,0 +1,84 @@
+use anyhow::{Context, Result};
+use aoc_common::args::ParseArgs;
+use aoc_common::error::InvalidInput;
+use aoc_common::{completions, input, solver};
+use args::Args;
+use day_11::flash_animation;
+use day_11::octopus_grid::OctopusGrid;
+use day_11::solver::DumboOctopusSolver;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+extern crate anyhow;
+extern crate aoc_common;
+extern crate clap;
+extern crate day_11;
+
+mod args;
+
+fn main() -> ExitCode {
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
+}
+
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return solver::write_answers_csv(&DumboOctopusSolver, inputs, &args.common);
+    }
+
+    if args.common.watch {
+        return solver::watch_answers(&DumboOctopusSolver, &args.common);
+    }
+
+    let serialized_octopus_grid = input::load(args.common.input_source())?;
+
+    if args.common.visualize {
+        let octopus_grid =
+            OctopusGrid::deserialize(&serialized_octopus_grid).context(InvalidInput)?;
+
+        return animate(
+            octopus_grid,
+            args.steps,
+            Duration::from_millis(args.frame_delay),
+        );
+    }
+
+    solver::print_answers(&DumboOctopusSolver, &serialized_octopus_grid, &args.common)
+}
+
+/// Prints a frame of `octopus_grid` after each of the specified number of
+/// `steps`, or until every octopus flashes at once if there is no limit,
+/// waiting `frame_delay` between frames.
+fn animate(
+    mut octopus_grid: OctopusGrid,
+    steps: Option<usize>,
+    frame_delay: Duration,
+) -> Result<()> {
+    print!("{}", flash_animation::render_frame(&octopus_grid, 0, 0));
+
+    for step in 1..=steps.unwrap_or(usize::MAX) {
+        thread::sleep(frame_delay);
+
+        let flash_count = octopus_grid.step();
+        print!(
+            "{}",
+            flash_animation::render_frame(&octopus_grid, step, flash_count)
+        );
+
+        if steps.is_none() && flash_count == octopus_grid.len() {
+            break;
+        }
+    }
+
+    Ok(())
+}
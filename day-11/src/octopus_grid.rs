@@ -0,0 +1,128 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::cancellation;
+use aoc_common::grid::Grid2D;
+use tracing::instrument;
+
+/// Energy level above which a dumbo octopus flashes.
+pub const FLASH_THRESHOLD: u8 = 9;
+
+/// Grid of dumbo octopuses, each with its own energy level.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OctopusGrid(Grid2D<u8>);
+
+impl OctopusGrid {
+    /// Interprets a newline-delimited [str] of rows of single-digit energy
+    /// levels as an [OctopusGrid] of any size.
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_octopus_grid: &str) -> Result<OctopusGrid> {
+        let rows = serialized_octopus_grid
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.trim()
+                    .chars()
+                    .map(|character| {
+                        character
+                            .to_digit(10)
+                            .map(|digit| digit as u8)
+                            .ok_or_else(|| anyhow!("\"{}\" is not a valid energy level", character))
+                    })
+                    .collect::<Result<Vec<u8>>>()
+                    .with_context(|| format!("\"{}\" is not a valid row of octopuses", line))
+            })
+            .collect::<Result<Vec<Vec<u8>>>>()?;
+
+        let energy_levels = Grid2D::from_rows(rows).context("Octopus grid is not rectangular")?;
+
+        Ok(OctopusGrid(energy_levels))
+    }
+
+    /// Returns the [Grid2D] of energy levels underlying this [OctopusGrid].
+    pub fn energy_levels(&self) -> &Grid2D<u8> {
+        &self.0
+    }
+
+    /// Returns the number of the first step after which every octopus flashes
+    /// at once, counting from one, or [Option::None] if that does not happen
+    /// within `max_steps` steps or before being cancelled through
+    /// [cancellation].
+    pub fn first_synchronized_step(&mut self, max_steps: usize) -> Option<usize> {
+        (1..=max_steps)
+            .take_while(|_| !cancellation::is_cancelled())
+            .find(|_| self.step() == self.len())
+    }
+
+    /// Returns `true` if this [OctopusGrid] contains no octopuses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns how many octopuses are in this [OctopusGrid].
+    pub fn len(&self) -> usize {
+        self.0.width() * self.0.height()
+    }
+
+    /// Advances this [OctopusGrid] by a single step, returning how many
+    /// octopuses flashed during it.
+    ///
+    /// Every octopus gains one energy; each one with more than
+    /// [FLASH_THRESHOLD] energy then flashes, giving one energy to all eight
+    /// of its neighbors, which may make them flash in turn. Every octopus that
+    /// flashed ends the step with no energy.
+    pub fn step(&mut self) -> usize {
+        let mut flashing = vec![];
+
+        for row in 0..self.0.height() {
+            for column in 0..self.0.width() {
+                if self.energize(row, column) {
+                    flashing.push((row, column));
+                }
+            }
+        }
+
+        let mut flash_count = 0;
+        while let Some((row, column)) = flashing.pop() {
+            flash_count += 1;
+
+            for (neighbor_row, neighbor_column) in self.neighbors_of(row, column) {
+                if self.energize(neighbor_row, neighbor_column) {
+                    flashing.push((neighbor_row, neighbor_column));
+                }
+            }
+        }
+
+        for row in 0..self.0.height() {
+            for column in 0..self.0.width() {
+                if self.0[(row, column)] > FLASH_THRESHOLD {
+                    self.0[(row, column)] = 0;
+                }
+            }
+        }
+
+        flash_count
+    }
+
+    /// Advances this [OctopusGrid] by `steps` steps, returning how many
+    /// flashes happened across all of them.
+    pub fn steps(&mut self, steps: usize) -> usize {
+        (0..steps).map(|_| self.step()).sum()
+    }
+
+    /// Gives one energy to the octopus at the specified position, returning
+    /// `true` if that makes it flash for the first time this step.
+    fn energize(&mut self, row: usize, column: usize) -> bool {
+        let energy_level = &mut self.0[(row, column)];
+
+        // Octopuses that already flashed keep counting up so that they are
+        // never mistaken for ones that have yet to flash.
+        *energy_level = energy_level.saturating_add(1);
+
+        *energy_level == FLASH_THRESHOLD + 1
+    }
+
+    /// Returns the position of every octopus horizontally, vertically, or
+    /// diagonally adjacent to the specified position.
+    fn neighbors_of(&self, row: usize, column: usize) -> Vec<(usize, usize)> {
+        self.0.neighbors8(row, column).collect()
+    }
+}
@@ -0,0 +1,80 @@
+use crate::octopus_grid::OctopusGrid;
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+
+/// Number of steps after which the first part of the puzzle counts flashes.
+const PART_ONE_STEPS: usize = 100;
+
+/// Number of steps after which the second part of the puzzle gives up on the
+/// octopuses ever flashing at once.
+const MAX_STEPS: usize = 1_000_000;
+
+/// [Solver] for the "Dumbo Octopus" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DumboOctopusSolver;
+
+impl Solver for DumboOctopusSolver {
+    type Parsed = OctopusGrid;
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn parse(&self, input: &str) -> Result<OctopusGrid> {
+        OctopusGrid::deserialize(input)
+    }
+
+    fn part_one(&self, octopus_grid: &OctopusGrid) -> Result<Answer> {
+        // Stepping changes the energy of every octopus, so each part steps
+        // its own copy of the grid.
+        let mut octopus_grid = octopus_grid.clone();
+
+        Ok(octopus_grid.steps(PART_ONE_STEPS).into())
+    }
+
+    fn part_two(&self, octopus_grid: &OctopusGrid) -> Result<Answer> {
+        let mut octopus_grid = octopus_grid.clone();
+        let first_synchronized_step = octopus_grid
+            .first_synchronized_step(MAX_STEPS)
+            .with_context(|| {
+                NoSolution::new(format!(
+                    "The octopuses never flash at once within {} steps",
+                    MAX_STEPS
+                ))
+            })?;
+
+        Ok(first_synchronized_step.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = DumboOctopusSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(DumboOctopusSolver.part_one(&parsed).unwrap(), "1656");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = DumboOctopusSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(DumboOctopusSolver.part_two(&parsed).unwrap(), "195");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&DumboOctopusSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
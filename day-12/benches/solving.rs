@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_12::solver::PassagePathingSolver;
+
+fn bench_passage_pathing_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &PassagePathingSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_passage_pathing_solver);
+criterion_main!(benches);
@@ -0,0 +1,134 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::graph::Graph;
+use tracing::instrument;
+
+/// Name of the cave where every path begins.
+const START: &str = "start";
+
+/// Name of the cave where every path ends.
+const END: &str = "end";
+
+/// Largest number of caves that a [CaveSystem] can track visits to.
+const MAX_CAVES: usize = u64::BITS as usize;
+
+/// Network of caves connected by two-way passages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaveSystem {
+    /// Index of the cave named [END] within [CaveSystem::passages].
+    end: usize,
+    /// Every cave, labeled by name, and the passages between them.
+    passages: Graph<String>,
+    /// Index of the cave named [START] within [CaveSystem::passages].
+    start: usize,
+}
+
+/// Where a path through a [CaveSystem] has been so far.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct PathState {
+    /// Is `true` if the path may still visit one small cave a second time.
+    can_revisit_small_cave: bool,
+    /// Bit set of the indices of every small cave that the path has visited.
+    visited_small_caves: u64,
+}
+
+impl CaveSystem {
+    /// Interprets a newline-delimited [str] of `a-b` passages as a
+    /// [CaveSystem].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_cave_system: &str) -> Result<CaveSystem> {
+        let mut passages = Graph::new();
+
+        for line in serialized_cave_system
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            let (a, b) = line
+                .split_once('-')
+                .filter(|(a, b)| !a.is_empty() && !b.is_empty())
+                .ok_or_else(|| anyhow!("\"{}\" is not a valid passage", line))?;
+
+            passages.add_edge(a.to_string(), b.to_string());
+        }
+
+        if passages.len() > MAX_CAVES {
+            return Err(anyhow!(
+                "Cave system has {} caves (more than {})",
+                passages.len(),
+                MAX_CAVES
+            ));
+        }
+
+        let start = passages
+            .index_of(&START.to_string())
+            .with_context(|| format!("Cave system lacks a \"{}\" cave", START))?;
+        let end = passages
+            .index_of(&END.to_string())
+            .with_context(|| format!("Cave system lacks an \"{}\" cave", END))?;
+
+        Ok(CaveSystem {
+            end,
+            passages,
+            start,
+        })
+    }
+
+    /// Returns how many paths lead from the start cave to the end cave,
+    /// passing through big caves any number of times but through each small
+    /// cave at most once.
+    ///
+    /// Parameters:
+    /// *   `can_revisit_one_small_cave`\
+    ///     Is `true` if a single small cave other than the start and end caves
+    ///     may be visited twice.
+    pub fn count_paths(&self, can_revisit_one_small_cave: bool) -> Result<u64> {
+        let initial_state = PathState {
+            can_revisit_small_cave: can_revisit_one_small_cave,
+            visited_small_caves: 1 << self.start,
+        };
+
+        self.passages
+            .count_paths(self.start, self.end, initial_state, |state, cave| {
+                self.visit(state, cave)
+            })
+            .context("Cave system has infinitely many paths")
+    }
+
+    /// Returns `true` if the cave at index `cave` is small, meaning that its
+    /// name is lowercase.
+    fn is_small(&self, cave: usize) -> bool {
+        self.passages
+            .label_of(cave)
+            .map(|name| name.chars().all(|character| character.is_lowercase()))
+            .unwrap_or(false)
+    }
+
+    /// Returns the state of a path in `state` after it goes on to the cave at
+    /// index `cave`, or [Option::None] if it may not go there.
+    fn visit(&self, state: &PathState, cave: usize) -> Option<PathState> {
+        if cave == self.start {
+            return None;
+        }
+
+        if !self.is_small(cave) {
+            return Some(*state);
+        }
+
+        let cave_bit = 1 << cave;
+        if state.visited_small_caves & cave_bit == 0 {
+            return Some(PathState {
+                visited_small_caves: state.visited_small_caves | cave_bit,
+                ..*state
+            });
+        }
+
+        if state.can_revisit_small_cave && cave != self.end {
+            return Some(PathState {
+                can_revisit_small_cave: false,
+                ..*state
+            });
+        }
+
+        None
+    }
+}
@@ -0,0 +1,7 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod cave_system;
+pub mod fixtures;
+pub mod solver;
@@ -0,0 +1,58 @@
+use crate::cave_system::CaveSystem;
+
+use anyhow::Result;
+use aoc_common::answer::Answer;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Passage Pathing" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PassagePathingSolver;
+
+impl Solver for PassagePathingSolver {
+    type Parsed = CaveSystem;
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input: &str) -> Result<CaveSystem> {
+        CaveSystem::deserialize(input)
+    }
+
+    fn part_one(&self, cave_system: &CaveSystem) -> Result<Answer> {
+        Ok(cave_system.count_paths(false)?.into())
+    }
+
+    fn part_two(&self, cave_system: &CaveSystem) -> Result<Answer> {
+        Ok(cave_system.count_paths(true)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = PassagePathingSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(PassagePathingSolver.part_one(&parsed).unwrap(), "10");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = PassagePathingSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(PassagePathingSolver.part_two(&parsed).unwrap(), "36");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&PassagePathingSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
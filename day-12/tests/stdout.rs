@@ -0,0 +1,48 @@
+use aoc_common::error::{ErrorKind, USAGE_EXIT_CODE};
+use serde_json::Value;
+use std::process::{Command, Output};
+
+#[test]
+fn prints_only_the_selected_part_as_json() {
+    let output = run(&[
+        "--input",
+        "files/sample.txt",
+        "--format",
+        "json",
+        "--part",
+        "2",
+    ]);
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let printed = serde_json::from_slice::<Value>(&output.stdout).unwrap();
+    assert_eq!(printed["answers"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn exits_with_io_code_given_missing_input() {
+    let output = run(&["--input", "files/missing.txt"]);
+
+    assert_eq!(output.status.code(), Some(ErrorKind::Io.exit_code().into()));
+}
+
+#[test]
+fn exits_with_usage_code_given_unknown_arguments() {
+    let output = run(&["--no-such-flag"]);
+
+    assert_eq!(output.status.code(), Some(USAGE_EXIT_CODE.into()));
+}
+
+/// Returns what this day's program wrote, and how it exited, when run from
+/// the day's own directory with `args`.
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_day-12"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(args)
+        .output()
+        .unwrap()
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_13::solver::TransparentOrigamiSolver;
+
+fn bench_transparent_origami_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &TransparentOrigamiSolver::default(),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_transparent_origami_solver);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Folds transparent paper to reveal an activation code")]
+pub struct Args {
+    /// Reads the letters of the revealed code instead of drawing them.
+    #[clap(long)]
+    pub ocr: bool,
+
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
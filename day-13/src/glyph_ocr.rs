@@ -0,0 +1,94 @@
+use crate::transparent_paper::{TransparentPaper, DOT, EMPTY};
+
+use anyhow::{anyhow, Result};
+use aoc_common::geometry::Coordinate;
+
+/// Width of every glyph, in dots.
+const GLYPH_WIDTH: i32 = 4;
+
+/// Height of every glyph, in dots.
+const GLYPH_HEIGHT: i32 = 6;
+
+/// Horizontal distance between the left edges of consecutive glyphs, which
+/// leaves a blank column between them.
+const GLYPH_STRIDE: i32 = GLYPH_WIDTH + 1;
+
+/// Every known glyph, drawn with [DOT] and [EMPTY] one row at a time, along
+/// with the letter it represents.
+const GLYPHS: [(char, &str); 17] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Reads the letters spelled out by the dots on `paper`, which must be drawn
+/// in the standard 4x6 glyphs starting from `(0, 0)`, one glyph every five
+/// columns.
+///
+/// Returns [Err] if any glyph is not recognized.
+pub fn read_letters(paper: &TransparentPaper) -> Result<String> {
+    let bottom_right = match paper.bounds() {
+        Some((top_left, bottom_right)) if top_left.x >= 0 && top_left.y >= 0 => bottom_right,
+        Some((top_left, _)) => {
+            return Err(anyhow!(
+                "Dots extend above or left of the first glyph (to {})",
+                top_left
+            ))
+        }
+        None => return Err(anyhow!("There are no dots to read")),
+    };
+
+    if bottom_right.y >= GLYPH_HEIGHT {
+        return Err(anyhow!(
+            "Dots extend below the glyphs (to row {})",
+            bottom_right.y
+        ));
+    }
+
+    (0..=bottom_right.x / GLYPH_STRIDE)
+        .map(|glyph_index| {
+            let glyph = glyph_at(paper, glyph_index * GLYPH_STRIDE);
+
+            GLYPHS
+                .iter()
+                .find(|(_, known_glyph)| *known_glyph == glyph)
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Glyph {} (\"{}\") is not a known letter",
+                        glyph_index + 1,
+                        glyph
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Returns the glyph drawn on `paper` whose left edge is at column `left`,
+/// drawn with [DOT] and [EMPTY] one row at a time.
+fn glyph_at(paper: &TransparentPaper, left: i32) -> String {
+    (0..GLYPH_HEIGHT)
+        .flat_map(|y| (left..left + GLYPH_WIDTH).map(move |x| Coordinate { x, y }))
+        .map(|coordinate| {
+            if paper.has_dot_at(coordinate) {
+                DOT
+            } else {
+                EMPTY
+            }
+        })
+        .collect()
+}
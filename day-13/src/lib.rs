@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod fixtures;
+pub mod glyph_ocr;
+pub mod solver;
+pub mod transparent_paper;
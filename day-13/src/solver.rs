@@ -0,0 +1,85 @@
+use crate::glyph_ocr;
+use crate::transparent_paper::{Fold, TransparentPaper};
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Transparent Origami" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TransparentOrigamiSolver {
+    /// Is `true` if the code revealed by the second part of the puzzle should
+    /// be read into letters instead of being drawn.
+    pub is_ocr_enabled: bool,
+}
+
+impl Solver for TransparentOrigamiSolver {
+    type Parsed = (TransparentPaper, Vec<Fold>);
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input: &str) -> Result<(TransparentPaper, Vec<Fold>)> {
+        TransparentPaper::deserialize_with_folds(input)
+    }
+
+    fn part_one(&self, (paper, folds): &(TransparentPaper, Vec<Fold>)) -> Result<Answer> {
+        let first_fold = folds
+            .first()
+            .context(NoSolution::new("There are no fold instructions"))?;
+
+        Ok(paper.folded(first_fold).len().into())
+    }
+
+    fn part_two(&self, (paper, folds): &(TransparentPaper, Vec<Fold>)) -> Result<Answer> {
+        let folded_paper = folds
+            .iter()
+            .fold(paper.clone(), |paper, fold| paper.folded(fold));
+
+        if self.is_ocr_enabled {
+            return glyph_ocr::read_letters(&folded_paper)
+                .map(Answer::from)
+                .with_context(|| format!("Failed to read the code:\n{}", folded_paper));
+        }
+
+        Ok(format!("\n{}", folded_paper.to_string().trim_end()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let solver = TransparentOrigamiSolver::default();
+        let parsed = solver.parse(SAMPLE).unwrap();
+
+        assert_eq!(solver.part_one(&parsed).unwrap(), "17");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let solver = TransparentOrigamiSolver::default();
+        let parsed = solver.parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            solver.part_two(&parsed).unwrap(),
+            "\n#####\n#...#\n#...#\n#...#\n#####"
+        );
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(
+            answers_table(&TransparentOrigamiSolver::default(), SAMPLE, None)
+                .unwrap()
+                .to_string()
+        );
+    }
+}
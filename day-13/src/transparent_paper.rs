@@ -0,0 +1,188 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::geometry::Coordinate;
+use aoc_common::parse;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use tracing::instrument;
+
+/// Character used to render a dot on [TransparentPaper].
+pub const DOT: char = '#';
+
+/// Character used to render a spot on [TransparentPaper] without a dot.
+pub const EMPTY: char = '.';
+
+/// Enumerates the axes that [TransparentPaper] can be folded along.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Axis {
+    /// Folds along a vertical line, bringing the right half over to the left.
+    X,
+    /// Folds along a horizontal line, bringing the bottom half up.
+    Y,
+}
+
+/// Single instruction to fold [TransparentPaper] along a line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fold {
+    /// Axis that the fold line is perpendicular to.
+    pub axis: Axis,
+    /// Position of the fold line along [Fold::axis].
+    pub line: i32,
+}
+
+impl Fold {
+    /// Interprets the given [str] formatted like `fold along y=7` as a
+    /// [Fold].
+    pub fn deserialize(serialized_fold: &str) -> Result<Fold> {
+        let (serialized_axis, serialized_line) = serialized_fold
+            .trim()
+            .strip_prefix("fold along ")
+            .and_then(|instruction| instruction.split_once('='))
+            .ok_or_else(|| anyhow!("\"{}\" is not a valid fold instruction", serialized_fold))?;
+
+        let axis = match serialized_axis {
+            "x" => Axis::X,
+            "y" => Axis::Y,
+            _ => {
+                return Err(anyhow!(
+                    "\"{}\" lacks a valid axis (\"{}\" is neither x nor y)",
+                    serialized_fold,
+                    serialized_axis
+                ))
+            }
+        };
+        let line = parse::parse_number::<i32>(serialized_line, serialized_fold)
+            .with_context(|| format!("\"{}\" lacks a valid fold line", serialized_fold))?;
+
+        Ok(Fold { axis, line })
+    }
+
+    /// Returns where `dot` ends up after this [Fold], mirroring it across the
+    /// fold line if it is beyond it.
+    pub fn apply_to(&self, dot: Coordinate) -> Coordinate {
+        match self.axis {
+            Axis::X if dot.x > self.line => Coordinate {
+                x: 2 * self.line - dot.x,
+                y: dot.y,
+            },
+            Axis::Y if dot.y > self.line => Coordinate {
+                x: dot.x,
+                y: 2 * self.line - dot.y,
+            },
+            _ => dot,
+        }
+    }
+}
+
+/// Sheet of transparent paper marked with dots, stored sparsely.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransparentPaper {
+    /// Position of every dot on this [TransparentPaper].
+    dots: HashSet<Coordinate>,
+}
+
+impl TransparentPaper {
+    /// Interprets the given [str] of newline-delimited `x,y` dots, followed
+    /// by a blank line and newline-delimited fold instructions, as a
+    /// [TransparentPaper] and the [Fold] instructions that go with it.
+    #[instrument(skip_all)]
+    pub fn deserialize_with_folds(
+        serialized_manual: &str,
+    ) -> Result<(TransparentPaper, Vec<Fold>)> {
+        let mut dots = HashSet::new();
+        let mut folds = vec![];
+
+        for line in serialized_manual
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            if line.starts_with("fold") {
+                folds.push(Fold::deserialize(line)?);
+
+                continue;
+            }
+
+            dots.insert(deserialize_dot(line)?);
+        }
+
+        Ok((TransparentPaper { dots }, folds))
+    }
+
+    /// Returns a copy of this [TransparentPaper] after folding it according
+    /// to `fold`, merging any dots that end up on top of each other.
+    pub fn folded(&self, fold: &Fold) -> TransparentPaper {
+        TransparentPaper {
+            dots: self.dots.iter().map(|dot| fold.apply_to(*dot)).collect(),
+        }
+    }
+
+    /// Returns `true` if there is a dot at `coordinate`.
+    pub fn has_dot_at(&self, coordinate: Coordinate) -> bool {
+        self.dots.contains(&coordinate)
+    }
+
+    /// Returns `true` if there are no dots on this [TransparentPaper].
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty()
+    }
+
+    /// Returns how many dots are visible on this [TransparentPaper].
+    pub fn len(&self) -> usize {
+        self.dots.len()
+    }
+
+    /// Returns the top-left and bottom-right corners of the smallest
+    /// rectangle containing every dot, or [Option::None] if there are none.
+    pub fn bounds(&self) -> Option<(Coordinate, Coordinate)> {
+        let min_x = self.dots.iter().map(|dot| dot.x).min()?;
+        let max_x = self.dots.iter().map(|dot| dot.x).max()?;
+        let min_y = self.dots.iter().map(|dot| dot.y).min()?;
+        let max_y = self.dots.iter().map(|dot| dot.y).max()?;
+
+        Some((
+            Coordinate { x: min_x, y: min_y },
+            Coordinate { x: max_x, y: max_y },
+        ))
+    }
+}
+
+impl Display for TransparentPaper {
+    /// Renders every dot within the bounds of this [TransparentPaper] as
+    /// [DOT], and every other spot as [EMPTY], one row per line.
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let (top_left, bottom_right) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+
+        for y in top_left.y..=bottom_right.y {
+            let row = (top_left.x..=bottom_right.x)
+                .map(|x| {
+                    if self.has_dot_at(Coordinate { x, y }) {
+                        DOT
+                    } else {
+                        EMPTY
+                    }
+                })
+                .collect::<String>();
+
+            writeln!(f, "{}", row)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Interprets the given [str] as an `x,y` dot.
+fn deserialize_dot(serialized_dot: &str) -> Result<Coordinate> {
+    let (serialized_x, serialized_y) = serialized_dot
+        .split_once(',')
+        .ok_or_else(|| anyhow!("\"{}\" is not a valid dot", serialized_dot))?;
+
+    let x = parse::parse_number::<i32>(serialized_x, serialized_dot)
+        .with_context(|| format!("\"{}\" lacks a valid x", serialized_dot))?;
+    let y = parse::parse_number::<i32>(serialized_y, serialized_dot)
+        .with_context(|| format!("\"{}\" lacks a valid y", serialized_dot))?;
+
+    Ok(Coordinate { x, y })
+}
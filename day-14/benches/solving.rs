@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_14::solver::PolymerizationSolver;
+
+fn bench_polymerization_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &PolymerizationSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_polymerization_solver);
+criterion_main!(benches);
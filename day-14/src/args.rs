@@ -0,0 +1,16 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Grows a polymer by pair insertion")]
+pub struct Args {
+    /// Prints the spread between the most and least common elements after
+    /// this many steps, counted without any risk of overflow, instead of
+    /// printing answers.
+    #[clap(long, value_name = "N")]
+    pub steps: Option<usize>,
+
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
@@ -0,0 +1,2 @@
+/// Example input given in the description of the puzzle.
+pub const SAMPLE: &str = include_str!("../files/sample.txt");
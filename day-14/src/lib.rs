@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate num_bigint;
+extern crate tracing;
+
+pub mod fixtures;
+pub mod polymer;
+pub mod solver;
@@ -0,0 +1,56 @@
+use anyhow::{Context, Result};
+use aoc_common::args::ParseArgs;
+use aoc_common::error::InvalidInput;
+use aoc_common::table::Table;
+use aoc_common::{completions, input, solver};
+use args::Args;
+use day_14::polymer::Polymer;
+use day_14::solver::PolymerizationSolver;
+use num_bigint::BigUint;
+use std::process::ExitCode;
+
+extern crate anyhow;
+extern crate aoc_common;
+extern crate clap;
+extern crate day_14;
+extern crate num_bigint;
+
+mod args;
+
+fn main() -> ExitCode {
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
+}
+
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return solver::write_answers_csv(&PolymerizationSolver, inputs, &args.common);
+    }
+
+    if args.common.watch {
+        return solver::watch_answers(&PolymerizationSolver, &args.common);
+    }
+
+    let polymer_manual = input::load(args.common.input_source())?;
+
+    if let Some(steps) = args.steps {
+        let mut polymer = Polymer::<BigUint>::deserialize(&polymer_manual).context(InvalidInput)?;
+        polymer.step(steps);
+
+        return args.common.print(Table::new().answer(
+            &format!("Spread after {} steps", steps),
+            polymer.element_count_spread(),
+        ));
+    }
+
+    solver::print_answers(&PolymerizationSolver, &polymer_manual, &args.common)
+}
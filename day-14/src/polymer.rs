@@ -0,0 +1,146 @@
+use anyhow::{anyhow, Result};
+use aoc_common::counter::{Count, Counter};
+use std::collections::HashMap;
+use std::ops::Sub;
+use tracing::instrument;
+
+/// Pair of adjacent elements within a [Polymer].
+type Pair = (char, char);
+
+/// Numeric type able to count the elements of a [Polymer].
+///
+/// [u128] counts overflow after roughly 120 steps of a typical polymer, while
+/// [num_bigint::BigUint] counts never overflow.
+pub trait ElementCount: Count + Sub<Output = Self> {}
+
+impl<C> ElementCount for C where C: Count + Sub<Output = Self> {}
+
+/// Polymer grown by pair insertion, represented by how many times each pair of
+/// adjacent elements occurs instead of by the full chain of elements.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polymer<C = u128> {
+    /// Final element of the polymer, which pair insertion never changes.
+    last_element: Option<char>,
+    /// Number of times each pair of adjacent elements occurs.
+    pair_counts: Counter<Pair, C>,
+    /// Element inserted between each pair of adjacent elements during a step.
+    rules: HashMap<Pair, char>,
+}
+
+impl<C: ElementCount> Polymer<C> {
+    /// Interprets the given [str], made up of a polymer template followed by
+    /// a blank line and newline-delimited `AB -> C` pair insertion rules, as a
+    /// [Polymer].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_polymer: &str) -> Result<Polymer<C>> {
+        let mut lines = serialized_polymer
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty());
+
+        let template = lines
+            .next()
+            .ok_or_else(|| anyhow!("Polymer lacks a template"))?
+            .chars()
+            .collect::<Vec<char>>();
+        let rules = lines
+            .map(deserialize_rule)
+            .collect::<Result<HashMap<Pair, char>>>()?;
+
+        let pair_counts = template
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect::<Counter<Pair, C>>();
+
+        Ok(Polymer {
+            last_element: template.last().copied(),
+            pair_counts,
+            rules,
+        })
+    }
+
+    /// Returns how many times each element occurs in this [Polymer].
+    pub fn element_counts(&self) -> Counter<char, C> {
+        let mut element_counts = Counter::new();
+
+        // Every element starts exactly one pair, except for the last one.
+        for ((first_element, _), count) in self.pair_counts.iter() {
+            element_counts.add_count(*first_element, count);
+        }
+        if let Some(last_element) = self.last_element {
+            element_counts.add(last_element);
+        }
+
+        element_counts
+    }
+
+    /// Returns the least and most common elements of this [Polymer], along
+    /// with how many times each occurs, or [Option::None] if it is empty.
+    ///
+    /// Ties go to the alphabetically first element.
+    pub fn element_count_extremes(&self) -> Option<((char, C), (char, C))> {
+        let element_counts = self.element_counts();
+
+        let least_common = element_counts
+            .iter()
+            .min_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))?;
+        let most_common = element_counts
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))?;
+
+        Some((
+            (*least_common.0, least_common.1.clone()),
+            (*most_common.0, most_common.1.clone()),
+        ))
+    }
+
+    /// Returns how many more times the most common element of this [Polymer]
+    /// occurs than the least common one, which is zero if it is empty.
+    pub fn element_count_spread(&self) -> C {
+        self.element_count_extremes()
+            .map(|((_, least_common_count), (_, most_common_count))| {
+                most_common_count - least_common_count
+            })
+            .unwrap_or_else(|| C::from(0))
+    }
+
+    /// Applies `steps` steps of pair insertion to this [Polymer], where each
+    /// step simultaneously inserts an element between every pair of adjacent
+    /// elements that has a matching rule.
+    pub fn step(&mut self, steps: usize) {
+        for _ in 0..steps {
+            let mut pair_counts = Counter::new();
+
+            for (pair, count) in self.pair_counts.iter() {
+                match self.rules.get(pair) {
+                    Some(inserted_element) => {
+                        pair_counts.add_count((pair.0, *inserted_element), count);
+                        pair_counts.add_count((*inserted_element, pair.1), count);
+                    }
+                    None => pair_counts.add_count(*pair, count),
+                }
+            }
+
+            self.pair_counts = pair_counts;
+        }
+    }
+}
+
+/// Interprets the given [str] formatted like `AB -> C` as a pair insertion
+/// rule.
+fn deserialize_rule(serialized_rule: &str) -> Result<(Pair, char)> {
+    let (serialized_pair, serialized_element) = serialized_rule
+        .split_once("->")
+        .ok_or_else(|| anyhow!("\"{}\" is not a valid pair insertion rule", serialized_rule))?;
+
+    let pair = match serialized_pair.trim().chars().collect::<Vec<char>>()[..] {
+        [first, second] => (first, second),
+        _ => return Err(anyhow!("\"{}\" lacks a valid pair", serialized_rule)),
+    };
+    let element = match serialized_element.trim().chars().collect::<Vec<char>>()[..] {
+        [element] => element,
+        _ => return Err(anyhow!("\"{}\" lacks a valid element", serialized_rule)),
+    };
+
+    Ok((pair, element))
+}
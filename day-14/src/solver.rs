@@ -0,0 +1,78 @@
+use crate::polymer::Polymer;
+
+use anyhow::Result;
+use aoc_common::answer::Answer;
+use aoc_common::solver::Solver;
+
+/// Number of pair insertion steps applied by the first part of the puzzle.
+const PART_ONE_STEPS: usize = 10;
+
+/// Number of pair insertion steps applied by the second part of the puzzle.
+const PART_TWO_STEPS: usize = 40;
+
+/// [Solver] for the "Extended Polymerization" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PolymerizationSolver;
+
+impl PolymerizationSolver {
+    /// Returns the spread between the most and least common elements of
+    /// `polymer` after the given number of `steps`.
+    fn element_count_spread_after(polymer: &Polymer<u128>, steps: usize) -> Result<Answer> {
+        let mut polymer = polymer.clone();
+        polymer.step(steps);
+
+        Ok(polymer.element_count_spread().into())
+    }
+}
+
+impl Solver for PolymerizationSolver {
+    type Parsed = Polymer<u128>;
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse(&self, input: &str) -> Result<Polymer<u128>> {
+        Polymer::deserialize(input)
+    }
+
+    fn part_one(&self, polymer: &Polymer<u128>) -> Result<Answer> {
+        PolymerizationSolver::element_count_spread_after(polymer, PART_ONE_STEPS)
+    }
+
+    fn part_two(&self, polymer: &Polymer<u128>) -> Result<Answer> {
+        PolymerizationSolver::element_count_spread_after(polymer, PART_TWO_STEPS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = PolymerizationSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(PolymerizationSolver.part_one(&parsed).unwrap(), "1588");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = PolymerizationSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            PolymerizationSolver.part_two(&parsed).unwrap(),
+            "2188189693529"
+        );
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&PolymerizationSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
@@ -0,0 +1,52 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_15::chiton_cave::{ChitonCave, SearchStrategy};
+
+/// Side length of the generated caves before they are tiled.
+const SIDE_LENGTH: usize = 100;
+
+/// Returns a [ChitonCave] of pseudo-random risk levels, generated the same way
+/// every time so that runs are comparable.
+fn generated_chiton_cave() -> ChitonCave {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut serialized_chiton_cave = String::new();
+
+    for _ in 0..SIDE_LENGTH {
+        for _ in 0..SIDE_LENGTH {
+            // Xorshift keeps the generator dependency-free.
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            serialized_chiton_cave.push(char::from(b'1' + (state % 9) as u8));
+        }
+
+        serialized_chiton_cave.push('\n');
+    }
+
+    ChitonCave::deserialize(&serialized_chiton_cave).unwrap()
+}
+
+fn compare_search_strategies(c: &mut Criterion) {
+    let chiton_cave = generated_chiton_cave();
+    let mut group = c.benchmark_group("lowest_total_risk");
+
+    for tiling_factor in [1, 5] {
+        let tiled_chiton_cave = chiton_cave.tiled(tiling_factor);
+
+        for (name, search_strategy) in [
+            ("dijkstra", SearchStrategy::Dijkstra),
+            ("a_star", SearchStrategy::AStar),
+        ] {
+            group.bench_with_input(
+                BenchmarkId::new(name, format!("{}x", tiling_factor)),
+                &tiled_chiton_cave,
+                |b, chiton_cave| b.iter(|| chiton_cave.lowest_total_risk(search_strategy)),
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, compare_search_strategies);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_15::solver::ChitonSolver;
+
+fn bench_chiton_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &ChitonSolver::default(),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_chiton_solver);
+criterion_main!(benches);
@@ -0,0 +1,10 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Finds the path of lowest total risk through a cave full of chitons")]
+pub struct Args {
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
@@ -0,0 +1,114 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::grid::Grid2D;
+use aoc_common::pathfinding::{self, Path};
+use tracing::instrument;
+
+/// Highest risk level of any position in a [ChitonCave].
+const MAX_RISK_LEVEL: u8 = 9;
+
+/// Enumerates the supported ways of searching for the lowest-risk path.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SearchStrategy {
+    /// A* search, guided by the distance left to the bottom right corner.
+    AStar,
+    /// Dijkstra's algorithm, which expands positions purely by risk so far.
+    Dijkstra,
+}
+
+/// Cave whose every position has a risk level based on the density of chitons
+/// there.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChitonCave(Grid2D<u8>);
+
+impl ChitonCave {
+    /// Interprets a newline-delimited [str] of rows of risk levels from `1`
+    /// to `9` as a [ChitonCave].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_chiton_cave: &str) -> Result<ChitonCave> {
+        let rows = serialized_chiton_cave
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.trim()
+                    .chars()
+                    .map(|character| {
+                        character
+                            .to_digit(10)
+                            .map(|digit| digit as u8)
+                            .filter(|risk_level| *risk_level > 0)
+                            .ok_or_else(|| anyhow!("\"{}\" is not a valid risk level", character))
+                    })
+                    .collect::<Result<Vec<u8>>>()
+                    .with_context(|| format!("\"{}\" is not a valid row of risk levels", line))
+            })
+            .collect::<Result<Vec<Vec<u8>>>>()?;
+
+        let risk_levels = Grid2D::from_rows(rows).context("Chiton cave is not rectangular")?;
+
+        Ok(ChitonCave(risk_levels))
+    }
+
+    /// Returns the lowest total risk of any path from the top left position
+    /// to the bottom right position, not counting the risk of the top left
+    /// position, or [Option::None] if this [ChitonCave] is empty.
+    pub fn lowest_total_risk(&self, search_strategy: SearchStrategy) -> Option<u64> {
+        self.lowest_risk_path(search_strategy).map(|path| path.cost)
+    }
+
+    /// Returns the path from the top left position to the bottom right
+    /// position with the lowest total risk, or [Option::None] if this
+    /// [ChitonCave] is empty.
+    pub fn lowest_risk_path(
+        &self,
+        search_strategy: SearchStrategy,
+    ) -> Option<Path<(usize, usize)>> {
+        if self.0.width() == 0 || self.0.height() == 0 {
+            return None;
+        }
+
+        let goal = (self.0.height() - 1, self.0.width() - 1);
+        let neighbors = |position: &(usize, usize)| self.neighbors_of(*position);
+        let is_goal = |position: &(usize, usize)| *position == goal;
+
+        match search_strategy {
+            SearchStrategy::AStar => pathfinding::a_star(
+                (0, 0),
+                neighbors,
+                // Every step has a risk of at least one, so the distance left
+                // never overestimates the risk left.
+                |(row, column)| ((goal.0 - row) + (goal.1 - column)) as u64,
+                is_goal,
+            ),
+            SearchStrategy::Dijkstra => pathfinding::dijkstra((0, 0), neighbors, is_goal),
+        }
+    }
+
+    /// Returns a copy of this [ChitonCave] repeated `factor` times in each
+    /// direction, where each repetition has risk levels one higher than the
+    /// one above or to the left of it, wrapping from `9` back around to `1`.
+    pub fn tiled(&self, factor: usize) -> ChitonCave {
+        let (width, height) = (self.0.width(), self.0.height());
+        let mut risk_levels = Grid2D::filled_with(0, width * factor, height * factor);
+
+        for row in 0..height * factor {
+            for column in 0..width * factor {
+                let increase = (row / height + column / width) as u8;
+                let original_risk_level = self.0[(row % height, column % width)];
+
+                risk_levels[(row, column)] =
+                    (original_risk_level + increase - 1) % MAX_RISK_LEVEL + 1;
+            }
+        }
+
+        ChitonCave(risk_levels)
+    }
+
+    /// Returns every position directly up, down, left, or right of
+    /// `position`, along with the risk of moving there.
+    fn neighbors_of(&self, (row, column): (usize, usize)) -> Vec<((usize, usize), u64)> {
+        self.0
+            .neighbors4(row, column)
+            .map(|neighbor| (neighbor, self.0[neighbor] as u64))
+            .collect()
+    }
+}
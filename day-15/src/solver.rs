@@ -0,0 +1,84 @@
+use crate::chiton_cave::{ChitonCave, SearchStrategy};
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+
+/// Number of times the cave is repeated in each direction by the second part
+/// of the puzzle.
+const TILING_FACTOR: usize = 5;
+
+/// [Solver] for the "Chiton" puzzle.
+#[derive(Clone, Copy, Debug)]
+pub struct ChitonSolver {
+    /// How to search for the lowest-risk path.
+    pub search_strategy: SearchStrategy,
+}
+
+impl ChitonSolver {
+    /// Returns the lowest total risk of getting through `chiton_cave`.
+    fn lowest_total_risk(&self, chiton_cave: &ChitonCave) -> Result<Answer> {
+        chiton_cave
+            .lowest_total_risk(self.search_strategy)
+            .map(Answer::from)
+            .context(NoSolution::new("Chiton cave is empty"))
+    }
+}
+
+impl Default for ChitonSolver {
+    fn default() -> Self {
+        ChitonSolver {
+            search_strategy: SearchStrategy::AStar,
+        }
+    }
+}
+
+impl Solver for ChitonSolver {
+    type Parsed = ChitonCave;
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse(&self, input: &str) -> Result<ChitonCave> {
+        ChitonCave::deserialize(input)
+    }
+
+    fn part_one(&self, chiton_cave: &ChitonCave) -> Result<Answer> {
+        self.lowest_total_risk(chiton_cave)
+    }
+
+    fn part_two(&self, chiton_cave: &ChitonCave) -> Result<Answer> {
+        self.lowest_total_risk(&chiton_cave.tiled(TILING_FACTOR))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = ChitonSolver::default().parse(SAMPLE).unwrap();
+
+        assert_eq!(ChitonSolver::default().part_one(&parsed).unwrap(), "40");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = ChitonSolver::default().parse(SAMPLE).unwrap();
+
+        assert_eq!(ChitonSolver::default().part_two(&parsed).unwrap(), "315");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&ChitonSolver::default(), SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_17::solver::TrickShotSolver;
+
+fn bench_trick_shot_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &TrickShotSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_trick_shot_solver);
+criterion_main!(benches);
@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod fixtures;
+pub mod solver;
+pub mod target_area;
+pub mod trick_shot;
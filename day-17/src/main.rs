@@ -0,0 +1,41 @@
+use anyhow::Result;
+use aoc_common::args::ParseArgs;
+use aoc_common::{completions, input, solver};
+use args::Args;
+use day_17::solver::TrickShotSolver;
+use std::process::ExitCode;
+
+extern crate anyhow;
+extern crate aoc_common;
+extern crate clap;
+extern crate day_17;
+
+mod args;
+
+fn main() -> ExitCode {
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
+}
+
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return solver::write_answers_csv(&TrickShotSolver, inputs, &args.common);
+    }
+
+    if args.common.watch {
+        return solver::watch_answers(&TrickShotSolver, &args.common);
+    }
+
+    let target_area = input::load(args.common.input_source())?;
+
+    solver::print_answers(&TrickShotSolver, &target_area, &args.common)
+}
@@ -0,0 +1,63 @@
+use crate::target_area::TargetArea;
+use crate::trick_shot::{max_apex_height, valid_velocities};
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Trick Shot" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TrickShotSolver;
+
+impl Solver for TrickShotSolver {
+    type Parsed = TargetArea;
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn parse(&self, input: &str) -> Result<TargetArea> {
+        TargetArea::deserialize(input)
+    }
+
+    fn part_one(&self, target_area: &TargetArea) -> Result<Answer> {
+        let max_apex_height = max_apex_height(target_area)
+            .with_context(|| NoSolution::new(format!("No launch reaches {:?}", target_area)))?;
+
+        Ok(max_apex_height.into())
+    }
+
+    fn part_two(&self, target_area: &TargetArea) -> Result<Answer> {
+        Ok(valid_velocities(target_area).len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = TrickShotSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(TrickShotSolver.part_one(&parsed).unwrap(), "45");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = TrickShotSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(TrickShotSolver.part_two(&parsed).unwrap(), "112");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&TrickShotSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
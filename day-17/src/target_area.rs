@@ -0,0 +1,66 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::parse;
+use std::ops::RangeInclusive;
+use tracing::instrument;
+
+/// Rectangular region of the ocean trench that the probe must pass through,
+/// which is entirely above or entirely below the launch point.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TargetArea {
+    /// Every X-coordinate within this [TargetArea].
+    pub x: RangeInclusive<i64>,
+    /// Every Y-coordinate within this [TargetArea].
+    pub y: RangeInclusive<i64>,
+}
+
+impl TargetArea {
+    /// Interprets the given [str] formatted like `target area: x=20..30,
+    /// y=-10..-5` as a [TargetArea].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_target_area: &str) -> Result<TargetArea> {
+        let (serialized_x, serialized_y) = serialized_target_area
+            .trim()
+            .strip_prefix("target area:")
+            .and_then(|ranges| ranges.split_once(','))
+            .ok_or_else(|| anyhow!("\"{}\" is not a valid target area", serialized_target_area))?;
+
+        let x = deserialize_range(serialized_x, "x")
+            .with_context(|| format!("\"{}\" lacks a valid x range", serialized_target_area))?;
+        let y = deserialize_range(serialized_y, "y")
+            .with_context(|| format!("\"{}\" lacks a valid y range", serialized_target_area))?;
+
+        // Every probe launched upward comes back down through the height it
+        // was launched from, so there would be endlessly many valid launches.
+        if y.contains(&0) {
+            return Err(anyhow!(
+                "\"{}\" is not a valid target area (it spans the launch height)",
+                serialized_target_area
+            ));
+        }
+
+        Ok(TargetArea { x, y })
+    }
+
+    /// Returns `true` if the point at `(x, y)` is within this [TargetArea].
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.x.contains(&x) && self.y.contains(&y)
+    }
+}
+
+/// Interprets the given [str] formatted like `x=20..30` as an inclusive range
+/// of the axis named `axis`.
+fn deserialize_range(serialized_range: &str, axis: &str) -> Result<RangeInclusive<i64>> {
+    let (serialized_start, serialized_end) = serialized_range
+        .trim()
+        .strip_prefix(axis)
+        .and_then(|range| range.strip_prefix('='))
+        .and_then(|range| range.split_once(".."))
+        .ok_or_else(|| anyhow!("\"{}\" is not a valid {} range", serialized_range, axis))?;
+
+    let start = parse::parse_number::<i64>(serialized_start, serialized_range)
+        .with_context(|| format!("\"{}\" lacks a valid start", serialized_range))?;
+    let end = parse::parse_number::<i64>(serialized_end, serialized_range)
+        .with_context(|| format!("\"{}\" lacks a valid end", serialized_range))?;
+
+    Ok(start.min(end)..=start.max(end))
+}
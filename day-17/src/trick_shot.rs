@@ -0,0 +1,113 @@
+use crate::target_area::TargetArea;
+
+/// Initial velocity of a launched probe.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Velocity {
+    /// Horizontal component, positive going forward.
+    pub x: i64,
+    /// Vertical component, positive going up.
+    pub y: i64,
+}
+
+/// Returns the highest Y-coordinate reached by any launch that ends up within
+/// `target_area`, or [Option::None] if no launch does.
+///
+/// Uses [max_apex_height_analytically] when possible, falling back to
+/// [max_apex_height_by_brute_force] otherwise.
+pub fn max_apex_height(target_area: &TargetArea) -> Option<i64> {
+    max_apex_height_analytically(target_area)
+        .or_else(|| max_apex_height_by_brute_force(target_area))
+}
+
+/// Returns the highest Y-coordinate reached by any launch that ends up within
+/// `target_area` without simulating any launches, or [Option::None] if the
+/// shortcut does not apply.
+///
+/// A probe launched upward at `vy` comes back down through `y = 0` going
+/// `vy + 1` downward, so the fastest launch that can still land is the one
+/// whose next step lands on the bottom of the target area. That only works
+/// if the target area is entirely below the launch point, and if some
+/// horizontal velocity stalls within it, leaving the probe time to fall.
+pub fn max_apex_height_analytically(target_area: &TargetArea) -> Option<i64> {
+    let bottom = *target_area.y.start();
+    if *target_area.y.end() >= 0 {
+        return None;
+    }
+
+    let has_stalling_velocity = (0..=target_area.x.end().abs())
+        .map(|vx| vx * (vx + 1) / 2)
+        .any(|stall_distance| {
+            target_area.x.contains(&stall_distance) || target_area.x.contains(&-stall_distance)
+        });
+    if !has_stalling_velocity {
+        return None;
+    }
+
+    let vy = -bottom - 1;
+
+    Some(vy * (vy + 1) / 2)
+}
+
+/// Returns the highest Y-coordinate reached by any launch that ends up within
+/// `target_area`, found by trying every plausible launch, or [Option::None]
+/// if no launch does.
+pub fn max_apex_height_by_brute_force(target_area: &TargetArea) -> Option<i64> {
+    valid_velocities(target_area)
+        .iter()
+        .map(|velocity| apex_height_of(*velocity))
+        .max()
+}
+
+/// Returns every initial [Velocity] that leaves the probe within
+/// `target_area` after some whole number of steps, ordered by X and then by
+/// Y.
+///
+/// Only velocities that cannot overshoot the target area on their very first
+/// step are tried, which bounds the search without missing any launch.
+pub fn valid_velocities(target_area: &TargetArea) -> Vec<Velocity> {
+    let min_vx = (*target_area.x.start()).min(0);
+    let max_vx = (*target_area.x.end()).max(0);
+    let min_vy = (*target_area.y.start()).min(0);
+    // Probes launched upward come back down through every height they rose
+    // through, so launches faster than the farthest edge always skip it.
+    let max_vy = target_area.y.start().abs().max(target_area.y.end().abs());
+
+    (min_vx..=max_vx)
+        .flat_map(|x| (min_vy..=max_vy).map(move |y| Velocity { x, y }))
+        .filter(|velocity| hits(target_area, *velocity))
+        .collect()
+}
+
+/// Returns the highest Y-coordinate reached by a probe launched at
+/// `velocity`.
+fn apex_height_of(velocity: Velocity) -> i64 {
+    if velocity.y <= 0 {
+        return 0;
+    }
+
+    velocity.y * (velocity.y + 1) / 2
+}
+
+/// Returns `true` if a probe launched at `velocity` is within `target_area`
+/// after some whole number of steps.
+fn hits(target_area: &TargetArea, velocity: Velocity) -> bool {
+    let (mut x, mut y) = (0, 0);
+    let (mut vx, mut vy) = (velocity.x, velocity.y);
+
+    loop {
+        x += vx;
+        y += vy;
+        vx -= vx.signum();
+        vy -= 1;
+
+        if target_area.contains(x, y) {
+            return true;
+        }
+
+        let is_falling_past = vy < 0 && y < *target_area.y.start();
+        let is_stalled_outside = vx == 0 && !target_area.x.contains(&x);
+        if is_falling_past || is_stalled_outside {
+            return false;
+        }
+    }
+}
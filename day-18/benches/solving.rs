@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_18::solver::SnailfishSolver;
+
+fn bench_snailfish_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &SnailfishSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_snailfish_solver);
+criterion_main!(benches);
@@ -0,0 +1,10 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Adds up the homework of snailfish numbers")]
+pub struct Args {
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
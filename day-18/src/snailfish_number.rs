@@ -0,0 +1,439 @@
+use anyhow::{anyhow, Context, Error, Result};
+use aoc_common::parse;
+use std::fmt::{Display, Formatter};
+use std::iter::{Peekable, Sum};
+use std::ops::Add;
+use std::str::{CharIndices, FromStr};
+
+/// Number of pairs that a pair must be nested inside of to explode.
+const EXPLOSION_DEPTH: usize = 4;
+
+/// Smallest regular number that splits.
+const SPLIT_THRESHOLD: u64 = 10;
+
+/// Snailfish number, which is either a regular number or a pair of snailfish
+/// numbers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SnailfishNumber {
+    /// Pair of a left and a right snailfish number, written `[left,right]`.
+    Pair(Box<SnailfishNumber>, Box<SnailfishNumber>),
+    /// Plain, non-negative number.
+    Regular(u64),
+}
+
+impl SnailfishNumber {
+    /// Creates a new [SnailfishNumber] pairing `left` with `right`, without
+    /// reducing it.
+    pub fn pair(left: SnailfishNumber, right: SnailfishNumber) -> SnailfishNumber {
+        SnailfishNumber::Pair(Box::new(left), Box::new(right))
+    }
+
+    /// Explodes the leftmost pair of regular numbers nested inside at least
+    /// four pairs, returning `false` if there is no such pair.
+    ///
+    /// The left number of the exploding pair is added to the first regular
+    /// number to its left, the right number is added to the first regular
+    /// number to its right, and the pair itself becomes a regular `0`.
+    pub fn explode(&mut self) -> bool {
+        self.explode_at(0).is_some()
+    }
+
+    /// Returns the magnitude of this [SnailfishNumber]: three times the
+    /// magnitude of its left element plus twice that of its right element for
+    /// pairs, or the number itself for regular numbers.
+    pub fn magnitude(&self) -> u64 {
+        match self {
+            SnailfishNumber::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+            SnailfishNumber::Regular(value) => *value,
+        }
+    }
+
+    /// Repeatedly explodes and splits this [SnailfishNumber] until neither
+    /// action applies, always preferring to explode.
+    pub fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    /// Splits the leftmost regular number that is `10` or greater into a pair
+    /// of its halves, rounding the left half down and the right half up,
+    /// returning `false` if there is no such number.
+    pub fn split(&mut self) -> bool {
+        match self {
+            SnailfishNumber::Pair(left, right) => left.split() || right.split(),
+            SnailfishNumber::Regular(value) if *value >= SPLIT_THRESHOLD => {
+                *self = SnailfishNumber::pair(
+                    SnailfishNumber::Regular(*value / 2),
+                    SnailfishNumber::Regular(value.div_ceil(2)),
+                );
+
+                true
+            }
+            SnailfishNumber::Regular(_) => false,
+        }
+    }
+
+    /// Adds `amount` to the leftmost regular number in this
+    /// [SnailfishNumber].
+    fn add_to_leftmost(&mut self, amount: u64) {
+        match self {
+            SnailfishNumber::Pair(left, _) => left.add_to_leftmost(amount),
+            SnailfishNumber::Regular(value) => *value += amount,
+        }
+    }
+
+    /// Adds `amount` to the rightmost regular number in this
+    /// [SnailfishNumber].
+    fn add_to_rightmost(&mut self, amount: u64) {
+        match self {
+            SnailfishNumber::Pair(_, right) => right.add_to_rightmost(amount),
+            SnailfishNumber::Regular(value) => *value += amount,
+        }
+    }
+
+    /// Recursive helper of [SnailfishNumber::explode] for a
+    /// [SnailfishNumber] nested inside `depth` pairs.
+    ///
+    /// Returns [Option::None] if nothing exploded, or otherwise the amounts
+    /// still waiting to be added to the nearest regular numbers on the left
+    /// and right of this [SnailfishNumber].
+    fn explode_at(&mut self, depth: usize) -> Option<(Option<u64>, Option<u64>)> {
+        let (left, right) = match self {
+            SnailfishNumber::Pair(left, right) => (left, right),
+            SnailfishNumber::Regular(_) => return None,
+        };
+
+        if depth >= EXPLOSION_DEPTH {
+            if let (SnailfishNumber::Regular(left_value), SnailfishNumber::Regular(right_value)) =
+                (left.as_ref(), right.as_ref())
+            {
+                let carries = (Some(*left_value), Some(*right_value));
+                *self = SnailfishNumber::Regular(0);
+
+                return Some(carries);
+            }
+        }
+
+        if let Some((left_carry, right_carry)) = left.explode_at(depth + 1) {
+            if let Some(right_carry) = right_carry {
+                right.add_to_leftmost(right_carry);
+            }
+
+            return Some((left_carry, None));
+        }
+
+        if let Some((left_carry, right_carry)) = right.explode_at(depth + 1) {
+            if let Some(left_carry) = left_carry {
+                left.add_to_rightmost(left_carry);
+            }
+
+            return Some((None, right_carry));
+        }
+
+        None
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    /// Pairs this [SnailfishNumber] with `other` and reduces the result.
+    fn add(self, other: SnailfishNumber) -> Self::Output {
+        let mut sum = SnailfishNumber::pair(self, other);
+        sum.reduce();
+
+        sum
+    }
+}
+
+impl Display for SnailfishNumber {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            SnailfishNumber::Pair(left, right) => write!(f, "[{},{}]", left, right),
+            SnailfishNumber::Regular(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = Error;
+
+    /// Interprets the given [str] formatted like `[[1,2],3]` as a
+    /// [SnailfishNumber], without reducing it.
+    fn from_str(serialized_snailfish_number: &str) -> Result<Self> {
+        let trimmed = serialized_snailfish_number.trim();
+        let mut characters = trimmed.char_indices().peekable();
+
+        let snailfish_number = parse_element(&mut characters).with_context(|| {
+            format!(
+                "\"{}\" is not a valid snailfish number",
+                serialized_snailfish_number
+            )
+        })?;
+
+        if let Some((position, character)) = characters.next() {
+            return Err(anyhow!(
+                "\"{}\" is not a valid snailfish number (unexpected \"{}\" at position {})",
+                serialized_snailfish_number,
+                character,
+                position
+            ));
+        }
+
+        Ok(snailfish_number)
+    }
+}
+
+impl Sum for SnailfishNumber {
+    /// Adds every [SnailfishNumber] in order, reducing after each addition.
+    ///
+    /// The sum of no snailfish numbers is a regular `0`.
+    fn sum<I: Iterator<Item = SnailfishNumber>>(iter: I) -> Self {
+        iter.reduce(|sum, snailfish_number| sum + snailfish_number)
+            .unwrap_or(SnailfishNumber::Regular(0))
+    }
+}
+
+/// Parses the next regular number or pair out of `characters`.
+fn parse_element(characters: &mut Peekable<CharIndices>) -> Result<SnailfishNumber> {
+    match characters.peek().copied() {
+        Some((_, '[')) => {
+            characters.next();
+
+            let left = parse_element(characters)?;
+            expect(characters, ',')?;
+            let right = parse_element(characters)?;
+            expect(characters, ']')?;
+
+            Ok(SnailfishNumber::pair(left, right))
+        }
+        Some((position, character)) if character.is_ascii_digit() => {
+            let mut digits = String::new();
+            while let Some((_, digit)) = characters.next_if(|(_, next)| next.is_ascii_digit()) {
+                digits.push(digit);
+            }
+
+            let value = parse::signed_int::<u64>(&digits)
+                .with_context(|| format!("Invalid regular number at position {}", position))?;
+
+            Ok(SnailfishNumber::Regular(value))
+        }
+        Some((position, character)) => Err(anyhow!(
+            "Expected \"[\" or a digit at position {} (not \"{}\")",
+            position,
+            character
+        )),
+        None => Err(anyhow!("Expected \"[\" or a digit (not the end)")),
+    }
+}
+
+/// Consumes the `expected` character from `characters`, returning [Err] if
+/// the next character is anything else.
+fn expect(characters: &mut Peekable<CharIndices>, expected: char) -> Result<()> {
+    match characters.next() {
+        Some((_, character)) if character == expected => Ok(()),
+        Some((position, character)) => Err(anyhow!(
+            "Expected \"{}\" at position {} (not \"{}\")",
+            expected,
+            position,
+            character
+        )),
+        None => Err(anyhow!("Expected \"{}\" (not the end)", expected)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(serialized_snailfish_number: &str) -> SnailfishNumber {
+        serialized_snailfish_number.parse().unwrap()
+    }
+
+    fn sum_of(serialized_snailfish_numbers: &[&str]) -> SnailfishNumber {
+        serialized_snailfish_numbers
+            .iter()
+            .map(|serialized_snailfish_number| number(serialized_snailfish_number))
+            .sum()
+    }
+
+    #[test]
+    fn round_trips_through_display() {
+        for serialized_snailfish_number in [
+            "[1,2]",
+            "[[1,2],3]",
+            "[9,[8,7]]",
+            "[[1,9],[8,5]]",
+            "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]",
+            "[[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]",
+            "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+            "[10,123]",
+        ] {
+            assert_eq!(
+                number(serialized_snailfish_number).to_string(),
+                serialized_snailfish_number
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        for serialized_snailfish_number in [
+            "", "[", "[1]", "[1,2", "[1,2]]", "[1;2]", "[a,2]", "[,2]", "[1,2],",
+        ] {
+            assert!(
+                serialized_snailfish_number
+                    .parse::<SnailfishNumber>()
+                    .is_err(),
+                "\"{}\" should not parse",
+                serialized_snailfish_number
+            );
+        }
+    }
+
+    #[test]
+    fn explodes_the_leftmost_deeply_nested_pair() {
+        for (before, after) in [
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ] {
+            let mut snailfish_number = number(before);
+
+            assert!(snailfish_number.explode(), "{} should explode", before);
+            assert_eq!(snailfish_number, number(after));
+        }
+    }
+
+    #[test]
+    fn does_not_explode_shallow_pairs() {
+        let mut snailfish_number = number("[[[[1,2],3],4],5]");
+
+        assert!(!snailfish_number.explode());
+        assert_eq!(snailfish_number, number("[[[[1,2],3],4],5]"));
+    }
+
+    #[test]
+    fn splits_the_leftmost_large_regular_number() {
+        for (before, after) in [
+            ("[10,0]", "[[5,5],0]"),
+            ("[11,0]", "[[5,6],0]"),
+            ("[12,13]", "[[6,6],13]"),
+            ("[0,[9,15]]", "[0,[9,[7,8]]]"),
+        ] {
+            let mut snailfish_number = number(before);
+
+            assert!(snailfish_number.split(), "{} should split", before);
+            assert_eq!(snailfish_number, number(after));
+        }
+
+        assert!(!number("[9,[9,9]]").split());
+    }
+
+    #[test]
+    fn reduces_step_by_step() {
+        let mut snailfish_number = number("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+
+        assert!(snailfish_number.explode());
+        assert_eq!(
+            snailfish_number,
+            number("[[[[0,7],4],[7,[[8,4],9]]],[1,1]]")
+        );
+        assert!(snailfish_number.explode());
+        assert_eq!(snailfish_number, number("[[[[0,7],4],[15,[0,13]]],[1,1]]"));
+        assert!(!snailfish_number.explode());
+        assert!(snailfish_number.split());
+        assert_eq!(
+            snailfish_number,
+            number("[[[[0,7],4],[[7,8],[0,13]]],[1,1]]")
+        );
+        assert!(!snailfish_number.explode());
+        assert!(snailfish_number.split());
+        assert_eq!(
+            snailfish_number,
+            number("[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]")
+        );
+        assert!(snailfish_number.explode());
+        assert_eq!(
+            snailfish_number,
+            number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")
+        );
+        assert!(!snailfish_number.explode());
+        assert!(!snailfish_number.split());
+    }
+
+    #[test]
+    fn adds_and_reduces() {
+        assert_eq!(
+            number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]"),
+            number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")
+        );
+    }
+
+    #[test]
+    fn sums_lists_of_numbers() {
+        assert_eq!(
+            sum_of(&["[1,1]", "[2,2]", "[3,3]", "[4,4]"]),
+            number("[[[[1,1],[2,2]],[3,3]],[4,4]]")
+        );
+        assert_eq!(
+            sum_of(&["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]"]),
+            number("[[[[3,0],[5,3]],[4,4]],[5,5]]")
+        );
+        assert_eq!(
+            sum_of(&["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"]),
+            number("[[[[5,0],[7,4]],[5,5]],[6,6]]")
+        );
+        assert_eq!(
+            sum_of(&[
+                "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+                "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+                "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+                "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+                "[7,[5,[[3,8],[1,4]]]]",
+                "[[2,[2,2]],[8,[8,1]]]",
+                "[2,9]",
+                "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+                "[[[5,[7,4]],7],1]",
+                "[[[[4,2],2],6],[8,7]]",
+            ]),
+            number("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")
+        );
+    }
+
+    #[test]
+    fn sums_nothing_to_zero() {
+        assert_eq!(sum_of(&[]), SnailfishNumber::Regular(0));
+    }
+
+    #[test]
+    fn computes_magnitudes() {
+        for (serialized_snailfish_number, magnitude) in [
+            ("[9,1]", 29),
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            ("[[[[3,0],[5,3]],[4,4]],[5,5]]", 791),
+            ("[[[[5,0],[7,4]],[5,5]],[6,6]]", 1137),
+            (
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+                3488,
+            ),
+        ] {
+            assert_eq!(
+                number(serialized_snailfish_number).magnitude(),
+                magnitude,
+                "{} should have a magnitude of {}",
+                serialized_snailfish_number,
+                magnitude
+            );
+        }
+    }
+}
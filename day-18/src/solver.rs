@@ -0,0 +1,92 @@
+use crate::snailfish_number::SnailfishNumber;
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+use tracing::instrument;
+
+/// [Solver] for the "Snailfish" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SnailfishSolver;
+
+impl SnailfishSolver {
+    /// Interprets the newline-delimited snailfish numbers in `input`.
+    #[instrument(skip_all)]
+    fn deserialize(input: &str) -> Result<Vec<SnailfishNumber>> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+}
+
+impl Solver for SnailfishSolver {
+    type Parsed = Vec<SnailfishNumber>;
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<SnailfishNumber>> {
+        SnailfishSolver::deserialize(input)
+    }
+
+    fn part_one(&self, snailfish_numbers: &Vec<SnailfishNumber>) -> Result<Answer> {
+        let sum = snailfish_numbers.iter().cloned().sum::<SnailfishNumber>();
+
+        Ok(sum.magnitude().into())
+    }
+
+    fn part_two(&self, snailfish_numbers: &Vec<SnailfishNumber>) -> Result<Answer> {
+        // Snailfish addition is not commutative, so both orders of every
+        // pair have to be tried.
+        let largest_magnitude = snailfish_numbers
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| {
+                snailfish_numbers
+                    .iter()
+                    .enumerate()
+                    .filter(move |(j, _)| i != *j)
+                    .map(move |(_, b)| (a.clone() + b.clone()).magnitude())
+            })
+            .max()
+            .context(NoSolution::new(
+                "There are fewer than two snailfish numbers",
+            ))?;
+
+        Ok(largest_magnitude.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = SnailfishSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SnailfishSolver.part_one(&parsed).unwrap(), "4140");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = SnailfishSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SnailfishSolver.part_two(&parsed).unwrap(), "3993");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&SnailfishSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
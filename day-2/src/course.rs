@@ -0,0 +1,155 @@
+use crate::movement::Movement;
+
+use anyhow::{Context, Error, Result};
+use aoc_common::input::{DeserializeLines, FromInput};
+use std::ops::Deref;
+
+/// Movement commands that the submarine follows, in the order that the
+/// puzzle input lists them.
+pub struct SubmarineMovements(Vec<Movement>);
+
+impl Deref for SubmarineMovements {
+    type Target = [Movement];
+
+    fn deref(&self) -> &[Movement] {
+        &self.0
+    }
+}
+
+impl DeserializeLines for SubmarineMovements {
+    type Line = Movement;
+
+    fn deserialize_line(line: &str) -> Result<Movement> {
+        Ok(line.parse::<Movement>()?)
+    }
+
+    fn from_lines(movements: Vec<Movement>) -> SubmarineMovements {
+        SubmarineMovements(movements)
+    }
+}
+
+impl FromInput for SubmarineMovements {
+    fn from_input(input: &str) -> Result<SubmarineMovements> {
+        parse_submarine_movements(input).map(SubmarineMovements)
+    }
+}
+
+/// Returns the horizontal position and depth of the submarine after following
+/// `submarine_movements`, treating up and down as changes in depth.
+pub fn aimless_position_after(submarine_movements: &[Movement]) -> (i32, i32) {
+    submarine_movements
+        .iter()
+        .map(|movement| match movement {
+            Movement::Down(magnitude) => (0, *magnitude),
+            Movement::Up(magnitude) => (0, -1 * *magnitude),
+            Movement::Forward(magnitude) => (*magnitude, 0),
+        })
+        .reduce(|a, b| (a.0 + b.0, a.1 + b.1))
+        .unwrap_or((0, 0))
+}
+
+/// Interprets `serialized_movements` as a newline-separated list of
+/// serialized movement commands.
+pub fn parse_submarine_movements(serialized_movements: &str) -> Result<Vec<Movement>, Error> {
+    serialized_movements
+        .lines()
+        .map(|raw_submarine_movement| {
+            raw_submarine_movement
+                .parse::<Movement>()
+                .with_context(|| format!("\"{}\" is not a valid movement", raw_submarine_movement))
+        })
+        .collect::<Result<Vec<Movement>>>()
+}
+
+/// Returns the horizontal position and depth of the submarine after following
+/// `submarine_movements`, treating up and down as changes in aim.
+pub fn position_after(submarine_movements: &[Movement]) -> (i32, i32) {
+    let mut aim = 0;
+    let mut depth = 0;
+    let mut horizontal_position = 0;
+
+    for submarine_movement in submarine_movements {
+        match submarine_movement {
+            Movement::Down(magnitude) => {
+                aim += magnitude;
+            }
+            Movement::Up(magnitude) => {
+                aim -= magnitude;
+            }
+            Movement::Forward(magnitude) => {
+                horizontal_position += magnitude;
+                depth += aim * magnitude;
+            }
+        }
+    }
+
+    (horizontal_position, depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use crate::movement::MovementParseError;
+    use proptest::prelude::*;
+
+    #[test]
+    fn follows_sample_course_without_aim() {
+        let submarine_movements = parse_submarine_movements(SAMPLE).unwrap();
+
+        assert_eq!(aimless_position_after(&submarine_movements), (15, 10));
+    }
+
+    #[test]
+    fn follows_sample_course_with_aim() {
+        let submarine_movements = parse_submarine_movements(SAMPLE).unwrap();
+
+        assert_eq!(position_after(&submarine_movements), (15, 60));
+    }
+
+    #[test]
+    fn rejects_malformed_movements() {
+        assert!(matches!(
+            "sideways 5".parse::<Movement>(),
+            Err(MovementParseError::InvalidDirection { direction, .. }) if direction == "sideways"
+        ));
+        assert!(matches!(
+            "up five".parse::<Movement>(),
+            Err(MovementParseError::InvalidMagnitude { .. })
+        ));
+        assert!(matches!(
+            "forward".parse::<Movement>(),
+            Err(MovementParseError::Malformed { .. })
+        ));
+    }
+
+    /// Returns a [Strategy] for a course of up to fifty [Movement]s, small
+    /// enough that following it with aim cannot overflow.
+    fn movements() -> impl Strategy<Value = Vec<Movement>> {
+        let movement = prop_oneof![
+            (-100..100).prop_map(Movement::Down),
+            (-100..100).prop_map(Movement::Forward),
+            (-100..100).prop_map(Movement::Up),
+        ];
+
+        prop::collection::vec(movement, 0..50)
+    }
+
+    proptest! {
+        #[test]
+        fn parses_what_it_displays(movements in movements()) {
+            let serialized_movements = movements
+                .iter()
+                .map(|movement| movement.to_string())
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            prop_assert_eq!(parse_submarine_movements(&serialized_movements).unwrap(), movements);
+        }
+
+        #[test]
+        fn aim_never_changes_horizontal_position(movements in movements()) {
+            prop_assert_eq!(aimless_position_after(&movements).0, position_after(&movements).0);
+        }
+    }
+}
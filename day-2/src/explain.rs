@@ -0,0 +1,54 @@
+use aoc_common::table::Table;
+use day_2::movement::Movement;
+
+/// Prints where the submarine is after each of `movements`, first treating
+/// "down" and "up" as changes in depth and then as changes in aim, the way
+/// the puzzle narrates its examples.
+pub fn narrate_course(movements: &[Movement]) {
+    let mut table = Table::new();
+
+    let mut depth = 0;
+    let mut horizontal_position = 0;
+
+    table.title("Treating \"down\" and \"up\" as changes in depth:");
+    for movement in movements {
+        match movement {
+            Movement::Down(magnitude) => depth += magnitude,
+            Movement::Forward(magnitude) => horizontal_position += magnitude,
+            Movement::Up(magnitude) => depth -= magnitude,
+        }
+
+        table.row([
+            movement.to_string(),
+            format!("horizontal position {}", horizontal_position),
+            format!("depth {}", depth),
+        ]);
+    }
+
+    let mut aim = 0;
+    depth = 0;
+    horizontal_position = 0;
+
+    table
+        .separator()
+        .title("Treating \"down\" and \"up\" as changes in aim:");
+    for movement in movements {
+        match movement {
+            Movement::Down(magnitude) => aim += magnitude,
+            Movement::Forward(magnitude) => {
+                horizontal_position += magnitude;
+                depth += aim * magnitude;
+            }
+            Movement::Up(magnitude) => aim -= magnitude,
+        }
+
+        table.row([
+            movement.to_string(),
+            format!("horizontal position {}", horizontal_position),
+            format!("depth {}", depth),
+            format!("aim {}", aim),
+        ]);
+    }
+
+    table.separator().print();
+}
@@ -0,0 +1,12 @@
+//! Solves the "Dive!" puzzle.
+
+extern crate anyhow;
+extern crate aoc_common;
+#[cfg(feature = "serde")]
+extern crate serde;
+extern crate thiserror;
+
+pub mod course;
+pub mod fixtures;
+pub mod movement;
+pub mod solver;
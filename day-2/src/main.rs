@@ -1,104 +1,140 @@
 extern crate anyhow;
-extern crate lazy_static;
-extern crate regex;
-
-mod movement;
-
-use anyhow::{Context, Error, Result};
-use movement::Movement;
-use std::env::current_dir;
-use tokio::fs::File;
-use tokio::io::AsyncReadExt;
-
-#[tokio::main]
-async fn main() -> Result<()> {
-    let submarine_movements = read_submarine_movements("files/input.txt").await?;
-
-    println!("# of movements: {}\n", (&submarine_movements).len());
-
-    let aimless_position = submarine_movements
-        .iter()
-        .map(|movement| match movement {
-            Movement::Down(magnitude) => (0, *magnitude),
-            Movement::Up(magnitude) => (0, -1 * *magnitude),
-            Movement::Forward(magnitude) => (*magnitude, 0),
-        })
-        .reduce(|a, b| (a.0 + b.0, a.1 + b.1))
-        .unwrap_or((0, 0));
-
-    println!("Aimless horizontal position:\t{}", aimless_position.0);
-    println!("Aimless depth:\t\t\t{}", aimless_position.1);
-    println!(
-        "Product:\t\t\t{}\n",
-        aimless_position.0 * aimless_position.1
-    );
-
-    let mut aim = 0;
-    let mut depth = 0;
-    let mut horizontal_position = 0;
-
-    for submarine_movement in submarine_movements {
-        match submarine_movement {
-            Movement::Down(magnitude) => {
-                aim += magnitude;
-            }
-            Movement::Up(magnitude) => {
-                aim -= magnitude;
-            }
-            Movement::Forward(magnitude) => {
-                horizontal_position += magnitude;
-                depth += aim * magnitude;
-            }
-        }
+extern crate aoc_common;
+extern crate clap;
+extern crate day_2;
+extern crate tracing;
+
+mod args;
+mod explain;
+
+use anyhow::{Context, Result};
+use aoc_common::args::ParseArgs;
+use aoc_common::args::Part;
+use aoc_common::batch;
+use aoc_common::completions;
+use aoc_common::concurrent;
+use aoc_common::error::InvalidInput;
+use aoc_common::input;
+use aoc_common::table::Table;
+use aoc_common::timing::Timings;
+use aoc_common::watch;
+use args::Args;
+use day_2::course::{self, SubmarineMovements};
+use day_2::movement::Movement;
+use std::process::ExitCode;
+use tracing::info_span;
+
+fn main() -> ExitCode {
+    #[cfg(feature = "embedded-inputs")]
+    input::embed(include_str!("../files/input.txt"));
+
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
+}
+
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return batch::write_answers_csv(
+            inputs,
+            args.common.out.as_deref(),
+            args.common.part,
+            |input, part| {
+                Ok(answers_table(
+                    &course::parse_submarine_movements(input).context(InvalidInput)?,
+                    Some(part),
+                ))
+            },
+        );
+    }
+
+    if args.common.watch {
+        return watch::watch_answers(&args.common, |input| {
+            Ok(answers_table(
+                &course::parse_submarine_movements(input).context(InvalidInput)?,
+                args.common.part,
+            ))
+        });
     }
 
-    println!("Horizontal position:\t\t{}", horizontal_position);
-    println!("Depth:\t\t\t\t{}", depth);
-    println!("Product:\t\t\t{}\n", horizontal_position * depth);
+    args.common.forbid_visualize(2)?;
+    let mut timings = Timings::new();
+    let submarine_movements: SubmarineMovements =
+        input::load_parsed(args.common.input_source(), &mut timings)?;
+
+    if args.explain {
+        explain::narrate_course(&submarine_movements);
+    }
 
-    Ok(())
+    args.common.print_answers(
+        2,
+        &concurrent::solve_parts(args.common.part, timings, |part| {
+            Ok(answers_table(&submarine_movements, Some(part)))
+        })?,
+    )
 }
 
-/// Reads the contents of the "submarine movements" input file as a
-/// newline-separated list of serialized movement commands.
-async fn read_submarine_movements(
-    submarine_movement_file_path: &str,
-) -> Result<Vec<Movement>, Error> {
-    let pwd = current_dir().context("Failed to read current working directory")?;
-    let submarine_movement_file_path_buf = pwd.join(submarine_movement_file_path);
-
-    let mut submarine_movement_file = File::open(&submarine_movement_file_path_buf)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to open file at path \"{}\"",
-                submarine_movement_file_path_buf.display()
-            )
-        })?;
-    let mut raw_submarine_movement_file_contents = vec![];
-
-    submarine_movement_file
-        .read_to_end(&mut raw_submarine_movement_file_contents)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to read file at path \"{}\"",
-                submarine_movement_file_path_buf.display()
-            )
-        })?;
-
-    let submarine_movement_file_contents =
-        String::from_utf8_lossy(&raw_submarine_movement_file_contents);
-
-    let submarine_movements = submarine_movement_file_contents
-        .lines()
-        .map(|raw_submarine_movement| {
-            raw_submarine_movement
-                .parse::<Movement>()
-                .with_context(|| format!("\"{}\" is not a valid movement", raw_submarine_movement))
-        })
-        .collect::<Result<Vec<Movement>>>()
-        .context("Failed to parse submarine movements")?;
-
-    Ok(submarine_movements)
+/// Returns a [Table] of the answers to the puzzle for the specified
+/// `submarine_movements`, including only the specified `part` if there is one
+/// and both parts otherwise.
+fn answers_table(submarine_movements: &[Movement], part: Option<Part>) -> Table {
+    let mut table = Table::new();
+
+    if Part::One.is_selected_by(part) {
+        let _part_one_span = info_span!("part_one").entered();
+        let aimless_position = course::aimless_position_after(submarine_movements);
+
+        table
+            .entry("# of movements", submarine_movements.len())
+            .separator()
+            .entry("Aimless horizontal position", aimless_position.0)
+            .entry("Aimless depth", aimless_position.1)
+            .answer("Product", aimless_position.0 * aimless_position.1);
+    }
+
+    if Part::Two.is_selected_by(part) {
+        let _part_two_span = info_span!("part_two").entered();
+        let (horizontal_position, depth) = course::position_after(submarine_movements);
+
+        if !table.is_empty() {
+            table.separator();
+        }
+        table
+            .entry("Horizontal position", horizontal_position)
+            .entry("Depth", depth)
+            .answer("Product", horizontal_position * depth);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers;
+    use day_2::fixtures::{INPUT, SAMPLE};
+    use insta::assert_snapshot;
+
+    /// Returns the movements listed in `serialized_movements`.
+    fn movements_in(serialized_movements: &str) -> Vec<Movement> {
+        course::parse_submarine_movements(serialized_movements).unwrap()
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&movements_in(SAMPLE), None).to_string());
+    }
+
+    #[test]
+    fn agrees_with_local_answers_to_input() {
+        answers::assert_matches_local_answers(2, &answers_table(&movements_in(INPUT), None));
+    }
 }
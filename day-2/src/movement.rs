@@ -1,10 +1,12 @@
-use anyhow::{anyhow, Context};
-use lazy_static::lazy_static;
-use regex::Regex;
+use aoc_common::parse;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
+use thiserror::Error;
 
 /// Enumerates every possible direction of movement for the submarine.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum Movement {
     /// Describes a downward movement with a specified [i32] magnitude.
     Down(i32),
@@ -14,51 +16,60 @@ pub enum Movement {
     Up(i32),
 }
 
-lazy_static! {
-    /// Regular expression designed to match strings that look like
-    /// " forward 2" and "up 6  ".
-    ///
-    /// Capture groups:
-    /// *   [`1`] direction
-    /// *   [`2`] magnitude
-    static ref MOVEMENT_PATTERN: Regex =
-        Regex::new(
-            format!(
-                r"\s*(?P<{}>[a-z]+)\s(?P<{}>\d)\s*",
-                capture_group_name::MOVEMENT_DIRECTION,
-                capture_group_name::MOVEMENT_MAGNITUDE,
-            ).as_str()).unwrap();
+/// Enumerates the ways that text can fail to be interpreted as a [Movement].
+#[derive(Debug, Error)]
+pub enum MovementParseError {
+    /// Direction is not one that the submarine can move in.
+    #[error("\"{input}\" is not a valid movement (invalid direction \"{direction}\")")]
+    InvalidDirection {
+        /// Direction that the text named.
+        direction: String,
+        /// Text that was interpreted.
+        input: String,
+    },
+    /// Magnitude is not an integer.
+    #[error("\"{input}\" is not a valid movement (invalid magnitude)")]
+    InvalidMagnitude {
+        /// Text that was interpreted.
+        input: String,
+        /// Why the magnitude is not an integer.
+        source: anyhow::Error,
+    },
+    /// Text is not a direction followed by a magnitude.
+    #[error("\"{input}\" is not a valid movement")]
+    Malformed {
+        /// Text that was interpreted.
+        input: String,
+        /// Why the text is not a direction followed by a magnitude.
+        source: anyhow::Error,
+    },
 }
 
 impl FromStr for Movement {
-    type Err = anyhow::Error;
+    type Err = MovementParseError;
 
-    fn from_str(input: &str) -> Result<Movement, Self::Err> {
-        let captures = MOVEMENT_PATTERN
-            .captures(input)
-            .with_context(|| format!("\"{}\" is not a valid movement", input))?;
-        let raw_direction = captures
-            .name(capture_group_name::MOVEMENT_DIRECTION)
-            .with_context(|| format!("\"{}\" is not a valid movement (invalid direction)", input))?
-            .as_str();
-        let raw_magnitude = captures
-            .name(capture_group_name::MOVEMENT_MAGNITUDE)
-            .with_context(|| {
-                format!("\"{}\" is not a valid movement (invalid magnitude)", input)
+    fn from_str(input: &str) -> Result<Movement, MovementParseError> {
+        let (raw_direction, raw_magnitude) =
+            parse::labeled(input).map_err(|source| MovementParseError::Malformed {
+                input: input.to_string(),
+                source,
             })?;
 
-        let magnitude = raw_magnitude.as_str().parse::<i32>().with_context(|| {
-            format!("\"{}\" is not a valid movement (invalid magnitude)", input)
+        let magnitude = parse::signed_int::<i32>(raw_magnitude).map_err(|source| {
+            MovementParseError::InvalidMagnitude {
+                input: input.to_string(),
+                source,
+            }
         })?;
 
         match raw_direction {
             direction_label::DOWN => Ok(Movement::Down(magnitude)),
             direction_label::FORWARD => Ok(Movement::Forward(magnitude)),
             direction_label::UP => Ok(Movement::Up(magnitude)),
-            _ => Err(anyhow!(
-                "\"{}\" is not a valid movement (invalid direction)",
-                raw_direction
-            )),
+            _ => Err(MovementParseError::InvalidDirection {
+                direction: raw_direction.to_string(),
+                input: input.to_string(),
+            }),
         }
     }
 }
@@ -77,15 +88,6 @@ impl fmt::Display for Movement {
     }
 }
 
-/// Module used to namespace movement regular expression capture group names.
-mod capture_group_name {
-    /// Name of the capture group used to select the direction of movement string.
-    pub const MOVEMENT_DIRECTION: &str = "direction";
-
-    /// Name of the capture group used to select the magnitude of movement string.
-    pub const MOVEMENT_MAGNITUDE: &str = "magnitude";
-}
-
 /// Module used to namespace text labels for movement directions.
 mod direction_label {
     /// Text snippet associated with [Movement::Down].
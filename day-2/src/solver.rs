@@ -0,0 +1,54 @@
+use crate::course::{self, SubmarineMovements};
+
+use anyhow::Result;
+use aoc_common::answer::Answer;
+use aoc_common::input::FromInput;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Dive!" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DiveSolver;
+
+impl Solver for DiveSolver {
+    type Parsed = SubmarineMovements;
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input: &str) -> Result<SubmarineMovements> {
+        SubmarineMovements::from_input(input)
+    }
+
+    fn part_one(&self, submarine_movements: &SubmarineMovements) -> Result<Answer> {
+        let (horizontal_position, depth) = course::aimless_position_after(submarine_movements);
+
+        Ok((horizontal_position * depth).into())
+    }
+
+    fn part_two(&self, submarine_movements: &SubmarineMovements) -> Result<Answer> {
+        let (horizontal_position, depth) = course::position_after(submarine_movements);
+
+        Ok((horizontal_position * depth).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = DiveSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(DiveSolver.part_one(&parsed).unwrap(), "150");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = DiveSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(DiveSolver.part_two(&parsed).unwrap(), "900");
+    }
+}
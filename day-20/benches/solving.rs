@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_20::solver::TrenchMapSolver;
+
+fn bench_trench_map_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &TrenchMapSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_trench_map_solver);
+criterion_main!(benches);
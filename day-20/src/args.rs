@@ -0,0 +1,10 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Enhances a trench map image")]
+pub struct Args {
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
@@ -0,0 +1,48 @@
+use crate::pixel::deserialize_pixel;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::bit::Bit;
+
+/// Number of pixels that an [EnhancementAlgorithm] maps each 3x3 window of
+/// pixels to, one for every possible window.
+pub const LEN: usize = 1 << WINDOW_PIXELS;
+
+/// Number of pixels in the 3x3 window that determines each output pixel.
+pub const WINDOW_PIXELS: usize = 9;
+
+/// Image enhancement algorithm that maps each 3x3 window of pixels, read row
+/// by row as a 9-bit binary number, to a single output pixel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnhancementAlgorithm([Bit; LEN]);
+
+impl EnhancementAlgorithm {
+    /// Interprets the given [str] of exactly [LEN] pixels as an
+    /// [EnhancementAlgorithm].
+    pub fn deserialize(serialized_enhancement_algorithm: &str) -> Result<EnhancementAlgorithm> {
+        let pixels = serialized_enhancement_algorithm
+            .trim()
+            .chars()
+            .map(deserialize_pixel)
+            .collect::<Result<Vec<Bit>>>()
+            .context("Image enhancement algorithm contains an invalid pixel")?;
+
+        let pixel_count = pixels.len();
+        let pixels = <[Bit; LEN]>::try_from(pixels).map_err(|_| {
+            anyhow!(
+                "Image enhancement algorithm has {} pixels (not {})",
+                pixel_count,
+                LEN
+            )
+        })?;
+
+        Ok(EnhancementAlgorithm(pixels))
+    }
+
+    /// Returns the output pixel for the 3x3 window of pixels encoded by
+    /// `window`.
+    ///
+    /// Only the lowest [WINDOW_PIXELS] bits of `window` are considered.
+    pub fn output_for(&self, window: usize) -> Bit {
+        self.0[window % LEN]
+    }
+}
@@ -0,0 +1,9 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod enhancement_algorithm;
+pub mod fixtures;
+pub mod pixel;
+pub mod solver;
+pub mod trench_image;
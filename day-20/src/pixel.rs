@@ -0,0 +1,26 @@
+use anyhow::{anyhow, Result};
+use aoc_common::bit::Bit;
+
+/// Character used to render a dark pixel.
+pub const DARK: char = '.';
+
+/// Character used to render a light pixel.
+pub const LIGHT: char = '#';
+
+/// Interprets the given [char] as a pixel, where a light pixel is a
+/// [Bit::One] and a dark pixel is a [Bit::Zero].
+pub fn deserialize_pixel(pixel_char: char) -> Result<Bit> {
+    match pixel_char {
+        DARK => Ok(Bit::Zero),
+        LIGHT => Ok(Bit::One),
+        _ => Err(anyhow!("'{}' is not a valid pixel", pixel_char)),
+    }
+}
+
+/// Returns the [char] used to render the given pixel.
+pub fn serialize_pixel(pixel: Bit) -> char {
+    match pixel {
+        Bit::One => LIGHT,
+        Bit::Zero => DARK,
+    }
+}
@@ -0,0 +1,100 @@
+use crate::enhancement_algorithm::EnhancementAlgorithm;
+use crate::trench_image::TrenchImage;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+use tracing::instrument;
+
+/// [Solver] for the "Trench Map" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TrenchMapSolver;
+
+impl TrenchMapSolver {
+    /// Interprets `input` as an [EnhancementAlgorithm] followed by a blank line
+    /// and then the [TrenchImage] it should be applied to.
+    #[instrument(skip_all)]
+    fn deserialize(input: &str) -> Result<(EnhancementAlgorithm, TrenchImage)> {
+        let input = input.trim().replace("\r\n", "\n");
+        let (serialized_enhancement_algorithm, serialized_trench_image) = input
+            .split_once("\n\n")
+            .ok_or_else(|| anyhow!("Input lacks a blank line after the enhancement algorithm"))?;
+
+        let enhancement_algorithm =
+            EnhancementAlgorithm::deserialize(serialized_enhancement_algorithm)
+                .context("Failed to read image enhancement algorithm")?;
+        let trench_image = TrenchImage::deserialize(serialized_trench_image)
+            .context("Failed to read input image")?;
+
+        Ok((enhancement_algorithm, trench_image))
+    }
+
+    /// Returns how many pixels are lit after enhancing the parsed image the
+    /// specified number of `times`.
+    fn lit_pixels_after(
+        (enhancement_algorithm, trench_image): &(EnhancementAlgorithm, TrenchImage),
+        times: usize,
+    ) -> Result<Answer> {
+        let lit_pixel_count = trench_image
+            .enhanced_times(enhancement_algorithm, times)
+            .lit_pixel_count()
+            .with_context(|| {
+                NoSolution::new(format!(
+                    "Infinitely many pixels are lit after {} enhancements",
+                    times
+                ))
+            })?;
+
+        Ok(lit_pixel_count.into())
+    }
+}
+
+impl Solver for TrenchMapSolver {
+    type Parsed = (EnhancementAlgorithm, TrenchImage);
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn parse(&self, input: &str) -> Result<(EnhancementAlgorithm, TrenchImage)> {
+        TrenchMapSolver::deserialize(input)
+    }
+
+    fn part_one(&self, parsed: &(EnhancementAlgorithm, TrenchImage)) -> Result<Answer> {
+        TrenchMapSolver::lit_pixels_after(parsed, 2)
+    }
+
+    fn part_two(&self, parsed: &(EnhancementAlgorithm, TrenchImage)) -> Result<Answer> {
+        TrenchMapSolver::lit_pixels_after(parsed, 50)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = TrenchMapSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(TrenchMapSolver.part_one(&parsed).unwrap(), "35");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = TrenchMapSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(TrenchMapSolver.part_two(&parsed).unwrap(), "3351");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&TrenchMapSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
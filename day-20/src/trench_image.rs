@@ -0,0 +1,142 @@
+use crate::enhancement_algorithm::EnhancementAlgorithm;
+use crate::pixel::{deserialize_pixel, serialize_pixel};
+
+use anyhow::{Context, Result};
+use aoc_common::bit::Bit;
+use aoc_common::grid::Grid2D;
+use std::fmt::{Display, Formatter};
+
+/// Infinite image whose pixels all match [TrenchImage::background] outside of
+/// a finite window of interest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrenchImage {
+    /// Pixel shared by every position outside of [TrenchImage::pixels].
+    background: Bit,
+    /// Pixels within the window of interest of this [TrenchImage].
+    pixels: Grid2D<Bit>,
+}
+
+impl TrenchImage {
+    /// Interprets the given newline-delimited rows of pixels as a
+    /// [TrenchImage] surrounded by dark pixels.
+    pub fn deserialize(serialized_trench_image: &str) -> Result<TrenchImage> {
+        let rows = serialized_trench_image
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(deserialize_pixel).collect())
+            .collect::<Result<Vec<Vec<Bit>>>>()
+            .context("Image contains an invalid pixel")?;
+        let pixels = Grid2D::from_rows(rows).context("Image is not rectangular")?;
+
+        Ok(TrenchImage {
+            background: Bit::Zero,
+            pixels,
+        })
+    }
+
+    /// Returns the pixel shared by every position outside of the window of
+    /// interest of this [TrenchImage].
+    pub fn background(&self) -> Bit {
+        self.background
+    }
+
+    /// Returns a copy of this [TrenchImage] after applying `algorithm` once.
+    ///
+    /// Each enhancement pads the window of interest by one pixel on every
+    /// side, since those pixels are the only ones outside of the window whose
+    /// 3x3 neighborhoods overlap it. Every pixel further out sees nothing but
+    /// background, so the new background is whatever `algorithm` outputs for
+    /// a window made entirely of the old background. This is why algorithms
+    /// that map a dark window to a light pixel make the background flicker.
+    pub fn enhanced(&self, algorithm: &EnhancementAlgorithm) -> TrenchImage {
+        let height = self.pixels.height() + 2;
+        let width = self.pixels.width() + 2;
+
+        let mut pixels = Grid2D::filled_with(Bit::Zero, width, height);
+        for row in 0..height {
+            for column in 0..width {
+                // Position (row, column) of the padded image lies at
+                // (row - 1, column - 1) of this image.
+                let window = self.window_around(row as isize - 1, column as isize - 1);
+
+                pixels[(row, column)] = algorithm.output_for(window);
+            }
+        }
+
+        let background_window = match self.background {
+            Bit::One => usize::MAX,
+            Bit::Zero => 0,
+        };
+
+        TrenchImage {
+            background: algorithm.output_for(background_window),
+            pixels,
+        }
+    }
+
+    /// Returns a copy of this [TrenchImage] after applying `algorithm` the
+    /// specified number of `times`.
+    pub fn enhanced_times(&self, algorithm: &EnhancementAlgorithm, times: usize) -> TrenchImage {
+        (0..times).fold(self.clone(), |trench_image, _| {
+            trench_image.enhanced(algorithm)
+        })
+    }
+
+    /// Returns how many pixels of this [TrenchImage] are light, or
+    /// [Option::None] if infinitely many are because the background is light.
+    pub fn lit_pixel_count(&self) -> Option<usize> {
+        if self.background == Bit::One {
+            return None;
+        }
+
+        Some(
+            self.pixels
+                .iter()
+                .filter(|(_, pixel)| **pixel == Bit::One)
+                .count(),
+        )
+    }
+
+    /// Returns the pixel at the specified position, relative to the top-left
+    /// corner of the window of interest, falling back to the background.
+    fn pixel_at(&self, row: isize, column: isize) -> Bit {
+        if row < 0 || column < 0 {
+            return self.background;
+        }
+
+        self.pixels
+            .get(row as usize, column as usize)
+            .copied()
+            .unwrap_or(self.background)
+    }
+
+    /// Returns the 3x3 window centered on the specified position, read row by
+    /// row as a binary number.
+    fn window_around(&self, row: isize, column: isize) -> usize {
+        let mut window = 0;
+        for row_offset in -1..=1 {
+            for column_offset in -1..=1 {
+                let pixel = self.pixel_at(row + row_offset, column + column_offset);
+
+                window = (window << 1) | pixel.value();
+            }
+        }
+
+        window
+    }
+}
+
+impl Display for TrenchImage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.pixels.height() {
+            let line = (0..self.pixels.width())
+                .map(|column| serialize_pixel(self.pixels[(row, column)]))
+                .collect::<String>();
+
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_21::solver::DiracDiceSolver;
+
+fn bench_dirac_dice_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &DiracDiceSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_dirac_dice_solver);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+use crate::game::{GameRules, GameState};
+
+/// Die that rolls `1`, then `2`, and so on up to its number of sides, before
+/// starting over at `1`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DeterministicDie {
+    /// Value of the next roll.
+    next_roll: u64,
+    /// Number of times this [DeterministicDie] has been rolled.
+    rolls: u64,
+    /// Number of sides on this [DeterministicDie].
+    sides: u64,
+}
+
+impl DeterministicDie {
+    /// Creates a new [DeterministicDie] with the specified number of `sides`.
+    pub fn with_sides(sides: u64) -> DeterministicDie {
+        DeterministicDie {
+            next_roll: 1,
+            rolls: 0,
+            sides,
+        }
+    }
+
+    /// Rolls this [DeterministicDie], returning the value rolled.
+    pub fn roll(&mut self) -> u64 {
+        let roll = self.next_roll;
+
+        self.next_roll = roll % self.sides + 1;
+        self.rolls += 1;
+
+        roll
+    }
+
+    /// Returns how many times this [DeterministicDie] has been rolled.
+    pub fn rolls(&self) -> u64 {
+        self.rolls
+    }
+}
+
+/// Describes how a game played with a [DeterministicDie] ended.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DeterministicOutcome {
+    /// [GameState] as of the winning turn.
+    pub final_state: GameState,
+    /// Number of times the die was rolled over the whole game.
+    pub rolls: u64,
+}
+
+impl DeterministicOutcome {
+    /// Returns the lowest score of any player multiplied by the number of
+    /// times the die was rolled.
+    pub fn losing_score_times_rolls(&self) -> u64 {
+        self.final_state.scores.iter().min().copied().unwrap_or(0) * self.rolls
+    }
+}
+
+/// Plays out the game starting from `initial_state` with a [DeterministicDie]
+/// until somebody wins.
+pub fn play_with_deterministic_die(
+    initial_state: &GameState,
+    rules: &GameRules,
+) -> DeterministicOutcome {
+    let mut die = DeterministicDie::with_sides(rules.die_sides);
+    let mut game_state = *initial_state;
+
+    while game_state.winner(rules).is_none() {
+        let roll_total = (0..rules.rolls_per_turn).map(|_| die.roll()).sum();
+
+        game_state = game_state.after_turn(rules, roll_total);
+    }
+
+    DeterministicOutcome {
+        final_state: game_state,
+        rolls: die.rolls(),
+    }
+}
@@ -0,0 +1,76 @@
+use crate::game::{GameRules, GameState, PLAYERS};
+
+use aoc_common::memo::Memo;
+use std::collections::BTreeMap;
+
+/// Returns how many universes each player wins in when every roll of the die
+/// splits the universe into one copy per side, starting from `initial_state`.
+pub fn count_wins_across_universes(
+    initial_state: &GameState,
+    rules: &GameRules,
+) -> [u128; PLAYERS] {
+    let roll_total_frequencies = roll_total_frequencies(rules);
+
+    count_wins_from(
+        *initial_state,
+        rules,
+        &roll_total_frequencies,
+        &mut Memo::new(),
+    )
+}
+
+/// Returns how many of the universes split off by a single turn roll each
+/// possible total, ordered by total.
+///
+/// Grouping universes by their roll total lets a turn branch once per total
+/// instead of once per sequence of rolls.
+pub fn roll_total_frequencies(rules: &GameRules) -> Vec<(u64, u128)> {
+    let mut frequencies = BTreeMap::from([(0, 1)]);
+
+    for _ in 0..rules.rolls_per_turn {
+        let mut next_frequencies = BTreeMap::new();
+
+        for (total, frequency) in frequencies {
+            for roll in 1..=rules.die_sides {
+                *next_frequencies.entry(total + roll).or_insert(0) += frequency;
+            }
+        }
+
+        frequencies = next_frequencies;
+    }
+
+    frequencies.into_iter().collect()
+}
+
+/// Memoized helper of [count_wins_across_universes].
+fn count_wins_from(
+    game_state: GameState,
+    rules: &GameRules,
+    roll_total_frequencies: &[(u64, u128)],
+    memo: &mut Memo<GameState, [u128; PLAYERS]>,
+) -> [u128; PLAYERS] {
+    memo.get_or_insert_with(game_state, |memo| {
+        let mut wins = [0; PLAYERS];
+
+        if let Some(winner) = game_state.winner(rules) {
+            wins[winner] = 1;
+
+            return wins;
+        }
+
+        for (roll_total, frequency) in roll_total_frequencies {
+            let next_wins = count_wins_from(
+                game_state.after_turn(rules, *roll_total),
+                rules,
+                roll_total_frequencies,
+                memo,
+            );
+
+            for (player, next_win_count) in next_wins.iter().enumerate() {
+                wins[player] += frequency * next_win_count;
+            }
+        }
+
+        wins
+    })
+}
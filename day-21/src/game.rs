@@ -0,0 +1,222 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::parse;
+use tracing::instrument;
+
+/// Number of players in a game.
+pub const PLAYERS: usize = 2;
+
+/// Rules that a game of Dirac Dice is played by.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GameRules {
+    /// Number of spaces on the circular track, numbered `1` through
+    /// `board_size`.
+    pub board_size: u64,
+    /// Number of sides on the die, numbered `1` through `die_sides`.
+    pub die_sides: u64,
+    /// Number of times the die is rolled each turn.
+    pub rolls_per_turn: u64,
+    /// Score that a player has to reach to win.
+    pub winning_score: u64,
+}
+
+impl GameRules {
+    /// Rules of the practice game played with a deterministic 100-sided die.
+    pub const DETERMINISTIC: GameRules = GameRules {
+        board_size: 10,
+        die_sides: 100,
+        rolls_per_turn: 3,
+        winning_score: 1000,
+    };
+
+    /// Rules of the real game played with the three-sided Dirac die.
+    pub const DIRAC: GameRules = GameRules {
+        board_size: 10,
+        die_sides: 3,
+        rolls_per_turn: 3,
+        winning_score: 21,
+    };
+}
+
+/// Snapshot of a game between turns.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GameState {
+    /// Index of the player who takes the next turn.
+    pub next_player: usize,
+    /// Space that each player's pawn is on.
+    pub positions: [u64; PLAYERS],
+    /// Score of each player.
+    pub scores: [u64; PLAYERS],
+}
+
+impl GameState {
+    /// Interprets the given `Player N starting position: P` lines as the
+    /// [GameState] of a game that has not started yet.
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_starting_positions: &str) -> Result<GameState> {
+        let positions = serialized_starting_positions
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(deserialize_starting_position)
+            .collect::<Result<Vec<u64>>>()?;
+
+        let player_count = positions.len();
+        let positions = <[u64; PLAYERS]>::try_from(positions).map_err(|_| {
+            anyhow!(
+                "There are {} starting positions (not {})",
+                player_count,
+                PLAYERS
+            )
+        })?;
+
+        Ok(GameState::starting_at(positions))
+    }
+
+    /// Creates the [GameState] of a game that has not started yet, with each
+    /// player's pawn on the space in `positions`.
+    pub fn starting_at(positions: [u64; PLAYERS]) -> GameState {
+        GameState {
+            next_player: 0,
+            positions,
+            scores: [0; PLAYERS],
+        }
+    }
+
+    /// Returns the [GameState] after the next player moves their pawn forward
+    /// by `roll_total` spaces and scores the space it lands on.
+    pub fn after_turn(&self, rules: &GameRules, roll_total: u64) -> GameState {
+        let mut positions = self.positions;
+        let mut scores = self.scores;
+
+        let position = &mut positions[self.next_player];
+        *position = (*position - 1 + roll_total) % rules.board_size + 1;
+        scores[self.next_player] += *position;
+
+        GameState {
+            next_player: (self.next_player + 1) % PLAYERS,
+            positions,
+            scores,
+        }
+    }
+
+    /// Returns the index of the player who has won the game, or
+    /// [Option::None] if nobody has won yet.
+    pub fn winner(&self, rules: &GameRules) -> Option<usize> {
+        self.scores
+            .iter()
+            .position(|score| *score >= rules.winning_score)
+    }
+}
+
+/// Interprets the given `Player N starting position: P` line as the space
+/// that the player starts on.
+fn deserialize_starting_position(serialized_starting_position: &str) -> Result<u64> {
+    let (_, serialized_position) =
+        serialized_starting_position
+            .split_once(':')
+            .ok_or_else(|| {
+                anyhow!(
+                    "\"{}\" is not a valid starting position",
+                    serialized_starting_position
+                )
+            })?;
+
+    let position = parse::parse_number::<u64>(serialized_position, serialized_starting_position)
+        .with_context(|| {
+            format!(
+                "\"{}\" lacks a valid starting position",
+                serialized_starting_position
+            )
+        })?;
+
+    if position == 0 {
+        return Err(anyhow!(
+            "\"{}\" starts before the first space",
+            serialized_starting_position
+        ));
+    }
+
+    Ok(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deterministic_die::play_with_deterministic_die;
+    use crate::dirac_dice::count_wins_across_universes;
+
+    const SAMPLE: &str = "Player 1 starting position: 4\nPlayer 2 starting position: 8";
+
+    #[test]
+    fn deserializes_starting_positions() {
+        assert_eq!(
+            GameState::deserialize(SAMPLE).unwrap(),
+            GameState::starting_at([4, 8])
+        );
+        assert!(GameState::deserialize("Player 1 starting position: 4").is_err());
+        assert!(GameState::deserialize(
+            "Player 1 starting position: 0\nPlayer 2 starting position: 8"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn wraps_around_the_board() {
+        let game_state = GameState::starting_at([7, 8]).after_turn(&GameRules::DETERMINISTIC, 5);
+
+        assert_eq!(game_state.positions, [2, 8]);
+        assert_eq!(game_state.scores, [2, 0]);
+        assert_eq!(game_state.next_player, 1);
+    }
+
+    #[test]
+    fn plays_the_practice_game() {
+        let outcome = play_with_deterministic_die(
+            &GameState::deserialize(SAMPLE).unwrap(),
+            &GameRules::DETERMINISTIC,
+        );
+
+        assert_eq!(
+            outcome.final_state.winner(&GameRules::DETERMINISTIC),
+            Some(0)
+        );
+        assert_eq!(outcome.final_state.scores, [1000, 745]);
+        assert_eq!(outcome.rolls, 993);
+    }
+
+    #[test]
+    fn counts_wins_across_universes() {
+        assert_eq!(
+            count_wins_across_universes(
+                &GameState::deserialize(SAMPLE).unwrap(),
+                &GameRules::DIRAC
+            ),
+            [444356092776315, 341960390180808]
+        );
+    }
+
+    #[test]
+    fn counts_wins_under_alternative_rules() {
+        // With a one-sided die every turn is forced, so there is exactly one
+        // universe, and it plays out the same as the deterministic game would.
+        let rules = GameRules {
+            board_size: 10,
+            die_sides: 1,
+            rolls_per_turn: 1,
+            winning_score: 10,
+        };
+
+        let mut game_state = GameState::starting_at([1, 2]);
+        while game_state.winner(&rules).is_none() {
+            game_state = game_state.after_turn(&rules, 1);
+        }
+
+        let mut expected_wins = [0; PLAYERS];
+        expected_wins[game_state.winner(&rules).unwrap()] = 1;
+
+        assert_eq!(
+            count_wins_across_universes(&GameState::starting_at([1, 2]), &rules),
+            expected_wins
+        );
+    }
+}
@@ -0,0 +1,9 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod deterministic_die;
+pub mod dirac_dice;
+pub mod fixtures;
+pub mod game;
+pub mod solver;
@@ -0,0 +1,67 @@
+use crate::deterministic_die::play_with_deterministic_die;
+use crate::dirac_dice::count_wins_across_universes;
+use crate::game::{GameRules, GameState};
+
+use anyhow::Result;
+use aoc_common::answer::Answer;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Dirac Dice" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DiracDiceSolver;
+
+impl Solver for DiracDiceSolver {
+    type Parsed = GameState;
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse(&self, input: &str) -> Result<GameState> {
+        GameState::deserialize(input)
+    }
+
+    fn part_one(&self, game_state: &GameState) -> Result<Answer> {
+        let outcome = play_with_deterministic_die(game_state, &GameRules::DETERMINISTIC);
+
+        Ok(outcome.losing_score_times_rolls().into())
+    }
+
+    fn part_two(&self, game_state: &GameState) -> Result<Answer> {
+        let wins = count_wins_across_universes(game_state, &GameRules::DIRAC);
+
+        Ok(wins.iter().max().copied().unwrap_or(0).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = DiracDiceSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(DiracDiceSolver.part_one(&parsed).unwrap(), "739785");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = DiracDiceSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            DiracDiceSolver.part_two(&parsed).unwrap(),
+            "444356092776315"
+        );
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&DiracDiceSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_23::solver::AmphipodSolver;
+
+fn bench_amphipod_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &AmphipodSolver::default(),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_amphipod_solver);
+criterion_main!(benches);
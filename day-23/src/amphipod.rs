@@ -0,0 +1,57 @@
+/// Number of distinct kinds of [Amphipod], each of which has its own room.
+pub const KINDS: usize = 4;
+
+/// Enumerates every kind of amphipod.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Amphipod {
+    /// Amber amphipod, which belongs in the first room.
+    Amber,
+    /// Bronze amphipod, which belongs in the second room.
+    Bronze,
+    /// Copper amphipod, which belongs in the third room.
+    Copper,
+    /// Desert amphipod, which belongs in the fourth room.
+    Desert,
+}
+
+impl Amphipod {
+    /// Every kind of [Amphipod], in the order of the rooms they belong in.
+    pub const ALL: [Amphipod; KINDS] = [
+        Amphipod::Amber,
+        Amphipod::Bronze,
+        Amphipod::Copper,
+        Amphipod::Desert,
+    ];
+
+    /// Interprets the given [char] as an [Amphipod], returning [Option::None]
+    /// if interpretation fails.
+    pub fn from(amphipod_char: char) -> Option<Amphipod> {
+        match amphipod_char {
+            'A' => Some(Amphipod::Amber),
+            'B' => Some(Amphipod::Bronze),
+            'C' => Some(Amphipod::Copper),
+            'D' => Some(Amphipod::Desert),
+            _ => None,
+        }
+    }
+
+    /// Returns the [char] used to render this [Amphipod].
+    pub fn letter(&self) -> char {
+        match self {
+            Amphipod::Amber => 'A',
+            Amphipod::Bronze => 'B',
+            Amphipod::Copper => 'C',
+            Amphipod::Desert => 'D',
+        }
+    }
+
+    /// Returns the index of the room that this [Amphipod] belongs in.
+    pub fn room(&self) -> usize {
+        *self as usize
+    }
+
+    /// Returns how much energy this [Amphipod] spends moving a single step.
+    pub fn step_energy(&self) -> u64 {
+        10u64.pow(*self as u32)
+    }
+}
@@ -0,0 +1,393 @@
+use crate::amphipod::{Amphipod, KINDS};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::pathfinding::{self, Path};
+use std::fmt::{Display, Formatter};
+use tracing::instrument;
+
+/// Number of spaces in the hallway of a [Burrow].
+pub const HALLWAY_LEN: usize = 11;
+
+/// Largest number of amphipods that fit in a single room of a [Burrow].
+pub const MAX_ROOM_DEPTH: usize = 4;
+
+/// Rows hidden by the fold in the diagram of a folded [Burrow], from top to
+/// bottom.
+const FOLDED_ROWS: [[Amphipod; KINDS]; 2] = [
+    [
+        Amphipod::Desert,
+        Amphipod::Copper,
+        Amphipod::Bronze,
+        Amphipod::Amber,
+    ],
+    [
+        Amphipod::Desert,
+        Amphipod::Bronze,
+        Amphipod::Amber,
+        Amphipod::Copper,
+    ],
+];
+
+/// Character used to render an empty space.
+const EMPTY: char = '.';
+
+/// Character used to render a wall.
+const WALL: char = '#';
+
+/// Enumerates the supported ways of searching for the cheapest way to
+/// organize a [Burrow].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SearchStrategy {
+    /// A* search, guided by [Burrow::energy_lower_bound].
+    AStar,
+    /// Dijkstra's algorithm, which expands burrows purely by energy so far.
+    Dijkstra,
+}
+
+/// Arrangement of amphipods within a burrow made up of a hallway and one side
+/// room per kind of amphipod.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Burrow {
+    /// Amphipod in each space of the hallway, from left to right.
+    hallway: [Option<Amphipod>; HALLWAY_LEN],
+    /// Amphipod in each space of each room, from the space next to the
+    /// hallway downward; only the first [Burrow::room_depth] are used.
+    rooms: [[Option<Amphipod>; MAX_ROOM_DEPTH]; KINDS],
+    /// Number of amphipods that fit in each room.
+    room_depth: usize,
+}
+
+impl Burrow {
+    /// Interprets the given diagram as a [Burrow].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_burrow: &str) -> Result<Burrow> {
+        let lines = serialized_burrow
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<&str>>();
+
+        let serialized_hallway = lines
+            .get(1)
+            .ok_or_else(|| anyhow!("Burrow diagram lacks a hallway"))?;
+        let mut hallway = [None; HALLWAY_LEN];
+        for (position, space) in hallway.iter_mut().enumerate() {
+            *space = deserialize_space(serialized_hallway, position + 1)
+                .with_context(|| format!("\"{}\" is not a valid hallway", serialized_hallway))?;
+        }
+
+        // Every line between the hallway and the closing wall is a row of
+        // rooms.
+        let serialized_room_rows = lines.get(2..lines.len().saturating_sub(1)).unwrap_or(&[]);
+        let room_depth = serialized_room_rows.len();
+        if room_depth == 0 || room_depth > MAX_ROOM_DEPTH {
+            return Err(anyhow!(
+                "Burrow has rooms {} spaces deep (not 1 through {})",
+                room_depth,
+                MAX_ROOM_DEPTH
+            ));
+        }
+
+        let mut rooms = [[None; MAX_ROOM_DEPTH]; KINDS];
+        for (depth, serialized_room_row) in serialized_room_rows.iter().enumerate() {
+            for (room, spaces) in rooms.iter_mut().enumerate() {
+                spaces[depth] =
+                    deserialize_space(serialized_room_row, hallway_position_of(room) + 1)
+                        .with_context(|| {
+                            format!("\"{}\" is not a valid row of rooms", serialized_room_row)
+                        })?;
+            }
+        }
+
+        Ok(Burrow {
+            hallway,
+            rooms,
+            room_depth,
+        })
+    }
+
+    /// Returns a lower bound on the energy it takes to organize this
+    /// [Burrow], which makes it a suitable A* heuristic.
+    ///
+    /// Every amphipod that is not yet settled has to walk to the space
+    /// outside of its room (stepping out of the way first if it is already in
+    /// its room) and then step in. Amphipods of the same kind cannot all stop
+    /// in the topmost space, so each one that enters a room has to walk at
+    /// least one space further than the one before it.
+    pub fn energy_lower_bound(&self) -> u64 {
+        let mut energy = 0;
+        let mut entering_amphipods = [0u64; KINDS];
+
+        for (position, space) in self.hallway.iter().enumerate() {
+            if let Some(amphipod) = space {
+                let steps = position.abs_diff(hallway_position_of(amphipod.room())) + 1;
+
+                energy += steps as u64 * amphipod.step_energy();
+                entering_amphipods[amphipod.room()] += 1;
+            }
+        }
+
+        for room in 0..KINDS {
+            for depth in 0..self.room_depth {
+                let amphipod = match self.rooms[room][depth] {
+                    Some(amphipod) if !self.is_settled(room, depth) => amphipod,
+                    _ => continue,
+                };
+
+                let hallway_steps = if amphipod.room() == room {
+                    2
+                } else {
+                    hallway_position_of(room).abs_diff(hallway_position_of(amphipod.room()))
+                };
+                let steps = (depth + 1) + hallway_steps + 1;
+
+                energy += steps as u64 * amphipod.step_energy();
+                entering_amphipods[amphipod.room()] += 1;
+            }
+        }
+
+        for (amphipod, entering_amphipods) in Amphipod::ALL.iter().zip(entering_amphipods) {
+            let extra_steps = entering_amphipods * entering_amphipods.saturating_sub(1) / 2;
+
+            energy += extra_steps * amphipod.step_energy();
+        }
+
+        energy
+    }
+
+    /// Returns `true` if every amphipod in this [Burrow] is in its own room.
+    pub fn is_organized(&self) -> bool {
+        self.rooms.iter().enumerate().all(|(room, spaces)| {
+            spaces[..self.room_depth]
+                .iter()
+                .all(|space| space.map(|amphipod| amphipod.room()) == Some(room))
+        })
+    }
+
+    /// Returns the least energy it takes to organize this [Burrow], or
+    /// [Option::None] if it cannot be organized.
+    pub fn minimum_energy(&self, search_strategy: SearchStrategy) -> Option<u64> {
+        self.cheapest_organization(search_strategy)
+            .map(|path| path.cost)
+    }
+
+    /// Returns the cheapest sequence of moves that organizes this [Burrow],
+    /// or [Option::None] if it cannot be organized.
+    pub fn cheapest_organization(&self, search_strategy: SearchStrategy) -> Option<Path<Burrow>> {
+        match search_strategy {
+            SearchStrategy::AStar => {
+                self.cheapest_organization_with(|burrow| burrow.energy_lower_bound())
+            }
+            SearchStrategy::Dijkstra => self.cheapest_organization_with(|_| 0),
+        }
+    }
+
+    /// Returns the cheapest sequence of moves that organizes this [Burrow]
+    /// using A* search guided by `heuristic`, or [Option::None] if it cannot
+    /// be organized.
+    ///
+    /// The returned [Path] is only guaranteed to be the cheapest if
+    /// `heuristic` never overestimates the energy left to spend.
+    pub fn cheapest_organization_with<F>(&self, heuristic: F) -> Option<Path<Burrow>>
+    where
+        F: FnMut(&Burrow) -> u64,
+    {
+        pathfinding::a_star(*self, Burrow::moves, heuristic, Burrow::is_organized)
+    }
+
+    /// Returns every [Burrow] that a single amphipod move leads to, along
+    /// with the energy spent on that move.
+    ///
+    /// Amphipods only ever move out of a room into the hallway, or out of the
+    /// hallway into their own room once it holds no other kind of amphipod.
+    pub fn moves(&self) -> Vec<(Burrow, u64)> {
+        let mut moves = vec![];
+
+        for (position, space) in self.hallway.iter().enumerate() {
+            let amphipod = match space {
+                Some(amphipod) => *amphipod,
+                None => continue,
+            };
+
+            let room = amphipod.room();
+            let depth = match self.open_depth_of(room) {
+                Some(depth) => depth,
+                None => continue,
+            };
+            if !self.is_hallway_clear(position, hallway_position_of(room)) {
+                continue;
+            }
+
+            let steps = position.abs_diff(hallway_position_of(room)) + depth + 1;
+
+            let mut burrow = *self;
+            burrow.hallway[position] = None;
+            burrow.rooms[room][depth] = Some(amphipod);
+
+            moves.push((burrow, steps as u64 * amphipod.step_energy()));
+        }
+
+        for room in 0..KINDS {
+            let (depth, amphipod) = match self.topmost_unsettled_amphipod_of(room) {
+                Some(topmost_unsettled_amphipod) => topmost_unsettled_amphipod,
+                None => continue,
+            };
+
+            for position in 0..HALLWAY_LEN {
+                if is_outside_of_a_room(position)
+                    || !self.is_hallway_clear(hallway_position_of(room), position)
+                {
+                    continue;
+                }
+
+                let steps = depth + 1 + position.abs_diff(hallway_position_of(room));
+
+                let mut burrow = *self;
+                burrow.rooms[room][depth] = None;
+                burrow.hallway[position] = Some(amphipod);
+
+                moves.push((burrow, steps as u64 * amphipod.step_energy()));
+            }
+        }
+
+        moves
+    }
+
+    /// Returns a copy of this [Burrow] with the two rows hidden by the fold in
+    /// its diagram inserted between the top and bottom rows of its rooms, or
+    /// [Err] if its rooms are not exactly two spaces deep.
+    pub fn unfolded(&self) -> Result<Burrow> {
+        if self.room_depth != 2 {
+            return Err(anyhow!(
+                "Only burrows with rooms 2 spaces deep can be unfolded (not {})",
+                self.room_depth
+            ));
+        }
+
+        let mut rooms = [[None; MAX_ROOM_DEPTH]; KINDS];
+        for (room, spaces) in rooms.iter_mut().enumerate() {
+            *spaces = [
+                self.rooms[room][0],
+                Some(FOLDED_ROWS[0][room]),
+                Some(FOLDED_ROWS[1][room]),
+                self.rooms[room][1],
+            ];
+        }
+
+        Ok(Burrow {
+            hallway: self.hallway,
+            rooms,
+            room_depth: MAX_ROOM_DEPTH,
+        })
+    }
+
+    /// Returns `true` if no amphipod stands in the hallway strictly between
+    /// `from` and `to`, or on `to` itself.
+    fn is_hallway_clear(&self, from: usize, to: usize) -> bool {
+        let between = if from < to {
+            from + 1..to + 1
+        } else {
+            to..from
+        };
+
+        self.hallway[between].iter().all(Option::is_none)
+    }
+
+    /// Returns `true` if the amphipod at the specified `depth` of `room`
+    /// belongs there, and so does every amphipod below it.
+    fn is_settled(&self, room: usize, depth: usize) -> bool {
+        self.rooms[room][depth..self.room_depth]
+            .iter()
+            .all(|space| space.map(|amphipod| amphipod.room()) == Some(room))
+    }
+
+    /// Returns the deepest empty space of `room` if it holds no other kind of
+    /// amphipod, or [Option::None] otherwise.
+    fn open_depth_of(&self, room: usize) -> Option<usize> {
+        let spaces = &self.rooms[room][..self.room_depth];
+        let is_open = spaces
+            .iter()
+            .flatten()
+            .all(|amphipod| amphipod.room() == room);
+        if !is_open {
+            return None;
+        }
+
+        spaces.iter().rposition(Option::is_none)
+    }
+
+    /// Returns the depth and kind of the topmost amphipod in `room` if it or
+    /// any amphipod below it still has to leave, or [Option::None] otherwise.
+    fn topmost_unsettled_amphipod_of(&self, room: usize) -> Option<(usize, Amphipod)> {
+        let depth = self.rooms[room][..self.room_depth]
+            .iter()
+            .position(Option::is_some)?;
+
+        if self.is_settled(room, depth) {
+            return None;
+        }
+
+        self.rooms[room][depth].map(|amphipod| (depth, amphipod))
+    }
+}
+
+impl Display for Burrow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let wall = WALL.to_string().repeat(HALLWAY_LEN + 2);
+
+        writeln!(f, "{}", wall)?;
+        writeln!(
+            f,
+            "{}{}{}",
+            WALL,
+            self.hallway.iter().map(serialize_space).collect::<String>(),
+            WALL
+        )?;
+
+        for depth in 0..self.room_depth {
+            let rooms = self
+                .rooms
+                .iter()
+                .map(|spaces| serialize_space(&spaces[depth]).to_string())
+                .collect::<Vec<String>>()
+                .join(&WALL.to_string());
+
+            // Only the top row of rooms is flanked by walls all the way out to
+            // the edges of the hallway.
+            if depth == 0 {
+                writeln!(f, "{0}{0}{0}{1}{0}{0}{0}", WALL, rooms)?;
+            } else {
+                writeln!(f, "  {0}{1}{0}", WALL, rooms)?;
+            }
+        }
+
+        writeln!(f, "  {}", WALL.to_string().repeat(HALLWAY_LEN - 2))
+    }
+}
+
+/// Interprets the character at `column` of a line of a burrow diagram as an
+/// empty space or an amphipod.
+fn deserialize_space(serialized_line: &str, column: usize) -> Result<Option<Amphipod>> {
+    match serialized_line.chars().nth(column) {
+        Some(EMPTY) => Ok(None),
+        Some(space_char) => Amphipod::from(space_char)
+            .map(Some)
+            .ok_or_else(|| anyhow!("'{}' is neither empty nor an amphipod", space_char)),
+        None => Err(anyhow!("Column {} is missing", column)),
+    }
+}
+
+/// Returns the position of the hallway space right outside of `room`.
+fn hallway_position_of(room: usize) -> usize {
+    2 + 2 * room
+}
+
+/// Returns `true` if the hallway space at `position` is right outside of a
+/// room, and so amphipods may not stop there.
+fn is_outside_of_a_room(position: usize) -> bool {
+    (0..KINDS).any(|room| hallway_position_of(room) == position)
+}
+
+/// Returns the [char] used to render the given space.
+fn serialize_space(space: &Option<Amphipod>) -> char {
+    space.map(|amphipod| amphipod.letter()).unwrap_or(EMPTY)
+}
@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod amphipod;
+pub mod burrow;
+pub mod fixtures;
+pub mod solver;
@@ -0,0 +1,86 @@
+use crate::burrow::{Burrow, SearchStrategy};
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Amphipod" puzzle.
+#[derive(Clone, Copy, Debug)]
+pub struct AmphipodSolver {
+    /// How to search for the cheapest way to organize the burrow.
+    pub search_strategy: SearchStrategy,
+}
+
+impl AmphipodSolver {
+    /// Returns the least energy it takes to organize `burrow`.
+    fn minimum_energy(&self, burrow: &Burrow) -> Result<Answer> {
+        burrow
+            .minimum_energy(self.search_strategy)
+            .map(Answer::from)
+            .context(NoSolution::new("Burrow cannot be organized"))
+    }
+}
+
+impl Default for AmphipodSolver {
+    fn default() -> Self {
+        AmphipodSolver {
+            search_strategy: SearchStrategy::AStar,
+        }
+    }
+}
+
+impl Solver for AmphipodSolver {
+    type Parsed = Burrow;
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn parse(&self, input: &str) -> Result<Burrow> {
+        Burrow::deserialize(input)
+    }
+
+    fn part_one(&self, burrow: &Burrow) -> Result<Answer> {
+        self.minimum_energy(burrow)
+    }
+
+    fn part_two(&self, burrow: &Burrow) -> Result<Answer> {
+        self.minimum_energy(&burrow.unfolded()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = AmphipodSolver::default().parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            AmphipodSolver::default().part_one(&parsed).unwrap(),
+            "12521"
+        );
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = AmphipodSolver::default().parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            AmphipodSolver::default().part_two(&parsed).unwrap(),
+            "44169"
+        );
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&AmphipodSolver::default(), SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_24::solver::MonadSolver;
+
+fn bench_monad_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &MonadSolver::default(),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_monad_solver);
+criterion_main!(benches);
@@ -0,0 +1,214 @@
+use crate::instruction::{Instruction, Operand, Register, REGISTERS};
+
+use anyhow::{anyhow, Result};
+
+/// Arithmetic logic unit that runs programs made up of [Instruction]
+/// instances on four integer registers, all of which start at `0`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Alu {
+    /// Value stored in each [Register], indexed by [Register::index].
+    registers: [i64; REGISTERS],
+}
+
+impl Alu {
+    /// Creates an [Alu] whose registers start out holding `registers`,
+    /// indexed by [Register::index].
+    pub fn with_registers(registers: [i64; REGISTERS]) -> Alu {
+        Alu { registers }
+    }
+
+    /// Executes a single `instruction`, reading from `inputs` if it needs an
+    /// input.
+    ///
+    /// Returns [Err] without changing any register if `inputs` has run dry,
+    /// or if the instruction would divide by zero or take the remainder of a
+    /// negative number or by a non-positive number.
+    pub fn execute<I>(&mut self, instruction: &Instruction, inputs: &mut I) -> Result<()>
+    where
+        I: Iterator<Item = i64>,
+    {
+        match *instruction {
+            Instruction::Add(register, operand) => {
+                self.registers[register.index()] += self.value_of(operand);
+            }
+            Instruction::Div(register, operand) => {
+                let divisor = self.value_of(operand);
+                if divisor == 0 {
+                    return Err(anyhow!("\"{}\" divides by zero", instruction));
+                }
+
+                self.registers[register.index()] /= divisor;
+            }
+            Instruction::Eql(register, operand) => {
+                let is_equal = self.registers[register.index()] == self.value_of(operand);
+
+                self.registers[register.index()] = is_equal as i64;
+            }
+            Instruction::Inp(register) => {
+                let input = inputs
+                    .next()
+                    .ok_or_else(|| anyhow!("\"{}\" has no input left to read", instruction))?;
+
+                self.registers[register.index()] = input;
+            }
+            Instruction::Mod(register, operand) => {
+                let dividend = self.registers[register.index()];
+                let divisor = self.value_of(operand);
+                if dividend < 0 || divisor <= 0 {
+                    return Err(anyhow!(
+                        "\"{}\" takes {} mod {}",
+                        instruction,
+                        dividend,
+                        divisor
+                    ));
+                }
+
+                self.registers[register.index()] = dividend % divisor;
+            }
+            Instruction::Mul(register, operand) => {
+                self.registers[register.index()] *= self.value_of(operand);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the value stored in `register`.
+    pub fn register(&self, register: Register) -> i64 {
+        self.registers[register.index()]
+    }
+
+    /// Returns the value stored in every register, indexed by
+    /// [Register::index].
+    pub fn registers(&self) -> [i64; REGISTERS] {
+        self.registers
+    }
+
+    /// Executes every instruction of `program` in order, feeding `inp`
+    /// instructions from `inputs`, stopping at the first instruction that
+    /// fails.
+    pub fn run<I>(&mut self, program: &[Instruction], inputs: I) -> Result<()>
+    where
+        I: IntoIterator<Item = i64>,
+    {
+        let mut inputs = inputs.into_iter();
+
+        for (index, instruction) in program.iter().enumerate() {
+            self.execute(instruction, &mut inputs)
+                .map_err(|error| error.context(format!("Instruction {} failed", index)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the value that `operand` currently stands for.
+    fn value_of(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Literal(value) => value,
+            Operand::Register(register) => self.registers[register.index()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(serialized_program: &str, inputs: &[i64]) -> Result<Alu> {
+        let program = Instruction::deserialize_all(serialized_program)?;
+
+        let mut alu = Alu::default();
+        alu.run(&program, inputs.iter().copied())?;
+
+        Ok(alu)
+    }
+
+    #[test]
+    fn negates_its_input() {
+        let alu = run("inp x\nmul x -1", &[7]).unwrap();
+
+        assert_eq!(alu.register(Register::X), -7);
+    }
+
+    #[test]
+    fn checks_whether_the_second_input_is_thrice_the_first() {
+        let program = "inp z\ninp x\nmul z 3\neql z x";
+
+        assert_eq!(run(program, &[3, 9]).unwrap().register(Register::Z), 1);
+        assert_eq!(run(program, &[3, 8]).unwrap().register(Register::Z), 0);
+    }
+
+    #[test]
+    fn splits_its_input_into_bits() {
+        let program = "inp w
+            add z w
+            mod z 2
+            div w 2
+            add y w
+            mod y 2
+            div w 2
+            add x w
+            mod x 2
+            div w 2
+            mod w 2";
+
+        assert_eq!(run(program, &[11]).unwrap().registers(), [1, 0, 1, 1]);
+        assert_eq!(run(program, &[6]).unwrap().registers(), [0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn truncates_division_toward_zero() {
+        assert_eq!(
+            run("inp x\ndiv x 2", &[-7]).unwrap().register(Register::X),
+            -3
+        );
+        assert_eq!(
+            run("inp x\ndiv x -2", &[7]).unwrap().register(Register::X),
+            -3
+        );
+    }
+
+    #[test]
+    fn starts_from_the_given_registers() {
+        let program = Instruction::deserialize_all("add w x\nmul w y\neql z w").unwrap();
+
+        let mut alu = Alu::with_registers([1, 2, 3, 9]);
+        alu.run(&program, []).unwrap();
+
+        assert_eq!(alu.registers(), [9, 2, 3, 1]);
+    }
+
+    #[test]
+    fn refuses_invalid_arithmetic() {
+        assert!(run("div x 0", &[]).is_err());
+        assert!(run("inp x\nmod x 5", &[-1]).is_err());
+        assert!(run("inp x\nmod x 0", &[1]).is_err());
+        assert!(run("inp x\nmod x -5", &[1]).is_err());
+    }
+
+    #[test]
+    fn refuses_to_read_past_its_inputs() {
+        assert!(run("inp x\ninp y", &[1]).is_err());
+    }
+
+    #[test]
+    fn refuses_malformed_programs() {
+        assert!(Instruction::deserialize_all("inp").is_err());
+        assert!(Instruction::deserialize_all("add x").is_err());
+        assert!(Instruction::deserialize_all("sub x 1").is_err());
+        assert!(Instruction::deserialize_all("add q 1").is_err());
+        assert!(Instruction::deserialize_all("add x one").is_err());
+    }
+
+    #[test]
+    fn round_trips_instructions_through_display() {
+        for serialized_instruction in ["inp w", "add x -12", "mul y z", "div z 26", "eql x w"] {
+            assert_eq!(
+                Instruction::deserialize(serialized_instruction)
+                    .unwrap()
+                    .to_string(),
+                serialized_instruction
+            );
+        }
+    }
+}
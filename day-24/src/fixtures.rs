@@ -0,0 +1,3 @@
+/// MONAD program used as the example input, since the description of the
+/// puzzle only gives fragments of ALU programs.
+pub const SAMPLE: &str = include_str!("../files/sample.txt");
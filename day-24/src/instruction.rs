@@ -0,0 +1,167 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::parse;
+use std::fmt::{Display, Formatter};
+
+/// Number of registers in an ALU.
+pub const REGISTERS: usize = 4;
+
+/// Enumerates the registers of an ALU.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Register {
+    /// Register named `w`.
+    W,
+    /// Register named `x`.
+    X,
+    /// Register named `y`.
+    Y,
+    /// Register named `z`.
+    Z,
+}
+
+impl Register {
+    /// Interprets the given [str] as a [Register].
+    pub fn deserialize(serialized_register: &str) -> Result<Register> {
+        match serialized_register {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => Err(anyhow!(
+                "\"{}\" is not a valid register",
+                serialized_register
+            )),
+        }
+    }
+
+    /// Returns the index of this [Register] within the registers of an ALU.
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Register::W => "w",
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Second argument of a binary [Instruction].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Operand {
+    /// Literal number.
+    Literal(i64),
+    /// Value currently stored in a [Register].
+    Register(Register),
+}
+
+impl Operand {
+    /// Interprets the given [str] as an [Operand].
+    pub fn deserialize(serialized_operand: &str) -> Result<Operand> {
+        if let Ok(register) = Register::deserialize(serialized_operand) {
+            return Ok(Operand::Register(register));
+        }
+
+        parse::signed_int::<i64>(serialized_operand)
+            .map(Operand::Literal)
+            .with_context(|| {
+                format!(
+                    "\"{}\" is neither a register nor a number",
+                    serialized_operand
+                )
+            })
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Literal(value) => write!(f, "{}", value),
+            Operand::Register(register) => write!(f, "{}", register),
+        }
+    }
+}
+
+/// Single instruction of the ALU instruction set.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Instruction {
+    /// Adds the operand to the register.
+    Add(Register, Operand),
+    /// Divides the register by the operand, truncating toward zero.
+    Div(Register, Operand),
+    /// Sets the register to `1` if it equals the operand, or `0` otherwise.
+    Eql(Register, Operand),
+    /// Reads the next input into the register.
+    Inp(Register),
+    /// Sets the register to the remainder of dividing it by the operand.
+    Mod(Register, Operand),
+    /// Multiplies the register by the operand.
+    Mul(Register, Operand),
+}
+
+impl Instruction {
+    /// Interprets the given line of an ALU program as an [Instruction].
+    pub fn deserialize(serialized_instruction: &str) -> Result<Instruction> {
+        let parts = serialized_instruction
+            .split_whitespace()
+            .collect::<Vec<&str>>();
+
+        let instruction = match parts.as_slice() {
+            ["inp", register] => Instruction::Inp(Register::deserialize(register)?),
+            [operation, register, operand] => {
+                let register = Register::deserialize(register)?;
+                let operand = Operand::deserialize(operand)?;
+
+                match *operation {
+                    "add" => Instruction::Add(register, operand),
+                    "div" => Instruction::Div(register, operand),
+                    "eql" => Instruction::Eql(register, operand),
+                    "mod" => Instruction::Mod(register, operand),
+                    "mul" => Instruction::Mul(register, operand),
+                    _ => return Err(anyhow!("\"{}\" is not a valid operation", operation)),
+                }
+            }
+            _ => {
+                return Err(anyhow!(
+                    "\"{}\" is not a valid instruction",
+                    serialized_instruction
+                ))
+            }
+        };
+
+        Ok(instruction)
+    }
+
+    /// Interprets the given newline-delimited ALU program as a list of
+    /// [Instruction] instances.
+    pub fn deserialize_all(serialized_program: &str) -> Result<Vec<Instruction>> {
+        serialized_program
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                Instruction::deserialize(line)
+                    .with_context(|| format!("\"{}\" is not a valid instruction", line))
+            })
+            .collect()
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Add(register, operand) => write!(f, "add {} {}", register, operand),
+            Instruction::Div(register, operand) => write!(f, "div {} {}", register, operand),
+            Instruction::Eql(register, operand) => write!(f, "eql {} {}", register, operand),
+            Instruction::Inp(register) => write!(f, "inp {}", register),
+            Instruction::Mod(register, operand) => write!(f, "mod {} {}", register, operand),
+            Instruction::Mul(register, operand) => write!(f, "mul {} {}", register, operand),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod alu;
+pub mod fixtures;
+pub mod instruction;
+pub mod monad;
+pub mod solver;
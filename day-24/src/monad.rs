@@ -0,0 +1,470 @@
+use crate::alu::Alu;
+use crate::instruction::{Instruction, Operand, Register, REGISTERS};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::cancellation;
+use std::collections::HashSet;
+use tracing::instrument;
+
+/// Number of digits in a model number.
+pub const MODEL_NUMBER_DIGITS: usize = 14;
+
+/// Every instruction of a block of MONAD, where `{a}`, `{b}`, and `{c}` stand
+/// for the literals that differ from block to block.
+const BLOCK_TEMPLATE: [&str; 18] = [
+    "inp w",
+    "mul x 0",
+    "add x z",
+    "mod x 26",
+    "div z {a}",
+    "add x {b}",
+    "eql x w",
+    "eql x 0",
+    "mul y 0",
+    "add y 25",
+    "mul y x",
+    "add y 1",
+    "mul z y",
+    "mul y 0",
+    "add y w",
+    "add y {c}",
+    "mul y x",
+    "add z y",
+];
+
+/// Base of the stack of numbers that MONAD packs into register `z`.
+const STACK_BASE: i64 = 26;
+
+/// Enumerates the supported ways of finding valid model numbers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SearchStrategy {
+    /// Reads the relationships between pairs of digits off of the structure
+    /// of MONAD, falling back to [SearchStrategy::Exhaustive] if MONAD does
+    /// not have the expected structure.
+    Constraints,
+    /// Tries every digit of every position, skipping ALU states already
+    /// known to lead nowhere.
+    ///
+    /// This works for any program, but takes exponential time on programs
+    /// like MONAD whose state keeps growing with every digit.
+    Exhaustive,
+}
+
+/// Requirement that a later digit of a valid model number equals an earlier
+/// digit plus a fixed offset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DigitConstraint {
+    /// Index of the earlier digit.
+    pub earlier: usize,
+    /// Index of the later digit.
+    pub later: usize,
+    /// Amount that the later digit exceeds the earlier digit by.
+    pub offset: i64,
+}
+
+/// MOdel Number Automatic Detector program, which accepts a model number by
+/// leaving `0` in register `z` after reading its digits one at a time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Monad {
+    /// Instructions of this [Monad], split before every `inp` instruction.
+    blocks: Vec<Vec<Instruction>>,
+}
+
+impl Monad {
+    /// Interprets the given newline-delimited ALU program as a [Monad],
+    /// returning [Err] unless it reads exactly [MODEL_NUMBER_DIGITS] inputs.
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_monad: &str) -> Result<Monad> {
+        let program = Instruction::deserialize_all(serialized_monad)?;
+
+        let mut blocks: Vec<Vec<Instruction>> = vec![];
+        for instruction in program {
+            match (instruction, blocks.last_mut()) {
+                (Instruction::Inp(_), _) => blocks.push(vec![instruction]),
+                (_, Some(block)) => block.push(instruction),
+                (_, None) => {
+                    return Err(anyhow!("\"{}\" comes before the first input", instruction))
+                }
+            }
+        }
+
+        if blocks.len() != MODEL_NUMBER_DIGITS {
+            return Err(anyhow!(
+                "MONAD reads {} inputs (not {})",
+                blocks.len(),
+                MODEL_NUMBER_DIGITS
+            ));
+        }
+
+        Ok(Monad { blocks })
+    }
+
+    /// Returns `true` if this [Monad] accepts the model number made up of
+    /// `digits`, or [Err] if the ALU fails while checking it.
+    pub fn accepts(&self, digits: &[i64]) -> Result<bool> {
+        let mut alu = Alu::default();
+        alu.run(&self.blocks.concat(), digits.iter().copied())?;
+
+        Ok(alu.register(Register::Z) == 0)
+    }
+
+    /// Returns the constraints that every pair of related digits of a valid
+    /// model number must satisfy, or [Err] if this [Monad] does not have the
+    /// expected structure.
+    ///
+    /// Each block of MONAD either pushes its digit plus some offset onto a
+    /// stack of base-26 numbers packed into register `z`, or pops the top of
+    /// that stack and pushes again unless its digit matches the popped
+    /// number plus another offset. `z` can only end up `0` if every popping
+    /// block matches, which ties each of their digits to the digit of the
+    /// block that pushed the number they pop.
+    pub fn constraints(&self) -> Result<Vec<DigitConstraint>> {
+        let mut constraints = vec![];
+        let mut stack = vec![];
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            let (a, b, c) = block_literals_of(block)
+                .with_context(|| format!("Block {} does not match the MONAD template", index))?;
+
+            match a {
+                1 if b > 9 && (0..STACK_BASE - 9).contains(&c) => stack.push((index, c)),
+                STACK_BASE => {
+                    let (earlier, pushed_offset) = stack
+                        .pop()
+                        .ok_or_else(|| anyhow!("Block {} pops an empty stack", index))?;
+
+                    constraints.push(DigitConstraint {
+                        earlier,
+                        later: index,
+                        offset: pushed_offset + b,
+                    });
+                }
+                _ => {
+                    return Err(anyhow!(
+                        "Block {} neither always pushes nor always pops",
+                        index
+                    ))
+                }
+            }
+        }
+
+        if !stack.is_empty() {
+            return Err(anyhow!("{} pushed numbers are never popped", stack.len()));
+        }
+
+        Ok(constraints)
+    }
+
+    /// Returns the largest model number that this [Monad] accepts, or
+    /// [Option::None] if it accepts none.
+    pub fn largest_model_number(&self, search_strategy: SearchStrategy) -> Result<Option<u64>> {
+        self.model_number(search_strategy, &[9, 8, 7, 6, 5, 4, 3, 2, 1])
+    }
+
+    /// Returns the smallest model number that this [Monad] accepts, or
+    /// [Option::None] if it accepts none.
+    pub fn smallest_model_number(&self, search_strategy: SearchStrategy) -> Result<Option<u64>> {
+        self.model_number(search_strategy, &[1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+
+    /// Returns the first model number that this [Monad] accepts when digits
+    /// are tried in `digit_order` from left to right, or [Option::None] if
+    /// it accepts none.
+    fn model_number(
+        &self,
+        search_strategy: SearchStrategy,
+        digit_order: &[i64],
+    ) -> Result<Option<u64>> {
+        let digits = match search_strategy {
+            SearchStrategy::Constraints => match self.constraints() {
+                Ok(constraints) => {
+                    self.model_number_digits_by_constraints(&constraints, digit_order)
+                }
+                Err(_) => return self.model_number(SearchStrategy::Exhaustive, digit_order),
+            },
+            SearchStrategy::Exhaustive => {
+                self.model_number_digits_by_search(digit_order, &mut HashSet::new())?
+            }
+        };
+
+        let digits = match digits {
+            Some(digits) => digits,
+            None => return Ok(None),
+        };
+
+        if !self.accepts(&digits)? {
+            return Err(anyhow!(
+                "MONAD rejects {}, which it should have accepted",
+                to_model_number(&digits)
+            ));
+        }
+
+        Ok(Some(to_model_number(&digits)))
+    }
+
+    /// Returns the digits of the first model number satisfying every one of
+    /// `constraints` when digits are tried in `digit_order`, or
+    /// [Option::None] if no digits can satisfy them.
+    fn model_number_digits_by_constraints(
+        &self,
+        constraints: &[DigitConstraint],
+        digit_order: &[i64],
+    ) -> Option<Vec<i64>> {
+        let mut digits = vec![0; MODEL_NUMBER_DIGITS];
+
+        for constraint in constraints {
+            let earlier_digit = digit_order
+                .iter()
+                .copied()
+                .find(|digit| (1..=9).contains(&(digit + constraint.offset)))?;
+
+            digits[constraint.earlier] = earlier_digit;
+            digits[constraint.later] = earlier_digit + constraint.offset;
+        }
+
+        Some(digits)
+    }
+
+    /// Depth-first search for the digits of the first accepted model number
+    /// when digits are tried in `digit_order`, where `dead_ends` holds every
+    /// block index and register state already known to lead to rejection.
+    fn model_number_digits_by_search(
+        &self,
+        digit_order: &[i64],
+        dead_ends: &mut HashSet<(usize, [i64; REGISTERS])>,
+    ) -> Result<Option<Vec<i64>>> {
+        let mut digits = vec![];
+
+        let is_found = self.search_from(0, Alu::default(), digit_order, &mut digits, dead_ends)?;
+
+        Ok(if is_found { Some(digits) } else { None })
+    }
+
+    /// Helper of [Monad::model_number_digits_by_search] that extends
+    /// `digits` from the block at `index` onward, starting with `alu`.
+    fn search_from(
+        &self,
+        index: usize,
+        alu: Alu,
+        digit_order: &[i64],
+        digits: &mut Vec<i64>,
+        dead_ends: &mut HashSet<(usize, [i64; REGISTERS])>,
+    ) -> Result<bool> {
+        if cancellation::is_cancelled() {
+            return Err(anyhow!("Search for a model number was cancelled"));
+        }
+
+        let block = match self.blocks.get(index) {
+            Some(block) => block,
+            None => return Ok(alu.register(Register::Z) == 0),
+        };
+
+        // Registers that the block overwrites before reading cannot affect
+        // anything, so forgetting them lets far more states share dead ends.
+        let mut registers = alu.registers();
+        for (register, is_live) in registers.iter_mut().zip(live_registers_of(block)) {
+            if !is_live {
+                *register = 0;
+            }
+        }
+        let alu = Alu::with_registers(registers);
+
+        if dead_ends.contains(&(index, alu.registers())) {
+            return Ok(false);
+        }
+
+        for digit in digit_order {
+            let mut next_alu = alu;
+            if next_alu.run(block, [*digit]).is_err() {
+                // MONAD crashing on a digit means that it rejects it.
+                continue;
+            }
+
+            digits.push(*digit);
+            if self.search_from(index + 1, next_alu, digit_order, digits, dead_ends)? {
+                return Ok(true);
+            }
+            digits.pop();
+        }
+
+        dead_ends.insert((index, alu.registers()));
+
+        Ok(false)
+    }
+}
+
+/// Returns the literals that stand in for `{a}`, `{b}`, and `{c}` in
+/// [BLOCK_TEMPLATE] within `block`, or [Err] if `block` does not otherwise
+/// match [BLOCK_TEMPLATE].
+fn block_literals_of(block: &[Instruction]) -> Result<(i64, i64, i64)> {
+    if block.len() != BLOCK_TEMPLATE.len() {
+        return Err(anyhow!(
+            "Block has {} instructions (not {})",
+            block.len(),
+            BLOCK_TEMPLATE.len()
+        ));
+    }
+
+    let mut literals = vec![];
+    for (instruction, template) in block.iter().zip(BLOCK_TEMPLATE) {
+        let serialized_instruction = instruction.to_string();
+
+        // Placeholders always come last, so everything before one has to
+        // match exactly, and everything after it has to be a literal.
+        let is_match = match template.split_once('{') {
+            Some((prefix, _)) => {
+                match serialized_instruction
+                    .strip_prefix(prefix)
+                    .and_then(|serialized_literal| serialized_literal.parse::<i64>().ok())
+                {
+                    Some(literal) => {
+                        literals.push(literal);
+
+                        true
+                    }
+                    None => false,
+                }
+            }
+            None => serialized_instruction == template,
+        };
+
+        if !is_match {
+            return Err(anyhow!(
+                "\"{}\" does not match \"{}\"",
+                serialized_instruction,
+                template
+            ));
+        }
+    }
+
+    Ok((literals[0], literals[1], literals[2]))
+}
+
+/// Returns whether each register, indexed by [Register::index], is read by
+/// `block` before `block` overwrites it.
+fn live_registers_of(block: &[Instruction]) -> [bool; REGISTERS] {
+    let mut is_live = [false; REGISTERS];
+    let mut is_written = [false; REGISTERS];
+
+    for instruction in block {
+        let (register, operand) = match *instruction {
+            // Multiplying by zero overwrites the register without reading it.
+            Instruction::Inp(register) | Instruction::Mul(register, Operand::Literal(0)) => {
+                is_written[register.index()] = true;
+
+                continue;
+            }
+            Instruction::Add(register, operand)
+            | Instruction::Div(register, operand)
+            | Instruction::Eql(register, operand)
+            | Instruction::Mod(register, operand)
+            | Instruction::Mul(register, operand) => (register, operand),
+        };
+
+        let operand_register = match operand {
+            Operand::Literal(_) => None,
+            Operand::Register(operand_register) => Some(operand_register),
+        };
+
+        for read_register in [Some(register), operand_register].into_iter().flatten() {
+            if !is_written[read_register.index()] {
+                is_live[read_register.index()] = true;
+            }
+        }
+
+        is_written[register.index()] = true;
+    }
+
+    // Registers that the block never writes carry over to the next block.
+    for (is_live, is_written) in is_live.iter_mut().zip(is_written) {
+        *is_live |= !is_written;
+    }
+
+    is_live
+}
+
+/// Returns the model number made up of `digits`, most significant first.
+fn to_model_number(digits: &[i64]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |model_number, digit| model_number * 10 + *digit as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn extracts_digit_constraints() {
+        let constraints = Monad::deserialize(SAMPLE).unwrap().constraints().unwrap();
+
+        assert_eq!(constraints.len(), MODEL_NUMBER_DIGITS / 2);
+        assert!(constraints
+            .iter()
+            .all(|constraint| constraint.earlier < constraint.later));
+    }
+
+    #[test]
+    fn finds_model_numbers_by_constraints() {
+        let monad = Monad::deserialize(SAMPLE).unwrap();
+
+        assert_eq!(
+            monad
+                .largest_model_number(SearchStrategy::Constraints)
+                .unwrap(),
+            Some(39992992962899)
+        );
+        assert_eq!(
+            monad
+                .smallest_model_number(SearchStrategy::Constraints)
+                .unwrap(),
+            Some(11181841811127)
+        );
+    }
+
+    #[test]
+    fn accepts_only_model_numbers_satisfying_every_constraint() {
+        let monad = Monad::deserialize(SAMPLE).unwrap();
+        let digits_of = |model_number: u64| {
+            model_number
+                .to_string()
+                .chars()
+                .map(|digit| digit.to_digit(10).unwrap() as i64)
+                .collect::<Vec<i64>>()
+        };
+
+        assert!(monad.accepts(&digits_of(39992992962899)).unwrap());
+        assert!(monad.accepts(&digits_of(11181841811127)).unwrap());
+        assert!(!monad.accepts(&digits_of(39992992962898)).unwrap());
+        assert!(!monad.accepts(&digits_of(11181841811128)).unwrap());
+        assert!(!monad.accepts(&digits_of(99999999999999)).unwrap());
+    }
+
+    #[test]
+    fn falls_back_to_searching_unfamiliar_programs() {
+        // Accepts exactly the model numbers whose digits are all 4 or less.
+        let serialized_monad = "inp w\nadd w 4\ndiv w 9\nadd z w\n".repeat(MODEL_NUMBER_DIGITS);
+        let monad = Monad::deserialize(&serialized_monad).unwrap();
+
+        assert!(monad.constraints().is_err());
+        assert_eq!(
+            monad
+                .largest_model_number(SearchStrategy::Constraints)
+                .unwrap(),
+            Some(44444444444444)
+        );
+        assert_eq!(
+            monad
+                .smallest_model_number(SearchStrategy::Constraints)
+                .unwrap(),
+            Some(11111111111111)
+        );
+    }
+
+    #[test]
+    fn refuses_programs_with_the_wrong_number_of_inputs() {
+        assert!(Monad::deserialize("inp w\nadd z w").is_err());
+        assert!(Monad::deserialize("add z 1\n").is_err());
+    }
+}
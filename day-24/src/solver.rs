@@ -0,0 +1,82 @@
+use crate::monad::{Monad, SearchStrategy};
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Arithmetic Logic Unit" puzzle.
+#[derive(Clone, Copy, Debug)]
+pub struct MonadSolver {
+    /// How to search for valid model numbers.
+    pub search_strategy: SearchStrategy,
+}
+
+impl Default for MonadSolver {
+    fn default() -> Self {
+        MonadSolver {
+            search_strategy: SearchStrategy::Constraints,
+        }
+    }
+}
+
+impl Solver for MonadSolver {
+    type Parsed = Monad;
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn parse(&self, input: &str) -> Result<Monad> {
+        Monad::deserialize(input)
+    }
+
+    fn part_one(&self, monad: &Monad) -> Result<Answer> {
+        monad
+            .largest_model_number(self.search_strategy)?
+            .map(Answer::from)
+            .context(NoSolution::new("MONAD accepts no model number"))
+    }
+
+    fn part_two(&self, monad: &Monad) -> Result<Answer> {
+        monad
+            .smallest_model_number(self.search_strategy)?
+            .map(Answer::from)
+            .context(NoSolution::new("MONAD accepts no model number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = MonadSolver::default().parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            MonadSolver::default().part_one(&parsed).unwrap(),
+            "39992992962899"
+        );
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = MonadSolver::default().parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            MonadSolver::default().part_two(&parsed).unwrap(),
+            "11181841811127"
+        );
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&MonadSolver::default(), SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
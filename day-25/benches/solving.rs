@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_25::solver::SeaCucumberSolver;
+
+fn bench_sea_cucumber_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &SeaCucumberSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_sea_cucumber_solver);
+criterion_main!(benches);
@@ -0,0 +1,19 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Simulates two herds of sea cucumbers on the sea floor")]
+pub struct Args {
+    /// Milliseconds to wait between frames of the animation.
+    #[clap(long, value_name = "MS", default_value_t = 100, requires = "visualize")]
+    pub frame_delay: u64,
+
+    /// Number of steps to animate, stopping once no sea cucumber moves if
+    /// unspecified.
+    #[clap(long, value_name = "N", requires = "visualize")]
+    pub steps: Option<usize>,
+
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
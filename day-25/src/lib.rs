@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod fixtures;
+pub mod sea_cucumber_animation;
+pub mod sea_floor;
+pub mod solver;
@@ -0,0 +1,79 @@
+use anyhow::{Context, Result};
+use aoc_common::args::ParseArgs;
+use aoc_common::error::InvalidInput;
+use aoc_common::{completions, input, solver};
+use args::Args;
+use day_25::sea_cucumber_animation;
+use day_25::sea_floor::SeaFloor;
+use day_25::solver::SeaCucumberSolver;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+extern crate anyhow;
+extern crate aoc_common;
+extern crate clap;
+extern crate day_25;
+
+mod args;
+
+fn main() -> ExitCode {
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
+}
+
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return solver::write_answers_csv(&SeaCucumberSolver, inputs, &args.common);
+    }
+
+    if args.common.watch {
+        return solver::watch_answers(&SeaCucumberSolver, &args.common);
+    }
+
+    let serialized_sea_floor = input::load(args.common.input_source())?;
+
+    if args.common.visualize {
+        let sea_floor = SeaFloor::deserialize(&serialized_sea_floor).context(InvalidInput)?;
+
+        return animate(
+            sea_floor,
+            args.steps,
+            Duration::from_millis(args.frame_delay),
+        );
+    }
+
+    solver::print_answers(&SeaCucumberSolver, &serialized_sea_floor, &args.common)
+}
+
+/// Prints a frame of `sea_floor` after each of the specified number of
+/// `steps`, or until no sea cucumber moves if there is no limit, waiting
+/// `frame_delay` between frames.
+fn animate(mut sea_floor: SeaFloor, steps: Option<usize>, frame_delay: Duration) -> Result<()> {
+    print!("{}", sea_cucumber_animation::render_frame(&sea_floor, 0, 0));
+
+    for step in 1..=steps.unwrap_or(usize::MAX) {
+        thread::sleep(frame_delay);
+
+        let move_count = sea_floor.step();
+        print!(
+            "{}",
+            sea_cucumber_animation::render_frame(&sea_floor, step, move_count)
+        );
+
+        if steps.is_none() && move_count == 0 {
+            break;
+        }
+    }
+
+    Ok(())
+}
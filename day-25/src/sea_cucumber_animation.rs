@@ -0,0 +1,47 @@
+use crate::sea_floor::{SeaFloor, Spot};
+
+use std::fmt::Write;
+
+/// ANSI escape sequence that clears the terminal and moves the cursor to its
+/// top left corner.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// ANSI escape sequence that resets all text styling.
+const RESET: &str = "\x1b[0m";
+
+/// ANSI escape sequence styling sea cucumbers in the east-facing herd.
+const EAST_FACING_STYLE: &str = "\x1b[1;96m";
+
+/// ANSI escape sequence styling empty spots.
+const EMPTY_STYLE: &str = "\x1b[38;5;24m";
+
+/// ANSI escape sequence styling sea cucumbers in the south-facing herd.
+const SOUTH_FACING_STYLE: &str = "\x1b[1;93m";
+
+/// Returns a single frame of an animation of `sea_floor` for display in a
+/// terminal, replacing whatever was on screen, captioned with the number of
+/// the step it shows and how many sea cucumbers moved during that step.
+pub fn render_frame(sea_floor: &SeaFloor, step: usize, move_count: usize) -> String {
+    let spots = sea_floor.spots();
+    let mut frame = String::from(CLEAR_SCREEN);
+
+    for row in 0..spots.height() {
+        for column in 0..spots.width() {
+            let spot = spots[(row, column)];
+            let style = match spot {
+                Spot::EastFacing => EAST_FACING_STYLE,
+                Spot::Empty => EMPTY_STYLE,
+                Spot::SouthFacing => SOUTH_FACING_STYLE,
+            };
+
+            // Writing to a String cannot fail.
+            let _ = write!(frame, "{}{}{}", style, spot.symbol(), RESET);
+        }
+
+        frame.push('\n');
+    }
+
+    let _ = writeln!(frame, "\nStep:\t{}\nMoves:\t{}", step, move_count);
+
+    frame
+}
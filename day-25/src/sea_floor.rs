@@ -0,0 +1,149 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::cancellation;
+use aoc_common::grid::Grid2D;
+use std::fmt::{Display, Formatter};
+use tracing::instrument;
+
+/// Enumerates everything that can occupy a spot on the [SeaFloor].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Spot {
+    /// Sea cucumber that belongs to the herd moving east.
+    EastFacing,
+    /// Spot without a sea cucumber.
+    Empty,
+    /// Sea cucumber that belongs to the herd moving south.
+    SouthFacing,
+}
+
+impl Spot {
+    /// Interprets the given [char] as a [Spot], returning [Option::None] if
+    /// interpretation fails.
+    pub fn from(spot_char: char) -> Option<Spot> {
+        match spot_char {
+            '>' => Some(Spot::EastFacing),
+            '.' => Some(Spot::Empty),
+            'v' => Some(Spot::SouthFacing),
+            _ => None,
+        }
+    }
+
+    /// Returns the [char] used to render this [Spot].
+    pub fn symbol(&self) -> char {
+        match self {
+            Spot::EastFacing => '>',
+            Spot::Empty => '.',
+            Spot::SouthFacing => 'v',
+        }
+    }
+}
+
+/// Patch of sea floor covered by two herds of sea cucumbers, where sea
+/// cucumbers that move off of one edge wrap around to the opposite edge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeaFloor {
+    /// Scratch grid that each half step is written into before it is swapped
+    /// with [SeaFloor::spots], so that stepping never allocates.
+    back_buffer: Grid2D<Spot>,
+    /// What currently occupies every spot of this [SeaFloor].
+    spots: Grid2D<Spot>,
+}
+
+impl SeaFloor {
+    /// Interprets a newline-delimited [str] of rows of `>`, `v`, and `.` as a
+    /// [SeaFloor].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_sea_floor: &str) -> Result<SeaFloor> {
+        let rows = serialized_sea_floor
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.chars()
+                    .map(|spot_char| {
+                        Spot::from(spot_char)
+                            .ok_or_else(|| anyhow!("'{}' is not a valid spot", spot_char))
+                    })
+                    .collect::<Result<Vec<Spot>>>()
+                    .with_context(|| format!("\"{}\" is not a valid row of spots", line))
+            })
+            .collect::<Result<Vec<Vec<Spot>>>>()?;
+
+        let spots = Grid2D::from_rows(rows).context("Sea floor is not rectangular")?;
+
+        Ok(SeaFloor {
+            back_buffer: spots.clone(),
+            spots,
+        })
+    }
+
+    /// Returns the first step during which no sea cucumber moves, giving up
+    /// after `max_steps` steps or once cancelled through [cancellation].
+    pub fn first_step_without_movement(&mut self, max_steps: usize) -> Option<usize> {
+        (1..=max_steps)
+            .take_while(|_| !cancellation::is_cancelled())
+            .find(|_| self.step() == 0)
+    }
+
+    /// Returns what currently occupies every spot of this [SeaFloor].
+    pub fn spots(&self) -> &Grid2D<Spot> {
+        &self.spots
+    }
+
+    /// Moves the east-facing herd and then the south-facing herd, returning
+    /// how many sea cucumbers moved in total.
+    ///
+    /// Every sea cucumber in a herd considers the spot in front of it at the
+    /// same time, so a sea cucumber only moves if that spot was empty before
+    /// any of its herd moved.
+    pub fn step(&mut self) -> usize {
+        self.move_herd(Spot::EastFacing, (0, 1)) + self.move_herd(Spot::SouthFacing, (1, 0))
+    }
+
+    /// Moves every sea cucumber of the `herd` facing in `direction`, given as
+    /// a `(row, column)` offset, returning how many moved.
+    fn move_herd(&mut self, herd: Spot, (row_offset, column_offset): (isize, isize)) -> usize {
+        let (height, width) = (self.spots.height(), self.spots.width());
+        let mut moves = 0;
+
+        for row in 0..height {
+            for column in 0..width {
+                let ahead = self
+                    .spots
+                    .wrapping_offset((row, column), (row_offset, column_offset));
+                let behind = self
+                    .spots
+                    .wrapping_offset((row, column), (-row_offset, -column_offset));
+
+                let spot = self.spots[(row, column)];
+                self.back_buffer[(row, column)] =
+                    if spot == herd && self.spots[ahead] == Spot::Empty {
+                        moves += 1;
+
+                        Spot::Empty
+                    } else if spot == Spot::Empty && self.spots[behind] == herd {
+                        herd
+                    } else {
+                        spot
+                    };
+            }
+        }
+
+        std::mem::swap(&mut self.spots, &mut self.back_buffer);
+
+        moves
+    }
+}
+
+impl Display for SeaFloor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.spots.height() {
+            let line = (0..self.spots.width())
+                .map(|column| self.spots[(row, column)].symbol())
+                .collect::<String>();
+
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
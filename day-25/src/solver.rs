@@ -0,0 +1,78 @@
+use crate::sea_floor::SeaFloor;
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+
+/// Most steps to simulate before concluding that the sea cucumbers never stop.
+const MAX_STEPS: usize = 1_000_000;
+
+/// [Solver] for the "Sea Cucumber" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SeaCucumberSolver;
+
+impl Solver for SeaCucumberSolver {
+    type Parsed = SeaFloor;
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn parse(&self, input: &str) -> Result<SeaFloor> {
+        SeaFloor::deserialize(input)
+    }
+
+    fn part_one(&self, sea_floor: &SeaFloor) -> Result<Answer> {
+        // Herds move in place, so step a copy of the parsed sea floor.
+        let first_step_without_movement = sea_floor
+            .clone()
+            .first_step_without_movement(MAX_STEPS)
+            .with_context(|| {
+                NoSolution::new(format!(
+                    "Sea cucumbers are still moving after {} steps",
+                    MAX_STEPS
+                ))
+            })?;
+
+        Ok(first_step_without_movement.into())
+    }
+
+    /// The final day has no second puzzle; its star is awarded for finishing
+    /// every other puzzle, which remotely starts the sleigh.
+    fn part_two(&self, _: &SeaFloor) -> Result<Answer> {
+        Ok(Answer::from("Merry Christmas!"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = SeaCucumberSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SeaCucumberSolver.part_one(&parsed).unwrap(), "58");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = SeaCucumberSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(
+            SeaCucumberSolver.part_two(&parsed).unwrap(),
+            "Merry Christmas!"
+        );
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&SeaCucumberSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
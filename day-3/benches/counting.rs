@@ -0,0 +1,53 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_3::binary_grid::BinaryGrid;
+
+/// Number of bits in every generated binary number.
+const COLUMNS: usize = 32;
+
+/// Returns a newline-delimited [String] of `rows` pseudo-random binary numbers,
+/// generated the same way every time so that runs are comparable.
+fn generated_diagnostic_report(rows: usize) -> String {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut serialized_diagnostic_report = String::new();
+
+    for _ in 0..rows {
+        // Xorshift keeps the generator dependency-free.
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        for column in 0..COLUMNS {
+            serialized_diagnostic_report.push(if state >> column & 1 == 1 { '1' } else { '0' });
+        }
+        serialized_diagnostic_report.push('\n');
+    }
+
+    serialized_diagnostic_report
+}
+
+fn compare_counting_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("count_ones_in_each_column");
+
+    for rows in [1_000, 1_000_000] {
+        let diagnostic_report =
+            BinaryGrid::deserialize(&generated_diagnostic_report(rows)).unwrap();
+
+        group.throughput(Throughput::Elements((rows * COLUMNS) as u64));
+        group.bench_with_input(
+            BenchmarkId::new("scalar", rows),
+            &diagnostic_report,
+            |b, diagnostic_report| b.iter(|| diagnostic_report.count_ones_in_each_column()),
+        );
+        #[cfg(feature = "simd")]
+        group.bench_with_input(
+            BenchmarkId::new("simd", rows),
+            &diagnostic_report,
+            |b, diagnostic_report| b.iter(|| diagnostic_report.count_ones_in_each_column_simd()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, compare_counting_strategies);
+criterion_main!(benches);
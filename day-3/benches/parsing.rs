@@ -0,0 +1,60 @@
+#[cfg(feature = "arena")]
+use bumpalo::Bump;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_3::binary_grid::BinaryGrid;
+
+/// Number of bits in every generated binary number.
+const COLUMNS: usize = 32;
+
+/// Returns a newline-delimited [String] of `rows` pseudo-random binary numbers,
+/// generated the same way every time so that runs are comparable.
+fn generated_diagnostic_report(rows: usize) -> String {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut serialized_diagnostic_report = String::new();
+
+    for _ in 0..rows {
+        // Xorshift keeps the generator dependency-free.
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        for column in 0..COLUMNS {
+            serialized_diagnostic_report.push(if state >> column & 1 == 1 { '1' } else { '0' });
+        }
+        serialized_diagnostic_report.push('\n');
+    }
+
+    serialized_diagnostic_report
+}
+
+fn compare_allocation_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize");
+
+    for rows in [1_000, 100_000] {
+        let serialized_diagnostic_report = generated_diagnostic_report(rows);
+
+        group.throughput(Throughput::Bytes(serialized_diagnostic_report.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("heap", rows),
+            &serialized_diagnostic_report,
+            |b, serialized_diagnostic_report| {
+                b.iter(|| BinaryGrid::deserialize(serialized_diagnostic_report).unwrap())
+            },
+        );
+        #[cfg(feature = "arena")]
+        group.bench_with_input(
+            BenchmarkId::new("arena", rows),
+            &serialized_diagnostic_report,
+            |b, serialized_diagnostic_report| {
+                b.iter(|| {
+                    BinaryGrid::deserialize_in(serialized_diagnostic_report, &Bump::new()).unwrap()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, compare_allocation_strategies);
+criterion_main!(benches);
@@ -1,9 +1,34 @@
-use anyhow::{Context, Result};
-use bit::BitAggregator;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use aoc_common::diagnostic::{self, InputDiagnostic};
+#[cfg(feature = "std")]
+use aoc_common::input::{DeserializeLines, FromInput};
+use aoc_common::render::{Cell, Color, Renderable, Style};
 pub use bit::{Bit, BitSequence};
+#[cfg(feature = "arena")]
+use bumpalo::Bump;
+use core::cmp::Ordering;
+#[cfg(feature = "simd")]
+use core::simd::num::SimdUint;
+#[cfg(feature = "simd")]
+use core::simd::Simd;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use thiserror::Error;
+#[cfg(feature = "std")]
+use tracing::instrument;
+
+/// Number of columns whose ones are counted at once by
+/// [BinaryGrid::count_ones_in_each_column_simd].
+#[cfg(feature = "simd")]
+const LANES: usize = 32;
 
 /// 2D grid of ones and zeroes.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct BinaryGrid {
     /// 2D [Vec] of [Bit] instances.
     bits: Vec<Vec<Bit>>,
@@ -11,11 +36,90 @@ pub struct BinaryGrid {
     width: usize,
 }
 
+/// Iteration of repeatedly culling a [BinaryGrid], keeping only the rows
+/// whose bit in one column matches the bit selected for that column.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct CullingStep {
+    /// Index of the column whose bits were compared.
+    pub column_index: usize,
+    /// Bit that the kept rows have in the compared column.
+    pub kept_bit: Bit,
+    /// Number of rows with a one in the compared column before culling.
+    pub ones: usize,
+    /// Rows that remain after culling.
+    pub remaining: BinaryGrid,
+    /// Number of rows with a zero in the compared column before culling.
+    pub zeroes: usize,
+}
+
+/// [Iterator] over the [CullingStep]s taken to cull a [BinaryGrid] down to a
+/// single row, one column at a time.
+pub struct CullingSteps<F> {
+    /// Index of the column to compare next.
+    column_index: usize,
+    /// Rows that remain so far.
+    remaining: BinaryGrid,
+    /// Selects the bit that kept rows must have in a column of the remaining
+    /// rows.
+    select_bit: F,
+}
+
+impl<F> Iterator for CullingSteps<F>
+where
+    F: Fn(&BinaryGrid, usize) -> Bit,
+{
+    type Item = CullingStep;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.column_index >= self.remaining.columns() || self.remaining.rows() <= 1 {
+            return None;
+        }
+
+        let column_index = self.column_index;
+        let bit_counts = self.remaining.count_bits_in_column(column_index);
+        let kept_bit = (self.select_bit)(&self.remaining, column_index);
+
+        self.remaining = self.remaining.cull(BinaryGridCullOptions {
+            rows_with_bits_matching: kept_bit,
+            at_index: column_index,
+        });
+        self.column_index += 1;
+
+        Some(CullingStep {
+            column_index,
+            kept_bit,
+            ones: bit_counts.ones,
+            remaining: self.remaining.clone(),
+            zeroes: bit_counts.zeroes,
+        })
+    }
+}
+
 pub struct BinaryGridCullOptions {
     pub rows_with_bits_matching: Bit,
     pub at_index: usize,
 }
 
+/// Number of ones and zeroes in a column of a [BinaryGrid].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct BitCounts {
+    /// Number of rows with a one in the column.
+    ones: usize,
+    /// Number of rows with a zero in the column.
+    zeroes: usize,
+}
+
+/// Enumerates the ways that text can fail to be interpreted as a
+/// [BinaryGrid].
+#[cfg(feature = "std")]
+#[derive(Debug, Error)]
+pub enum BinaryGridParseError {
+    /// Character is neither `0` nor `1`.
+    #[error("Diagnostic report contains an invalid bit")]
+    InvalidBit(#[source] Box<InputDiagnostic>),
+}
+
 impl From<Vec<Vec<Bit>>> for BinaryGrid {
     fn from(bits: Vec<Vec<Bit>>) -> Self {
         let width = bits.iter().map(|row| row.len()).max().unwrap_or(0);
@@ -24,27 +128,105 @@ impl From<Vec<Vec<Bit>>> for BinaryGrid {
     }
 }
 
+impl BitCounts {
+    /// Returns the [Bit] that is counted as `ordering` compared to the other
+    /// one, or [Option::None] if both are counted equally often.
+    fn bit_counted(&self, ordering: Ordering) -> Option<Bit> {
+        match self.ones.cmp(&self.zeroes) {
+            Ordering::Equal => None,
+            ones_ordering if ones_ordering == ordering => Some(Bit::One),
+            _ => Some(Bit::Zero),
+        }
+    }
+
+    /// Returns how many times `bit` was counted.
+    fn count_of(&self, bit: Bit) -> usize {
+        match bit {
+            Bit::One => self.ones,
+            Bit::Zero => self.zeroes,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl DeserializeLines for BinaryGrid {
+    type Line = Vec<Bit>;
+
+    fn deserialize_line(line: &str) -> anyhow::Result<Vec<Bit>> {
+        Ok(line
+            .char_indices()
+            .map(|(i, bit_char)| deserialize_bit(bit_char, line, i))
+            .collect::<Result<Vec<Bit>, BinaryGridParseError>>()?)
+    }
+
+    fn from_lines(rows: Vec<Vec<Bit>>) -> BinaryGrid {
+        BinaryGrid::from(rows)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromInput for BinaryGrid {
+    /// Interprets the puzzle input as a [BinaryGrid], with the help of an
+    /// arena when the `arena` feature is enabled.
+    fn from_input(input: &str) -> anyhow::Result<BinaryGrid> {
+        #[cfg(feature = "arena")]
+        return Ok(BinaryGrid::deserialize_in(input, &Bump::new())?);
+        #[cfg(not(feature = "arena"))]
+        Ok(BinaryGrid::deserialize(input)?)
+    }
+}
+
 impl BinaryGrid {
     /// Interprets a newline-delimited [str] of binary numbers as a
     /// [BinaryGrid].
-    pub fn deserialize(serialized_binary_grid: &str) -> Result<BinaryGrid> {
+    #[cfg(feature = "std")]
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_binary_grid: &str) -> Result<BinaryGrid, BinaryGridParseError> {
         let bits = serialized_binary_grid
             .lines()
             .map(|line| {
-                line.chars()
-                    .map(|bit_char| {
-                        Bit::from(bit_char)
-                            .with_context(|| format!("\"{}\" is not a valid bit char", bit_char))
+                let line_start = diagnostic::span_of(line, serialized_binary_grid).start;
+
+                line.char_indices()
+                    .map(|(i, bit_char)| {
+                        deserialize_bit(bit_char, serialized_binary_grid, line_start + i)
                     })
-                    .collect::<Result<Vec<Bit>>>()
+                    .collect::<Result<Vec<Bit>, BinaryGridParseError>>()
             })
-            .collect::<Result<Vec<Vec<Bit>>>>()
-            .with_context(|| {
-                format!(
-                    "\"{}\" is not a valid serialized binary grid",
-                    serialized_binary_grid
-                )
-            })?;
+            .collect::<Result<Vec<Vec<Bit>>, BinaryGridParseError>>()?;
+
+        Ok(BinaryGrid::from(bits))
+    }
+
+    /// Interprets a newline-delimited [str] of binary numbers as a
+    /// [BinaryGrid], like [BinaryGrid::deserialize], but growing each row in
+    /// `arena` so that the global allocator is only asked for every row once,
+    /// at its final size.
+    ///
+    /// Everything staged in `arena` is freed when `arena` is dropped.
+    #[cfg(feature = "arena")]
+    #[instrument(skip_all)]
+    pub fn deserialize_in(
+        serialized_binary_grid: &str,
+        arena: &Bump,
+    ) -> Result<BinaryGrid, BinaryGridParseError> {
+        let bits = serialized_binary_grid
+            .lines()
+            .map(|line| {
+                let line_start = diagnostic::span_of(line, serialized_binary_grid).start;
+
+                let mut row = bumpalo::collections::Vec::new_in(arena);
+                for (i, bit_char) in line.char_indices() {
+                    row.push(deserialize_bit(
+                        bit_char,
+                        serialized_binary_grid,
+                        line_start + i,
+                    )?);
+                }
+
+                Ok(row.to_vec())
+            })
+            .collect::<Result<Vec<Vec<Bit>>, BinaryGridParseError>>()?;
 
         Ok(BinaryGrid::from(bits))
     }
@@ -78,30 +260,91 @@ impl BinaryGrid {
         BinaryGrid::from(bits)
     }
 
+    /// Returns an [Iterator] over the [CullingStep]s taken to cull this
+    /// [BinaryGrid] down to a single row, starting from the first column and
+    /// keeping the rows whose bit in each column matches the one picked by
+    /// `select_bit`.
+    ///
+    /// Stops early if every column has been compared or at most one row
+    /// remains.
+    pub fn culling_steps<F>(&self, select_bit: F) -> CullingSteps<F>
+    where
+        F: Fn(&BinaryGrid, usize) -> Bit,
+    {
+        CullingSteps {
+            column_index: 0,
+            remaining: self.clone(),
+            select_bit,
+        }
+    }
+
+    /// Returns how many rows have a one in each column, in order.
+    pub fn count_ones_in_each_column(&self) -> Vec<usize> {
+        let mut ones = vec![0; self.width];
+        for row in &self.bits {
+            for (column_ones, bit) in ones.iter_mut().zip(row) {
+                *column_ones += bit.value();
+            }
+        }
+
+        ones
+    }
+
+    /// Returns how many rows have a one in each column, in order, like
+    /// [BinaryGrid::count_ones_in_each_column], but adding up [LANES] columns
+    /// of every row at a time.
+    #[cfg(feature = "simd")]
+    pub fn count_ones_in_each_column_simd(&self) -> Vec<usize> {
+        let mut lane_ones = vec![Simd::<u32, LANES>::splat(0); self.width.div_ceil(LANES)];
+        for row in &self.bits {
+            let (chunks, remainder) = row.as_chunks::<LANES>();
+            for (column_ones, chunk) in lane_ones.iter_mut().zip(chunks) {
+                *column_ones += Simd::from_array(chunk.map(|bit| bit as u8)).cast::<u32>();
+            }
+
+            if let Some(column_ones) = lane_ones.get_mut(chunks.len()) {
+                for (column_ones, bit) in column_ones.as_mut_array().iter_mut().zip(remainder) {
+                    *column_ones += *bit as u32;
+                }
+            }
+        }
+
+        lane_ones
+            .iter()
+            .flat_map(|column_ones| column_ones.to_array())
+            .take(self.width)
+            .map(|column_ones| column_ones as usize)
+            .collect()
+    }
+
+    /// Returns how many rows have `bit` in the column indicated by
+    /// `column_index`.
+    pub fn count_of_bit_in_column(&self, bit: Bit, column_index: usize) -> usize {
+        self.count_bits_in_column(column_index).count_of(bit)
+    }
+
     /// Returns the least common [Bit] in the column indicated by
     /// `column_index`, returning [Option::None] if no such [Bit] exists.
     pub fn least_common_bit_in_column(&self, column_index: usize) -> Option<Bit> {
-        self.aggregate_bits_in_column(column_index).least_common()
+        self.count_bits_in_column(column_index)
+            .bit_counted(Ordering::Less)
     }
 
     /// Returns a [BitSequence] of the least common bit in each column.
     pub fn least_common_bit_in_each_column(&self) -> BitSequence {
-        (0..self.width)
-            .map(|column_index| self.least_common_bit_in_column(column_index).unwrap())
-            .collect::<BitSequence>()
+        self.bit_in_each_column_with_ones(Ordering::Less)
     }
 
     /// Returns the most common [Bit] in the column indicated by `column_index`,
     /// returning [Option::None] if no such [Bit] exists.
     pub fn most_common_bit_in_column(&self, column_index: usize) -> Option<Bit> {
-        self.aggregate_bits_in_column(column_index).most_common()
+        self.count_bits_in_column(column_index)
+            .bit_counted(Ordering::Greater)
     }
 
     /// Returns a [BitSequence] of the most common bit in each column.
     pub fn most_common_bit_in_each_column(&self) -> BitSequence {
-        (0..self.width)
-            .map(|column_index| self.most_common_bit_in_column(column_index).unwrap())
-            .collect::<BitSequence>()
+        self.bit_in_each_column_with_ones(Ordering::Greater)
     }
 
     /// Returns the [BitSequence] of the row indicated by `row_index`.
@@ -118,116 +361,131 @@ impl BinaryGrid {
         self.bits.len()
     }
 
-    /// Summarizes an entire column of [Bit] in a [BitAggregator], returning
-    /// the [BitAggregator] thereafter.
-    fn aggregate_bits_in_column(&self, column_index: usize) -> BitAggregator {
-        if column_index >= self.width {
-            return BitAggregator::zero();
-        }
-
+    /// Returns this [BinaryGrid] as a newline-delimited [String] of binary
+    /// numbers, which [BinaryGrid::deserialize] interprets as an equal
+    /// [BinaryGrid].
+    pub fn serialize(&self) -> String {
         self.bits
             .iter()
-            .map(|bits| bits[column_index])
-            .map(BitAggregator::from)
-            .reduce(BitAggregator::combine)
-            .unwrap_or_else(BitAggregator::zero)
-    }
-}
-
-/// Module encupsulating bitwise logic used by the [super::BinaryGrid].
-mod bit {
-    /// Character representing bitwise one.
-    const ONE: char = '1';
-
-    /// Character representing bitwise zero.
-    const ZERO: char = '0';
-
-    /// Enumerates both possible values for a bit.
-    #[derive(Clone, Copy, Debug, PartialEq)]
-    pub enum Bit {
-        /// Enum representation of a bitwise one.
-        One,
-        /// Enum representation of a bitwise zero.
-        Zero,
-    }
-
-    impl Bit {
-        /// Attempts to interpret the given [char] as a [Bit], returning
-        /// [Option::None] if interpretation fails.
-        pub fn from(bit_char: char) -> Option<Bit> {
-            match bit_char {
-                ONE => Some(Bit::One),
-                ZERO => Some(Bit::Zero),
-                _ => None,
-            }
-        }
+            .map(|bits| BitSequence::from(bits.clone()).to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
     }
 
-    /// Utility type used to summarize [Bit] collections.
-    ///
-    /// The `0` field refers to the number of [Bit::Zero] instances in a
-    /// collection.
+    /// Returns a [BitSequence] of [Bit::One] for every column whose number of
+    /// ones compares as `ordering` to its number of zeroes, and [Bit::Zero]
+    /// for every other column.
     ///
-    /// The `1` field refers to the number of [Bit::One] instances in a
-    /// collection.
-    #[derive(Debug, PartialEq)]
-    pub struct BitAggregator(usize, usize);
-
-    impl BitAggregator {
-        /// Combines two instances of [BitAggregator] by summing their
-        /// respective fields within a new [BitAggregator] instance.
-        pub fn combine(a: BitAggregator, b: BitAggregator) -> BitAggregator {
-            BitAggregator(a.0 + b.0, a.1 + b.1)
-        }
-
-        /// Creates a new [BitAggregator] both its fields set to `0`.
-        pub fn zero() -> BitAggregator {
-            BitAggregator(0, 0)
-        }
+    /// Panics if any column has as many ones as zeroes, since neither bit is
+    /// then more or less common than the other.
+    fn bit_in_each_column_with_ones(&self, ordering: Ordering) -> BitSequence {
+        #[cfg(feature = "simd")]
+        let ones_in_each_column = self.count_ones_in_each_column_simd();
+        #[cfg(not(feature = "simd"))]
+        let ones_in_each_column = self.count_ones_in_each_column();
+
+        ones_in_each_column
+            .into_iter()
+            .enumerate()
+            .map(
+                |(column_index, ones)| match ones.cmp(&(self.rows() - ones)) {
+                    Ordering::Equal => panic!(
+                        "Column {} has as many ones as zeroes, so no bit is more common",
+                        column_index
+                    ),
+                    ones_ordering if ones_ordering == ordering => Bit::One,
+                    _ => Bit::Zero,
+                },
+            )
+            .collect::<BitSequence>()
+    }
 
-        /// Returns the [Bit] indicated to be the least common according to this
-        /// [BitAggregator], returning [Option::None] if no such [Bit] is
-        /// indicated.
-        pub fn least_common(&self) -> Option<Bit> {
-            if self.0 < self.1 {
-                Some(Bit::Zero)
-            } else if self.1 < self.0 {
-                Some(Bit::One)
-            } else {
-                None
+    /// Counts how many of each [Bit] the column indicated by `column_index`
+    /// contains.
+    fn count_bits_in_column(&self, column_index: usize) -> BitCounts {
+        // Both bits start out counted zero times so that a bit missing from
+        // the column is still the least common one.
+        let mut bit_counts = BitCounts::default();
+
+        if column_index < self.width {
+            for bits in &self.bits {
+                match bits[column_index] {
+                    Bit::One => bit_counts.ones += 1,
+                    Bit::Zero => bit_counts.zeroes += 1,
+                }
             }
         }
 
-        /// Returns the [Bit] indicated to be the most common according to this
-        /// [BitAggregator], returning [Option::None] if no such [Bit] is
-        /// indicated.
-        pub fn most_common(&self) -> Option<Bit> {
-            if self.0 > self.1 {
-                Some(Bit::Zero)
-            } else if self.1 > self.0 {
-                Some(Bit::One)
-            } else {
-                None
-            }
-        }
+        bit_counts
     }
+}
 
-    impl From<Bit> for BitAggregator {
-        fn from(bit: Bit) -> Self {
-            match bit {
-                Bit::One => BitAggregator(0, 1),
-                Bit::Zero => BitAggregator(1, 0),
-            }
-        }
+impl Renderable for BinaryGrid {
+    /// Returns every [Bit] of this [BinaryGrid] as a [Cell], with ones
+    /// standing out from the zeroes around them.
+    fn cells(&self) -> Vec<Vec<Cell>> {
+        self.bits
+            .iter()
+            .map(|bits| {
+                bits.iter()
+                    .map(|bit| match bit {
+                        Bit::One => Cell::new("1", Style::PLAIN.bold().fg(Color::Green)),
+                        Bit::Zero => Cell::new("0", Style::PLAIN.dim()),
+                    })
+                    .collect()
+            })
+            .collect()
     }
+}
+
+/// Interprets the `bit_char` found `offset` bytes into
+/// `serialized_binary_grid` as a [Bit].
+#[cfg(feature = "std")]
+fn deserialize_bit(
+    bit_char: char,
+    serialized_binary_grid: &str,
+    offset: usize,
+) -> Result<Bit, BinaryGridParseError> {
+    Bit::from(bit_char).ok_or_else(|| {
+        BinaryGridParseError::InvalidBit(Box::new(
+            InputDiagnostic::new(
+                format!("{:?} is not a valid bit", bit_char),
+                serialized_binary_grid,
+                offset..(offset + bit_char.len_utf8()),
+            )
+            .with_help("Every bit must be either 0 or 1"),
+        ))
+    })
+}
+
+/// Module encupsulating bitwise logic used by the [super::BinaryGrid].
+mod bit {
+    pub use aoc_common::bit::Bit;
+
+    use alloc::vec::Vec;
+    use core::fmt;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
 
     /// Represents an ordered collection of [Bit] instances.
     #[derive(Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
     pub struct BitSequence(Vec<Bit>);
 
-    impl Into<u32> for BitSequence {
-        fn into(self) -> u32 {
-            self.0
+    impl fmt::Display for BitSequence {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for bit in self.0.iter() {
+                write!(f, "{}", bit.value())?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl From<BitSequence> for u32 {
+        fn from(bit_sequence: BitSequence) -> Self {
+            bit_sequence
+                .0
                 .iter()
                 .rev()
                 .enumerate()
@@ -261,3 +519,69 @@ mod bit {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Returns a [Strategy] for a [BinaryGrid] of up to 32 rows, each of the
+    /// same number of columns.
+    fn binary_grid() -> impl Strategy<Value = BinaryGrid> {
+        (1..12usize).prop_flat_map(|columns| {
+            prop::collection::vec(
+                prop::collection::vec(prop_oneof![Just(Bit::Zero), Just(Bit::One)], columns),
+                0..32,
+            )
+            .prop_map(BinaryGrid::from)
+        })
+    }
+
+    proptest! {
+        #[cfg(feature = "std")]
+        #[test]
+        fn deserializes_what_it_serializes(binary_grid in binary_grid()) {
+            prop_assert_eq!(BinaryGrid::deserialize(&binary_grid.serialize()).unwrap(), binary_grid);
+        }
+
+        #[test]
+        fn renders_the_same_bits_that_it_serializes(binary_grid in binary_grid()) {
+            let rendering = binary_grid.render(false);
+            let serialized_binary_grid = binary_grid.serialize();
+
+            prop_assert_eq!(
+                rendering.lines().collect::<Vec<&str>>(),
+                serialized_binary_grid.lines().collect::<Vec<&str>>()
+            );
+        }
+
+        #[test]
+        fn cull_never_increases_row_count(
+            binary_grid in binary_grid(),
+            at_index in 0..16usize,
+            is_one in any::<bool>(),
+        ) {
+            let rows_with_bits_matching = if is_one { Bit::One } else { Bit::Zero };
+            let culled_binary_grid = binary_grid.cull(BinaryGridCullOptions {
+                rows_with_bits_matching,
+                at_index,
+            });
+
+            prop_assert!(culled_binary_grid.rows() <= binary_grid.rows());
+            for row_index in 0..culled_binary_grid.rows() {
+                prop_assert_eq!(culled_binary_grid.bits[row_index][at_index], rows_with_bits_matching);
+            }
+        }
+
+        #[test]
+        fn counts_every_bit_in_each_column(binary_grid in binary_grid()) {
+            for column_index in 0..binary_grid.columns() {
+                prop_assert_eq!(
+                    binary_grid.count_of_bit_in_column(Bit::One, column_index)
+                        + binary_grid.count_of_bit_in_column(Bit::Zero, column_index),
+                    binary_grid.rows()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use day_3::binary_grid::{BinaryGrid, Bit, BitSequence, CullingStep};
+use day_3::life_support::{co2_scrubber_bit_in, oxygen_generator_bit_in};
+use serde::{Deserialize, Serialize};
+
+/// Intermediate decision made while decoding a diagnostic report, as recorded
+/// by `--record`.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub enum Decision {
+    /// Step taken to cull the diagnostic report down to the single row that
+    /// determines `rating`.
+    Culling { rating: Rating, step: CullingStep },
+    /// Least and most common bit in each column of the diagnostic report,
+    /// which make up the epsilon and gamma rates respectively.
+    PowerConsumption {
+        epsilon_bits: BitSequence,
+        gamma_bits: BitSequence,
+    },
+}
+
+/// Enumerates the life support ratings found by culling a diagnostic report.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Rating {
+    /// Rating kept by the least common bit in each column.
+    Co2Scrubber,
+    /// Rating kept by the most common bit in each column.
+    OxygenGenerator,
+}
+
+impl Rating {
+    /// Returns the bit that rows must have in the column indicated by
+    /// `column_index` of `diagnostic_report` to count towards this [Rating].
+    fn bit_in(&self, diagnostic_report: &BinaryGrid, column_index: usize) -> Bit {
+        match self {
+            Rating::Co2Scrubber => co2_scrubber_bit_in(diagnostic_report, column_index),
+            Rating::OxygenGenerator => oxygen_generator_bit_in(diagnostic_report, column_index),
+        }
+    }
+}
+
+/// Returns every [Decision] made while decoding `diagnostic_report`, in order:
+/// the bits of its power consumption, followed by each step of culling it for
+/// the oxygen generator rating and then for the CO2 scrubber rating.
+pub fn decisions_of(diagnostic_report: &BinaryGrid) -> Vec<Decision> {
+    let mut decisions = vec![Decision::PowerConsumption {
+        epsilon_bits: diagnostic_report.least_common_bit_in_each_column(),
+        gamma_bits: diagnostic_report.most_common_bit_in_each_column(),
+    }];
+
+    for rating in [Rating::OxygenGenerator, Rating::Co2Scrubber] {
+        decisions.extend(
+            diagnostic_report
+                .culling_steps(|remaining: &BinaryGrid, column_index| {
+                    rating.bit_in(remaining, column_index)
+                })
+                .map(|step| Decision::Culling { rating, step }),
+        );
+    }
+
+    decisions
+}
@@ -0,0 +1,77 @@
+use aoc_common::table::Table;
+use day_3::binary_grid::{BinaryGrid, Bit};
+
+/// Prints how many ones and zeroes each column of `diagnostic_report` has,
+/// along with the bits of the gamma and epsilon rates that those counts
+/// decide.
+pub fn narrate_power_consumption(diagnostic_report: &BinaryGrid) {
+    let mut table = Table::new();
+
+    table.header(["Position", "Ones", "Zeroes", "Gamma bit", "Epsilon bit"]);
+    for column_index in 0..diagnostic_report.columns() {
+        let bit_label =
+            |bit: Option<Bit>| bit.map_or("tie".to_string(), |bit| bit.value().to_string());
+
+        table.row([
+            (column_index + 1).to_string(),
+            diagnostic_report
+                .count_of_bit_in_column(Bit::One, column_index)
+                .to_string(),
+            diagnostic_report
+                .count_of_bit_in_column(Bit::Zero, column_index)
+                .to_string(),
+            bit_label(diagnostic_report.most_common_bit_in_column(column_index)),
+            bit_label(diagnostic_report.least_common_bit_in_column(column_index)),
+        ]);
+    }
+
+    table.separator().print();
+}
+
+/// Prints each step of culling `diagnostic_report` down to the single row
+/// that determines the named rating, keeping the rows whose bits match the
+/// ones picked by `select_bit`.
+pub fn narrate_rating(
+    rating_name: &str,
+    diagnostic_report: &BinaryGrid,
+    select_bit: fn(&BinaryGrid, usize) -> Bit,
+) {
+    let mut remaining = diagnostic_report.clone();
+    let mut table = Table::new();
+
+    table.title(format!("Finding the {} rating:", rating_name));
+    for step in diagnostic_report.culling_steps(select_bit) {
+        table.row([
+            format!("Position {}", step.column_index + 1),
+            format!("{} ones, {} zeroes", step.ones, step.zeroes),
+            format!(
+                "keeping the {} rows with a {}",
+                step.remaining.rows(),
+                step.kept_bit.value()
+            ),
+        ]);
+
+        remaining = step.remaining;
+    }
+
+    match remaining.row(0) {
+        Some(row) => {
+            let serialized_row = row.to_string();
+
+            table.title(format!(
+                "{} is left, so the {} rating is {}.",
+                serialized_row,
+                rating_name,
+                u32::from(row)
+            ));
+        }
+        None => {
+            table.warning(format!(
+                "No rows are left, so there is no {} rating.",
+                rating_name
+            ));
+        }
+    }
+
+    table.separator().print();
+}
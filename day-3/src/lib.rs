@@ -0,0 +1,28 @@
+//! Solves the "Binary Diagnostic" puzzle.
+//!
+//! Without the default `std` feature, only the [binary_grid] bit aggregation
+//! is built, without parsing, and it only needs `alloc`, so that it can run
+//! on targets without an operating system.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+extern crate alloc;
+extern crate anyhow;
+extern crate aoc_common;
+#[cfg(feature = "arena")]
+extern crate bumpalo;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
+#[cfg(feature = "serde")]
+extern crate serde;
+extern crate thiserror;
+extern crate tracing;
+
+pub mod binary_grid;
+pub mod fixtures;
+pub mod life_support;
+#[cfg(feature = "pyo3")]
+pub mod python;
+#[cfg(feature = "std")]
+pub mod solver;
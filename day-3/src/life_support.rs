@@ -0,0 +1,50 @@
+use crate::binary_grid::{BinaryGrid, Bit};
+
+use tracing::instrument;
+
+/// Returns the CO2 scrubber rating of the specified `diagnostic_report`,
+/// returning [Option::None] if no such rating exists.
+#[instrument(skip_all)]
+pub fn co2_scrubber_rating_of(diagnostic_report: &BinaryGrid) -> Option<u32> {
+    rating_of(diagnostic_report, co2_scrubber_bit_in)
+}
+
+/// Returns the bit that rows must have in the column indicated by
+/// `column_index` to count towards the CO2 scrubber rating: the least common
+/// bit, or [Bit::Zero] if both are equally common.
+pub fn co2_scrubber_bit_in(diagnostic_report: &BinaryGrid, column_index: usize) -> Bit {
+    diagnostic_report
+        .least_common_bit_in_column(column_index)
+        .unwrap_or(Bit::Zero)
+}
+
+/// Returns the Oxygen generator rating of the specified `diagnostic_report`,
+/// returning [Option::None] if no such rating exists.
+#[instrument(skip_all)]
+pub fn oxygen_generator_rating_of(diagnostic_report: &BinaryGrid) -> Option<u32> {
+    rating_of(diagnostic_report, oxygen_generator_bit_in)
+}
+
+/// Returns the bit that rows must have in the column indicated by
+/// `column_index` to count towards the oxygen generator rating: the most
+/// common bit, or [Bit::One] if both are equally common.
+pub fn oxygen_generator_bit_in(diagnostic_report: &BinaryGrid, column_index: usize) -> Bit {
+    diagnostic_report
+        .most_common_bit_in_column(column_index)
+        .unwrap_or(Bit::One)
+}
+
+/// Returns the value of the row left after culling `diagnostic_report` with
+/// the bits picked by `select_bit`, returning [Option::None] if no row is
+/// left.
+fn rating_of(
+    diagnostic_report: &BinaryGrid,
+    select_bit: fn(&BinaryGrid, usize) -> Bit,
+) -> Option<u32> {
+    diagnostic_report
+        .culling_steps(select_bit)
+        .last()
+        .map_or_else(|| diagnostic_report.clone(), |step| step.remaining)
+        .row(0)
+        .map(|row| -> u32 { row.into() })
+}
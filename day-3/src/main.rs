@@ -1,116 +1,307 @@
 extern crate anyhow;
-extern crate tokio;
+extern crate aoc_common;
+#[cfg(feature = "arena")]
+extern crate bumpalo;
+extern crate clap;
+extern crate day_3;
+#[cfg(feature = "record")]
+extern crate serde;
+extern crate tracing;
 
-mod binary_grid;
+mod args;
+#[cfg(feature = "record")]
+mod decision;
+mod explain;
 
 use anyhow::{Context, Result};
-use binary_grid::{BinaryGrid, BinaryGridCullOptions, Bit};
-use std::env::current_dir;
-use tokio::fs::File;
-use tokio::io::AsyncReadExt;
-
-#[tokio::main]
-async fn main() -> Result<()> {
-    let diagnostic_report = read_diagnostic_report("files/input.txt").await?;
-
-    let epsilon_rate: u32 = diagnostic_report.least_common_bit_in_each_column().into();
-    let gamma_rate: u32 = diagnostic_report.most_common_bit_in_each_column().into();
-
-    println!("Epsilon rate:\t{}", epsilon_rate);
-    println!("Gamma rate:\t{}", gamma_rate);
-    println!("Product:\t{}", epsilon_rate * gamma_rate);
-
-    let co2_scrubber_rating = co2_scrubber_rating_of(&diagnostic_report)
-        .context("Failed to read CO2 generator rating")?;
-    let oxygen_generator_rating = oxygen_generator_rating_of(&diagnostic_report)
-        .context("Failed to read oxygen generator rating")?;
-
-    println!();
-    println!("CO2 scrubber rating:\t\t{}", co2_scrubber_rating);
-    println!("Oxygen generator rating:\t{}", oxygen_generator_rating);
-    println!(
-        "Product:\t\t\t{}",
-        co2_scrubber_rating * oxygen_generator_rating
-    );
-
-    Ok(())
+use aoc_common::args::ParseArgs;
+use aoc_common::args::Part;
+use aoc_common::batch;
+use aoc_common::completions;
+use aoc_common::concurrent;
+use aoc_common::error::{InvalidInput, NoSolution};
+use aoc_common::input;
+use aoc_common::output;
+#[cfg(feature = "record")]
+use aoc_common::recording;
+use aoc_common::render::Renderable;
+use aoc_common::shuffle;
+use aoc_common::table::Table;
+use aoc_common::timing::Timings;
+use aoc_common::watch;
+use args::Args;
+use day_3::binary_grid::BinaryGrid;
+use day_3::life_support::{
+    co2_scrubber_bit_in, co2_scrubber_rating_of, oxygen_generator_bit_in,
+    oxygen_generator_rating_of,
+};
+#[cfg(feature = "record")]
+use decision::Decision;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    #[cfg(feature = "embedded-inputs")]
+    input::embed(include_str!("../files/input.txt"));
+
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
 }
 
-/// Returns the CO2 scrubber rating of the specified `diagnostic_report`,
-/// returning [Option::None] if no such rating exists.
-fn co2_scrubber_rating_of(diagnostic_report: &BinaryGrid) -> Option<u32> {
-    let mut column_index = 0;
-    let mut culled_diagnostic_report = diagnostic_report.clone();
-    while column_index < culled_diagnostic_report.columns() && culled_diagnostic_report.rows() > 1 {
-        let least_common_bit = culled_diagnostic_report
-            .least_common_bit_in_column(column_index)
-            .unwrap_or(Bit::Zero);
-
-        culled_diagnostic_report = culled_diagnostic_report.cull(BinaryGridCullOptions {
-            rows_with_bits_matching: least_common_bit,
-            at_index: column_index,
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return batch::write_answers_csv(
+            inputs,
+            args.common.out.as_deref(),
+            args.common.part,
+            |input, part| {
+                answers_table(
+                    &BinaryGrid::deserialize(input).context(InvalidInput)?,
+                    Some(part),
+                )
+            },
+        );
+    }
+
+    if args.common.watch {
+        return watch::watch_answers(&args.common, |input| {
+            answers_table(
+                &BinaryGrid::deserialize(input).context(InvalidInput)?,
+                args.common.part,
+            )
         });
+    }
+
+    if args.common.visualize {
+        let input = input::load(args.common.input_source())?;
+        let binary_grid = BinaryGrid::deserialize(&input).context(InvalidInput)?;
+        print!("{}", binary_grid.render(output::is_color_enabled()));
+
+        return Ok(());
+    }
+
+    if let Some(seed) = args.shuffle {
+        let input = input::load(args.common.input_source())?;
 
-        column_index = column_index + 1;
+        return args.common.print(&shuffle::check_answers_unchanged(
+            &input,
+            &shuffle::shuffle_lines(&input, seed),
+            seed,
+            |input| {
+                answers_table(
+                    &BinaryGrid::deserialize(input).context(InvalidInput)?,
+                    args.common.part,
+                )
+            },
+        )?);
     }
 
-    culled_diagnostic_report
-        .row(0)
-        .map(|row| -> u32 { row.into() })
+    #[cfg(feature = "record")]
+    if let Some(recording_path) = &args.record {
+        let input = input::load(args.common.input_source())?;
+        let diagnostic_report = BinaryGrid::deserialize(&input).context(InvalidInput)?;
+
+        return args.common.print(&recording::record(
+            3,
+            diagnostic_report,
+            recording_path,
+            decisions_and_answers_of,
+        )?);
+    }
+
+    #[cfg(feature = "record")]
+    if let Some(recording_path) = &args.replay {
+        return args.common.print(&recording::replay(
+            3,
+            recording_path,
+            decisions_and_answers_of,
+        )?);
+    }
+
+    let mut timings = Timings::new();
+    let diagnostic_report = input::load_parsed(args.common.input_source(), &mut timings)?;
+
+    if args.explain {
+        explain::narrate_power_consumption(&diagnostic_report);
+        explain::narrate_rating(
+            "oxygen generator",
+            &diagnostic_report,
+            oxygen_generator_bit_in,
+        );
+        explain::narrate_rating("CO2 scrubber", &diagnostic_report, co2_scrubber_bit_in);
+    }
+
+    args.common.print_answers(
+        3,
+        &concurrent::solve_parts(args.common.part, timings, |part| {
+            answers_table(&diagnostic_report, Some(part))
+        })?,
+    )
 }
 
-/// Returns the Oxygen generator rating of the specified `diagnostic_report`,
-/// returning [Option::None] if no such rating exists.
-fn oxygen_generator_rating_of(diagnostic_report: &BinaryGrid) -> Option<u32> {
-    let mut column_index = 0;
-    let mut culled_diagnostic_report = diagnostic_report.clone();
-    while column_index < culled_diagnostic_report.columns() && culled_diagnostic_report.rows() > 1 {
-        let most_common_bit = culled_diagnostic_report
-            .most_common_bit_in_column(column_index)
-            .unwrap_or(Bit::One);
-
-        culled_diagnostic_report = culled_diagnostic_report.cull(BinaryGridCullOptions {
-            rows_with_bits_matching: most_common_bit,
-            at_index: column_index,
-        });
+/// Returns a [Table] of the answers to the puzzle for the specified
+/// `diagnostic_report`, including only the specified `part` if there is one
+/// and both parts otherwise.
+fn answers_table(diagnostic_report: &BinaryGrid, part: Option<Part>) -> Result<Table> {
+    let mut table = Table::new();
 
-        column_index = column_index + 1;
+    if Part::One.is_selected_by(part) {
+        let epsilon_rate: u32 = diagnostic_report.least_common_bit_in_each_column().into();
+        let gamma_rate: u32 = diagnostic_report.most_common_bit_in_each_column().into();
+
+        table
+            .entry("Epsilon rate", epsilon_rate)
+            .entry("Gamma rate", gamma_rate)
+            .answer("Product", epsilon_rate * gamma_rate);
     }
 
-    culled_diagnostic_report
-        .row(0)
-        .map(|row| -> u32 { row.into() })
+    if Part::Two.is_selected_by(part) {
+        let co2_scrubber_rating = co2_scrubber_rating_of(diagnostic_report)
+            .context(NoSolution::new("Failed to read CO2 generator rating"))?;
+        let oxygen_generator_rating = oxygen_generator_rating_of(diagnostic_report)
+            .context(NoSolution::new("Failed to read oxygen generator rating"))?;
+
+        if !table.is_empty() {
+            table.separator();
+        }
+        table
+            .entry("CO2 scrubber rating", co2_scrubber_rating)
+            .entry("Oxygen generator rating", oxygen_generator_rating)
+            .answer("Product", co2_scrubber_rating * oxygen_generator_rating);
+    }
+
+    Ok(table)
 }
 
-/// Reads the contents of the "diagnostic report" input file as a
-/// newline-separated list of binary numbers.
-async fn read_diagnostic_report(diagnostic_report_file_path: &str) -> Result<BinaryGrid> {
-    let pwd = current_dir().context("Failed to read current working directory")?;
-    let diagnostic_report_file_path_buf = pwd.join(diagnostic_report_file_path);
-
-    let mut diagnostic_report_file = File::open(&diagnostic_report_file_path_buf)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to open file at path \"{}\"",
-                diagnostic_report_file_path_buf.display()
-            )
-        })?;
-    let mut raw_diagnostic_report_file_contents = vec![];
-
-    diagnostic_report_file
-        .read_to_end(&mut raw_diagnostic_report_file_contents)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to read file at path \"{}\"",
-                diagnostic_report_file_path_buf.display()
-            )
-        })?;
+/// Returns every [Decision] made while decoding `diagnostic_report`, along
+/// with a [Table] of the answers to both parts of the puzzle, as recorded by
+/// `--record`.
+#[cfg(feature = "record")]
+fn decisions_and_answers_of(diagnostic_report: &BinaryGrid) -> Result<(Vec<Decision>, Table)> {
+    Ok((
+        decision::decisions_of(diagnostic_report),
+        answers_table(diagnostic_report, None)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers;
+    use aoc_common::diagnostic;
+    use aoc_common::input::DeserializeLines;
+    use day_3::binary_grid::{BinaryGridParseError, Bit};
+    use day_3::fixtures::{INPUT, SAMPLE};
+    use insta::assert_snapshot;
+
+    #[test]
+    fn decodes_power_consumption_of_sample() {
+        let diagnostic_report = BinaryGrid::deserialize(SAMPLE).unwrap();
+        let epsilon_rate: u32 = diagnostic_report.least_common_bit_in_each_column().into();
+        let gamma_rate: u32 = diagnostic_report.most_common_bit_in_each_column().into();
+
+        assert_eq!(epsilon_rate, 9);
+        assert_eq!(gamma_rate, 22);
+    }
+
+    #[test]
+    fn counts_ones_in_each_column_of_input() {
+        let diagnostic_report = BinaryGrid::deserialize(INPUT).unwrap();
+        let ones = diagnostic_report.count_ones_in_each_column();
+
+        assert_eq!(ones.len(), diagnostic_report.columns());
+        for (column_index, column_ones) in ones.iter().enumerate() {
+            assert_eq!(
+                *column_ones,
+                diagnostic_report.count_of_bit_in_column(Bit::One, column_index)
+            );
+        }
+
+        #[cfg(feature = "simd")]
+        assert_eq!(diagnostic_report.count_ones_in_each_column_simd(), ones);
+    }
+
+    #[test]
+    fn deserializes_lines_like_whole_input() {
+        let rows = SAMPLE
+            .lines()
+            .map(BinaryGrid::deserialize_line)
+            .collect::<Result<Vec<Vec<Bit>>>>()
+            .unwrap();
+
+        assert_eq!(
+            BinaryGrid::from_lines(rows),
+            BinaryGrid::deserialize(SAMPLE).unwrap()
+        );
+        assert!(BinaryGrid::deserialize_line("10x1").is_err());
+    }
+
+    #[test]
+    fn points_at_invalid_bits() {
+        let serialized_diagnostic_report = "00100\n11210\n10110";
+        let error = BinaryGrid::deserialize(serialized_diagnostic_report).unwrap_err();
+        assert!(matches!(error, BinaryGridParseError::InvalidBit(_)));
 
-    let diagnostic_report_file_contents =
-        String::from_utf8_lossy(&raw_diagnostic_report_file_contents);
+        let error = anyhow::Error::from(error);
+        let input_diagnostic = diagnostic::find(&error).unwrap();
 
-    BinaryGrid::deserialize(&diagnostic_report_file_contents)
-        .context("Failed to interpret diagnostic report as a serialized binary grid")
+        assert_eq!(input_diagnostic.to_string(), "'2' is not a valid bit");
+        assert_eq!(input_diagnostic.span(), 8..9);
+    }
+
+    #[test]
+    fn decodes_life_support_ratings_of_sample() {
+        let diagnostic_report = BinaryGrid::deserialize(SAMPLE).unwrap();
+
+        assert_eq!(co2_scrubber_rating_of(&diagnostic_report), Some(10));
+        assert_eq!(oxygen_generator_rating_of(&diagnostic_report), Some(23));
+    }
+
+    #[cfg(feature = "record")]
+    #[test]
+    fn replays_recorded_sample() {
+        let recording_path =
+            std::env::temp_dir().join(format!("aoc-day-3-recording-{}.json", std::process::id()));
+        let diagnostic_report = BinaryGrid::deserialize(SAMPLE).unwrap();
+
+        let recorded_table = recording::record(
+            3,
+            diagnostic_report,
+            &recording_path,
+            decisions_and_answers_of,
+        )
+        .unwrap();
+        let replayed_table =
+            recording::replay(3, &recording_path, decisions_and_answers_of).unwrap();
+
+        assert_eq!(
+            replayed_table.answers().collect::<Vec<&str>>(),
+            recorded_table.answers().collect::<Vec<&str>>()
+        );
+
+        std::fs::remove_file(&recording_path).unwrap();
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        let diagnostic_report = BinaryGrid::deserialize(SAMPLE).unwrap();
+
+        assert_snapshot!(answers_table(&diagnostic_report, None).unwrap().to_string());
+    }
+
+    #[test]
+    fn agrees_with_local_answers_to_input() {
+        answers::assert_matches_local_answers(
+            3,
+            &answers_table(&BinaryGrid::deserialize(INPUT).unwrap(), None).unwrap(),
+        );
+    }
 }
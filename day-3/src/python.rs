@@ -0,0 +1,91 @@
+//! Python bindings for [BinaryGrid], added to a Python module by [register].
+
+use crate::binary_grid::{BinaryGrid, BinaryGridCullOptions, Bit};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// [BinaryGrid] as it appears to Python, where it is named `BinaryGrid`.
+#[pyclass(name = "BinaryGrid", frozen)]
+pub struct PyBinaryGrid(BinaryGrid);
+
+#[pymethods]
+impl PyBinaryGrid {
+    /// Interprets a newline-delimited string of binary numbers as a
+    /// `BinaryGrid`, raising `ValueError` if it is malformed.
+    #[staticmethod]
+    fn deserialize(serialized_binary_grid: &str) -> PyResult<PyBinaryGrid> {
+        BinaryGrid::deserialize(serialized_binary_grid)
+            .map(PyBinaryGrid)
+            .map_err(|error| PyValueError::new_err(format!("{:#}", error)))
+    }
+
+    /// Returns how many columns of bits this `BinaryGrid` has.
+    fn columns(&self) -> usize {
+        self.0.columns()
+    }
+
+    /// Returns how many rows have a one in each column, in order.
+    fn count_ones_in_each_column(&self) -> Vec<usize> {
+        self.0.count_ones_in_each_column()
+    }
+
+    /// Returns a `BinaryGrid` of only the rows whose bit at `column_index`
+    /// is `bit` (0 or 1).
+    fn cull(&self, bit: u8, column_index: usize) -> PyResult<PyBinaryGrid> {
+        let rows_with_bits_matching = match bit {
+            0 => Bit::Zero,
+            1 => Bit::One,
+            _ => return Err(PyValueError::new_err(format!("{} is not a bit", bit))),
+        };
+
+        Ok(PyBinaryGrid(self.0.cull(BinaryGridCullOptions {
+            rows_with_bits_matching,
+            at_index: column_index,
+        })))
+    }
+
+    /// Returns the least common bit of each column as a binary number.
+    fn least_common_bits(&self) -> String {
+        self.0.least_common_bit_in_each_column().to_string()
+    }
+
+    /// Returns the most common bit of each column as a binary number.
+    fn most_common_bits(&self) -> String {
+        self.0.most_common_bit_in_each_column().to_string()
+    }
+
+    /// Returns the row at `row_index` as a binary number, or `None` if there
+    /// is no such row.
+    fn row(&self, row_index: usize) -> Option<String> {
+        self.0.row(row_index).map(|row| row.to_string())
+    }
+
+    /// Returns how many rows of bits this `BinaryGrid` has.
+    fn rows(&self) -> usize {
+        self.0.rows()
+    }
+
+    /// Returns this `BinaryGrid` as a newline-delimited string of binary
+    /// numbers.
+    fn serialize(&self) -> String {
+        self.0.serialize()
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.rows()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BinaryGrid(rows={}, columns={})",
+            self.0.rows(),
+            self.0.columns()
+        )
+    }
+}
+
+/// Adds every class bound here to the Python `module`.
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyBinaryGrid>()
+}
@@ -0,0 +1,66 @@
+use crate::binary_grid::BinaryGrid;
+use crate::life_support;
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::input::FromInput;
+use aoc_common::shuffle;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Binary Diagnostic" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BinaryDiagnosticSolver;
+
+impl Solver for BinaryDiagnosticSolver {
+    type Parsed = BinaryGrid;
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input: &str) -> Result<BinaryGrid> {
+        BinaryGrid::from_input(input)
+    }
+
+    fn part_one(&self, diagnostic_report: &BinaryGrid) -> Result<Answer> {
+        let epsilon_rate: u32 = diagnostic_report.least_common_bit_in_each_column().into();
+        let gamma_rate: u32 = diagnostic_report.most_common_bit_in_each_column().into();
+
+        Ok((epsilon_rate * gamma_rate).into())
+    }
+
+    fn part_two(&self, diagnostic_report: &BinaryGrid) -> Result<Answer> {
+        let co2_scrubber_rating = life_support::co2_scrubber_rating_of(diagnostic_report)
+            .context(NoSolution::new("Failed to read CO2 generator rating"))?;
+        let oxygen_generator_rating =
+            life_support::oxygen_generator_rating_of(diagnostic_report)
+                .context(NoSolution::new("Failed to read oxygen generator rating"))?;
+
+        Ok((co2_scrubber_rating * oxygen_generator_rating).into())
+    }
+
+    fn shuffle(&self, input: &str, seed: u64) -> Option<String> {
+        Some(shuffle::shuffle_lines(input, seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = BinaryDiagnosticSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(BinaryDiagnosticSolver.part_one(&parsed).unwrap(), "198");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = BinaryDiagnosticSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(BinaryDiagnosticSolver.part_two(&parsed).unwrap(), "230");
+    }
+}
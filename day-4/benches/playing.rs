@@ -0,0 +1,29 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day_4::bingo_game::BingoGame;
+
+/// Personal puzzle input checked into this repository.
+const INPUT: &str = include_str!("../files/input.txt");
+
+fn bench_deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize");
+
+    group.throughput(Throughput::Bytes(INPUT.len() as u64));
+    group.bench_function("input", |b| {
+        b.iter(|| BingoGame::deserialize(INPUT).unwrap())
+    });
+
+    group.finish();
+}
+
+fn bench_play(c: &mut Criterion) {
+    let bingo_game = BingoGame::deserialize(INPUT).unwrap();
+    let mut group = c.benchmark_group("play");
+
+    group.bench_function("first_winner", |b| b.iter(|| bingo_game.play()));
+    group.bench_function("last_winner", |b| b.iter(|| bingo_game.play_exhaustively()));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_deserialize, bench_play);
+criterion_main!(benches);
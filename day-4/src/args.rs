@@ -0,0 +1,35 @@
+use aoc_common::args::CommonArgs;
+use clap::Parser;
+#[cfg(feature = "record")]
+use std::path::PathBuf;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Finds the first and last boards to win a game of bingo against the giant squid")]
+pub struct Args {
+    /// Narrates how the answers are found, step by step, before printing
+    /// them.
+    #[clap(long)]
+    pub explain: bool,
+
+    /// Writes the parsed game, along with the outcome of every draw, to the
+    /// specified file for a later `--replay`, solving both parts.
+    #[cfg(feature = "record")]
+    #[clap(long, value_name = "FILE", conflicts_with_all = ["inputs", "replay", "watch"])]
+    pub record: Option<PathBuf>,
+
+    /// Plays the game recorded to the specified file by `--record` again
+    /// instead of reading the input, failing at the first draw whose outcome
+    /// differs from the recording.
+    #[cfg(feature = "record")]
+    #[clap(long, value_name = "FILE", conflicts_with_all = ["inputs", "watch"])]
+    pub replay: Option<PathBuf>,
+
+    /// Solves the puzzle a second time with the order of its boards
+    /// shuffled by the specified seed, failing if the answers change.
+    #[clap(long, value_name = "SEED")]
+    pub shuffle: Option<u64>,
+
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
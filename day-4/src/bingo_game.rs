@@ -1,57 +1,99 @@
-use anyhow::{anyhow, Context, Result};
-use lazy_static::lazy_static;
-use regex::Regex;
-use std::collections::{HashMap, HashSet};
-
-lazy_static! {
-    /// Regular expression designed to match empty lines.
-    static ref EMPTY_LINE_PATTERN: Regex =
-    Regex::new( r"\r?\n\r?\n").unwrap();
-
-    /// Regular expression designed to match numbers.
-    static ref NUMBER_PATTERN: Regex =
-        Regex::new( r"\d+").unwrap();
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use aoc_common::input::FromInput;
+use aoc_common::render::{Cell, Color, Renderable, Style};
+#[cfg(feature = "std")]
+use aoc_common::{cancellation, parse};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use thiserror::Error;
+use tracing::{debug, instrument};
+
+/// Number of numbers on every [BingoGameBoard].
+pub const BOARD_SIZE: usize = 25;
+
+/// Outcome of drawing a single number in a [BingoGame].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct BingoDraw {
+    /// Indices of the boards on which the drawn number was marked.
+    pub marked_board_indices: Vec<usize>,
+    /// Number that was drawn.
+    pub number: u32,
+    /// Indices of the boards that got bingo for the first time with this
+    /// draw.
+    pub winning_board_indices: Vec<usize>,
+}
+
+/// Enumerates the ways that text can fail to be interpreted as a
+/// [BingoGame].
+#[cfg(feature = "std")]
+#[derive(Debug, Error)]
+pub enum BingoParseError {
+    /// Game board has numbers that are not all unsigned integers.
+    #[error("Failed to deserialize the numbers of a game board")]
+    InvalidBoardNumbers(#[source] anyhow::Error),
+    /// Numbers selected for the game are not all unsigned integers.
+    #[error("Failed to deserialize number selections")]
+    InvalidNumberSelections(#[source] anyhow::Error),
+    /// Number selections are not followed by any game boards.
+    #[error("Serialized bingo game had no boards")]
+    NoBoards,
+    /// Game board does not have exactly [BOARD_SIZE] numbers.
+    #[error("Serialized game board had {found} numbers (not {BOARD_SIZE})")]
+    WrongBoardSize {
+        /// Number of numbers that the game board had.
+        found: usize,
+    },
 }
 
 /// Represents a single bingo game.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct BingoGame {
     /// Game boards in this bingo game.
     boards: Vec<BingoGameBoard>,
     /// Sequence of numbers selected for this bingo game.
-    number_selections: Vec<u8>,
+    number_selections: Vec<u32>,
 }
 
 impl BingoGame {
+    /// Creates a [BingoGame] played on `boards`, in order, with the numbers of
+    /// `number_selections` drawn in order.
+    pub fn new(number_selections: Vec<u32>, boards: Vec<BingoGameBoard>) -> BingoGame {
+        BingoGame {
+            boards,
+            number_selections,
+        }
+    }
+
     /// Interprets an empty line-delimited [str] of bingo game data as a
     /// [BingoGame].
-    pub fn deserialize(serialized_bingo_game: &str) -> Result<Self> {
-        let line_groups = EMPTY_LINE_PATTERN
-            .split(serialized_bingo_game)
-            .collect::<Vec<&str>>();
+    #[cfg(feature = "std")]
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_bingo_game: &str) -> Result<Self, BingoParseError> {
+        let line_groups = parse::paragraphs(serialized_bingo_game).collect::<Vec<&str>>();
 
         if line_groups.len() < 2 {
-            return Err(anyhow!("Serialized bingo game had no boards"));
+            return Err(BingoParseError::NoBoards);
         }
 
         let serialized_number_selections = line_groups[0];
-        let number_selections = NUMBER_PATTERN
-            .find_iter(serialized_number_selections)
-            .map(|raw_number| {
-                raw_number
-                    .as_str()
-                    .parse::<u8>()
-                    .with_context(|| format!("\"{}\" is not a valid number", raw_number.as_str()))
-            })
-            .collect::<Result<Vec<u8>>>()
-            .context("Failed deserialize number selections")?;
+        let number_selections = parse::separated(serialized_number_selections, ',', |token| {
+            parse::parse_number::<u32>(token, serialized_number_selections)
+        })
+        .map_err(BingoParseError::InvalidNumberSelections)?;
 
         let boards = line_groups
             .iter()
             .skip(1)
             .map(|line_group| BingoGameBoard::deserialize(line_group))
-            .collect::<Result<Vec<BingoGameBoard>>>()
-            .context("Failed deserialize game boards")?;
+            .collect::<Result<Vec<BingoGameBoard>, BingoParseError>>()?;
 
         Ok(BingoGame {
             boards,
@@ -59,14 +101,63 @@ impl BingoGame {
         })
     }
 
+    /// Returns an [Iterator] over the outcome of drawing each of the numbers
+    /// selected for this [BingoGame], in order, with every board playing on
+    /// after it wins.
+    pub fn draws(&self) -> impl Iterator<Item = BingoDraw> + '_ {
+        let mut boards = self.boards.clone();
+
+        self.number_selections.iter().map(move |number| {
+            let mut marked_board_indices = vec![];
+            let mut winning_board_indices = vec![];
+
+            for (board_index, board) in boards.iter_mut().enumerate() {
+                if !board.index_by_number.contains_key(number) {
+                    continue;
+                }
+
+                let had_bingo = board.has_bingo;
+                board.select(*number);
+
+                marked_board_indices.push(board_index);
+                if board.has_bingo && !had_bingo {
+                    winning_board_indices.push(board_index);
+                }
+            }
+
+            BingoDraw {
+                marked_board_indices,
+                number: *number,
+                winning_board_indices,
+            }
+        })
+    }
+
+    /// Returns how many boards this [BingoGame] has.
+    pub fn board_count(&self) -> usize {
+        self.boards.len()
+    }
+
     /// Plays Bingo, returning a tuple of the **first** winning number and the
-    /// [BingoGameBoard] that won.
-    pub fn play(&mut self) -> Option<(u8, BingoGameBoard)> {
+    /// [BingoGameBoard] that won, or [Option::None] if no board wins before
+    /// the numbers run out or, with the `std` feature, the game is cancelled
+    /// through `aoc_common::cancellation`.
+    #[instrument(skip_all)]
+    pub fn play(&self) -> Option<(u32, BingoGameBoard)> {
+        let mut boards = self.boards.clone();
+
         for number in self.number_selections.iter() {
-            for board in self.boards.iter_mut() {
+            #[cfg(feature = "std")]
+            if cancellation::is_cancelled() {
+                return None;
+            }
+
+            for (board_index, board) in boards.iter_mut().enumerate() {
                 board.select(*number);
 
                 if board.has_bingo {
+                    debug!(board_index, number, "board got bingo");
+
                     return Some((*number, board.clone()));
                 }
             }
@@ -76,27 +167,37 @@ impl BingoGame {
     }
 
     /// Plays Bingo, returning a tuple of the **last** winning number and the
-    /// [BingoGameBoard] that won.
-    pub fn play_exhaustively(&mut self) -> Option<(u8, BingoGameBoard)> {
-        let mut boards = self.boards.clone();
+    /// [BingoGameBoard] that won, or [Option::None] if some board never wins
+    /// before the numbers run out or, with the `std` feature, the game is
+    /// cancelled through `aoc_common::cancellation`.
+    #[instrument(skip_all)]
+    pub fn play_exhaustively(&self) -> Option<(u32, BingoGameBoard)> {
+        let mut boards = self.boards.iter().cloned().enumerate().collect::<Vec<_>>();
         let mut number_index = 0;
 
-        while boards.len() > 0 && number_index < self.number_selections.len() {
+        while !boards.is_empty() && number_index < self.number_selections.len() {
+            #[cfg(feature = "std")]
+            if cancellation::is_cancelled() {
+                return None;
+            }
+
             let number = self.number_selections[number_index];
 
-            let mut board_index = 0;
-            while board_index < boards.len() {
-                let board = &mut boards[board_index];
+            let mut remaining_board_index = 0;
+            while remaining_board_index < boards.len() {
+                let (board_index, board) = &mut boards[remaining_board_index];
                 board.select(number);
 
                 if board.has_bingo {
+                    debug!(board_index, number, "board got bingo");
+
                     if boards.len() == 1 {
-                        return Some((number, boards[0].clone()));
+                        return Some((number, boards[0].1.clone()));
                     }
 
-                    boards.remove(board_index);
+                    boards.remove(remaining_board_index);
                 } else {
-                    board_index += 1;
+                    remaining_board_index += 1;
                 }
             }
 
@@ -105,68 +206,124 @@ impl BingoGame {
 
         None
     }
+
+    /// Returns this [BingoGame] as an empty line-delimited [String] of the
+    /// numbers drawn followed by every board, which
+    /// [BingoGame::deserialize] interprets as an equal [BingoGame].
+    pub fn serialize(&self) -> String {
+        let serialized_number_selections = self
+            .number_selections
+            .iter()
+            .map(|number| number.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        [serialized_number_selections]
+            .into_iter()
+            .chain(self.boards.iter().map(BingoGameBoard::serialize))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromInput for BingoGame {
+    fn from_input(input: &str) -> anyhow::Result<BingoGame> {
+        Ok(BingoGame::deserialize(input)?)
+    }
+}
+
+impl Renderable for BingoGame {
+    /// Returns the [Cell]s of every board of this [BingoGame], one after
+    /// another with an empty row between each of them.
+    fn cells(&self) -> Vec<Vec<Cell>> {
+        self.boards
+            .iter()
+            .enumerate()
+            .flat_map(|(board_index, board)| {
+                let separator = if board_index > 0 {
+                    vec![vec![]]
+                } else {
+                    vec![]
+                };
+
+                separator.into_iter().chain(board.cells())
+            })
+            .collect()
+    }
+
+    fn column_gap(&self) -> usize {
+        1
+    }
 }
 
 /// Represents a single bingo game.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct BingoGameBoard {
     /// `true` if this is a winning [BingoGameBoard].
     has_bingo: bool,
     /// Numbers in this [BingoGameBoard] indexed by their respective indices with in [numbers].
-    index_by_number: HashMap<u8, usize>,
+    index_by_number: BTreeMap<u32, usize>,
     /// Sequence of numbers selected for this bingo game.
-    numbers: Vec<u8>,
+    numbers: Vec<u32>,
     /// Indices of all selected numbers in this [BingoGameBoard].
     selected_number_indices: Vec<usize>,
 }
 
-impl BingoGameBoard {
-    /// Interprets a 5x5 grid of numbers as a [BingoGameBoard].
-    fn deserialize(serialized_bingo_game_board: &str) -> Result<Self> {
-        let numbers = NUMBER_PATTERN
-            .find_iter(serialized_bingo_game_board)
-            .map(|raw_number| {
-                raw_number
-                    .as_str()
-                    .parse::<u8>()
-                    .with_context(|| format!("\"{}\" is not a valid number", raw_number.as_str()))
-            })
-            .collect::<Result<Vec<u8>>>()
-            .context("Failed to read numbers")?;
-
-        if numbers.len() != 25 {
-            return Err(anyhow!(
-                "Serialized game board had {} numbers (not 25)",
-                numbers.len()
-            ));
-        }
-
+impl From<[u32; BOARD_SIZE]> for BingoGameBoard {
+    /// Creates a [BingoGameBoard] of the given numbers, row by row, none of
+    /// which are selected yet.
+    fn from(numbers: [u32; BOARD_SIZE]) -> Self {
         let index_by_number = numbers
             .iter()
             .enumerate()
             .map(|(i, number)| (*number, i))
-            .collect::<HashMap<u8, usize>>();
+            .collect::<BTreeMap<u32, usize>>();
 
-        Ok(BingoGameBoard {
+        BingoGameBoard {
             has_bingo: false,
             index_by_number,
-            numbers,
+            numbers: numbers.to_vec(),
             selected_number_indices: Vec::new(),
-        })
+        }
+    }
+}
+
+impl BingoGameBoard {
+    /// Interprets a 5x5 grid of numbers as a [BingoGameBoard].
+    #[cfg(feature = "std")]
+    fn deserialize(serialized_bingo_game_board: &str) -> Result<Self, BingoParseError> {
+        let numbers = serialized_bingo_game_board
+            .lines()
+            .map(|line| {
+                parse::whitespace_separated(line, |token| parse::parse_number::<u32>(token, line))
+            })
+            .collect::<anyhow::Result<Vec<Vec<u32>>>>()
+            .map_err(BingoParseError::InvalidBoardNumbers)?
+            .concat();
+
+        let numbers = <[u32; BOARD_SIZE]>::try_from(numbers).map_err(|numbers| {
+            BingoParseError::WrongBoardSize {
+                found: numbers.len(),
+            }
+        })?;
+
+        Ok(BingoGameBoard::from(numbers))
     }
 
     /// Returns a [Vec] containing all of the unselected numbers on this
     /// [BingoGameBoard].
-    pub fn unselected_numbers(&self) -> Vec<u8> {
+    pub fn unselected_numbers(&self) -> Vec<u32> {
         let selected_number_indices =
-            HashSet::<usize>::from_iter(self.selected_number_indices.iter().cloned());
+            BTreeSet::<usize>::from_iter(self.selected_number_indices.iter().cloned());
 
         self.numbers
             .iter()
             .enumerate()
             .filter(|(i, _)| !selected_number_indices.contains(i))
             .map(|(_, number)| *number)
-            .collect::<Vec<u8>>()
+            .collect::<Vec<u32>>()
     }
 
     /// Returns `true` if this [BingoGameBoard] has five numbers selected in a
@@ -192,7 +349,7 @@ impl BingoGameBoard {
             previous_index = *index;
         }
 
-        return false;
+        false
     }
 
     /// Returns `true` if this [BingoGameBoard] has five numbers selected in a
@@ -218,11 +375,26 @@ impl BingoGameBoard {
             }
         }
 
-        return false;
+        false
+    }
+
+    /// Returns this [BingoGameBoard] as five lines of five right-aligned
+    /// numbers each, as it appears in puzzle input.
+    fn serialize(&self) -> String {
+        self.numbers
+            .chunks(5)
+            .map(|row| {
+                row.iter()
+                    .map(|number| format!("{:>2}", number))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
     }
 
     /// Selects the specified `number` on this [BingoGameBoard].
-    fn select(&mut self, number: u8) {
+    fn select(&mut self, number: u32) {
         let index = self.index_by_number.get(&number);
         if index.is_none() {
             return;
@@ -236,3 +408,178 @@ impl BingoGameBoard {
         }
     }
 }
+
+impl Renderable for BingoGameBoard {
+    /// Returns every number of this [BingoGameBoard] as a right-aligned
+    /// [Cell], with the selected numbers standing out from the rest, and
+    /// more so once the board has bingo.
+    fn cells(&self) -> Vec<Vec<Cell>> {
+        let selected_style = match self.has_bingo {
+            true => Style::PLAIN.bold().fg(Color::Green),
+            false => Style::PLAIN.bold().fg(Color::Yellow),
+        };
+
+        self.numbers
+            .chunks(5)
+            .enumerate()
+            .map(|(row_index, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(column_index, number)| {
+                        let style = match self
+                            .selected_number_indices
+                            .contains(&(row_index * 5 + column_index))
+                        {
+                            true => selected_style,
+                            false => Style::PLAIN.dim(),
+                        };
+
+                        Cell::new(format!("{:>2}", number), style)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn column_gap(&self) -> usize {
+        1
+    }
+}
+
+// Games are generated by parsing them, which requires `std`.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Largest number that can be drawn or appear on a board.
+    const MAX_NUMBER: u32 = 99;
+
+    /// Returns a [Strategy] for a [BingoGame] with up to five boards of
+    /// distinct numbers, and up to every number drawn once in any order.
+    fn bingo_game() -> impl Strategy<Value = BingoGame> {
+        let numbers = (0..=MAX_NUMBER).collect::<Vec<u32>>();
+        let board = Just(numbers.clone())
+            .prop_shuffle()
+            .prop_map(|numbers| numbers[..BOARD_SIZE].to_vec());
+
+        (
+            Just(numbers).prop_shuffle(),
+            1..=MAX_NUMBER as usize + 1,
+            prop::collection::vec(board, 1..5),
+        )
+            .prop_map(|(draws, draw_count, boards)| {
+                let serialized_bingo_game = [draws[..draw_count]
+                    .iter()
+                    .map(|number| number.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",")]
+                .into_iter()
+                .chain(boards.iter().map(|numbers| {
+                    numbers
+                        .chunks(5)
+                        .map(|row| {
+                            row.iter()
+                                .map(|number| number.to_string())
+                                .collect::<Vec<String>>()
+                                .join(" ")
+                        })
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                }))
+                .collect::<Vec<String>>()
+                .join("\n\n");
+
+                BingoGame::deserialize(&serialized_bingo_game).unwrap()
+            })
+    }
+
+    #[test]
+    fn builds_games_without_parsing() {
+        let numbers = core::array::from_fn::<u32, BOARD_SIZE, _>(|i| i as u32 * 2);
+        let bingo_game = BingoGame::new(vec![0, 2, 4, 6, 8], vec![BingoGameBoard::from(numbers)]);
+
+        assert_eq!(
+            BingoGame::deserialize(&bingo_game.serialize()).unwrap(),
+            bingo_game
+        );
+        assert_eq!(bingo_game.play().map(|(number, _)| number), Some(8));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn reloads_dumped_games() {
+        let numbers = core::array::from_fn::<u32, BOARD_SIZE, _>(|i| i as u32 * 2);
+        let bingo_game = BingoGame::new(vec![0, 2, 4, 6, 8], vec![BingoGameBoard::from(numbers)]);
+        let (_, winning_board) = bingo_game.play().unwrap();
+
+        let dumped_bingo_game = serde_json::to_string(&bingo_game).unwrap();
+        let dumped_winning_board = serde_json::to_string(&winning_board).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<BingoGame>(&dumped_bingo_game).unwrap(),
+            bingo_game
+        );
+        assert_eq!(
+            serde_json::from_str::<BingoGameBoard>(&dumped_winning_board).unwrap(),
+            winning_board
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_games() {
+        assert!(matches!(
+            BingoGame::deserialize("1,2,3"),
+            Err(BingoParseError::NoBoards)
+        ));
+        assert!(matches!(
+            BingoGame::deserialize("1,x,3\n\n1 2 3 4 5"),
+            Err(BingoParseError::InvalidNumberSelections(_))
+        ));
+        assert!(matches!(
+            BingoGame::deserialize("1,2,3\n\n1 2 3 4 5\n6 7 8 9 ten"),
+            Err(BingoParseError::InvalidBoardNumbers(_))
+        ));
+        assert!(matches!(
+            BingoGame::deserialize("1,2,3\n\n1 2 3 4 5\n6 7 8 9 10"),
+            Err(BingoParseError::WrongBoardSize { found: 10 })
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn deserializes_what_it_serializes(bingo_game in bingo_game()) {
+            prop_assert_eq!(BingoGame::deserialize(&bingo_game.serialize()).unwrap(), bingo_game);
+        }
+
+        #[test]
+        fn renders_every_board_as_it_serializes(bingo_game in bingo_game()) {
+            let serialized_bingo_game = bingo_game.serialize();
+            let (_, serialized_boards) = serialized_bingo_game.split_once("\n\n").unwrap();
+
+            prop_assert_eq!(bingo_game.render(false), format!("{}\n", serialized_boards));
+        }
+
+        #[test]
+        fn first_winner_wins_no_later_than_last_winner(bingo_game in bingo_game()) {
+            let draw_index_of = |number: u32| {
+                bingo_game.number_selections.iter().position(|drawn| *drawn == number)
+            };
+
+            if let Some((winning_number, winning_board)) = bingo_game.play() {
+                prop_assert!(!winning_board.unselected_numbers().contains(&winning_number));
+
+                if let Some((last_winning_number, _)) = bingo_game.play_exhaustively() {
+                    prop_assert!(draw_index_of(winning_number) <= draw_index_of(last_winning_number));
+                }
+            }
+        }
+
+        #[test]
+        fn every_board_wins_once_every_number_is_drawn(bingo_game in bingo_game()) {
+            if bingo_game.number_selections.len() == MAX_NUMBER as usize + 1 {
+                prop_assert!(bingo_game.play_exhaustively().is_some());
+            }
+        }
+    }
+}
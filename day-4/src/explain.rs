@@ -0,0 +1,50 @@
+use aoc_common::table::Table;
+use day_4::bingo_game::BingoGame;
+
+/// Prints each number drawn in `bingo_game`, how many boards it was marked
+/// on, and which boards it made win, until every board has won or the numbers
+/// run out.
+pub fn narrate_draws(bingo_game: &BingoGame) {
+    let board_count = bingo_game.board_count();
+    let mut table = Table::new();
+    let mut winner_count = 0;
+
+    table.header(["Draw", "Number", "Marked on", "Winners"]);
+    for (draw_index, draw) in bingo_game.draws().enumerate() {
+        winner_count += draw.winning_board_indices.len();
+
+        table.row([
+            (draw_index + 1).to_string(),
+            draw.number.to_string(),
+            format!(
+                "{} of {} boards",
+                draw.marked_board_indices.len(),
+                board_count
+            ),
+            draw.winning_board_indices
+                .iter()
+                .map(|board_index| format!("board {}", board_index + 1))
+                .collect::<Vec<String>>()
+                .join(", "),
+        ]);
+
+        if winner_count == board_count {
+            table.separator().title(format!(
+                "Every board has won after {} draws.",
+                draw_index + 1
+            ));
+
+            break;
+        }
+    }
+
+    if winner_count < board_count {
+        table.separator().warning(format!(
+            "{} of {} boards never win.",
+            board_count - winner_count,
+            board_count
+        ));
+    }
+
+    table.separator().print();
+}
@@ -0,0 +1,25 @@
+//! Solves the "Giant Squid" puzzle.
+//!
+//! Without the default `std` feature, only the [bingo_game] engine that plays
+//! bingo and detects wins is built, without parsing or cancellation, and it
+//! only needs `alloc`, so that it can run on targets without an operating
+//! system.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+extern crate anyhow;
+extern crate aoc_common;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
+#[cfg(feature = "serde")]
+extern crate serde;
+extern crate thiserror;
+extern crate tracing;
+
+pub mod bingo_game;
+pub mod fixtures;
+#[cfg(feature = "pyo3")]
+pub mod python;
+#[cfg(feature = "std")]
+pub mod solver;
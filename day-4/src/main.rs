@@ -1,83 +1,292 @@
 extern crate anyhow;
-extern crate lazy_static;
-extern crate regex;
-extern crate tokio;
+extern crate aoc_common;
+extern crate clap;
+extern crate day_4;
+extern crate tracing;
 
-mod bingo_game;
+mod args;
+mod explain;
 
 use anyhow::{Context, Result};
-use bingo_game::BingoGame;
-use std::env::current_dir;
-use tokio::fs::File;
-use tokio::io::AsyncReadExt;
-
-#[tokio::main]
-async fn main() -> Result<()> {
-    let bingo_game = read_bingo_game("files/input.txt").await?;
-
-    let (winning_number, winning_board) =
-        bingo_game.clone().play().context("There was no winner!")?;
-    let winning_board_sum: u32 = winning_board
-        .unselected_numbers()
-        .iter()
-        .map(|number| *number as u32)
-        .sum();
-
-    println!("Winning number:\t\t{}", winning_number);
-    println!("Winning board sum:\t{:?}", winning_board_sum);
-    println!(
-        "Product:\t\t{}",
-        (winning_number as u32) * winning_board_sum
-    );
-
-    let (last_winning_number, last_winning_board) = bingo_game
-        .clone()
-        .play_exhaustively()
-        .context("There wasn't a last winner!")?;
-    let last_winning_board_sum: u32 = last_winning_board
-        .unselected_numbers()
-        .iter()
-        .map(|number| *number as u32)
-        .sum();
-
-    println!();
-    println!("Last winning number:\t{}", last_winning_number);
-    println!("Last winning board sum:\t{:?}", last_winning_board_sum);
-    println!(
-        "Product:\t\t{}",
-        (last_winning_number as u32) * last_winning_board_sum
-    );
-
-    Ok(())
+use aoc_common::args::ParseArgs;
+use aoc_common::args::Part;
+use aoc_common::batch;
+use aoc_common::completions;
+use aoc_common::concurrent;
+use aoc_common::error::{InvalidInput, NoSolution};
+use aoc_common::input;
+use aoc_common::output;
+#[cfg(feature = "record")]
+use aoc_common::recording;
+use aoc_common::render::Renderable;
+use aoc_common::shuffle;
+use aoc_common::table::Table;
+use aoc_common::timing::Timings;
+use aoc_common::watch;
+use args::Args;
+#[cfg(feature = "record")]
+use day_4::bingo_game::BingoDraw;
+use day_4::bingo_game::BingoGame;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    #[cfg(feature = "embedded-inputs")]
+    input::embed(include_str!("../files/input.txt"));
+
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
 }
 
-/// Reads the contents of the "diagnostic report" input file as a
-/// newline-separated list of binary numbers.
-async fn read_bingo_game(bingo_game_file_path: &str) -> Result<BingoGame> {
-    let pwd = current_dir().context("Failed to read current working directory")?;
-    let bingo_game_file_path_buf = pwd.join(bingo_game_file_path);
-
-    let mut bingo_game_file = File::open(&bingo_game_file_path_buf)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to open file at path \"{}\"",
-                bingo_game_file_path_buf.display()
-            )
-        })?;
-    let mut raw_bingo_game_file_contents = vec![];
-
-    bingo_game_file
-        .read_to_end(&mut raw_bingo_game_file_contents)
-        .await
-        .with_context(|| {
-            format!(
-                "Failed to read file at path \"{}\"",
-                bingo_game_file_path_buf.display()
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return batch::write_answers_csv(
+            inputs,
+            args.common.out.as_deref(),
+            args.common.part,
+            |input, part| {
+                answers_table(
+                    &BingoGame::deserialize(input).context(InvalidInput)?,
+                    Some(part),
+                )
+            },
+        );
+    }
+
+    if args.common.watch {
+        return watch::watch_answers(&args.common, |input| {
+            answers_table(
+                &BingoGame::deserialize(input).context(InvalidInput)?,
+                args.common.part,
             )
-        })?;
+        });
+    }
+
+    if args.common.visualize {
+        let input = input::load(args.common.input_source())?;
+        let bingo_game = BingoGame::deserialize(&input).context(InvalidInput)?;
+        let (_, winning_board) = bingo_game
+            .play()
+            .context(NoSolution::new("There was no winner!"))?;
+        print!("{}", winning_board.render(output::is_color_enabled()));
+
+        return Ok(());
+    }
+
+    if let Some(seed) = args.shuffle {
+        let input = input::load(args.common.input_source())?;
+
+        // The first paragraph holds the numbers drawn, which have to stay
+        // both first and in order.
+        return args.common.print(&shuffle::check_answers_unchanged(
+            &input,
+            &shuffle::shuffle_paragraphs(&input, 1, seed),
+            seed,
+            |input| {
+                answers_table(
+                    &BingoGame::deserialize(input).context(InvalidInput)?,
+                    args.common.part,
+                )
+            },
+        )?);
+    }
+
+    #[cfg(feature = "record")]
+    if let Some(recording_path) = &args.record {
+        let input = input::load(args.common.input_source())?;
+        let bingo_game = BingoGame::deserialize(&input).context(InvalidInput)?;
+
+        return args.common.print(&recording::record(
+            4,
+            bingo_game,
+            recording_path,
+            draws_and_answers_of,
+        )?);
+    }
+
+    #[cfg(feature = "record")]
+    if let Some(recording_path) = &args.replay {
+        return args
+            .common
+            .print(&recording::replay(4, recording_path, draws_and_answers_of)?);
+    }
+
+    let mut timings = Timings::new();
+    let bingo_game = input::load_parsed(args.common.input_source(), &mut timings)?;
+
+    if args.explain {
+        explain::narrate_draws(&bingo_game);
+    }
+
+    args.common.print_answers(
+        4,
+        &concurrent::solve_parts(args.common.part, timings, |part| {
+            answers_table(&bingo_game, Some(part))
+        })?,
+    )
+}
+
+/// Returns a [Table] of the answers to the puzzle for the specified
+/// `bingo_game`, including only the specified `part` if there is one and both
+/// parts otherwise.
+fn answers_table(bingo_game: &BingoGame, part: Option<Part>) -> Result<Table> {
+    let mut table = Table::new();
+
+    if Part::One.is_selected_by(part) {
+        let (winning_number, winning_board) = bingo_game
+            .play()
+            .context(NoSolution::new("There was no winner!"))?;
+        let winning_board_sum: u64 = winning_board
+            .unselected_numbers()
+            .iter()
+            .map(|number| u64::from(*number))
+            .sum();
+
+        table
+            .entry("Winning number", winning_number)
+            .entry("Winning board sum", winning_board_sum)
+            .answer(
+                "Product",
+                u128::from(winning_number) * u128::from(winning_board_sum),
+            );
+    }
+
+    if Part::Two.is_selected_by(part) {
+        let (last_winning_number, last_winning_board) = bingo_game
+            .play_exhaustively()
+            .context(NoSolution::new("There wasn't a last winner!"))?;
+        let last_winning_board_sum: u64 = last_winning_board
+            .unselected_numbers()
+            .iter()
+            .map(|number| u64::from(*number))
+            .sum();
+
+        if !table.is_empty() {
+            table.separator();
+        }
+        table
+            .entry("Last winning number", last_winning_number)
+            .entry("Last winning board sum", last_winning_board_sum)
+            .answer(
+                "Product",
+                u128::from(last_winning_number) * u128::from(last_winning_board_sum),
+            );
+    }
+
+    Ok(table)
+}
+
+/// Returns the outcome of every draw in `bingo_game`, along with a [Table] of
+/// the answers to both parts of the puzzle, as recorded by `--record`.
+#[cfg(feature = "record")]
+fn draws_and_answers_of(bingo_game: &BingoGame) -> Result<(Vec<BingoDraw>, Table)> {
+    Ok((
+        bingo_game.draws().collect(),
+        answers_table(bingo_game, None)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers;
+    use day_4::fixtures::{INPUT, SAMPLE};
+    use insta::assert_snapshot;
+
+    #[test]
+    fn finds_first_winner_of_sample() {
+        let (winning_number, winning_board) =
+            BingoGame::deserialize(SAMPLE).unwrap().play().unwrap();
+        let winning_board_sum: u64 = winning_board
+            .unselected_numbers()
+            .iter()
+            .map(|number| u64::from(*number))
+            .sum();
+
+        assert_eq!(winning_number, 24);
+        assert_eq!(winning_board_sum, 188);
+    }
+
+    #[test]
+    fn finds_last_winner_of_sample() {
+        let (last_winning_number, last_winning_board) = BingoGame::deserialize(SAMPLE)
+            .unwrap()
+            .play_exhaustively()
+            .unwrap();
+        let last_winning_board_sum: u64 = last_winning_board
+            .unselected_numbers()
+            .iter()
+            .map(|number| u64::from(*number))
+            .sum();
+
+        assert_eq!(last_winning_number, 13);
+        assert_eq!(last_winning_board_sum, 148);
+    }
+
+    #[test]
+    fn scores_boards_with_large_numbers_without_overflowing() {
+        let rows = (0..4)
+            .map(|row| {
+                (1..=5)
+                    .map(|column| (4_000_000_000u32 + row * 5 + column).to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let bingo_game = BingoGame::deserialize(&format!(
+            "1,2,3,4,4000000000\n\n1 2 3 4 4000000000\n{}",
+            rows
+        ))
+        .unwrap();
+
+        let table = answers_table(&bingo_game, None).unwrap();
+
+        assert_eq!(
+            table.answers().collect::<Vec<&str>>(),
+            ["320000000840000000000", "320000000840000000000"]
+        );
+    }
+
+    #[cfg(feature = "record")]
+    #[test]
+    fn replays_recorded_sample() {
+        let recording_path =
+            std::env::temp_dir().join(format!("aoc-day-4-recording-{}.json", std::process::id()));
+        let bingo_game = BingoGame::deserialize(SAMPLE).unwrap();
+
+        let recorded_table =
+            recording::record(4, bingo_game, &recording_path, draws_and_answers_of).unwrap();
+        let replayed_table = recording::replay(4, &recording_path, draws_and_answers_of).unwrap();
+
+        assert_eq!(
+            replayed_table.answers().collect::<Vec<&str>>(),
+            recorded_table.answers().collect::<Vec<&str>>()
+        );
+
+        std::fs::remove_file(&recording_path).unwrap();
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        let bingo_game = BingoGame::deserialize(SAMPLE).unwrap();
 
-    let bingo_game_file_contents = String::from_utf8_lossy(&raw_bingo_game_file_contents);
+        assert_snapshot!(answers_table(&bingo_game, None).unwrap().to_string());
+    }
 
-    BingoGame::deserialize(&bingo_game_file_contents).context("Failed to read bingo game file")
+    #[test]
+    fn agrees_with_local_answers_to_input() {
+        answers::assert_matches_local_answers(
+            4,
+            &answers_table(&BingoGame::deserialize(INPUT).unwrap(), None).unwrap(),
+        );
+    }
 }
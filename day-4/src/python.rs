@@ -0,0 +1,77 @@
+//! Python bindings for [BingoGame], added to a Python module by [register].
+
+use crate::bingo_game::{BingoGame, BingoGameBoard};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// [BingoGame] as it appears to Python, where it is named `BingoGame`.
+#[pyclass(name = "BingoGame", frozen)]
+pub struct PyBingoGame(BingoGame);
+
+#[pymethods]
+impl PyBingoGame {
+    /// Interprets the numbers drawn and the boards of a bingo game, separated
+    /// by empty lines, as a `BingoGame`, raising `ValueError` if they are
+    /// malformed.
+    #[staticmethod]
+    fn deserialize(serialized_bingo_game: &str) -> PyResult<PyBingoGame> {
+        BingoGame::deserialize(serialized_bingo_game)
+            .map(PyBingoGame)
+            .map_err(|error| PyValueError::new_err(format!("{:#}", error)))
+    }
+
+    /// Returns how many boards this `BingoGame` has.
+    fn board_count(&self) -> usize {
+        self.0.board_count()
+    }
+
+    /// Returns a `(number, marked_board_indices, winning_board_indices)`
+    /// tuple for every number drawn, in order, with every board playing on
+    /// after it wins.
+    fn draws(&self) -> Vec<(u32, Vec<usize>, Vec<usize>)> {
+        self.0
+            .draws()
+            .map(|draw| {
+                (
+                    draw.number,
+                    draw.marked_board_indices,
+                    draw.winning_board_indices,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the first winning number along with the unselected numbers of
+    /// the board that won, or `None` if no board wins.
+    fn play(&self) -> Option<(u32, Vec<u32>)> {
+        self.0.play().map(winner_of)
+    }
+
+    /// Returns the last winning number along with the unselected numbers of
+    /// the board that won, or `None` if some board never wins.
+    fn play_exhaustively(&self) -> Option<(u32, Vec<u32>)> {
+        self.0.play_exhaustively().map(winner_of)
+    }
+
+    /// Returns this `BingoGame` as the numbers drawn followed by every board,
+    /// separated by empty lines.
+    fn serialize(&self) -> String {
+        self.0.serialize()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("BingoGame(boards={})", self.0.board_count())
+    }
+}
+
+/// Adds every class bound here to the Python `module`.
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyBingoGame>()
+}
+
+/// Returns the `winning_number` and the unselected numbers of the
+/// `winning_board` that it belongs to.
+fn winner_of((winning_number, winning_board): (u32, BingoGameBoard)) -> (u32, Vec<u32>) {
+    (winning_number, winning_board.unselected_numbers())
+}
@@ -0,0 +1,87 @@
+use crate::bingo_game::{BingoGame, BingoGameBoard};
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::shuffle;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Giant Squid" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GiantSquidSolver;
+
+impl Solver for GiantSquidSolver {
+    type Parsed = BingoGame;
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input: &str) -> Result<BingoGame> {
+        Ok(BingoGame::deserialize(input)?)
+    }
+
+    fn part_one(&self, bingo_game: &BingoGame) -> Result<Answer> {
+        let (winning_number, winning_board) = bingo_game
+            .play()
+            .context(NoSolution::new("There was no winner!"))?;
+
+        Ok(score_of(winning_number, &winning_board).into())
+    }
+
+    fn part_two(&self, bingo_game: &BingoGame) -> Result<Answer> {
+        let (last_winning_number, last_winning_board) = bingo_game
+            .play_exhaustively()
+            .context(NoSolution::new("There wasn't a last winner!"))?;
+
+        Ok(score_of(last_winning_number, &last_winning_board).into())
+    }
+
+    fn shuffle(&self, input: &str, seed: u64) -> Option<String> {
+        // The first paragraph holds the numbers drawn, which have to stay
+        // both first and in order.
+        Some(shuffle::shuffle_paragraphs(input, 1, seed))
+    }
+}
+
+/// Returns the score of `board` winning with `winning_number`: the sum of its
+/// unselected numbers multiplied by `winning_number`.
+fn score_of(winning_number: u32, board: &BingoGameBoard) -> u128 {
+    let unselected_number_sum: u64 = board
+        .unselected_numbers()
+        .iter()
+        .map(|number| u64::from(*number))
+        .sum();
+
+    u128::from(winning_number) * u128::from(unselected_number_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = GiantSquidSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(GiantSquidSolver.part_one(&parsed).unwrap(), "4512");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = GiantSquidSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(GiantSquidSolver.part_two(&parsed).unwrap(), "1924");
+    }
+
+    #[test]
+    fn shuffles_boards_without_changing_answers() {
+        let shuffled_sample = GiantSquidSolver.shuffle(SAMPLE, 7).unwrap();
+        let parsed = GiantSquidSolver.parse(&shuffled_sample).unwrap();
+
+        assert!(shuffled_sample.starts_with(SAMPLE.lines().next().unwrap()));
+        assert_eq!(GiantSquidSolver.part_one(&parsed).unwrap(), "4512");
+        assert_eq!(GiantSquidSolver.part_two(&parsed).unwrap(), "1924");
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "arena")]
+use bumpalo::Bump;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_5::hydrothermal_vent_lines::HydrothermalVentLines;
+
+/// Number of points in every generated vent line.
+const POINTS_PER_LINE: usize = 8;
+
+/// Returns a newline-delimited [String] of `lines` pseudo-random vent lines,
+/// generated the same way every time so that runs are comparable.
+fn generated_hydrothermal_vent_lines(lines: usize) -> String {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut serialized_hydrothermal_vent_lines = String::new();
+
+    for _ in 0..lines {
+        let points = (0..POINTS_PER_LINE)
+            .map(|_| {
+                // Xorshift keeps the generator dependency-free.
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+
+                format!("{},{}", state % 1000, (state >> 32) % 1000)
+            })
+            .collect::<Vec<String>>();
+
+        serialized_hydrothermal_vent_lines.push_str(&points.join(" -> "));
+        serialized_hydrothermal_vent_lines.push('\n');
+    }
+
+    serialized_hydrothermal_vent_lines
+}
+
+fn compare_allocation_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize");
+
+    for lines in [500, 50_000] {
+        let serialized_hydrothermal_vent_lines = generated_hydrothermal_vent_lines(lines);
+
+        group.throughput(Throughput::Bytes(
+            serialized_hydrothermal_vent_lines.len() as u64
+        ));
+        group.bench_with_input(
+            BenchmarkId::new("heap", lines),
+            &serialized_hydrothermal_vent_lines,
+            |b, serialized_hydrothermal_vent_lines| {
+                b.iter(|| {
+                    HydrothermalVentLines::deserialize(serialized_hydrothermal_vent_lines).unwrap()
+                })
+            },
+        );
+        #[cfg(feature = "arena")]
+        group.bench_with_input(
+            BenchmarkId::new("arena", lines),
+            &serialized_hydrothermal_vent_lines,
+            |b, serialized_hydrothermal_vent_lines| {
+                b.iter(|| {
+                    HydrothermalVentLines::deserialize_in(
+                        serialized_hydrothermal_vent_lines,
+                        &Bump::new(),
+                    )
+                    .unwrap()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, compare_allocation_strategies);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_5::hydrothermal_vent_lines::HydrothermalVentLines;
+use day_5::vent_map::VentMap;
+
+/// Number of points in every generated vent line.
+const POINTS_PER_LINE: usize = 8;
+
+/// Returns a newline-delimited [String] of `lines` pseudo-random vent lines
+/// within `extent` of the origin, generated the same way every time so that
+/// runs are comparable.
+fn generated_hydrothermal_vent_lines(lines: usize, extent: u64) -> String {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut serialized_hydrothermal_vent_lines = String::new();
+
+    for _ in 0..lines {
+        let points = (0..POINTS_PER_LINE)
+            .map(|_| {
+                // Xorshift keeps the generator dependency-free.
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+
+                format!("{},{}", state % extent, (state >> 32) % extent)
+            })
+            .collect::<Vec<String>>();
+
+        serialized_hydrothermal_vent_lines.push_str(&points.join(" -> "));
+        serialized_hydrothermal_vent_lines.push('\n');
+    }
+
+    serialized_hydrothermal_vent_lines
+}
+
+fn compare_scanning_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("overlapping_coordinate_count");
+
+    for extent in [100, 4_000] {
+        let hydrothermal_vent_lines =
+            HydrothermalVentLines::deserialize(&generated_hydrothermal_vent_lines(500, extent))
+                .unwrap();
+        let vent_map = VentMap::of(&hydrothermal_vent_lines, false).unwrap();
+
+        group.throughput(Throughput::Elements(extent * extent));
+        group.bench_with_input(
+            BenchmarkId::new("scalar", extent),
+            &vent_map,
+            |b, vent_map| b.iter(|| vent_map.overlapping_coordinate_count()),
+        );
+        #[cfg(feature = "simd")]
+        group.bench_with_input(
+            BenchmarkId::new("simd", extent),
+            &vent_map,
+            |b, vent_map| b.iter(|| vent_map.overlapping_coordinate_count_simd()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, compare_scanning_strategies);
+criterion_main!(benches);
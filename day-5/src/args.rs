@@ -0,0 +1,90 @@
+use aoc_common::args::CommonArgs;
+use aoc_common::svg::ColorRamp;
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Counts the overlaps between hydrothermal vent lines")]
+pub struct Args {
+    /// Compares the overlap counts of the input against those of the
+    /// hydrothermal vent lines in the specified file.
+    #[clap(long, value_name = "PATH")]
+    pub compare: Option<PathBuf>,
+
+    /// Counts each joint shared by consecutive legs of a multi-segment vent
+    /// line once per leg, instead of once per vent line.
+    #[clap(long)]
+    pub count_joints_per_leg: bool,
+
+    /// Narrates how the overlaps are found, one vent line at a time, before
+    /// printing them.
+    #[clap(long)]
+    pub explain: bool,
+
+    /// Writes the hydrothermal vent lines and their overlaps to stdout in the
+    /// specified format instead of printing overlap counts.
+    #[clap(long, value_enum)]
+    pub export: Option<ExportFormat>,
+
+    /// Lists at most this many overlapping coordinates; requires
+    /// `--list-overlaps`.
+    #[clap(long, value_name = "N", requires = "list_overlaps")]
+    pub limit: Option<usize>,
+
+    /// Lists every coordinate covered by more than one vent line, sorted by
+    /// row and then by column, along with how many vent lines cover it.
+    #[clap(long)]
+    pub list_overlaps: bool,
+
+    /// Skips this many overlapping coordinates before listing the rest;
+    /// requires `--list-overlaps`.
+    #[clap(
+        long,
+        value_name = "M",
+        default_value_t = 0,
+        requires = "list_overlaps"
+    )]
+    pub offset: usize,
+
+    /// Reports the connected regions of coordinates that no vent line covers
+    /// within the bounding box of the vent lines.
+    #[clap(long)]
+    pub regions: bool,
+
+    /// Solves the puzzle a second time with the order of its vent lines
+    /// shuffled by the specified seed, failing if the answers change.
+    #[clap(long, value_name = "SEED")]
+    pub shuffle: Option<u64>,
+
+    /// Reports how the number of vent lines covering each coordinate is
+    /// distributed, including a histogram of overlap counts and percentiles.
+    #[clap(long)]
+    pub stats: bool,
+
+    /// Draws the vent lines, and the coordinates that they overlap at
+    /// colored by how many overlap there, to the specified SVG file instead
+    /// of printing overlap counts.
+    #[clap(long, value_name = "PATH")]
+    pub svg: Option<PathBuf>,
+
+    /// Colors the overlaps drawn by `--svg` along the specified gradient,
+    /// from the fewest overlapping vent lines to the most.
+    #[clap(long, value_enum, value_name = "RAMP", default_value_t = ColorRamp::Heat, requires = "svg")]
+    pub svg_ramp: ColorRamp,
+
+    /// Draws every coordinate as a square this many pixels wide with `--svg`.
+    #[clap(long, value_name = "PIXELS", default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..), requires = "svg")]
+    pub svg_scale: u16,
+
+    #[clap(flatten)]
+    pub common: CommonArgs,
+}
+
+/// Enumerates every supported format for exporting hydrothermal vent lines.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+    /// GeoJSON `FeatureCollection` of vent lines and overlapping coordinates.
+    #[clap(name = "geojson")]
+    GeoJson,
+}
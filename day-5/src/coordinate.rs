@@ -1,25 +1,10 @@
-use std::{collections::HashMap, fmt};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use aoc_common::counter::Counter;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
-/// Represents a point in space.
-#[derive(Clone, Copy, Eq, Hash, PartialEq)]
-pub struct Coordinate {
-    /// X-component of this [Coordinate].
-    pub x: i32,
-    /// Y-component of this [Coordinate].
-    pub y: i32,
-}
-
-impl fmt::Debug for Coordinate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self, f)
-    }
-}
-
-impl fmt::Display for Coordinate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
-    }
-}
+pub use aoc_common::geometry::{Coordinate, Rectangle, Vector, WideVector};
 
 /// Represents multiple points in space.
 #[derive(Clone, Debug, PartialEq)]
@@ -28,17 +13,28 @@ pub struct Coordinates(Vec<Coordinate>);
 impl Coordinates {
     /// Returns a [HashMap] relating each [Coordinate] to a count of how many
     /// copies of that [Coordinate] exist in this [Coordinates].
+    #[cfg(feature = "std")]
     pub fn aggregate(&self) -> HashMap<Coordinate, usize> {
-        let mut coordinate_counts = HashMap::<Coordinate, usize>::new();
+        self.0
+            .iter()
+            .copied()
+            .collect::<Counter<Coordinate>>()
+            .into_counts()
+    }
 
-        for coordinate in self.0.iter() {
-            coordinate_counts.insert(
-                *coordinate,
-                *coordinate_counts.get(coordinate).unwrap_or(&0) + 1,
-            );
-        }
+    /// Returns `true` if this [Coordinates] contains no [Coordinate].
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an [Iterator] over every [Coordinate] in this [Coordinates].
+    pub fn iter(&self) -> impl Iterator<Item = &Coordinate> {
+        self.0.iter()
+    }
 
-        coordinate_counts
+    /// Returns how many [Coordinate] instances this [Coordinates] contains.
+    pub fn len(&self) -> usize {
+        self.0.len()
     }
 }
 
@@ -64,8 +60,7 @@ impl FromIterator<Coordinates> for Coordinates {
     fn from_iter<T: IntoIterator<Item = Coordinates>>(iter: T) -> Self {
         Coordinates(
             iter.into_iter()
-                .map(|coordinates| coordinates.0)
-                .flatten()
+                .flat_map(|coordinates| coordinates.0)
                 .collect(),
         )
     }
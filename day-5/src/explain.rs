@@ -0,0 +1,123 @@
+use anyhow::Result;
+use aoc_common::counter::Counter;
+use aoc_common::table::Table;
+use day_5::coordinate::Coordinate;
+use day_5::hydrothermal_vent_lines::HydrothermalVentLines;
+use day_5::traceable::Traceable;
+
+/// Largest width or height of the area covered by vent lines for which a
+/// diagram of overlap counts is printed, like the one in the puzzle.
+const MAX_DIAGRAM_SIZE: i32 = 40;
+
+/// Prints each vent line in `hydrothermal_vent_lines` as it is traced, along
+/// with how many new overlaps it creates, followed by a diagram of the
+/// overlap counts if the vent lines cover a small enough area.
+///
+/// Parameters:
+/// *   `are_diagonals_allowed`\
+///     Is `true` if diagonal lines are considered to be traceable.
+pub fn narrate_overlaps(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    are_diagonals_allowed: bool,
+) -> Result<()> {
+    let mut coordinate_counts = Counter::<Coordinate>::new();
+    let mut overlap_count = 0;
+    let mut table = Table::new();
+
+    table.title(if are_diagonals_allowed {
+        "Tracing straight and diagonal vent lines:"
+    } else {
+        "Tracing straight vent lines:"
+    });
+    for polyline in hydrothermal_vent_lines.iter() {
+        let serialized_points = polyline
+            .points()
+            .iter()
+            .map(|point| format!("{},{}", point.x, point.y))
+            .collect::<Vec<String>>()
+            .join(" -> ");
+
+        let traceable_polyline = match polyline.without_untraceable_legs(are_diagonals_allowed) {
+            Some(traceable_polyline) => traceable_polyline,
+            None => {
+                table.row([serialized_points, "skipped (diagonal)".to_string()]);
+
+                continue;
+            }
+        };
+
+        let coordinates = traceable_polyline.trace(are_diagonals_allowed)?;
+        let mut new_overlap_count = 0;
+        for coordinate in coordinates.iter() {
+            coordinate_counts.add(*coordinate);
+
+            if coordinate_counts.count_of(coordinate) == 2 {
+                new_overlap_count += 1;
+            }
+        }
+        overlap_count += new_overlap_count;
+
+        table.row([
+            serialized_points,
+            format!("covers {} points", coordinates.len()),
+            format!("{} new overlaps", new_overlap_count),
+            format!("{} in total", overlap_count),
+        ]);
+    }
+
+    if let Some(diagram) = diagram_of(&coordinate_counts) {
+        table.separator().title(diagram);
+    }
+
+    table
+        .separator()
+        .title(format!(
+            "{} points are covered by at least two vent lines.",
+            overlap_count
+        ))
+        .separator()
+        .print();
+
+    Ok(())
+}
+
+/// Returns a diagram of how many vent lines cover each coordinate in
+/// `coordinate_counts`, drawn the way the puzzle draws it, returning
+/// [Option::None] if the covered area is empty or too big to draw.
+fn diagram_of(coordinate_counts: &Counter<Coordinate>) -> Option<String> {
+    let min_x = coordinate_counts
+        .iter()
+        .map(|(coordinate, _)| coordinate.x)
+        .min()?;
+    let max_x = coordinate_counts
+        .iter()
+        .map(|(coordinate, _)| coordinate.x)
+        .max()?;
+    let min_y = coordinate_counts
+        .iter()
+        .map(|(coordinate, _)| coordinate.y)
+        .min()?;
+    let max_y = coordinate_counts
+        .iter()
+        .map(|(coordinate, _)| coordinate.y)
+        .max()?;
+
+    if max_x - min_x >= MAX_DIAGRAM_SIZE || max_y - min_y >= MAX_DIAGRAM_SIZE {
+        return None;
+    }
+
+    let diagram = (min_y..=max_y)
+        .map(|y| {
+            (min_x..=max_x)
+                .map(|x| match coordinate_counts.count_of(&Coordinate { x, y }) {
+                    0 => '.',
+                    count @ 1..=9 => char::from_digit(count as u32, 10).unwrap_or('+'),
+                    _ => '+',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    Some(diagram)
+}
@@ -0,0 +1,5 @@
+/// Example input given in the description of the puzzle.
+pub const SAMPLE: &str = include_str!("../files/sample.txt");
+
+/// Personal puzzle input checked into this repository.
+pub const INPUT: &str = include_str!("../files/input.txt");
@@ -0,0 +1,140 @@
+use crate::coordinate::Coordinate;
+use crate::hydrothermal_vent_lines::{HydrothermalVentLine, HydrothermalVentLines};
+use crate::vent_polyline::VentPolyline;
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// Renders the given `hydrothermal_vent_lines` and `coordinate_counts` as a
+/// GeoJSON `FeatureCollection`.
+///
+/// Every [VentPolyline] becomes a `LineString` feature, and every
+/// [Coordinate] covered by at least `minimum_overlap` vent lines becomes a
+/// `Point` feature with a `count` property.
+pub fn feature_collection_of(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    coordinate_counts: &HashMap<Coordinate, usize>,
+    minimum_overlap: usize,
+) -> Value {
+    let mut hotspots = coordinate_counts
+        .iter()
+        .filter(|(_, coordinate_count)| **coordinate_count >= minimum_overlap)
+        .collect::<Vec<(&Coordinate, &usize)>>();
+
+    // Sort the hotspots so that exports of the same input are identical.
+    hotspots.sort_by_key(|(coordinate, _)| (coordinate.y, coordinate.x));
+
+    let features =
+        hydrothermal_vent_lines
+            .iter()
+            .map(line_string_feature_of)
+            .chain(hotspots.into_iter().map(|(coordinate, coordinate_count)| {
+                point_feature_of(coordinate, *coordinate_count)
+            }))
+            .collect::<Vec<Value>>();
+
+    json!({
+        "type": "FeatureCollection",
+        "features": features,
+    })
+}
+
+/// Returns the GeoJSON position of the given [Coordinate].
+fn position_of(coordinate: &Coordinate) -> Value {
+    json!([coordinate.x, coordinate.y])
+}
+
+/// Returns a GeoJSON `LineString` feature tracing the given [VentPolyline].
+///
+/// The `orientation` property is shared by every leg of the [VentPolyline],
+/// or `"mixed"` if its legs are oriented differently.
+fn line_string_feature_of(vent_polyline: &VentPolyline) -> Value {
+    let mut orientations = vent_polyline.legs().map(orientation_of);
+    let first_orientation = orientations.next().unwrap_or("oblique");
+    let orientation = if orientations.all(|orientation| orientation == first_orientation) {
+        first_orientation
+    } else {
+        "mixed"
+    };
+
+    json!({
+        "type": "Feature",
+        "geometry": {
+            "type": "LineString",
+            "coordinates": vent_polyline
+                .points()
+                .iter()
+                .map(position_of)
+                .collect::<Vec<Value>>(),
+        },
+        "properties": {
+            "orientation": orientation,
+        },
+    })
+}
+
+/// Returns a label describing the orientation of the given
+/// [HydrothermalVentLine].
+fn orientation_of(hydrothermal_vent_line: &HydrothermalVentLine) -> &'static str {
+    if hydrothermal_vent_line.is_horizontal() {
+        "horizontal"
+    } else if hydrothermal_vent_line.is_vertical() {
+        "vertical"
+    } else if hydrothermal_vent_line.is_diagonal() {
+        "diagonal"
+    } else {
+        "oblique"
+    }
+}
+
+/// Returns a GeoJSON `Point` feature marking the given [Coordinate] as being
+/// covered by `coordinate_count` vent lines.
+fn point_feature_of(coordinate: &Coordinate, coordinate_count: usize) -> Value {
+    json!({
+        "type": "Feature",
+        "geometry": {
+            "type": "Point",
+            "coordinates": position_of(coordinate),
+        },
+        "properties": {
+            "count": coordinate_count,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn exports_every_line_and_hotspot_of_sample() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(SAMPLE).unwrap();
+        let coordinate_counts = hydrothermal_vent_lines.overlap_counts(true).unwrap();
+
+        let feature_collection =
+            feature_collection_of(&hydrothermal_vent_lines, &coordinate_counts, 2);
+        let features = feature_collection["features"].as_array().unwrap();
+
+        assert_eq!(feature_collection["type"], "FeatureCollection");
+        // Every vent line, followed by the 12 coordinates that they overlap
+        // on.
+        assert_eq!(features.len(), 22);
+        assert_eq!(
+            features[0],
+            json!({
+                "type": "Feature",
+                "geometry": { "type": "LineString", "coordinates": [[0, 9], [5, 9]] },
+                "properties": { "orientation": "horizontal" },
+            })
+        );
+        assert_eq!(
+            features[10],
+            json!({
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [7, 1] },
+                "properties": { "count": 2 },
+            })
+        );
+    }
+}
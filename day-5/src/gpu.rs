@@ -0,0 +1,435 @@
+use crate::coordinate::{Coordinate, WideVector};
+use crate::hydrothermal_vent_lines::{HydrothermalVentLine, HydrothermalVentLines};
+
+use anyhow::{anyhow, Context, Result};
+use bytemuck::{Pod, Zeroable};
+use std::sync::{mpsc, OnceLock};
+use wgpu::util::DeviceExt;
+
+/// Number of invocations in every workgroup, which must match
+/// `WORKGROUP_SIZE` in overlaps.wgsl.
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Counts the coordinates covered by more than one hydrothermal vent line on a
+/// GPU, rasterizing every line into a density grid with a compute shader and
+/// then reducing that grid to a count.
+#[derive(Debug)]
+pub struct GpuOverlapCounter {
+    /// Layout of the bindings shared by both passes of the compute shader.
+    bind_group_layout: wgpu::BindGroupLayout,
+    /// Device that runs the compute shader.
+    device: wgpu::Device,
+    /// Queue through which commands are submitted to [GpuOverlapCounter::device].
+    queue: wgpu::Queue,
+    /// Pipeline that traces every segment into the density grid.
+    rasterize_pipeline: wgpu::ComputePipeline,
+    /// Pipeline that counts the overlapping coordinates of the density grid.
+    reduce_pipeline: wgpu::ComputePipeline,
+}
+
+impl GpuOverlapCounter {
+    /// Connects to the default GPU adapter and compiles the compute shader
+    /// for it, returning [Err] if there is no such adapter.
+    pub fn new() -> Result<GpuOverlapCounter> {
+        pollster::block_on(GpuOverlapCounter::connect())
+    }
+
+    /// Returns the [GpuOverlapCounter] shared by the whole program, which is
+    /// connected the first time that it is asked for.
+    pub fn shared() -> Result<&'static GpuOverlapCounter> {
+        static SHARED: OnceLock<Result<GpuOverlapCounter, String>> = OnceLock::new();
+
+        SHARED
+            .get_or_init(|| GpuOverlapCounter::new().map_err(|error| format!("{:#}", error)))
+            .as_ref()
+            .map_err(|error| anyhow!("{}", error))
+    }
+
+    /// Returns how many coordinates are covered by more than one of the
+    /// traceable lines in `hydrothermal_vent_lines`, returning [Err] if their
+    /// bounding box does not fit in the memory of the GPU.
+    ///
+    /// Parameters:
+    /// *   `are_diagonals_allowed`\
+    ///     Is `true` if diagonal lines are considered to be traceable.
+    pub fn overlapping_coordinate_count(
+        &self,
+        hydrothermal_vent_lines: &HydrothermalVentLines,
+        are_diagonals_allowed: bool,
+    ) -> Result<usize> {
+        let segments = segments_of(hydrothermal_vent_lines, are_diagonals_allowed);
+        let Some((origin, width, height)) = bounding_box_of(&segments) else {
+            return Ok(0);
+        };
+
+        let limits = self.device.limits();
+        let max_density_bytes = limits
+            .max_storage_buffer_binding_size
+            .min(limits.max_buffer_size);
+        // Bounding boxes spanning the entire i32 range have more coordinates
+        // than fit in an i64, let alone on the GPU.
+        let density_bytes = width
+            .checked_mul(height)
+            .and_then(|area| u64::try_from(area).ok())
+            .and_then(|area| area.checked_mul(std::mem::size_of::<u32>() as u64))
+            .filter(|density_bytes| *density_bytes <= max_density_bytes);
+        let Some(density_bytes) = density_bytes else {
+            return Err(anyhow!(
+                "Bounding box of {}x{} coordinates is too large to map on the GPU",
+                width,
+                height
+            ));
+        };
+
+        let segments = segments
+            .iter()
+            .map(|(leg, skipped_points)| GpuSegment::of(leg, *skipped_points, origin))
+            .collect::<Vec<GpuSegment>>();
+        let grid = GpuGrid {
+            width: width as u32,
+            cell_count: (width * height) as u32,
+            segment_count: segments.len() as u32,
+            _padding: 0,
+        };
+
+        let grid_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("grid"),
+                contents: bytemuck::bytes_of(&grid),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let segment_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("segments"),
+                contents: bytemuck::cast_slice(&segments),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        let density_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("densities"),
+            size: density_bytes,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+        let overlap_count_buffer =
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("overlap count"),
+                    contents: bytemuck::bytes_of(&0u32),
+                    usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+                });
+        let readback_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("overlap count readback"),
+            size: std::mem::size_of::<u32>() as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("overlaps"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                grid_buffer.as_entire_binding(),
+                segment_buffer.as_entire_binding(),
+                density_buffer.as_entire_binding(),
+                overlap_count_buffer.as_entire_binding(),
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(binding, resource)| wgpu::BindGroupEntry {
+                binding: binding as u32,
+                resource,
+            })
+            .collect::<Vec<wgpu::BindGroupEntry>>(),
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_bind_group(0, &bind_group, &[]);
+
+            let (x, y) = self.workgroups_for(grid.segment_count)?;
+            pass.set_pipeline(&self.rasterize_pipeline);
+            pass.dispatch_workgroups(x, y, 1);
+
+            let (x, y) = self.workgroups_for(grid.cell_count)?;
+            pass.set_pipeline(&self.reduce_pipeline);
+            pass.dispatch_workgroups(x, y, 1);
+        }
+        encoder.copy_buffer_to_buffer(&overlap_count_buffer, 0, &readback_buffer, 0, None);
+        self.queue.submit([encoder.finish()]);
+
+        let (sender, receiver) = mpsc::channel();
+        readback_buffer.map_async(wgpu::MapMode::Read, .., move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .context("Failed to wait for the GPU")?;
+        receiver
+            .recv()
+            .context("GPU never finished counting overlaps")?
+            .context("Failed to read the overlap count back from the GPU")?;
+
+        let overlap_count = bytemuck::pod_read_unaligned::<u32>(
+            &readback_buffer
+                .get_mapped_range(..)
+                .context("Failed to read the overlap count back from the GPU")?,
+        );
+
+        Ok(overlap_count as usize)
+    }
+
+    /// Connects to the default GPU adapter and compiles the compute shader for
+    /// it.
+    async fn connect() -> Result<GpuOverlapCounter> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions::default())
+            .await
+            .context("Failed to find a GPU adapter")?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("day-5"),
+                required_limits: adapter.limits(),
+                ..Default::default()
+            })
+            .await
+            .context("Failed to connect to the GPU")?;
+
+        let shader = device.create_shader_module(wgpu::include_wgsl!("overlaps.wgsl"));
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("overlaps"),
+            entries: &[
+                wgpu::BufferBindingType::Uniform,
+                wgpu::BufferBindingType::Storage { read_only: true },
+                wgpu::BufferBindingType::Storage { read_only: false },
+                wgpu::BufferBindingType::Storage { read_only: false },
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(binding, ty)| wgpu::BindGroupLayoutEntry {
+                binding: binding as u32,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            })
+            .collect::<Vec<wgpu::BindGroupLayoutEntry>>(),
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("overlaps"),
+            bind_group_layouts: &[Some(&bind_group_layout)],
+            immediate_size: 0,
+        });
+        let pipeline_of = |entry_point| {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(&pipeline_layout),
+                module: &shader,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        };
+
+        Ok(GpuOverlapCounter {
+            rasterize_pipeline: pipeline_of("rasterize"),
+            reduce_pipeline: pipeline_of("reduce"),
+            bind_group_layout,
+            device,
+            queue,
+        })
+    }
+
+    /// Returns how many workgroups to dispatch along the X and Y axes so that
+    /// there is an invocation for each of `invocation_count` items, returning
+    /// [Err] if that is more than the GPU can dispatch at once.
+    fn workgroups_for(&self, invocation_count: u32) -> Result<(u32, u32)> {
+        let max_workgroups = self.device.limits().max_compute_workgroups_per_dimension;
+        let workgroups = invocation_count.div_ceil(WORKGROUP_SIZE);
+
+        let x = workgroups.clamp(1, max_workgroups);
+        let y = workgroups.div_ceil(x).max(1);
+        if y > max_workgroups {
+            return Err(anyhow!(
+                "{} invocations are too many to dispatch on the GPU",
+                invocation_count
+            ));
+        }
+
+        Ok((x, y))
+    }
+}
+
+/// Dimensions of the density grid, laid out like `Grid` in overlaps.wgsl.
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct GpuGrid {
+    /// Number of columns in the density grid.
+    width: u32,
+    /// Number of coordinates in the density grid.
+    cell_count: u32,
+    /// Number of [GpuSegment]s to rasterize.
+    segment_count: u32,
+    /// Pads this [GpuGrid] out to 16 bytes, as uniforms must be.
+    _padding: u32,
+}
+
+/// Leg of a vent line relative to the top-left corner of the density grid,
+/// laid out like `Segment` in overlaps.wgsl.
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+struct GpuSegment {
+    beginning_x: i32,
+    beginning_y: i32,
+    end_x: i32,
+    end_y: i32,
+    /// Number of points at the beginning of this [GpuSegment] that are not
+    /// traced.
+    skipped_points: u32,
+}
+
+impl GpuSegment {
+    /// Returns the [GpuSegment] of `leg` relative to `origin`, which skips
+    /// the first `skipped_points` of `leg`.
+    fn of(leg: &HydrothermalVentLine, skipped_points: u32, origin: Coordinate) -> GpuSegment {
+        let beginning = WideVector::between(origin, leg.beginning());
+        let end = WideVector::between(origin, leg.end());
+
+        // Offsets within a bounding box that fits on the GPU fit in an i32.
+        GpuSegment {
+            beginning_x: beginning.x as i32,
+            beginning_y: beginning.y as i32,
+            end_x: end.x as i32,
+            end_y: end.y as i32,
+            skipped_points,
+        }
+    }
+}
+
+/// Returns the top-left [Coordinate], width, and height of the bounding box
+/// of `segments`, or [None] if there are none.
+fn bounding_box_of(segments: &[(HydrothermalVentLine, u32)]) -> Option<(Coordinate, i64, i64)> {
+    let coordinates = || {
+        segments
+            .iter()
+            .flat_map(|(leg, _)| [leg.beginning(), leg.end()])
+    };
+
+    let origin = Coordinate {
+        x: coordinates().map(|coordinate| coordinate.x).min()?,
+        y: coordinates().map(|coordinate| coordinate.y).min()?,
+    };
+    let corner = Coordinate {
+        x: coordinates().map(|coordinate| coordinate.x).max()?,
+        y: coordinates().map(|coordinate| coordinate.y).max()?,
+    };
+    let diagonal = WideVector::between(origin, corner);
+
+    Some((origin, diagonal.x + 1, diagonal.y + 1))
+}
+
+/// Returns every traceable leg of `hydrothermal_vent_lines` along with how
+/// many of its points to skip, which is `1` for joints that were already
+/// traced by the previous leg of a polyline whose joints are deduplicated.
+fn segments_of(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    are_diagonals_allowed: bool,
+) -> Vec<(HydrothermalVentLine, u32)> {
+    let mut segments = vec![];
+
+    for polyline in hydrothermal_vent_lines
+        .without_untraceable_vent_lines(are_diagonals_allowed)
+        .iter()
+    {
+        let mut previous_leg: Option<&HydrothermalVentLine> = None;
+
+        for leg in polyline.legs() {
+            let is_joint_shared = previous_leg
+                .map(|previous_leg| previous_leg.end() == leg.beginning())
+                .unwrap_or(false);
+            let skipped_points = u32::from(polyline.are_joints_deduplicated() && is_joint_shared);
+
+            segments.push((*leg, skipped_points));
+
+            previous_leg = Some(leg);
+        }
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn skips_shared_joints() {
+        let hydrothermal_vent_lines =
+            HydrothermalVentLines::deserialize("0,0 -> 2,0 -> 2,2\n5,5 -> 6,6").unwrap();
+
+        assert_eq!(
+            segments_of(&hydrothermal_vent_lines, false)
+                .iter()
+                .map(|(_, skipped_points)| *skipped_points)
+                .collect::<Vec<u32>>(),
+            [0, 1]
+        );
+        assert_eq!(
+            segments_of(
+                &hydrothermal_vent_lines.with_joints_deduplicated(false),
+                true
+            )
+            .iter()
+            .map(|(_, skipped_points)| *skipped_points)
+            .collect::<Vec<u32>>(),
+            [0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn counts_overlapping_coordinates_in_sample() {
+        // Machines without a GPU adapter can only check the CPU fallback.
+        let Ok(gpu_overlap_counter) = GpuOverlapCounter::shared() else {
+            return;
+        };
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(SAMPLE).unwrap();
+
+        assert_eq!(
+            gpu_overlap_counter
+                .overlapping_coordinate_count(&hydrothermal_vent_lines, false)
+                .unwrap(),
+            5
+        );
+        assert_eq!(
+            gpu_overlap_counter
+                .overlapping_coordinate_count(&hydrothermal_vent_lines, true)
+                .unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn refuses_bounding_boxes_spanning_the_entire_i32_range() {
+        let Ok(gpu_overlap_counter) = GpuOverlapCounter::shared() else {
+            return;
+        };
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(
+            "-2147483648,-2147483648 -> -2147483648,-2147483648\n\
+             2147483647,2147483647 -> 2147483647,2147483647",
+        )
+        .unwrap();
+
+        assert!(gpu_overlap_counter
+            .overlapping_coordinate_count(&hydrothermal_vent_lines, false)
+            .is_err());
+    }
+}
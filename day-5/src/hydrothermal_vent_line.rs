@@ -0,0 +1,362 @@
+use crate::coordinate::{Coordinate, Coordinates, Rectangle, Vector, WideVector};
+#[cfg(feature = "std")]
+use crate::hydrothermal_vent_lines::VentLineParseError;
+use crate::traceable::Traceable;
+#[cfg(feature = "std")]
+use crate::vent_polyline::ARROW;
+
+#[cfg(feature = "std")]
+use alloc::string::ToString;
+use alloc::vec;
+use anyhow::{anyhow, Result};
+#[cfg(feature = "std")]
+use aoc_common::{geometry, parse};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a single hydrothermal vent line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct HydrothermalVentLine {
+    /// Where this [HydrothermalVentLine] starts.
+    beginning: Coordinate,
+    /// Where this [HydrothermalVentLine] terminates.
+    end: Coordinate,
+}
+
+impl HydrothermalVentLine {
+    /// Creates a new [HydrothermalVentLine] going from `beginning` to `end`.
+    pub fn between(beginning: Coordinate, end: Coordinate) -> HydrothermalVentLine {
+        HydrothermalVentLine { beginning, end }
+    }
+
+    /// Interprets the given [str] as a [HydrothermalVentLine].
+    #[cfg(feature = "std")]
+    pub fn deserialize(
+        serialized_hydrothermal_vent_line: &str,
+    ) -> Result<HydrothermalVentLine, VentLineParseError> {
+        let (raw_beginning, raw_end) = serialized_hydrothermal_vent_line
+            .split_once(ARROW)
+            .ok_or_else(|| VentLineParseError::MissingArrow {
+                input: serialized_hydrothermal_vent_line.to_string(),
+            })?;
+
+        let (x1, y1) = parse::coordinate_pair::<i32>(raw_beginning).map_err(|source| {
+            VentLineParseError::InvalidBeginning {
+                input: serialized_hydrothermal_vent_line.to_string(),
+                source,
+            }
+        })?;
+        let (x2, y2) = parse::coordinate_pair::<i32>(raw_end).map_err(|source| {
+            VentLineParseError::InvalidEnd {
+                input: serialized_hydrothermal_vent_line.to_string(),
+                source,
+            }
+        })?;
+
+        Ok(HydrothermalVentLine {
+            beginning: Coordinate { x: x1, y: y1 },
+            end: Coordinate { x: x2, y: y2 },
+        })
+    }
+
+    /// Returns the portion of this [HydrothermalVentLine] that lies within
+    /// `viewport`, or [Option::None] if no part of it does.
+    ///
+    /// Lines that cannot be traced, even with diagonals allowed, are returned
+    /// unclipped if they are only partially within `viewport`.
+    pub fn clipped_to(&self, viewport: &Rectangle) -> Option<HydrothermalVentLine> {
+        viewport
+            .clip(self.beginning, self.end)
+            .map(|(beginning, end)| HydrothermalVentLine { beginning, end })
+    }
+
+    /// Returns every [Coordinate] that this [HydrothermalVentLine] shares with
+    /// `other`, computed analytically instead of by tracing both lines.
+    ///
+    /// Lines that cannot be traced, even with diagonals allowed, share no
+    /// coordinates with any other line.
+    pub fn crossings_with(&self, other: &HydrothermalVentLine) -> Coordinates {
+        if !self.can_trace(true) || !other.can_trace(true) {
+            return Coordinates::from(vec![]);
+        }
+
+        let (step, length) = self.step_and_length();
+        let (other_step, other_length) = other.step_and_length();
+
+        if step == ZERO {
+            if other_step == ZERO {
+                return Coordinates::from(if self.beginning == other.beginning {
+                    vec![self.beginning]
+                } else {
+                    vec![]
+                });
+            }
+
+            // Measure everything along the line that actually has a
+            // direction.
+            return other.crossings_with(self);
+        }
+
+        let offset = WideVector::between(self.beginning, other.beginning);
+        let determinant = cross_product_of(step.into(), other_step);
+
+        if determinant == 0 {
+            // Parallel lines only share coordinates if they are collinear.
+            if cross_product_of(offset, step) != 0 {
+                return Coordinates::from(vec![]);
+            }
+
+            // Project both ends of `other` onto this line to find the span
+            // of steps that the two lines share.
+            let step_magnitude = dot_product_of(step.into(), step);
+            let other_beginning_steps = dot_product_of(offset, step) / step_magnitude;
+            let other_end_steps =
+                dot_product_of(WideVector::between(self.beginning, other.end), step)
+                    / step_magnitude;
+            let first_shared_step = other_beginning_steps.min(other_end_steps).max(0);
+            let last_shared_step = other_beginning_steps.max(other_end_steps).min(length);
+
+            return (first_shared_step..=last_shared_step)
+                .map(|steps| self.coordinate_after(steps))
+                .collect();
+        }
+
+        // Solve `beginning + steps * step == other.beginning + other_steps *
+        // other_step` using Cramer's rule; the lines only share a coordinate
+        // if both solutions are whole numbers of steps within each line.
+        let steps_numerator = cross_product_of(offset, other_step);
+        let other_steps_numerator = cross_product_of(offset, step);
+        if steps_numerator % determinant != 0 || other_steps_numerator % determinant != 0 {
+            return Coordinates::from(vec![]);
+        }
+
+        let steps = steps_numerator / determinant;
+        let other_steps = other_steps_numerator / determinant;
+        if !(0..=length).contains(&steps) || !(0..=other_length).contains(&other_steps) {
+            return Coordinates::from(vec![]);
+        }
+
+        Coordinates::from(vec![self.coordinate_after(steps)])
+    }
+
+    /// Returns the Euclidean distance between `coordinate` and the closest
+    /// point on this [HydrothermalVentLine].
+    #[cfg(feature = "std")]
+    pub fn distance_to(&self, coordinate: Coordinate) -> f64 {
+        geometry::distance_to_segment(coordinate, self.beginning, self.end)
+    }
+
+    /// Returns where this [HydrothermalVentLine] starts.
+    pub fn beginning(&self) -> Coordinate {
+        self.beginning
+    }
+
+    /// Returns where this [HydrothermalVentLine] terminates.
+    pub fn end(&self) -> Coordinate {
+        self.end
+    }
+
+    /// Returns `true` if this [HydrothermalVentLine] is a diagonal line.
+    pub fn is_diagonal(&self) -> bool {
+        let delta = WideVector::between(self.beginning, self.end);
+
+        delta.x.abs() == delta.y.abs()
+    }
+
+    /// Returns `true` if this [HydrothermalVentLine] is a horizontal line.
+    pub fn is_horizontal(&self) -> bool {
+        self.beginning.y == self.end.y
+    }
+
+    /// Returns `true` if this [HydrothermalVentLine] is a vertical line.
+    pub fn is_vertical(&self) -> bool {
+        self.beginning.x == self.end.x
+    }
+
+    /// Returns the inclusive range of Y-components spanned by this
+    /// [HydrothermalVentLine].
+    pub fn span_of_y(&self) -> (i32, i32) {
+        (
+            self.beginning.y.min(self.end.y),
+            self.beginning.y.max(self.end.y),
+        )
+    }
+
+    /// Returns the [Coordinate] reached by taking `steps` steps from the
+    /// beginning of this [HydrothermalVentLine] towards its end.
+    fn coordinate_after(&self, steps: i64) -> Coordinate {
+        let (step, _) = self.step_and_length();
+
+        // Coordinates along this line always fit within the range of its
+        // ends, so only the intermediate arithmetic needs to be widened.
+        Coordinate {
+            x: (self.beginning.x as i64 + steps * step.x as i64) as i32,
+            y: (self.beginning.y as i64 + steps * step.y as i64) as i32,
+        }
+    }
+
+    /// Returns the single-unit step taken by each iteration of tracing this
+    /// [HydrothermalVentLine], along with the number of such steps needed to
+    /// get from its beginning to its end.
+    ///
+    /// Only meaningful for lines that can be traced with diagonals allowed.
+    fn step_and_length(&self) -> (Vector, i64) {
+        let delta = WideVector::between(self.beginning, self.end);
+
+        (delta.signum(), delta.x.abs().max(delta.y.abs()))
+    }
+}
+
+impl Traceable for HydrothermalVentLine {
+    fn can_trace(&self, are_diagonals_allowed: bool) -> bool {
+        self.is_horizontal() || self.is_vertical() || (are_diagonals_allowed && self.is_diagonal())
+    }
+
+    fn trace(&self, are_diagonals_allowed: bool) -> Result<Coordinates> {
+        if !self.can_trace(are_diagonals_allowed) {
+            return Err(anyhow!("{:?} is untraceable", self));
+        }
+
+        let step = WideVector::between(self.beginning, self.end).signum();
+        let mut coordinate = self.beginning;
+        let mut coordinates = vec![coordinate];
+
+        while coordinate != self.end {
+            coordinate = coordinate + step;
+
+            coordinates.push(coordinate)
+        }
+
+        Ok(Coordinates::from(coordinates))
+    }
+}
+
+/// [Vector] that goes nowhere.
+const ZERO: Vector = Vector { x: 0, y: 0 };
+
+/// Returns the Z-component of the cross product of `a` and the unit `step`.
+///
+/// Since `step` has no component larger than `1`, the result cannot overflow.
+fn cross_product_of(a: WideVector, step: Vector) -> i64 {
+    a.x * step.y as i64 - a.y * step.x as i64
+}
+
+/// Returns the dot product of `a` and the unit `step`.
+///
+/// Since `step` has no component larger than `1`, the result cannot overflow.
+fn dot_product_of(a: WideVector, step: Vector) -> i64 {
+    a.x * step.x as i64 + a.y * step.y as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// Shorthand for creating a [HydrothermalVentLine] from `(x1, y1)` to
+    /// `(x2, y2)`.
+    fn line(x1: i32, y1: i32, x2: i32, y2: i32) -> HydrothermalVentLine {
+        HydrothermalVentLine::between(Coordinate { x: x1, y: y1 }, Coordinate { x: x2, y: y2 })
+    }
+
+    #[test]
+    fn clips_to_viewports() {
+        let viewport = Rectangle::spanning(Coordinate { x: 0, y: 0 }, Coordinate { x: 4, y: 4 });
+
+        assert_eq!(
+            line(1, 3, 3, 1).clipped_to(&viewport),
+            Some(line(1, 3, 3, 1))
+        );
+        assert_eq!(line(5, 0, 5, 4).clipped_to(&viewport), None);
+        assert_eq!(
+            line(2, 2, 2, 9).clipped_to(&viewport),
+            Some(line(2, 2, 2, 4))
+        );
+        assert_eq!(
+            line(6, 6, -1, -1).clipped_to(&viewport),
+            Some(line(4, 4, 0, 0))
+        );
+        assert_eq!(
+            line(-1, 1, 5, 4).clipped_to(&viewport),
+            Some(line(-1, 1, 5, 4))
+        );
+    }
+
+    #[test]
+    fn traces_up_to_the_i32_limit() {
+        let coordinates = line(i32::MAX - 2, i32::MAX, i32::MAX, i32::MAX - 2)
+            .trace(true)
+            .unwrap();
+
+        assert_eq!(
+            coordinates.iter().copied().collect::<Vec<Coordinate>>(),
+            vec![
+                Coordinate {
+                    x: i32::MAX - 2,
+                    y: i32::MAX
+                },
+                Coordinate {
+                    x: i32::MAX - 1,
+                    y: i32::MAX - 1
+                },
+                Coordinate {
+                    x: i32::MAX,
+                    y: i32::MAX - 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn traces_down_to_the_i32_limit() {
+        let coordinates = line(i32::MIN + 1, 0, i32::MIN, 0).trace(false).unwrap();
+
+        assert_eq!(coordinates.len(), 2);
+    }
+
+    #[test]
+    fn crosses_lines_spanning_the_entire_i32_range() {
+        let horizontal = line(i32::MIN, 7, i32::MAX, 7);
+        let vertical = line(-3, i32::MAX, -3, i32::MIN);
+
+        assert_eq!(
+            horizontal
+                .crossings_with(&vertical)
+                .iter()
+                .copied()
+                .collect::<Vec<Coordinate>>(),
+            vec![Coordinate { x: -3, y: 7 }]
+        );
+    }
+
+    #[test]
+    fn crosses_diagonals_spanning_the_entire_i32_range() {
+        let diagonal = line(i32::MIN, i32::MIN, i32::MAX, i32::MAX);
+        let anti_diagonal = line(i32::MIN, i32::MAX, i32::MAX, i32::MIN);
+
+        // The diagonals pass each other at (-0.5, -0.5) without sharing a
+        // coordinate.
+        assert!(diagonal.crossings_with(&anti_diagonal).is_empty());
+        assert_eq!(
+            diagonal
+                .crossings_with(&line(i32::MIN, 0, 0, i32::MIN))
+                .iter()
+                .copied()
+                .collect::<Vec<Coordinate>>(),
+            vec![Coordinate {
+                x: i32::MIN / 2,
+                y: i32::MIN / 2
+            }]
+        );
+    }
+
+    #[test]
+    fn overlaps_collinear_lines_at_the_i32_limit() {
+        let long = line(0, i32::MAX, i32::MAX, i32::MAX);
+        let short = line(i32::MAX, i32::MAX, i32::MAX - 3, i32::MAX);
+
+        assert_eq!(long.crossings_with(&short).len(), 4);
+        assert_eq!(short.crossings_with(&long).len(), 4);
+    }
+}
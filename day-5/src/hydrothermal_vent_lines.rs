@@ -1,144 +1,285 @@
-use crate::coordinate::{Coordinate, Coordinates};
+use crate::coordinate::{Coordinate, Coordinates, Rectangle};
 use crate::traceable::Traceable;
+use crate::vent_polyline::VentPolyline;
 
-use anyhow::{anyhow, Context, Result};
-use lazy_static::lazy_static;
-use regex::Regex;
-
-/// Represents a single hydrothermal vent line.
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct HydrothermalVentLine {
-    /// Where this [HydrothermalVentLine] starts.
-    beginning: Coordinate,
-    /// Where this [HydrothermalVentLine] terminates.
-    end: Coordinate,
+pub use crate::hydrothermal_vent_line::HydrothermalVentLine;
+
+use anyhow::{Context, Result};
+use aoc_common::diagnostic;
+use aoc_common::input::{DeserializeLines, FromInput};
+#[cfg(feature = "arena")]
+use bumpalo::Bump;
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use thiserror::Error;
+use tracing::instrument;
+
+/// Number of traceable vent lines above which
+/// [HydrothermalVentLines::all_crossings] switches from comparing every pair
+/// of lines to a sweep line.
+const SWEEP_LINE_THRESHOLD: usize = 64;
+
+/// Enumerates the ways that text can fail to be interpreted as a
+/// [HydrothermalVentLine] or as [HydrothermalVentLines].
+#[derive(Debug, Error)]
+pub enum VentLineParseError {
+    /// Beginning of the vent line is not a coordinate.
+    #[error("\"{input}\" lacks a valid beginning")]
+    InvalidBeginning {
+        /// Text that was interpreted.
+        input: String,
+        /// Why the beginning is not a coordinate.
+        source: anyhow::Error,
+    },
+    /// End of the vent line is not a coordinate.
+    #[error("\"{input}\" lacks a valid end")]
+    InvalidEnd {
+        /// Text that was interpreted.
+        input: String,
+        /// Why the end is not a coordinate.
+        source: anyhow::Error,
+    },
+    /// Line of the puzzle input is not a [VentPolyline].
+    #[error("Line {line_number} is not a valid hydrothermal vent line")]
+    InvalidLine {
+        /// Number of the offending line, counting from 1.
+        line_number: usize,
+        /// Why the line is not a [VentPolyline], pointing into the whole
+        /// puzzle input.
+        source: anyhow::Error,
+    },
+    /// Text has no arrow between its beginning and end.
+    #[error("\"{input}\" is not a valid serialized hydrothermal vent line")]
+    MissingArrow {
+        /// Text that was interpreted.
+        input: String,
+    },
 }
 
-impl HydrothermalVentLine {
-    /// Interprets the given [str] as a [HydrothermalVentLine].
-    pub fn deserialize(serialized_hydrothermal_vent_line: &str) -> Result<HydrothermalVentLine> {
-        let captures = VENT_LINE_PATTERN
-            .captures(serialized_hydrothermal_vent_line)
-            .with_context(|| {
-                format!(
-                    "\"{}\" is not a valid serialized hydrothermal vent line",
-                    serialized_hydrothermal_vent_line
-                )
-            })?;
-
-        let x1 = captures
-            .name(capture_group_name::X1)
-            .and_then(|capture| capture.as_str().parse::<i32>().ok())
-            .with_context(|| {
-                format!("\"{}\" lacks a valid x1", serialized_hydrothermal_vent_line)
-            })?;
-        let y1 = captures
-            .name(capture_group_name::Y1)
-            .and_then(|capture| capture.as_str().parse::<i32>().ok())
-            .with_context(|| {
-                format!("\"{}\" lacks a valid y1", serialized_hydrothermal_vent_line)
-            })?;
-        let x2 = captures
-            .name(capture_group_name::X2)
-            .and_then(|capture| capture.as_str().parse::<i32>().ok())
-            .with_context(|| {
-                format!("\"{}\" lacks a valid x2", serialized_hydrothermal_vent_line)
-            })?;
-        let y2 = captures
-            .name(capture_group_name::Y2)
-            .and_then(|capture| capture.as_str().parse::<i32>().ok())
-            .with_context(|| {
-                format!("\"{}\" lacks a valid y2", serialized_hydrothermal_vent_line)
-            })?;
-
-        Ok(HydrothermalVentLine {
-            beginning: Coordinate { x: x1, y: y1 },
-            end: Coordinate { x: x2, y: y2 },
-        })
+/// Represents a collection of hydrothermal vent lines, each of which may be a
+/// [VentPolyline] of several legs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HydrothermalVentLines(Vec<VentPolyline>);
+
+impl HydrothermalVentLines {
+    /// Interprets a newline-delimited [str] of serialized hydrothermal vent
+    /// lines as [HydrothermalVentLines].
+    #[instrument(skip_all)]
+    pub fn deserialize(
+        serialized_hydrothermal_vent_lines: &str,
+    ) -> Result<HydrothermalVentLines, VentLineParseError> {
+        let hydrothermal_vent_lines = serialized_hydrothermal_vent_lines
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                VentPolyline::deserialize(line).map_err(|error| {
+                    let line_start =
+                        diagnostic::span_of(line, serialized_hydrothermal_vent_lines).start;
+
+                    VentLineParseError::InvalidLine {
+                        line_number: line_index + 1,
+                        source: diagnostic::relocate(
+                            error,
+                            serialized_hydrothermal_vent_lines,
+                            line_start,
+                        ),
+                    }
+                })
+            })
+            .collect::<Result<Vec<VentPolyline>, VentLineParseError>>()?;
+
+        Ok(HydrothermalVentLines(hydrothermal_vent_lines))
     }
 
-    /// Returns `true` if this [HydrothermalVentLine] is a diagonal line.
-    fn is_diagonal(&self) -> bool {
-        (self.end.x - self.beginning.x).abs() == (self.end.y - self.beginning.y).abs()
+    /// Interprets a newline-delimited [str] of serialized hydrothermal vent
+    /// lines as [HydrothermalVentLines], like
+    /// [HydrothermalVentLines::deserialize], but collecting the points of
+    /// every line in `arena` instead of on the heap.
+    ///
+    /// Everything staged in `arena` is freed when `arena` is dropped.
+    #[cfg(feature = "arena")]
+    #[instrument(skip_all)]
+    pub fn deserialize_in(
+        serialized_hydrothermal_vent_lines: &str,
+        arena: &Bump,
+    ) -> Result<HydrothermalVentLines, VentLineParseError> {
+        let hydrothermal_vent_lines = serialized_hydrothermal_vent_lines
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                VentPolyline::deserialize_in(line, arena).map_err(|error| {
+                    let line_start =
+                        diagnostic::span_of(line, serialized_hydrothermal_vent_lines).start;
+
+                    VentLineParseError::InvalidLine {
+                        line_number: line_index + 1,
+                        source: diagnostic::relocate(
+                            error,
+                            serialized_hydrothermal_vent_lines,
+                            line_start,
+                        ),
+                    }
+                })
+            })
+            .collect::<Result<Vec<VentPolyline>, VentLineParseError>>()?;
+
+        Ok(HydrothermalVentLines(hydrothermal_vent_lines))
     }
 
-    /// Returns `true` if this [HydrothermalVentLine] is a horizontal line.
-    fn is_horizontal(&self) -> bool {
-        self.beginning.y == self.end.y
+    /// Returns every distinct [Coordinate] shared by at least two of the
+    /// traceable legs in this [HydrothermalVentLines], with diagonals
+    /// allowed, sorted by row and then by column.
+    ///
+    /// Joints between consecutive legs of a [VentPolyline] only count as
+    /// crossings if the [VentPolyline] does not deduplicate its joints.
+    ///
+    /// Small collections compare every pair of legs; larger collections
+    /// sweep across the X-axis so that only legs with overlapping extents
+    /// are compared.
+    pub fn all_crossings(&self) -> Coordinates {
+        let traceable_polylines = self.without_untraceable_vent_lines(true);
+        let traceable_legs = legs_of(&traceable_polylines);
+
+        let crossings = if traceable_legs.len() > SWEEP_LINE_THRESHOLD {
+            crossings_by_sweep_line(traceable_legs)
+        } else {
+            crossings_by_pairs(&traceable_legs)
+        };
+
+        let mut crossings = crossings.into_iter().collect::<Vec<Coordinate>>();
+        crossings.sort_by_key(|coordinate| (coordinate.y, coordinate.x));
+
+        Coordinates::from(crossings)
     }
 
-    /// Returns `true` if this [HydrothermalVentLine] is a vertical line.
-    fn is_vertical(&self) -> bool {
-        self.beginning.x == self.end.x
+    /// Returns a clone of this [HydrothermalVentLines] with every traceable
+    /// line clipped to `viewport`, so that tracing it only visits coordinates
+    /// within `viewport`.
+    ///
+    /// Lines that leave and re-enter `viewport` are split into several lines,
+    /// which keeps the overlap counts of every [Coordinate] within `viewport`
+    /// unchanged.
+    ///
+    /// Parameters:
+    /// *   `are_diagonals_allowed`\
+    ///     Is `true` if diagonal lines are considered to be traceable.
+    pub fn clipped_to(
+        &self,
+        viewport: &Rectangle,
+        are_diagonals_allowed: bool,
+    ) -> HydrothermalVentLines {
+        // Untraceable lines are dropped before clipping, since a diagonal
+        // line clipped down to a single coordinate would become traceable.
+        HydrothermalVentLines(
+            self.without_untraceable_vent_lines(are_diagonals_allowed)
+                .0
+                .iter()
+                .flat_map(|polyline| polyline.clipped_to(viewport))
+                .collect(),
+        )
     }
-}
 
-impl Traceable for HydrothermalVentLine {
-    fn can_trace(&self, are_diagonals_allowed: bool) -> bool {
-        self.is_horizontal() || self.is_vertical() || (are_diagonals_allowed && self.is_diagonal())
+    /// Returns `true` if this [HydrothermalVentLines] contains no lines.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
     }
 
-    fn trace(&self, are_diagonals_allowed: bool) -> Result<Coordinates> {
-        if !self.can_trace(are_diagonals_allowed) {
-            return Err(anyhow!("{:?} is untraceable", self));
-        }
+    /// Returns an [Iterator] over every [VentPolyline] in this
+    /// [HydrothermalVentLines].
+    pub fn iter(&self) -> impl Iterator<Item = &VentPolyline> {
+        self.0.iter()
+    }
 
-        let mut coordinate = self.beginning;
-        let Coordinate {
-            x: destination_x,
-            y: destination_y,
-        } = self.end;
-        let mut coordinates = vec![coordinate];
-
-        while coordinate != self.end {
-            coordinate = Coordinate {
-                x: if destination_x > coordinate.x {
-                    coordinate.x + 1
-                } else if destination_x < coordinate.x {
-                    coordinate.x - 1
-                } else {
-                    coordinate.x
-                },
-                y: if destination_y > coordinate.y {
-                    coordinate.y + 1
-                } else if destination_y < coordinate.y {
-                    coordinate.y - 1
+    /// Returns an [Iterator] over every leg of every [VentPolyline] in this
+    /// [HydrothermalVentLines].
+    pub fn legs(&self) -> impl Iterator<Item = &HydrothermalVentLine> {
+        self.0.iter().flat_map(|polyline| polyline.legs())
+    }
+
+    /// Returns how many lines this [HydrothermalVentLines] contains.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the [VentPolyline] in this [HydrothermalVentLines] closest to
+    /// `coordinate`, preferring whichever appears first in case of a tie, or
+    /// [Option::None] if there are no vent lines.
+    pub fn nearest_line(&self, coordinate: Coordinate) -> Option<&VentPolyline> {
+        self.0
+            .iter()
+            .map(|polyline| (polyline, polyline.distance_to(coordinate)))
+            .reduce(|nearest, candidate| {
+                if candidate.1 < nearest.1 {
+                    candidate
                 } else {
-                    coordinate.y
-                },
-            };
+                    nearest
+                }
+            })
+            .map(|(polyline, _)| polyline)
+    }
 
-            coordinates.push(coordinate)
-        }
+    /// Returns a [HashMap] relating each [Coordinate] covered by the traceable
+    /// lines in this [HydrothermalVentLines] to how many of them cover it.
+    ///
+    /// Parameters:
+    /// *   `are_diagonals_allowed`\
+    ///     Is `true` if diagonal lines are considered to be traceable.
+    pub fn overlap_counts(
+        &self,
+        are_diagonals_allowed: bool,
+    ) -> Result<HashMap<Coordinate, usize>> {
+        let coordinates = self
+            .without_untraceable_vent_lines(are_diagonals_allowed)
+            .trace(are_diagonals_allowed)?;
 
-        Ok(Coordinates::from(coordinates))
+        Ok(coordinates.aggregate())
     }
-}
 
-/// Represents a collection of hydrothermal vent lines.
-#[derive(Clone, Debug, PartialEq)]
-pub struct HydrothermalVentLines(Vec<HydrothermalVentLine>);
+    /// Returns every [Coordinate] covered by more than one of the traceable
+    /// lines in this [HydrothermalVentLines], along with how many lines cover
+    /// it, sorted by row and then by column.
+    ///
+    /// Parameters:
+    /// *   `are_diagonals_allowed`\
+    ///     Is `true` if diagonal lines are considered to be traceable.
+    pub fn overlaps(&self, are_diagonals_allowed: bool) -> Result<Vec<(Coordinate, usize)>> {
+        let mut overlaps = self
+            .overlap_counts(are_diagonals_allowed)?
+            .into_iter()
+            .filter(|(_, coordinate_count)| *coordinate_count > 1)
+            .collect::<Vec<(Coordinate, usize)>>();
 
-impl HydrothermalVentLines {
-    /// Interprets a newline-delimited [str] of serialized hydrothermal vent
-    /// lines as [HydrothermalVentLines].
-    pub fn deserialize(serialized_hydrothermal_vent_lines: &str) -> Result<HydrothermalVentLines> {
-        let hydrothermal_vent_lines = serialized_hydrothermal_vent_lines
-            .lines()
-            .map(HydrothermalVentLine::deserialize)
-            .collect::<Result<Vec<HydrothermalVentLine>>>()
-            .with_context(|| {
-                format!(
-                    "\"{}\" is not a valid collection of serialized hydrothermal vent lines",
-                    serialized_hydrothermal_vent_lines
-                )
-            })?;
+        overlaps.sort_by_key(|(coordinate, _)| (coordinate.y, coordinate.x));
 
-        Ok(HydrothermalVentLines(hydrothermal_vent_lines))
+        Ok(overlaps)
+    }
+
+    /// Returns these [HydrothermalVentLines] as a newline-delimited [String]
+    /// of serialized [VentPolyline]s, which
+    /// [HydrothermalVentLines::deserialize] interprets as equal
+    /// [HydrothermalVentLines] if their joints are deduplicated.
+    pub fn serialize(&self) -> String {
+        self.0
+            .iter()
+            .map(VentPolyline::serialize)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Returns a clone of this [HydrothermalVentLines] whose polylines trace
+    /// the joints shared by consecutive legs once if `are_joints_deduplicated`
+    /// is `true`, or once per leg otherwise.
+    pub fn with_joints_deduplicated(&self, are_joints_deduplicated: bool) -> HydrothermalVentLines {
+        HydrothermalVentLines(
+            self.0
+                .iter()
+                .map(|polyline| polyline.with_joints_deduplicated(are_joints_deduplicated))
+                .collect(),
+        )
     }
 
     /// Returns a clone of this [HydrothermalVentLines] sans any untraceable
-    /// hydrothermal vent lines.
+    /// hydrothermal vent lines or legs thereof.
     ///
     /// Parameters:
     /// *   `are_diagonals_allowed`\
@@ -150,23 +291,46 @@ impl HydrothermalVentLines {
         HydrothermalVentLines(
             self.0
                 .iter()
-                .filter(|vent_line| vent_line.can_trace(are_diagonals_allowed))
-                .map(|vent_line| vent_line.to_owned())
+                .filter_map(|polyline| polyline.without_untraceable_legs(are_diagonals_allowed))
                 .collect(),
         )
     }
 }
 
+impl DeserializeLines for HydrothermalVentLines {
+    type Line = VentPolyline;
+
+    fn deserialize_line(line: &str) -> Result<VentPolyline> {
+        VentPolyline::deserialize(line)
+    }
+
+    fn from_lines(vent_polylines: Vec<VentPolyline>) -> HydrothermalVentLines {
+        HydrothermalVentLines(vent_polylines)
+    }
+}
+
+impl FromInput for HydrothermalVentLines {
+    /// Interprets the puzzle input as [HydrothermalVentLines], with the help
+    /// of an arena when the `arena` feature is enabled.
+    fn from_input(input: &str) -> Result<HydrothermalVentLines> {
+        #[cfg(feature = "arena")]
+        return Ok(HydrothermalVentLines::deserialize_in(input, &Bump::new())?);
+        #[cfg(not(feature = "arena"))]
+        Ok(HydrothermalVentLines::deserialize(input)?)
+    }
+}
+
 impl Traceable for HydrothermalVentLines {
     fn can_trace(&self, _: bool) -> bool {
         true
     }
 
+    #[instrument(skip_all)]
     fn trace(&self, are_diagonals_allowed: bool) -> Result<Coordinates> {
         let coordinates = self
             .0
             .iter()
-            .map(|vent_line| vent_line.trace(are_diagonals_allowed))
+            .map(|polyline| polyline.trace(are_diagonals_allowed))
             .collect::<Result<Coordinates>>()
             .context("Cannot trace every hydrothermal vent line")?;
 
@@ -174,35 +338,305 @@ impl Traceable for HydrothermalVentLines {
     }
 }
 
-/// Module used to namespace regular expression capture group names.
-mod capture_group_name {
-    /// Name of the capture group used to select first X-coordinate.
-    pub const X1: &str = "x1";
+/// Single leg of a [VentPolyline], tagged with whether tracing it skips its
+/// beginning so that deduplicated joints are not mistaken for crossings.
+struct Leg<'a> {
+    /// Is `true` if the beginning of this [Leg] is a deduplicated joint that
+    /// only the previous leg of its [VentPolyline] covers.
+    is_beginning_skipped: bool,
+    /// Line traced by this [Leg].
+    vent_line: &'a HydrothermalVentLine,
+}
+
+impl<'a> Leg<'a> {
+    /// Returns every [Coordinate] covered by both this [Leg] and `other`.
+    fn crossings_with(&self, other: &Leg) -> Vec<Coordinate> {
+        self.vent_line
+            .crossings_with(other.vent_line)
+            .iter()
+            .filter(|coordinate| !self.skips(coordinate) && !other.skips(coordinate))
+            .copied()
+            .collect()
+    }
+
+    /// Returns `true` if tracing this [Leg] skips the specified [Coordinate].
+    fn skips(&self, coordinate: &Coordinate) -> bool {
+        self.is_beginning_skipped && *coordinate == self.vent_line.beginning()
+    }
+}
+
+/// Returns every [Coordinate] shared by at least two of the specified `legs`
+/// by comparing every pair of them.
+fn crossings_by_pairs(legs: &[Leg]) -> HashSet<Coordinate> {
+    let mut crossings = HashSet::<Coordinate>::new();
 
-    /// Name of the capture group used to select second X-coordinate.
-    pub const X2: &str = "x2";
+    for (i, leg) in legs.iter().enumerate() {
+        for other_leg in legs.iter().skip(i + 1) {
+            crossings.extend(leg.crossings_with(other_leg));
+        }
+    }
+
+    crossings
+}
+
+/// Returns every [Coordinate] shared by at least two of the specified `legs`
+/// by sweeping a vertical line across the X-axis, only comparing legs whose X
+/// and Y extents overlap.
+fn crossings_by_sweep_line(mut legs: Vec<Leg>) -> HashSet<Coordinate> {
+    legs.sort_by_key(|leg| leg.vent_line.beginning().x.min(leg.vent_line.end().x));
 
-    /// Name of the capture group used to select first Y-coordinate.
-    pub const Y1: &str = "y1";
+    let mut active_legs = Vec::<Leg>::new();
+    let mut crossings = HashSet::<Coordinate>::new();
 
-    /// Name of the capture group used to select second Y-coordinate.
-    pub const Y2: &str = "y2";
+    for leg in legs {
+        let sweep_x = leg.vent_line.beginning().x.min(leg.vent_line.end().x);
+        let (min_y, max_y) = leg.vent_line.span_of_y();
+
+        // Legs that end before the sweep line can never cross anything that
+        // is yet to come.
+        active_legs.retain(|active_leg| {
+            active_leg
+                .vent_line
+                .beginning()
+                .x
+                .max(active_leg.vent_line.end().x)
+                >= sweep_x
+        });
+
+        for active_leg in active_legs.iter() {
+            let (active_min_y, active_max_y) = active_leg.vent_line.span_of_y();
+            if active_min_y <= max_y && min_y <= active_max_y {
+                crossings.extend(leg.crossings_with(active_leg));
+            }
+        }
+
+        active_legs.push(leg);
+    }
+
+    crossings
 }
 
-lazy_static! {
-    /// Regular expression designed to match hydrothermal vent lines.
-    ///
-    /// Capture groups:
-    /// *   [`1`] x1
-    /// *   [`2`] y1
-    /// *   [`3`] x2
-    /// *   [`4`] y2
-    static ref VENT_LINE_PATTERN: Regex =
-        Regex::new(format!(
-            r"\s*(?P<{}>\d+),\s*(?P<{}>\d+)\s*->\s*(?P<{}>\d+),\s*(?P<{}>\d+)\s*",
-            capture_group_name::X1,
-            capture_group_name::Y1,
-            capture_group_name::X2,
-            capture_group_name::Y2,
-        ).as_str()).unwrap();
+/// Returns every leg of every [VentPolyline] in `vent_polylines` as a [Leg].
+fn legs_of(vent_polylines: &HydrothermalVentLines) -> Vec<Leg<'_>> {
+    vent_polylines
+        .iter()
+        .flat_map(|polyline| {
+            let previous_legs = iter::once(None).chain(polyline.legs().map(Some));
+
+            polyline
+                .legs()
+                .zip(previous_legs)
+                .map(|(leg, previous_leg)| Leg {
+                    is_beginning_skipped: polyline.are_joints_deduplicated()
+                        && previous_leg
+                            .map(|previous_leg| previous_leg.end() == leg.beginning())
+                            .unwrap_or(false),
+                    vent_line: leg,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use proptest::prelude::*;
+
+    #[test]
+    fn rejects_coordinates_beyond_the_i32_limit() {
+        assert!(matches!(
+            HydrothermalVentLine::deserialize("0,0 -> 2147483648,0"),
+            Err(VentLineParseError::InvalidEnd { .. })
+        ));
+        assert!(matches!(
+            HydrothermalVentLines::deserialize("0,0 -> 0,2147483648"),
+            Err(VentLineParseError::InvalidLine { line_number: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn points_at_malformed_vent_lines() {
+        for (serialized_hydrothermal_vent_lines, message, offending_text) in [
+            ("0,9 -> 5,9\n8,0 => 0,8", "\"=>\" is not an arrow", "=>"),
+            (
+                "0,9 -> 5,9\n8,0",
+                "Vent line has fewer than 2 points",
+                "8,0",
+            ),
+            (
+                "0,9 -> 5,9\n8,0 -> 0;8",
+                "\"0;8\" is not a valid coordinate (no comma)",
+                "0;8",
+            ),
+        ] {
+            let error = anyhow::Error::from(
+                HydrothermalVentLines::deserialize(serialized_hydrothermal_vent_lines).unwrap_err(),
+            );
+            let input_diagnostic = diagnostic::find(&error).unwrap();
+
+            assert_eq!(input_diagnostic.to_string(), message);
+            assert_eq!(
+                &serialized_hydrothermal_vent_lines[input_diagnostic.span()],
+                offending_text
+            );
+        }
+    }
+
+    #[test]
+    fn deserializes_lines_like_whole_input() {
+        let vent_polylines = SAMPLE
+            .lines()
+            .map(HydrothermalVentLines::deserialize_line)
+            .collect::<Result<Vec<VentPolyline>>>()
+            .unwrap();
+
+        assert_eq!(
+            HydrothermalVentLines::from_lines(vent_polylines),
+            HydrothermalVentLines::deserialize(SAMPLE).unwrap()
+        );
+    }
+
+    #[test]
+    fn counts_overlaps_in_sample() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(SAMPLE).unwrap();
+
+        assert_eq!(hydrothermal_vent_lines.overlaps(false).unwrap().len(), 5);
+        assert_eq!(hydrothermal_vent_lines.overlaps(true).unwrap().len(), 12);
+    }
+
+    #[test]
+    fn finds_the_nearest_line_preferring_the_first_of_a_tie() {
+        let hydrothermal_vent_lines =
+            HydrothermalVentLines::deserialize("0,0 -> 4,0\n0,4 -> 4,4 -> 4,8").unwrap();
+        let [top_line, bottom_line] = [0, 1].map(|index| &hydrothermal_vent_lines.0[index]);
+
+        assert_eq!(
+            hydrothermal_vent_lines.nearest_line(Coordinate { x: 2, y: 1 }),
+            Some(top_line)
+        );
+        assert_eq!(
+            hydrothermal_vent_lines.nearest_line(Coordinate { x: 6, y: 6 }),
+            Some(bottom_line)
+        );
+
+        // Both lines are 2 away from (2, 2), and from (-2, 2) as well.
+        for coordinate in [Coordinate { x: 2, y: 2 }, Coordinate { x: -2, y: 2 }] {
+            assert_eq!(
+                hydrothermal_vent_lines.nearest_line(coordinate),
+                Some(top_line)
+            );
+        }
+
+        let reversed_vent_lines =
+            HydrothermalVentLines(hydrothermal_vent_lines.0.iter().rev().cloned().collect());
+        assert_eq!(
+            reversed_vent_lines.nearest_line(Coordinate { x: 2, y: 2 }),
+            Some(bottom_line)
+        );
+
+        assert_eq!(
+            HydrothermalVentLines::from_lines(vec![]).nearest_line(Coordinate { x: 2, y: 2 }),
+            None
+        );
+    }
+
+    /// Returns a [Strategy] for [HydrothermalVentLines] made up of polylines
+    /// of two to four points within a 100x100 grid.
+    fn hydrothermal_vent_lines() -> impl Strategy<Value = HydrothermalVentLines> {
+        let point = (0..100, 0..100).prop_map(|(x, y)| format!("{},{}", x, y));
+        let polyline = prop::collection::vec(point, 2..5).prop_map(|points| points.join(" -> "));
+
+        prop::collection::vec(polyline, 0..20).prop_map(|polylines| {
+            HydrothermalVentLines::deserialize(&polylines.join("\n")).unwrap()
+        })
+    }
+
+    /// Returns a [Strategy] for [HydrothermalVentLines] made up of more than
+    /// [SWEEP_LINE_THRESHOLD] straight and diagonal legs, crowded into a
+    /// 120x120 grid so that they cross often, some of whose polylines
+    /// deduplicate their joints.
+    fn crowded_traceable_vent_lines() -> impl Strategy<Value = HydrothermalVentLines> {
+        let direction = prop::sample::select(vec![
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ]);
+        let leg = (direction, 0..20);
+        let polyline = (
+            (40..80, 40..80),
+            prop::collection::vec(leg, 1..4),
+            any::<bool>(),
+        )
+            .prop_map(|((x, y), legs, are_joints_deduplicated)| {
+                let mut points = vec![Coordinate { x, y }];
+                for ((dx, dy), length) in legs {
+                    let last_point = points[points.len() - 1];
+                    points.push(Coordinate {
+                        x: last_point.x + dx * length,
+                        y: last_point.y + dy * length,
+                    });
+                }
+
+                VentPolyline::through(&points).with_joints_deduplicated(are_joints_deduplicated)
+            });
+
+        prop::collection::vec(polyline, SWEEP_LINE_THRESHOLD + 1..100)
+            .prop_map(HydrothermalVentLines)
+    }
+
+    proptest! {
+        #[test]
+        fn sweeps_to_the_same_crossings_as_comparing_every_pair(
+            lines in crowded_traceable_vent_lines()
+        ) {
+            let traceable_lines = lines.without_untraceable_vent_lines(true);
+            prop_assert!(legs_of(&traceable_lines).len() > SWEEP_LINE_THRESHOLD);
+
+            prop_assert_eq!(
+                crossings_by_sweep_line(legs_of(&traceable_lines)),
+                crossings_by_pairs(&legs_of(&traceable_lines))
+            );
+        }
+
+        #[test]
+        fn deserializes_what_it_serializes(lines in hydrothermal_vent_lines()) {
+            prop_assert_eq!(HydrothermalVentLines::deserialize(&lines.serialize()).unwrap(), lines);
+        }
+
+        #[test]
+        fn clipping_keeps_overlap_counts_within_the_viewport(
+            lines in hydrothermal_vent_lines(),
+            (x1, y1, x2, y2) in (0..100, 0..100, 0..100, 0..100)
+        ) {
+            let viewport = Rectangle::spanning(Coordinate { x: x1, y: y1 }, Coordinate { x: x2, y: y2 });
+
+            for are_diagonals_allowed in [false, true] {
+                let overlap_counts_within_viewport = lines
+                    .overlap_counts(are_diagonals_allowed)
+                    .unwrap()
+                    .into_iter()
+                    .filter(|(coordinate, _)| viewport.contains(*coordinate))
+                    .collect::<HashMap<Coordinate, usize>>();
+
+                prop_assert_eq!(
+                    lines
+                        .clipped_to(&viewport, are_diagonals_allowed)
+                        .overlap_counts(are_diagonals_allowed)
+                        .unwrap(),
+                    overlap_counts_within_viewport
+                );
+            }
+        }
+
+        #[test]
+        fn diagonals_never_decrease_overlap_count(lines in hydrothermal_vent_lines()) {
+            prop_assert!(lines.overlaps(false).unwrap().len() <= lines.overlaps(true).unwrap().len());
+        }
+    }
 }
@@ -0,0 +1,49 @@
+//! Solves the "Hydrothermal Venture" puzzle.
+//!
+//! Without the default `std` feature, only the [hydrothermal_vent_line] and
+//! [vent_polyline] tracing and crossing of vent lines is built, without
+//! parsing, overlap counting, or rendering, and it only needs `alloc`, so that
+//! it can run on targets without an operating system.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+extern crate alloc;
+extern crate anyhow;
+extern crate aoc_common;
+#[cfg(feature = "arena")]
+extern crate bumpalo;
+#[cfg(feature = "wgpu")]
+extern crate bytemuck;
+#[cfg(feature = "wgpu")]
+extern crate pollster;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "std")]
+extern crate serde_json;
+extern crate thiserror;
+extern crate tracing;
+#[cfg(feature = "wgpu")]
+extern crate wgpu;
+
+pub mod coordinate;
+pub mod fixtures;
+#[cfg(feature = "std")]
+pub mod geojson;
+#[cfg(feature = "wgpu")]
+pub mod gpu;
+pub mod hydrothermal_vent_line;
+#[cfg(feature = "std")]
+pub mod hydrothermal_vent_lines;
+#[cfg(feature = "std")]
+pub mod overlap_statistics;
+#[cfg(feature = "std")]
+pub mod solver;
+#[cfg(feature = "std")]
+pub mod svg;
+pub mod traceable;
+#[cfg(feature = "std")]
+pub mod vent_map;
+#[cfg(feature = "std")]
+pub mod vent_map_comparison;
+pub mod vent_polyline;
@@ -1,89 +1,398 @@
 use anyhow::{Context, Result};
-use hydrothermal_vent_lines::HydrothermalVentLines;
-use std::env::current_dir;
-use tokio::fs::File;
-use tokio::io::AsyncReadExt;
-
-use crate::coordinate::Coordinate;
-use crate::traceable::Traceable;
+use aoc_common::args::ParseArgs;
+use aoc_common::args::Part;
+use aoc_common::batch;
+use aoc_common::completions;
+use aoc_common::concurrent;
+use aoc_common::error::InvalidInput;
+use aoc_common::input;
+use aoc_common::output;
+use aoc_common::render::Renderable;
+use aoc_common::shuffle;
+use aoc_common::table::Table;
+use aoc_common::timing::Timings;
+use aoc_common::watch;
+use args::{Args, ExportFormat};
+use day_5::coordinate::Coordinate;
+use day_5::geojson;
+use day_5::hydrothermal_vent_lines::HydrothermalVentLines;
+use day_5::overlap_statistics::OverlapStatistics;
+use day_5::solver;
+use day_5::svg;
+use day_5::vent_map::VentMap;
+use day_5::vent_map_comparison::VentMapComparison;
+use std::process::ExitCode;
 
 extern crate anyhow;
-extern crate lazy_static;
-extern crate regex;
-extern crate tokio;
-
-mod coordinate;
-mod hydrothermal_vent_lines;
-mod traceable;
-
-#[tokio::main]
-async fn main() -> Result<()> {
-    let hydrothermal_vent_lines = read_hydrothermal_vent_lines("files/input.txt").await?;
-
-    let mut are_diagonals_allowed = false;
-    let mut coordinates_with_multiple_overlapping_vent_lines = hydrothermal_vent_lines
-        .without_untraceable_vent_lines(are_diagonals_allowed)
-        .trace(are_diagonals_allowed)?
-        .aggregate()
-        .iter()
-        .filter(|(_, coordinate_count)| **coordinate_count > 1)
-        .map(|(coordinate, _)| *coordinate)
-        .collect::<Vec<Coordinate>>();
+extern crate aoc_common;
+#[cfg(feature = "arena")]
+extern crate bumpalo;
+extern crate clap;
+extern crate day_5;
+extern crate serde_json;
 
-    println!(
-        "Coordinates with multiple overlapping straight vent lines: {}",
-        coordinates_with_multiple_overlapping_vent_lines.len(),
-    );
+mod args;
+mod explain;
 
-    are_diagonals_allowed = true;
-    coordinates_with_multiple_overlapping_vent_lines = hydrothermal_vent_lines
-        .without_untraceable_vent_lines(are_diagonals_allowed)
-        .trace(are_diagonals_allowed)?
-        .aggregate()
-        .iter()
-        .filter(|(_, coordinate_count)| **coordinate_count > 1)
-        .map(|(coordinate, _)| *coordinate)
-        .collect::<Vec<Coordinate>>();
+fn main() -> ExitCode {
+    #[cfg(feature = "embedded-inputs")]
+    input::embed(include_str!("../files/input.txt"));
 
-    println!(
-        "Coordinates with multiple overlapping straight or diagonal vent lines: {}",
-        coordinates_with_multiple_overlapping_vent_lines.len(),
-    );
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
 
-    Ok(())
+    args.common.run(|| run(&args))
+}
+
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return batch::write_answers_csv(
+            inputs,
+            args.common.out.as_deref(),
+            args.common.part,
+            |input, part| {
+                let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(input)
+                    .context(InvalidInput)?
+                    .with_joints_deduplicated(!args.count_joints_per_leg);
+
+                answers_table(&hydrothermal_vent_lines, Some(part))
+            },
+        );
+    }
+
+    if args.common.watch {
+        return watch::watch_answers(&args.common, |input| {
+            let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(input)
+                .context(InvalidInput)?
+                .with_joints_deduplicated(!args.count_joints_per_leg);
+
+            answers_table(&hydrothermal_vent_lines, args.common.part)
+        });
+    }
+
+    if args.common.visualize {
+        let input = input::load(args.common.input_source())?;
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(&input)
+            .context(InvalidInput)?
+            .with_joints_deduplicated(!args.count_joints_per_leg);
+        let vent_map = VentMap::of(&hydrothermal_vent_lines, true)?;
+        print!("{}", vent_map.render(output::is_color_enabled()));
+
+        return Ok(());
+    }
+
+    if let Some(seed) = args.shuffle {
+        let input = input::load(args.common.input_source())?;
+
+        return args.common.print(&shuffle::check_answers_unchanged(
+            &input,
+            &shuffle::shuffle_lines(&input, seed),
+            seed,
+            |input| {
+                let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(input)
+                    .context(InvalidInput)?
+                    .with_joints_deduplicated(!args.count_joints_per_leg);
+
+                answers_table(&hydrothermal_vent_lines, args.common.part)
+            },
+        )?);
+    }
+
+    let mut timings = Timings::new();
+    let hydrothermal_vent_lines =
+        input::load_parsed::<HydrothermalVentLines>(args.common.input_source(), &mut timings)?
+            .with_joints_deduplicated(!args.count_joints_per_leg);
+
+    if let Some(export_format) = args.export {
+        return export(&hydrothermal_vent_lines, export_format);
+    }
+
+    if let Some(svg_path) = &args.svg {
+        let vent_map = VentMap::of(&hydrothermal_vent_lines, true)?;
+
+        return svg::drawing_of(
+            &hydrothermal_vent_lines,
+            &vent_map,
+            usize::from(args.svg_scale),
+            args.svg_ramp,
+        )
+        .save(svg_path);
+    }
+
+    if let Some(other_hydrothermal_vent_lines_file_path) = &args.compare {
+        let other_hydrothermal_vent_lines = input::load_parsed::<HydrothermalVentLines>(
+            other_hydrothermal_vent_lines_file_path.as_path(),
+            &mut Timings::new(),
+        )?
+        .with_joints_deduplicated(!args.count_joints_per_leg);
+
+        return args.common.print(&comparison_table(
+            &hydrothermal_vent_lines,
+            &other_hydrothermal_vent_lines,
+        )?);
+    }
+
+    if args.list_overlaps {
+        return args.common.print(&overlaps_table(
+            &hydrothermal_vent_lines,
+            args.offset,
+            args.limit,
+        )?);
+    }
+
+    if args.regions {
+        let vent_free_region_sizes =
+            VentMap::of(&hydrothermal_vent_lines, true)?.vent_free_region_sizes();
+
+        return args.common.print(
+            Table::new()
+                .answer("Vent-free regions", vent_free_region_sizes.len())
+                .entry(
+                    "Region sizes",
+                    vent_free_region_sizes
+                        .iter()
+                        .map(|region_size| region_size.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                ),
+        );
+    }
+
+    if args.stats {
+        return args
+            .common
+            .print(&statistics_table(&hydrothermal_vent_lines)?);
+    }
+
+    if args.explain {
+        explain::narrate_overlaps(&hydrothermal_vent_lines, false)?;
+        explain::narrate_overlaps(&hydrothermal_vent_lines, true)?;
+    }
+
+    args.common.print_answers(
+        5,
+        &concurrent::solve_parts(args.common.part, timings, |part| {
+            answers_table(&hydrothermal_vent_lines, Some(part))
+        })?,
+    )
+}
+
+/// Returns a [Table] of the answers to the puzzle for the specified
+/// `hydrothermal_vent_lines`, including only the specified `part` if there is
+/// one and both parts otherwise.
+fn answers_table(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    part: Option<Part>,
+) -> Result<Table> {
+    let mut table = Table::new();
+
+    if Part::One.is_selected_by(part) {
+        table.answer(
+            "Coordinates with multiple overlapping straight vent lines",
+            solver::overlap_count_of(hydrothermal_vent_lines, false)?,
+        );
+    }
+
+    if Part::Two.is_selected_by(part) {
+        table.answer(
+            "Coordinates with multiple overlapping straight or diagonal vent lines",
+            solver::overlap_count_of(hydrothermal_vent_lines, true)?,
+        );
+    }
+
+    Ok(table)
 }
 
-/// Reads the contents of the "diagnostic report" input file as a
-/// newline-separated list of binary numbers.
-async fn read_hydrothermal_vent_lines(
-    hydrothermal_vent_lines_file_path: &str,
-) -> Result<HydrothermalVentLines> {
-    let pwd = current_dir().context("Failed to read current working directory")?;
-    let hydrothermal_vent_lines_file_path_buf = pwd.join(hydrothermal_vent_lines_file_path);
-
-    let mut hydrothermal_vent_lines_file = File::open(&hydrothermal_vent_lines_file_path_buf)
-        .await
-        .with_context(|| {
+/// Returns a [Table] of every coordinate whose overlap count differs between
+/// `hydrothermal_vent_lines` and `other_hydrothermal_vent_lines`, followed by
+/// a summary of how the answers differ.
+fn comparison_table(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    other_hydrothermal_vent_lines: &HydrothermalVentLines,
+) -> Result<Table> {
+    let straight_comparison = VentMapComparison::between(
+        &hydrothermal_vent_lines.overlap_counts(false)?,
+        &other_hydrothermal_vent_lines.overlap_counts(false)?,
+    );
+    let comparison = VentMapComparison::between(
+        &hydrothermal_vent_lines.overlap_counts(true)?,
+        &other_hydrothermal_vent_lines.overlap_counts(true)?,
+    );
+
+    let mut table = Table::new();
+
+    table.title("Coordinates with differing overlap counts:");
+    for change in comparison.changes.iter() {
+        table.row([
+            change.coordinate.to_string(),
+            format!("{} -> {}", change.original_count, change.other_count),
+        ]);
+    }
+
+    table
+        .separator()
+        .entry(
+            "Vent lines",
             format!(
-                "Failed to open file at path \"{}\"",
-                hydrothermal_vent_lines_file_path_buf.display()
-            )
-        })?;
-    let mut raw_hydrothermal_vent_lines_file_contents = vec![];
-
-    hydrothermal_vent_lines_file
-        .read_to_end(&mut raw_hydrothermal_vent_lines_file_contents)
-        .await
-        .with_context(|| {
+                "{} -> {} ({:+})",
+                hydrothermal_vent_lines.len(),
+                other_hydrothermal_vent_lines.len(),
+                other_hydrothermal_vent_lines.len() as i64 - hydrothermal_vent_lines.len() as i64,
+            ),
+        )
+        .entry("Coordinates covered more", comparison.increases())
+        .entry("Coordinates covered less", comparison.decreases())
+        .answer(
+            "Straight overlaps",
             format!(
-                "Failed to read file at path \"{}\"",
-                hydrothermal_vent_lines_file_path_buf.display()
-            )
-        })?;
+                "{} -> {} ({:+})",
+                straight_comparison.original_overlaps,
+                straight_comparison.other_overlaps,
+                straight_comparison.overlaps_delta(),
+            ),
+        )
+        .answer(
+            "All overlaps",
+            format!(
+                "{} -> {} ({:+})",
+                comparison.original_overlaps,
+                comparison.other_overlaps,
+                comparison.overlaps_delta(),
+            ),
+        );
+
+    Ok(table)
+}
+
+/// Prints the specified `hydrothermal_vent_lines`, along with every
+/// coordinate covered by more than one of them, in the given `export_format`.
+fn export(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    export_format: ExportFormat,
+) -> Result<()> {
+    let coordinate_counts = hydrothermal_vent_lines.overlap_counts(true)?;
+
+    match export_format {
+        ExportFormat::GeoJson => {
+            let feature_collection =
+                geojson::feature_collection_of(hydrothermal_vent_lines, &coordinate_counts, 2);
+
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&feature_collection)
+                    .context("Failed to serialize GeoJSON feature collection")?
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns a [Table] of one page of the coordinates covered by more than one
+/// of the specified `hydrothermal_vent_lines`, skipping the first `offset` of
+/// them and listing at most `limit` (or all of the rest if there is no
+/// `limit`).
+fn overlaps_table(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    offset: usize,
+    limit: Option<usize>,
+) -> Result<Table> {
+    let overlaps = hydrothermal_vent_lines.overlaps(true)?;
+    let page = overlaps
+        .iter()
+        .skip(offset)
+        .take(limit.unwrap_or(usize::MAX))
+        .collect::<Vec<&(Coordinate, usize)>>();
+
+    let mut table = Table::new();
+
+    table.header(["Coordinate", "Overlap count"]);
+    for (coordinate, coordinate_count) in page.iter() {
+        table.row([coordinate.to_string(), coordinate_count.to_string()]);
+    }
+
+    table.separator();
+    if page.is_empty() {
+        table.warning(format!("Showing none of {}", overlaps.len()));
+    } else {
+        table.title(format!(
+            "Showing {}-{} of {}",
+            offset + 1,
+            offset + page.len(),
+            overlaps.len()
+        ));
+    }
+
+    Ok(table)
+}
+
+/// Returns a [Table] of how many coordinates are covered by each number of
+/// the specified `hydrothermal_vent_lines`, followed by percentiles of those
+/// overlap counts.
+fn statistics_table(hydrothermal_vent_lines: &HydrothermalVentLines) -> Result<Table> {
+    let statistics = OverlapStatistics::of(&hydrothermal_vent_lines.overlap_counts(true)?);
+
+    let mut table = Table::new();
+
+    table.header(["Overlap count", "Coordinates"]);
+    for (overlap, coordinates) in statistics.histogram.iter() {
+        table.row([overlap, coordinates]);
+    }
+
+    table
+        .separator()
+        .entry("Covered", statistics.covered_coordinates())
+        .entry("Exactly 1", statistics.histogram.get(&1).unwrap_or(&0))
+        .entry("Exactly 2", statistics.histogram.get(&2).unwrap_or(&0))
+        .entry("3 or more", statistics.coordinates_covered_by_at_least(3))
+        .separator();
+
+    if let Some(mean_overlap) = statistics.mean_overlap() {
+        table.entry("Mean", format!("{:.3}", mean_overlap));
+    }
+    for percent in PERCENTILES {
+        if let Some(overlap) = statistics.percentile(percent) {
+            table.entry(&format!("p{}", percent), overlap);
+        }
+    }
+    if let Some(max_overlap) = statistics.max_overlap() {
+        table.entry("Max", max_overlap);
+    }
+
+    Ok(table)
+}
+
+/// Percentiles of overlap counts listed by [statistics_table].
+const PERCENTILES: [f64; 4] = [50.0, 90.0, 95.0, 99.0];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers;
+    use day_5::fixtures::{INPUT, SAMPLE};
+    use insta::assert_snapshot;
+
+    #[test]
+    fn prints_answers_to_sample() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(SAMPLE).unwrap();
 
-    let hydrothermal_vent_lines_file_contents =
-        String::from_utf8_lossy(&raw_hydrothermal_vent_lines_file_contents);
+        assert_snapshot!(answers_table(&hydrothermal_vent_lines, None)
+            .unwrap()
+            .to_string());
+    }
 
-    HydrothermalVentLines::deserialize(&hydrothermal_vent_lines_file_contents)
-        .context("Failed to read hydrothermal vent lines file")
+    #[test]
+    fn agrees_with_local_answers_to_input() {
+        answers::assert_matches_local_answers(
+            5,
+            &answers_table(&HydrothermalVentLines::deserialize(INPUT).unwrap(), None).unwrap(),
+        );
+    }
 }
@@ -0,0 +1,148 @@
+use crate::coordinate::Coordinate;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// Describes how the number of vent lines covering each coordinate is
+/// distributed across a vent map.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverlapStatistics {
+    /// Relates each overlap count to how many coordinates are covered by
+    /// exactly that many vent lines, ordered by overlap count.
+    pub histogram: BTreeMap<usize, usize>,
+}
+
+impl OverlapStatistics {
+    /// Summarizes the given `coordinate_counts`, each relating a coordinate to
+    /// the number of vent lines that cover it.
+    ///
+    /// Coordinates covered by no vent line are not counted.
+    pub fn of(coordinate_counts: &HashMap<Coordinate, usize>) -> OverlapStatistics {
+        let mut histogram = BTreeMap::<usize, usize>::new();
+
+        for coordinate_count in coordinate_counts.values() {
+            if *coordinate_count > 0 {
+                *histogram.entry(*coordinate_count).or_insert(0) += 1;
+            }
+        }
+
+        OverlapStatistics { histogram }
+    }
+
+    /// Returns how many coordinates are covered by at least `minimum_overlap`
+    /// vent lines.
+    pub fn coordinates_covered_by_at_least(&self, minimum_overlap: usize) -> usize {
+        self.histogram
+            .range(minimum_overlap.max(1)..)
+            .map(|(_, coordinates)| *coordinates)
+            .sum()
+    }
+
+    /// Returns how many coordinates are covered by at least one vent line.
+    pub fn covered_coordinates(&self) -> usize {
+        self.coordinates_covered_by_at_least(1)
+    }
+
+    /// Returns the largest number of vent lines covering a single coordinate,
+    /// or [Option::None] if no coordinate is covered.
+    pub fn max_overlap(&self) -> Option<usize> {
+        self.histogram.keys().next_back().copied()
+    }
+
+    /// Returns the average number of vent lines covering each covered
+    /// coordinate, or [Option::None] if no coordinate is covered.
+    pub fn mean_overlap(&self) -> Option<f64> {
+        let covered_coordinates = self.covered_coordinates();
+        if covered_coordinates == 0 {
+            return None;
+        }
+
+        let total_overlap = self
+            .histogram
+            .iter()
+            .map(|(overlap, coordinates)| (overlap * coordinates) as f64)
+            .sum::<f64>();
+
+        Some(total_overlap / covered_coordinates as f64)
+    }
+
+    /// Returns the smallest overlap count that at least `percent` percent of
+    /// covered coordinates do not exceed (the nearest-rank percentile), or
+    /// [Option::None] if no coordinate is covered.
+    ///
+    /// `percent` is clamped to the range `0.0..=100.0`.
+    pub fn percentile(&self, percent: f64) -> Option<usize> {
+        let covered_coordinates = self.covered_coordinates();
+        if covered_coordinates == 0 {
+            return None;
+        }
+
+        let rank = ((percent.clamp(0.0, 100.0) / 100.0 * covered_coordinates as f64).ceil()
+            as usize)
+            .max(1);
+
+        let mut coordinates_so_far = 0;
+        for (overlap, coordinates) in self.histogram.iter() {
+            coordinates_so_far += coordinates;
+
+            if coordinates_so_far >= rank {
+                return Some(*overlap);
+            }
+        }
+
+        self.max_overlap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns [OverlapStatistics] of coordinates along a row, each covered
+    /// by the respective number of vent lines in `counts`.
+    fn statistics_of(counts: &[usize]) -> OverlapStatistics {
+        OverlapStatistics::of(
+            &counts
+                .iter()
+                .enumerate()
+                .map(|(x, count)| (Coordinate { x: x as i32, y: 0 }, *count))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn summarizes_covered_coordinates() {
+        let overlap_statistics = statistics_of(&[0, 1, 1, 1, 2, 2, 3, 4]);
+
+        assert_eq!(
+            overlap_statistics.histogram,
+            BTreeMap::from([(1, 3), (2, 2), (3, 1), (4, 1)])
+        );
+        assert_eq!(overlap_statistics.covered_coordinates(), 7);
+        assert_eq!(overlap_statistics.coordinates_covered_by_at_least(0), 7);
+        assert_eq!(overlap_statistics.coordinates_covered_by_at_least(2), 4);
+        assert_eq!(overlap_statistics.coordinates_covered_by_at_least(6), 0);
+        assert_eq!(overlap_statistics.max_overlap(), Some(4));
+        assert_eq!(overlap_statistics.mean_overlap(), Some(2.0));
+    }
+
+    #[test]
+    fn takes_nearest_rank_percentiles() {
+        let overlap_statistics = statistics_of(&[1, 1, 1, 2, 2, 3, 5]);
+
+        assert_eq!(overlap_statistics.percentile(0.0), Some(1));
+        assert_eq!(overlap_statistics.percentile(50.0), Some(2));
+        assert_eq!(overlap_statistics.percentile(90.0), Some(5));
+        assert_eq!(overlap_statistics.percentile(100.0), Some(5));
+        assert_eq!(overlap_statistics.percentile(250.0), Some(5));
+    }
+
+    #[test]
+    fn has_no_overlaps_without_covered_coordinates() {
+        let overlap_statistics = statistics_of(&[0, 0]);
+
+        assert_eq!(overlap_statistics.covered_coordinates(), 0);
+        assert_eq!(overlap_statistics.max_overlap(), None);
+        assert_eq!(overlap_statistics.mean_overlap(), None);
+        assert_eq!(overlap_statistics.percentile(50.0), None);
+    }
+}
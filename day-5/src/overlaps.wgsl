@@ -0,0 +1,87 @@
+// Rasterizes hydrothermal vent lines into a grid of densities, and then counts
+// the coordinates of that grid that are covered by more than one of them.
+
+/// Number of invocations in every workgroup, which must match
+/// `WORKGROUP_SIZE` in gpu.rs.
+const WORKGROUP_SIZE: u32 = 64u;
+
+/// Dimensions of the density grid and of what is dispatched over it.
+struct Grid {
+    /// Number of columns in the density grid.
+    width: u32,
+    /// Number of coordinates in the density grid.
+    cell_count: u32,
+    /// Number of segments to rasterize.
+    segment_count: u32,
+    _padding: u32,
+}
+
+/// Straight or diagonal leg of a vent line, relative to the top-left corner
+/// of the density grid.
+struct Segment {
+    beginning_x: i32,
+    beginning_y: i32,
+    end_x: i32,
+    end_y: i32,
+    /// Number of points at the beginning of the segment that are not traced,
+    /// which is `1` for joints already traced by the previous leg.
+    skipped_points: u32,
+}
+
+@group(0) @binding(0) var<uniform> grid: Grid;
+@group(0) @binding(1) var<storage, read> segments: array<Segment>;
+@group(0) @binding(2) var<storage, read_write> densities: array<atomic<u32>>;
+@group(0) @binding(3) var<storage, read_write> overlap_count: atomic<u32>;
+
+/// Overlapping coordinates counted by the current workgroup.
+var<workgroup> workgroup_overlap_count: atomic<u32>;
+
+/// Traces one segment into the density grid.
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn rasterize(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) workgroups: vec3<u32>,
+) {
+    let index = invocation_index(id, workgroups);
+    if (index >= grid.segment_count) {
+        return;
+    }
+
+    let segment = segments[index];
+    let delta = vec2<i32>(segment.end_x - segment.beginning_x, segment.end_y - segment.beginning_y);
+    let step = sign(delta);
+    let length = u32(max(abs(delta.x), abs(delta.y)));
+
+    for (var i = segment.skipped_points; i <= length; i++) {
+        let x = u32(segment.beginning_x + step.x * i32(i));
+        let y = u32(segment.beginning_y + step.y * i32(i));
+
+        atomicAdd(&densities[y * grid.width + x], 1u);
+    }
+}
+
+/// Counts whether one coordinate of the density grid is overlapping, adding
+/// the count of the whole workgroup to the total once.
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn reduce(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+    @builtin(num_workgroups) workgroups: vec3<u32>,
+) {
+    let index = invocation_index(id, workgroups);
+    if (index < grid.cell_count && atomicLoad(&densities[index]) > 1u) {
+        atomicAdd(&workgroup_overlap_count, 1u);
+    }
+
+    workgroupBarrier();
+
+    if (local_index == 0u) {
+        atomicAdd(&overlap_count, atomicLoad(&workgroup_overlap_count));
+    }
+}
+
+/// Returns the index of the invocation with the given `id` among every
+/// invocation of a dispatch that spans two dimensions of `workgroups`.
+fn invocation_index(id: vec3<u32>, workgroups: vec3<u32>) -> u32 {
+    return id.y * workgroups.x * WORKGROUP_SIZE + id.x;
+}
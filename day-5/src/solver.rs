@@ -0,0 +1,94 @@
+#[cfg(feature = "wgpu")]
+use crate::gpu::GpuOverlapCounter;
+use crate::hydrothermal_vent_lines::HydrothermalVentLines;
+use crate::traceable::Traceable;
+
+use anyhow::Result;
+use aoc_common::answer::Answer;
+use aoc_common::input::FromInput;
+use aoc_common::shuffle;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Hydrothermal Venture" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HydrothermalVentureSolver;
+
+impl Solver for HydrothermalVentureSolver {
+    type Parsed = HydrothermalVentLines;
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input: &str) -> Result<HydrothermalVentLines> {
+        HydrothermalVentLines::from_input(input)
+    }
+
+    fn part_one(&self, hydrothermal_vent_lines: &HydrothermalVentLines) -> Result<Answer> {
+        Ok(overlap_count_of(hydrothermal_vent_lines, false)?.into())
+    }
+
+    fn part_two(&self, hydrothermal_vent_lines: &HydrothermalVentLines) -> Result<Answer> {
+        Ok(overlap_count_of(hydrothermal_vent_lines, true)?.into())
+    }
+
+    fn shuffle(&self, input: &str, seed: u64) -> Option<String> {
+        Some(shuffle::shuffle_lines(input, seed))
+    }
+}
+
+/// Returns how many coordinates are covered by more than one of the specified
+/// `hydrothermal_vent_lines`.
+///
+/// With the `wgpu` feature, they are counted on the GPU unless it cannot be
+/// reached or cannot fit their bounding box, in which case they are counted
+/// on the CPU as usual.
+///
+/// Parameters:
+/// *   `are_diagonals_allowed`\
+///     Is `true` if diagonal lines are considered to be traceable.
+pub fn overlap_count_of(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    are_diagonals_allowed: bool,
+) -> Result<usize> {
+    #[cfg(feature = "wgpu")]
+    match GpuOverlapCounter::shared().and_then(|gpu_overlap_counter| {
+        gpu_overlap_counter
+            .overlapping_coordinate_count(hydrothermal_vent_lines, are_diagonals_allowed)
+    }) {
+        Ok(overlap_count) => return Ok(overlap_count),
+        Err(error) => {
+            tracing::warn!(error = %format!("{:#}", error), "counting overlaps on the CPU instead")
+        }
+    }
+
+    let overlap_count = hydrothermal_vent_lines
+        .without_untraceable_vent_lines(are_diagonals_allowed)
+        .trace(are_diagonals_allowed)?
+        .aggregate()
+        .iter()
+        .filter(|(_, coordinate_count)| **coordinate_count > 1)
+        .count();
+
+    Ok(overlap_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = HydrothermalVentureSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(HydrothermalVentureSolver.part_one(&parsed).unwrap(), "5");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = HydrothermalVentureSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(HydrothermalVentureSolver.part_two(&parsed).unwrap(), "12");
+    }
+}
@@ -0,0 +1,85 @@
+use crate::coordinate::{Coordinate, WideVector};
+use crate::hydrothermal_vent_lines::HydrothermalVentLines;
+use crate::vent_map::VentMap;
+
+use aoc_common::svg::{ColorRamp, Svg};
+
+/// Color of the vent lines beneath the overlaps.
+const LINE_STROKE: &str = "#5a5a7a";
+
+/// Returns an [Svg] of the traceable legs of `hydrothermal_vent_lines` over
+/// the bounding box of `vent_map`, every coordinate of which is `scale`
+/// pixels wide and tall.
+///
+/// Every coordinate that `vent_map` has covered by more than one vent line is
+/// filled over the lines with a color along `color_ramp`: its start for the
+/// fewest overlapping vent lines, and its end for the most.
+pub fn drawing_of(
+    hydrothermal_vent_lines: &HydrothermalVentLines,
+    vent_map: &VentMap,
+    scale: usize,
+    color_ramp: ColorRamp,
+) -> Svg {
+    let densities = vent_map.densities();
+    let offset_of = |coordinate: Coordinate| {
+        let offset = WideVector::between(vent_map.origin(), coordinate);
+
+        (offset.x as usize, offset.y as usize)
+    };
+
+    let mut svg = Svg::new(densities.width(), densities.height(), scale);
+
+    for leg in hydrothermal_vent_lines
+        .without_untraceable_vent_lines(true)
+        .legs()
+    {
+        svg.line(
+            offset_of(leg.beginning()),
+            offset_of(leg.end()),
+            LINE_STROKE,
+        );
+    }
+
+    let max_density = densities.cells().iter().copied().max().unwrap_or(0);
+    for row in 0..densities.height() {
+        for column in 0..densities.width() {
+            let density = densities[(row, column)];
+            if density < 2 {
+                continue;
+            }
+
+            // Overlaps start at two vent lines, so the ramp does too.
+            let fraction = match max_density {
+                2 => 1.0,
+                _ => (density - 2) as f64 / (max_density - 2) as f64,
+            };
+            svg.square(column, row, &color_ramp.color_at(fraction));
+        }
+    }
+
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn draws_every_leg_and_overlap_of_sample() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(SAMPLE).unwrap();
+        let vent_map = VentMap::of(&hydrothermal_vent_lines, true).unwrap();
+
+        let rendering =
+            drawing_of(&hydrothermal_vent_lines, &vent_map, 10, ColorRamp::Heat).render();
+
+        assert!(rendering
+            .starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100""#));
+        assert_eq!(rendering.matches("<line ").count(), 10);
+        // Every overlap, along with the background.
+        assert_eq!(rendering.matches("<rect ").count(), 13);
+        // Both coordinates covered by three vent lines are the most
+        // overlapped.
+        assert_eq!(rendering.matches(r##"fill="#cc0000""##).count(), 2);
+    }
+}
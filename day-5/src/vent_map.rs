@@ -0,0 +1,281 @@
+use crate::coordinate::{Coordinate, WideVector};
+use crate::hydrothermal_vent_lines::HydrothermalVentLines;
+
+use anyhow::{anyhow, Result};
+use aoc_common::grid::Grid2D;
+use aoc_common::render::{Cell, Color, Renderable, Style};
+#[cfg(feature = "simd")]
+use std::simd::cmp::SimdPartialOrd;
+#[cfg(feature = "simd")]
+use std::simd::num::SimdUint;
+#[cfg(feature = "simd")]
+use std::simd::{Select, Simd};
+
+/// Number of densities compared at once by
+/// [VentMap::overlapping_coordinate_count_simd].
+#[cfg(feature = "simd")]
+const LANES: usize = 8;
+
+/// Largest number of coordinates that a [VentMap] is willing to allocate.
+const MAX_AREA: i64 = 1 << 28;
+
+/// Dense map of how many hydrothermal vent lines cover each coordinate within
+/// the bounding box of those lines.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VentMap {
+    /// Number of vent lines covering each coordinate, where row `0` and column
+    /// `0` correspond to [VentMap::origin].
+    densities: Grid2D<usize>,
+    /// Top-left [Coordinate] of the bounding box covered by this [VentMap].
+    origin: Coordinate,
+}
+
+impl VentMap {
+    /// Traces the given `hydrothermal_vent_lines` into a [VentMap] spanning
+    /// their bounding box, returning [Err] if that bounding box is too large
+    /// to allocate.
+    ///
+    /// Parameters:
+    /// *   `are_diagonals_allowed`\
+    ///     Is `true` if diagonal lines are considered to be traceable.
+    pub fn of(
+        hydrothermal_vent_lines: &HydrothermalVentLines,
+        are_diagonals_allowed: bool,
+    ) -> Result<VentMap> {
+        let coordinate_counts = hydrothermal_vent_lines.overlap_counts(are_diagonals_allowed)?;
+
+        let min_x = coordinate_counts
+            .keys()
+            .map(|coordinate| coordinate.x)
+            .min();
+        let max_x = coordinate_counts
+            .keys()
+            .map(|coordinate| coordinate.x)
+            .max();
+        let min_y = coordinate_counts
+            .keys()
+            .map(|coordinate| coordinate.y)
+            .min();
+        let max_y = coordinate_counts
+            .keys()
+            .map(|coordinate| coordinate.y)
+            .max();
+
+        let (origin, width, height) = match (min_x, max_x, min_y, max_y) {
+            (Some(min_x), Some(max_x), Some(min_y), Some(max_y)) => {
+                let origin = Coordinate { x: min_x, y: min_y };
+                let diagonal = WideVector::between(origin, Coordinate { x: max_x, y: max_y });
+
+                (origin, diagonal.x + 1, diagonal.y + 1)
+            }
+            _ => (Coordinate { x: 0, y: 0 }, 0, 0),
+        };
+
+        // Both dimensions can be as large as 2^32, whose square overflows an
+        // i64, so a product that does not fit is just as much too large.
+        if width.checked_mul(height).is_none_or(|area| area > MAX_AREA) {
+            return Err(anyhow!(
+                "Bounding box of {}x{} coordinates is too large to map",
+                width,
+                height
+            ));
+        }
+
+        let mut densities = Grid2D::filled_with(0, width as usize, height as usize);
+        for (coordinate, coordinate_count) in coordinate_counts {
+            let offset = WideVector::between(origin, coordinate);
+
+            densities[(offset.y as usize, offset.x as usize)] = coordinate_count;
+        }
+
+        Ok(VentMap { densities, origin })
+    }
+
+    /// Returns the number of vent lines covering the specified [Coordinate],
+    /// which is `0` for coordinates outside of this [VentMap].
+    pub fn density_at(&self, coordinate: Coordinate) -> usize {
+        let offset = WideVector::between(self.origin, coordinate);
+        if offset.x < 0 || offset.y < 0 {
+            return 0;
+        }
+
+        *self
+            .densities
+            .get(offset.y as usize, offset.x as usize)
+            .unwrap_or(&0)
+    }
+
+    /// Returns the number of vent lines covering each coordinate, where row
+    /// `0` and column `0` correspond to [VentMap::origin].
+    pub fn densities(&self) -> &Grid2D<usize> {
+        &self.densities
+    }
+
+    /// Returns the top-left [Coordinate] of the bounding box covered by this
+    /// [VentMap].
+    pub fn origin(&self) -> Coordinate {
+        self.origin
+    }
+
+    /// Returns how many coordinates within this [VentMap] are covered by more
+    /// than one vent line.
+    pub fn overlapping_coordinate_count(&self) -> usize {
+        self.densities
+            .cells()
+            .iter()
+            .filter(|density| **density > 1)
+            .count()
+    }
+
+    /// Returns how many coordinates within this [VentMap] are covered by more
+    /// than one vent line, like [VentMap::overlapping_coordinate_count], but
+    /// comparing [LANES] densities at a time.
+    #[cfg(feature = "simd")]
+    pub fn overlapping_coordinate_count_simd(&self) -> usize {
+        let (chunks, remainder) = self.densities.cells().as_chunks::<LANES>();
+
+        let lane_overlaps = chunks
+            .iter()
+            .map(|chunk| {
+                Simd::from_array(*chunk)
+                    .simd_gt(Simd::splat(1))
+                    .select(Simd::splat(1), Simd::splat(0))
+            })
+            .fold(Simd::<usize, LANES>::splat(0), |sum, overlaps| {
+                sum + overlaps
+            });
+        let overlaps = lane_overlaps.reduce_sum();
+
+        overlaps + remainder.iter().filter(|density| **density > 1).count()
+    }
+
+    /// Returns the size of every region of connected coordinates within this
+    /// [VentMap] that no vent line covers, largest first.
+    pub fn vent_free_region_sizes(&self) -> Vec<usize> {
+        let mut region_sizes = self
+            .densities
+            .regions(|density| *density == 0)
+            .iter()
+            .map(Vec::len)
+            .collect::<Vec<usize>>();
+
+        region_sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        region_sizes
+    }
+}
+
+impl Renderable for VentMap {
+    /// Returns the density of every coordinate of this [VentMap] as a
+    /// [Cell], like the diagrams of the puzzle: "." where no vent line
+    /// covers the coordinate, and the number of vent lines covering it
+    /// otherwise ("+" beyond 9), with overlaps standing out.
+    fn cells(&self) -> Vec<Vec<Cell>> {
+        (0..self.densities.height())
+            .map(|row| {
+                (0..self.densities.width())
+                    .map(|column| match self.densities[(row, column)] {
+                        0 => Cell::new(".", Style::PLAIN.dim()),
+                        1 => Cell::new("1", Style::PLAIN.fg(Color::Yellow)),
+                        density @ 2..=9 => {
+                            Cell::new(density.to_string(), Style::PLAIN.bold().fg(Color::Red))
+                        }
+                        _ => Cell::new("+", Style::PLAIN.bold().fg(Color::Red)),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+
+    #[test]
+    fn maps_lines_near_the_i32_limit() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(
+            "2147483645,2147483647 -> 2147483647,2147483647\n2147483647,2147483645 -> 2147483647,2147483647",
+        )
+        .unwrap();
+
+        let vent_map = VentMap::of(&hydrothermal_vent_lines, false).unwrap();
+
+        assert_eq!(
+            vent_map.density_at(Coordinate {
+                x: i32::MAX,
+                y: i32::MAX
+            }),
+            2
+        );
+        assert_eq!(
+            vent_map.density_at(Coordinate {
+                x: i32::MIN,
+                y: i32::MIN
+            }),
+            0
+        );
+        assert_eq!(vent_map.vent_free_region_sizes(), vec![4]);
+    }
+
+    #[test]
+    fn counts_overlapping_coordinates_in_sample() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(SAMPLE).unwrap();
+
+        for (are_diagonals_allowed, overlapping_coordinate_count) in [(false, 5), (true, 12)] {
+            let vent_map = VentMap::of(&hydrothermal_vent_lines, are_diagonals_allowed).unwrap();
+
+            assert_eq!(
+                vent_map.overlapping_coordinate_count(),
+                overlapping_coordinate_count
+            );
+            #[cfg(feature = "simd")]
+            assert_eq!(
+                vent_map.overlapping_coordinate_count_simd(),
+                overlapping_coordinate_count
+            );
+        }
+    }
+
+    #[test]
+    fn renders_sample_like_the_puzzle() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(SAMPLE).unwrap();
+        let vent_map = VentMap::of(&hydrothermal_vent_lines, true).unwrap();
+
+        assert_eq!(
+            vent_map.render(false),
+            "1.1....11.\n\
+             .111...2..\n\
+             ..2.1.111.\n\
+             ...1.2.2..\n\
+             .112313211\n\
+             ...1.2....\n\
+             ..1...1...\n\
+             .1.....1..\n\
+             1.......1.\n\
+             222111....\n"
+        );
+    }
+
+    #[test]
+    fn refuses_to_map_enormous_bounding_boxes() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(
+            "0,0 -> 0,0\n2147483647,2147483647 -> 2147483647,2147483647",
+        )
+        .unwrap();
+
+        assert!(VentMap::of(&hydrothermal_vent_lines, false).is_err());
+    }
+
+    #[test]
+    fn refuses_to_map_bounding_boxes_spanning_the_entire_i32_range() {
+        let hydrothermal_vent_lines = HydrothermalVentLines::deserialize(
+            "-2147483648,-2147483648 -> -2147483648,-2147483648\n\
+             2147483647,2147483647 -> 2147483647,2147483647",
+        )
+        .unwrap();
+
+        assert!(VentMap::of(&hydrothermal_vent_lines, false).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::coordinate::Coordinate;
+
+use std::collections::HashMap;
+
+/// Describes how the number of vent lines covering a single [Coordinate]
+/// differs between two vent maps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OverlapCountChange {
+    /// [Coordinate] whose overlap count differs.
+    pub coordinate: Coordinate,
+    /// Number of vent lines covering [OverlapCountChange::coordinate] in the
+    /// original vent map.
+    pub original_count: usize,
+    /// Number of vent lines covering [OverlapCountChange::coordinate] in the
+    /// vent map being compared against the original.
+    pub other_count: usize,
+}
+
+/// Summarizes every difference between the overlap counts of two vent maps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VentMapComparison {
+    /// Every [Coordinate] whose overlap count differs between the two vent
+    /// maps, sorted by row and then by column.
+    pub changes: Vec<OverlapCountChange>,
+    /// Number of coordinates covered by multiple vent lines in the original
+    /// vent map.
+    pub original_overlaps: usize,
+    /// Number of coordinates covered by multiple vent lines in the vent map
+    /// being compared against the original.
+    pub other_overlaps: usize,
+}
+
+impl VentMapComparison {
+    /// Compares the `original_counts` of one vent map to the `other_counts` of
+    /// another, each relating coordinates to the number of vent lines that
+    /// cover them.
+    pub fn between(
+        original_counts: &HashMap<Coordinate, usize>,
+        other_counts: &HashMap<Coordinate, usize>,
+    ) -> VentMapComparison {
+        let mut changes = original_counts
+            .keys()
+            .chain(
+                other_counts
+                    .keys()
+                    .filter(|coordinate| !original_counts.contains_key(coordinate)),
+            )
+            .map(|coordinate| OverlapCountChange {
+                coordinate: *coordinate,
+                original_count: *original_counts.get(coordinate).unwrap_or(&0),
+                other_count: *other_counts.get(coordinate).unwrap_or(&0),
+            })
+            .filter(|change| change.original_count != change.other_count)
+            .collect::<Vec<OverlapCountChange>>();
+
+        changes.sort_by_key(|change| (change.coordinate.y, change.coordinate.x));
+
+        VentMapComparison {
+            changes,
+            original_overlaps: overlaps_in(original_counts),
+            other_overlaps: overlaps_in(other_counts),
+        }
+    }
+
+    /// Returns how many coordinates are covered by more vent lines in the
+    /// other vent map than in the original.
+    pub fn increases(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.other_count > change.original_count)
+            .count()
+    }
+
+    /// Returns how many coordinates are covered by fewer vent lines in the
+    /// other vent map than in the original.
+    pub fn decreases(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.other_count < change.original_count)
+            .count()
+    }
+
+    /// Returns the change in the number of coordinates covered by multiple
+    /// vent lines going from the original vent map to the other.
+    pub fn overlaps_delta(&self) -> i64 {
+        self.other_overlaps as i64 - self.original_overlaps as i64
+    }
+}
+
+/// Returns the number of coordinates in `coordinate_counts` that are covered
+/// by multiple vent lines.
+fn overlaps_in(coordinate_counts: &HashMap<Coordinate, usize>) -> usize {
+    coordinate_counts
+        .values()
+        .filter(|coordinate_count| **coordinate_count > 1)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the [Coordinate] at `x` and `y`.
+    fn at(x: i32, y: i32) -> Coordinate {
+        Coordinate { x, y }
+    }
+
+    #[test]
+    fn lists_changed_counts_by_row_and_then_column() {
+        let original_counts = HashMap::from([(at(0, 0), 2), (at(1, 0), 1), (at(0, 1), 3)]);
+        let other_counts = HashMap::from([(at(0, 0), 2), (at(0, 1), 1), (at(2, 0), 2)]);
+
+        let vent_map_comparison = VentMapComparison::between(&original_counts, &other_counts);
+
+        assert_eq!(
+            vent_map_comparison.changes,
+            [
+                OverlapCountChange {
+                    coordinate: at(1, 0),
+                    original_count: 1,
+                    other_count: 0,
+                },
+                OverlapCountChange {
+                    coordinate: at(2, 0),
+                    original_count: 0,
+                    other_count: 2,
+                },
+                OverlapCountChange {
+                    coordinate: at(0, 1),
+                    original_count: 3,
+                    other_count: 1,
+                },
+            ]
+        );
+        assert_eq!(vent_map_comparison.increases(), 1);
+        assert_eq!(vent_map_comparison.decreases(), 2);
+        assert_eq!(vent_map_comparison.original_overlaps, 2);
+        assert_eq!(vent_map_comparison.other_overlaps, 2);
+        assert_eq!(vent_map_comparison.overlaps_delta(), 0);
+    }
+
+    #[test]
+    fn finds_no_changes_between_identical_vent_maps() {
+        let coordinate_counts = HashMap::from([(at(3, 4), 2), (at(5, 6), 1)]);
+
+        let vent_map_comparison =
+            VentMapComparison::between(&coordinate_counts, &coordinate_counts);
+
+        assert!(vent_map_comparison.changes.is_empty());
+        assert_eq!(vent_map_comparison.overlaps_delta(), 0);
+    }
+
+    #[test]
+    fn counts_overlaps_lost_to_an_empty_vent_map() {
+        let original_counts = HashMap::from([(at(0, 0), 2), (at(1, 1), 4)]);
+
+        let vent_map_comparison = VentMapComparison::between(&original_counts, &HashMap::new());
+
+        assert_eq!(vent_map_comparison.decreases(), 2);
+        assert_eq!(vent_map_comparison.overlaps_delta(), -2);
+    }
+}
@@ -0,0 +1,345 @@
+use crate::coordinate::{Coordinate, Coordinates, Rectangle};
+use crate::hydrothermal_vent_line::HydrothermalVentLine;
+use crate::traceable::Traceable;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use anyhow::Result;
+#[cfg(feature = "std")]
+use aoc_common::diagnostic::{self, InputDiagnostic};
+#[cfg(feature = "std")]
+use aoc_common::parse;
+#[cfg(feature = "arena")]
+use bumpalo::Bump;
+
+/// Represents a hydrothermal vent line made up of a chain of connected legs,
+/// such as `0,9 -> 5,9 -> 5,3`.
+///
+/// A plain `x1,y1 -> x2,y2` vent line is a [VentPolyline] with a single leg.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VentPolyline {
+    /// Is `true` if the joint shared by two consecutive legs should only be
+    /// traced once.
+    are_joints_deduplicated: bool,
+    /// Every leg of this [VentPolyline], in order.
+    legs: Vec<HydrothermalVentLine>,
+}
+
+impl VentPolyline {
+    /// Interprets the given [str] as a [VentPolyline].
+    ///
+    /// The joints of the resulting [VentPolyline] are deduplicated.
+    ///
+    /// Errors are [InputDiagnostic]s that point into the given [str].
+    #[cfg(feature = "std")]
+    pub fn deserialize(serialized_vent_polyline: &str) -> Result<VentPolyline> {
+        check_for_arrow(serialized_vent_polyline)?;
+
+        let points = serialized_vent_polyline
+            .split(ARROW)
+            .map(|serialized_coordinate| {
+                deserialize_coordinate(serialized_coordinate, serialized_vent_polyline)
+            })
+            .collect::<Result<Vec<Coordinate>>>()?;
+
+        Ok(VentPolyline::through(&points))
+    }
+
+    /// Interprets the given [str] as a [VentPolyline], like
+    /// [VentPolyline::deserialize], but collecting its points in `arena`
+    /// instead of on the heap.
+    #[cfg(feature = "arena")]
+    pub fn deserialize_in(serialized_vent_polyline: &str, arena: &Bump) -> Result<VentPolyline> {
+        check_for_arrow(serialized_vent_polyline)?;
+
+        let mut points = bumpalo::collections::Vec::new_in(arena);
+        for serialized_coordinate in serialized_vent_polyline.split(ARROW) {
+            points.push(deserialize_coordinate(
+                serialized_coordinate,
+                serialized_vent_polyline,
+            )?);
+        }
+
+        Ok(VentPolyline::through(&points))
+    }
+
+    /// Returns `true` if the joint shared by two consecutive legs of this
+    /// [VentPolyline] is only traced once.
+    pub fn are_joints_deduplicated(&self) -> bool {
+        self.are_joints_deduplicated
+    }
+
+    /// Returns the pieces of this [VentPolyline] that lie within `viewport`,
+    /// clipping each leg to `viewport` and splitting wherever consecutive legs
+    /// do not share a joint within `viewport`.
+    ///
+    /// Splitting keeps clipped legs that happen to meet on the edge of
+    /// `viewport` from being mistaken for legs that share a joint.
+    pub fn clipped_to(&self, viewport: &Rectangle) -> Vec<VentPolyline> {
+        let mut pieces = vec![];
+        let mut legs = vec![];
+        let mut previous_leg: Option<&HydrothermalVentLine> = None;
+
+        for leg in self.legs.iter() {
+            let is_joint_visible = previous_leg
+                .map(|previous_leg| {
+                    previous_leg.end() == leg.beginning() && viewport.contains(leg.beginning())
+                })
+                .unwrap_or(false);
+
+            if !is_joint_visible && !legs.is_empty() {
+                pieces.push(self.with_legs(legs));
+                legs = vec![];
+            }
+
+            if let Some(clipped_leg) = leg.clipped_to(viewport) {
+                legs.push(clipped_leg);
+            }
+
+            previous_leg = Some(leg);
+        }
+
+        if !legs.is_empty() {
+            pieces.push(self.with_legs(legs));
+        }
+
+        pieces
+    }
+
+    /// Returns the Euclidean distance between `coordinate` and the closest
+    /// point on any leg of this [VentPolyline].
+    #[cfg(feature = "std")]
+    pub fn distance_to(&self, coordinate: Coordinate) -> f64 {
+        self.legs
+            .iter()
+            .map(|leg| leg.distance_to(coordinate))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns an [Iterator] over every leg of this [VentPolyline], in order.
+    pub fn legs(&self) -> impl Iterator<Item = &HydrothermalVentLine> {
+        self.legs.iter()
+    }
+
+    /// Returns the points connected by the legs of this [VentPolyline], in
+    /// order.
+    pub fn points(&self) -> Vec<Coordinate> {
+        self.legs
+            .first()
+            .map(|leg| leg.beginning())
+            .into_iter()
+            .chain(self.legs.iter().map(|leg| leg.end()))
+            .collect()
+    }
+
+    /// Returns this [VentPolyline] as its points separated by arrows, such
+    /// as `0,9 -> 5,9 -> 5,3`, which [VentPolyline::deserialize] interprets
+    /// as an equal [VentPolyline] if its joints are deduplicated.
+    pub fn serialize(&self) -> String {
+        self.points()
+            .iter()
+            .map(|point| format!("{},{}", point.x, point.y))
+            .collect::<Vec<String>>()
+            .join(&format!(" {} ", ARROW))
+    }
+
+    /// Returns the [VentPolyline] with a leg between every pair of
+    /// consecutive `points`, whose joints are deduplicated.
+    pub fn through(points: &[Coordinate]) -> VentPolyline {
+        VentPolyline {
+            are_joints_deduplicated: true,
+            legs: points
+                .windows(2)
+                .map(|pair| HydrothermalVentLine::between(pair[0], pair[1]))
+                .collect(),
+        }
+    }
+
+    /// Returns a clone of this [VentPolyline] that traces the joints shared
+    /// by consecutive legs once if `are_joints_deduplicated` is `true`, or
+    /// once per leg otherwise.
+    pub fn with_joints_deduplicated(&self, are_joints_deduplicated: bool) -> VentPolyline {
+        VentPolyline {
+            are_joints_deduplicated,
+            legs: self.legs.clone(),
+        }
+    }
+
+    /// Returns a clone of this [VentPolyline] sans any untraceable legs,
+    /// returning [Option::None] if no leg is traceable.
+    ///
+    /// Parameters:
+    /// *   `are_diagonals_allowed`\
+    ///     Is `true` if diagonal lines are considered to be traceable.
+    pub fn without_untraceable_legs(&self, are_diagonals_allowed: bool) -> Option<VentPolyline> {
+        let legs = self
+            .legs
+            .iter()
+            .filter(|leg| leg.can_trace(are_diagonals_allowed))
+            .copied()
+            .collect::<Vec<HydrothermalVentLine>>();
+
+        if legs.is_empty() {
+            return None;
+        }
+
+        Some(self.with_legs(legs))
+    }
+
+    /// Returns a [VentPolyline] made up of `legs` that treats its joints the
+    /// same way as this [VentPolyline].
+    fn with_legs(&self, legs: Vec<HydrothermalVentLine>) -> VentPolyline {
+        VentPolyline {
+            are_joints_deduplicated: self.are_joints_deduplicated,
+            legs,
+        }
+    }
+}
+
+impl Traceable for VentPolyline {
+    fn can_trace(&self, are_diagonals_allowed: bool) -> bool {
+        self.legs
+            .iter()
+            .all(|leg| leg.can_trace(are_diagonals_allowed))
+    }
+
+    fn trace(&self, are_diagonals_allowed: bool) -> Result<Coordinates> {
+        let mut coordinates = vec![];
+        let mut previous_leg: Option<&HydrothermalVentLine> = None;
+
+        for leg in self.legs.iter() {
+            let leg_coordinates = leg.trace(are_diagonals_allowed)?;
+
+            // Legs removed by `without_untraceable_legs` can leave gaps, so
+            // only skip points that truly are shared with the previous leg.
+            let is_joint_shared = previous_leg
+                .map(|previous_leg| previous_leg.end() == leg.beginning())
+                .unwrap_or(false);
+
+            let skipped_coordinates = if self.are_joints_deduplicated && is_joint_shared {
+                1
+            } else {
+                0
+            };
+
+            coordinates.extend(leg_coordinates.iter().skip(skipped_coordinates));
+
+            previous_leg = Some(leg);
+        }
+
+        Ok(Coordinates::from(coordinates))
+    }
+}
+
+impl From<HydrothermalVentLine> for VentPolyline {
+    fn from(hydrothermal_vent_line: HydrothermalVentLine) -> Self {
+        VentPolyline {
+            are_joints_deduplicated: true,
+            legs: vec![hydrothermal_vent_line],
+        }
+    }
+}
+
+/// Text separating consecutive points of a serialized [VentPolyline].
+pub(crate) const ARROW: &str = "->";
+
+/// Hint shown under every error raised while deserializing a [VentPolyline].
+#[cfg(feature = "std")]
+const SYNTAX_HELP: &str = "Vent lines are points separated by arrows, e.g. 0,9 -> 5,9";
+
+/// Returns [Err] pointing at whatever sits where the first [ARROW] should be
+/// if `serialized_vent_polyline` has none, since it then has fewer than two
+/// points.
+#[cfg(feature = "std")]
+fn check_for_arrow(serialized_vent_polyline: &str) -> Result<()> {
+    if serialized_vent_polyline.contains(ARROW) {
+        return Ok(());
+    }
+
+    // Whatever follows the first point is most likely a mistyped arrow,
+    // like `=>` or `-`; otherwise the line must only have the one point.
+    let (message, span) = match serialized_vent_polyline.split_whitespace().nth(1) {
+        Some(not_arrow) => (
+            format!("{:?} is not an arrow", not_arrow),
+            diagnostic::span_of(not_arrow, serialized_vent_polyline),
+        ),
+        None => (
+            "Vent line has fewer than 2 points".to_string(),
+            diagnostic::span_of(serialized_vent_polyline.trim(), serialized_vent_polyline),
+        ),
+    };
+
+    Err(
+        InputDiagnostic::new(message, serialized_vent_polyline, span)
+            .with_help(SYNTAX_HELP)
+            .into(),
+    )
+}
+
+/// Interprets `serialized_coordinate`, sliced from
+/// `serialized_vent_polyline`, as an `x,y` [Coordinate].
+#[cfg(feature = "std")]
+fn deserialize_coordinate(
+    serialized_coordinate: &str,
+    serialized_vent_polyline: &str,
+) -> Result<Coordinate> {
+    let (x, y) = parse::coordinate_pair::<i32>(serialized_coordinate).map_err(|error| {
+        InputDiagnostic::new(
+            error.root_cause(),
+            serialized_vent_polyline,
+            diagnostic::span_of(serialized_coordinate.trim(), serialized_vent_polyline),
+        )
+        .with_help(SYNTAX_HELP)
+    })?;
+
+    Ok(Coordinate { x, y })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shorthand for creating a [Coordinate] at `(x, y)`.
+    fn at(x: i32, y: i32) -> Coordinate {
+        Coordinate { x, y }
+    }
+
+    #[test]
+    fn keeps_joints_within_viewports() {
+        let viewport = Rectangle::spanning(at(0, 0), at(4, 4));
+
+        assert_eq!(
+            VentPolyline::through(&[at(-2, 2), at(2, 2), at(2, -2)]).clipped_to(&viewport),
+            vec![VentPolyline::through(&[at(0, 2), at(2, 2), at(2, 0)])]
+        );
+        assert_eq!(
+            VentPolyline::through(&[at(5, 5), at(9, 5), at(9, 9)]).clipped_to(&viewport),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn splits_polylines_whose_joints_lie_outside_of_viewports() {
+        let viewport = Rectangle::spanning(at(0, 0), at(4, 4));
+
+        // Leaves the viewport and comes back into it.
+        assert_eq!(
+            VentPolyline::through(&[at(1, 1), at(7, 1), at(7, 3), at(1, 3)]).clipped_to(&viewport),
+            vec![
+                VentPolyline::through(&[at(1, 1), at(4, 1)]),
+                VentPolyline::through(&[at(4, 3), at(1, 3)]),
+            ]
+        );
+        // Leaves the viewport and comes back through the same coordinate on
+        // its edge, which is covered by both legs rather than a shared joint.
+        assert_eq!(
+            VentPolyline::through(&[at(2, 4), at(6, 4), at(2, 4)]).clipped_to(&viewport),
+            vec![
+                VentPolyline::through(&[at(2, 4), at(4, 4)]),
+                VentPolyline::through(&[at(4, 4), at(2, 4)]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_6::solver::LanternfishSolver;
+
+fn bench_lanternfish_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &LanternfishSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_lanternfish_solver);
+criterion_main!(benches);
@@ -0,0 +1,115 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::counter::Counter;
+use aoc_common::parse;
+use tracing::instrument;
+
+/// Number of days a lanternfish waits between spawning new lanternfish.
+const SPAWN_INTERVAL: usize = 7;
+
+/// Number of extra days a newly spawned lanternfish waits before its first
+/// spawning cycle.
+const MATURATION_DELAY: usize = 2;
+
+/// Largest internal timer value that any lanternfish can have.
+pub const MAX_TIMER: u8 = (SPAWN_INTERVAL + MATURATION_DELAY - 1) as u8;
+
+/// School of lanternfish, represented by how many fish share each internal
+/// timer value instead of by one entry per fish.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LanternfishSchool {
+    /// Number of lanternfish whose internal timer equals the index.
+    counts_by_timer: [u128; MAX_TIMER as usize + 1],
+}
+
+impl LanternfishSchool {
+    /// Interprets the given [str] of comma-separated internal timers as a
+    /// [LanternfishSchool].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_timers: &str) -> Result<LanternfishSchool> {
+        let timers = serialized_timers
+            .trim()
+            .split(',')
+            .map(|serialized_timer| {
+                let timer = parse::parse_number::<u8>(serialized_timer, serialized_timers)
+                    .with_context(|| {
+                        format!("\"{}\" is not a valid internal timer", serialized_timer)
+                    })?;
+
+                if timer > MAX_TIMER {
+                    return Err(anyhow!(
+                        "\"{}\" is not a valid internal timer (it exceeds {})",
+                        serialized_timer,
+                        MAX_TIMER
+                    ));
+                }
+
+                Ok(timer)
+            })
+            .collect::<Result<Vec<u8>>>()
+            .with_context(|| {
+                format!(
+                    "\"{}\" is not a valid list of lanternfish",
+                    serialized_timers
+                )
+            })?;
+
+        Ok(LanternfishSchool::of(&timers))
+    }
+
+    /// Creates a [LanternfishSchool] containing one lanternfish per timer in
+    /// `timers`.
+    ///
+    /// Panics if any timer exceeds [MAX_TIMER].
+    pub fn of(timers: &[u8]) -> LanternfishSchool {
+        let mut counts_by_timer = [0; MAX_TIMER as usize + 1];
+
+        let timer_counts = timers.iter().copied().collect::<Counter<u8, u128>>();
+        for (timer, count) in timer_counts.iter() {
+            assert!(
+                *timer <= MAX_TIMER,
+                "{} is not a valid internal timer (it exceeds {})",
+                timer,
+                MAX_TIMER
+            );
+
+            counts_by_timer[*timer as usize] = *count;
+        }
+
+        LanternfishSchool { counts_by_timer }
+    }
+
+    /// Returns how many lanternfish are in this [LanternfishSchool].
+    pub fn len(&self) -> u128 {
+        self.counts_by_timer.iter().sum()
+    }
+
+    /// Returns `true` if this [LanternfishSchool] contains no lanternfish.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns this [LanternfishSchool] as it will be after one day passes.
+    pub fn next_day(&self) -> LanternfishSchool {
+        let mut counts_by_timer = self.counts_by_timer;
+
+        // Every timer counts down by one, and the fish whose timers were at
+        // zero both restart their cycle and spawn a fish with a full timer.
+        counts_by_timer.rotate_left(1);
+        counts_by_timer[SPAWN_INTERVAL - 1] += counts_by_timer[MAX_TIMER as usize];
+
+        LanternfishSchool { counts_by_timer }
+    }
+
+    /// Returns this [LanternfishSchool] as it will be after `days` days pass.
+    pub fn after(&self, days: usize) -> LanternfishSchool {
+        (0..days).fold(*self, |school, _| school.next_day())
+    }
+}
+
+/// Returns how many lanternfish there will be after `days` days pass, starting
+/// from one lanternfish per internal timer in `initial`.
+///
+/// Panics if any timer exceeds [MAX_TIMER].
+pub fn simulate(initial: &[u8], days: usize) -> u128 {
+    LanternfishSchool::of(initial).after(days).len()
+}
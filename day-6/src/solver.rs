@@ -0,0 +1,73 @@
+use crate::lanternfish::LanternfishSchool;
+
+use anyhow::Result;
+use aoc_common::answer::Answer;
+use aoc_common::solver::Solver;
+
+/// Number of days simulated by the first part of the puzzle.
+const PART_ONE_DAYS: usize = 80;
+
+/// Number of days simulated by the second part of the puzzle.
+const PART_TWO_DAYS: usize = 256;
+
+/// [Solver] for the "Lanternfish" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LanternfishSolver;
+
+impl Solver for LanternfishSolver {
+    type Parsed = LanternfishSchool;
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input: &str) -> Result<LanternfishSchool> {
+        LanternfishSchool::deserialize(input)
+    }
+
+    fn part_one(&self, school: &LanternfishSchool) -> Result<Answer> {
+        Ok(school.after(PART_ONE_DAYS).len().into())
+    }
+
+    fn part_two(&self, school: &LanternfishSchool) -> Result<Answer> {
+        Ok(school.after(PART_TWO_DAYS).len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use crate::lanternfish;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = LanternfishSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(LanternfishSolver.part_one(&parsed).unwrap(), "5934");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = LanternfishSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(LanternfishSolver.part_two(&parsed).unwrap(), "26984457539");
+    }
+
+    #[test]
+    fn counts_more_lanternfish_than_fit_in_a_u64() {
+        assert_eq!(
+            lanternfish::simulate(&[3], 600),
+            53_659_029_182_581_055_724_373
+        );
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&LanternfishSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
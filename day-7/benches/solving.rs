@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_7::solver::CrabAlignmentSolver;
+
+fn bench_crab_alignment_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &CrabAlignmentSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_crab_alignment_solver);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+use crate::crab_positions::CrabPositions;
+
+/// Model of how much fuel a crab submarine burns to move a given distance.
+///
+/// Any `Fn(u64) -> u64` closure is a [CostFn] without a fast path, so custom
+/// fuel models can be evaluated without declaring a new type.
+pub trait CostFn {
+    /// Returns how much fuel it takes a crab to move `distance` positions.
+    fn cost(&self, distance: u64) -> u64;
+
+    /// Returns every position that could possibly be the cheapest one for
+    /// `crab_positions` to align at under this [CostFn], or [Option::None] if
+    /// every position between the outermost crabs must be considered.
+    fn candidate_positions(&self, _crab_positions: &CrabPositions) -> Option<Vec<u32>> {
+        None
+    }
+}
+
+impl<F> CostFn for F
+where
+    F: Fn(u64) -> u64,
+{
+    fn cost(&self, distance: u64) -> u64 {
+        self(distance)
+    }
+}
+
+/// [CostFn] where each step costs a single unit of fuel.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ConstantCost;
+
+impl CostFn for ConstantCost {
+    fn cost(&self, distance: u64) -> u64 {
+        distance
+    }
+
+    fn candidate_positions(&self, crab_positions: &CrabPositions) -> Option<Vec<u32>> {
+        // The total distance to every crab is smallest at the median.
+        crab_positions.median().map(|median| vec![median])
+    }
+}
+
+/// [CostFn] where each step costs one more unit of fuel than the last.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TriangularCost;
+
+impl CostFn for TriangularCost {
+    fn cost(&self, distance: u64) -> u64 {
+        distance * (distance + 1) / 2
+    }
+
+    fn candidate_positions(&self, crab_positions: &CrabPositions) -> Option<Vec<u32>> {
+        // The total cost is within half a position of its minimum at the
+        // mean, so the cheapest whole position is at most one away from it.
+        let mean = crab_positions.mean()?;
+        let (min, max) = crab_positions.span()?;
+
+        let lowest = (mean.floor() as i64 - 1).max(min as i64) as u32;
+        let highest = (mean.ceil() as i64 + 1).min(max as i64) as u32;
+
+        Some((lowest..=highest).collect())
+    }
+}
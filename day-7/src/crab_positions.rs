@@ -0,0 +1,144 @@
+use crate::cost_fn::CostFn;
+
+use anyhow::{Context, Result};
+use aoc_common::parse;
+use tracing::instrument;
+
+/// Position that every crab submarine could move to, along with the total fuel
+/// needed for all of them to get there.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Alignment {
+    /// Total fuel burned by every crab to reach [Alignment::position].
+    pub fuel: u64,
+    /// Horizontal position shared by every crab once aligned.
+    pub position: u32,
+}
+
+/// Horizontal positions of a swarm of crab submarines, sorted in ascending
+/// order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrabPositions(Vec<u32>);
+
+impl CrabPositions {
+    /// Interprets the given [str] of comma-separated horizontal positions as
+    /// [CrabPositions].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_crab_positions: &str) -> Result<CrabPositions> {
+        let positions = serialized_crab_positions
+            .trim()
+            .split(',')
+            .map(|serialized_position| {
+                parse::parse_number::<u32>(serialized_position, serialized_crab_positions)
+                    .with_context(|| {
+                        format!("\"{}\" is not a valid crab position", serialized_position)
+                    })
+            })
+            .collect::<Result<Vec<u32>>>()
+            .with_context(|| {
+                format!(
+                    "\"{}\" is not a valid list of crab positions",
+                    serialized_crab_positions
+                )
+            })?;
+
+        Ok(CrabPositions::from(positions))
+    }
+
+    /// Returns the cheapest [Alignment] of these [CrabPositions] under
+    /// `cost_fn`, or [Option::None] if there are no crabs.
+    ///
+    /// Only the candidate positions suggested by `cost_fn` are considered if
+    /// it has any, falling back to every position between the outermost
+    /// crabs otherwise. Ties go to the leftmost position.
+    pub fn cheapest_alignment(&self, cost_fn: &impl CostFn) -> Option<Alignment> {
+        match cost_fn.candidate_positions(self) {
+            Some(candidate_positions) => {
+                self.cheapest_alignment_among(candidate_positions, cost_fn)
+            }
+            None => self.cheapest_alignment_by_brute_force(cost_fn),
+        }
+    }
+
+    /// Returns the cheapest [Alignment] of these [CrabPositions] under
+    /// `cost_fn` by considering every position between the outermost crabs,
+    /// or [Option::None] if there are no crabs.
+    ///
+    /// Ignores any fast path that `cost_fn` offers, which makes it useful for
+    /// verifying [CrabPositions::cheapest_alignment].
+    pub fn cheapest_alignment_by_brute_force(&self, cost_fn: &impl CostFn) -> Option<Alignment> {
+        let (min, max) = self.span()?;
+
+        self.cheapest_alignment_among(min..=max, cost_fn)
+    }
+
+    /// Returns the total fuel needed for every crab to move to `position`
+    /// under `cost_fn`.
+    pub fn fuel_to_align_at(&self, position: u32, cost_fn: &impl CostFn) -> u64 {
+        self.0
+            .iter()
+            .map(|crab_position| cost_fn.cost(crab_position.abs_diff(position) as u64))
+            .sum()
+    }
+
+    /// Returns `true` if there are no crabs.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns how many crabs there are.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the average position of every crab, or [Option::None] if there
+    /// are no crabs.
+    pub fn mean(&self) -> Option<f64> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let total = self.0.iter().map(|position| *position as u64).sum::<u64>();
+
+        Some(total as f64 / self.0.len() as f64)
+    }
+
+    /// Returns the lower median position of every crab, or [Option::None] if
+    /// there are no crabs.
+    pub fn median(&self) -> Option<u32> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        Some(self.0[(self.0.len() - 1) / 2])
+    }
+
+    /// Returns the leftmost and rightmost crab positions, or [Option::None]
+    /// if there are no crabs.
+    pub fn span(&self) -> Option<(u32, u32)> {
+        Some((*self.0.first()?, *self.0.last()?))
+    }
+
+    /// Returns the cheapest [Alignment] at any of the given `positions`, or
+    /// [Option::None] if there are no `positions`.
+    fn cheapest_alignment_among(
+        &self,
+        positions: impl IntoIterator<Item = u32>,
+        cost_fn: &impl CostFn,
+    ) -> Option<Alignment> {
+        positions
+            .into_iter()
+            .map(|position| Alignment {
+                fuel: self.fuel_to_align_at(position, cost_fn),
+                position,
+            })
+            .min_by_key(|alignment| (alignment.fuel, alignment.position))
+    }
+}
+
+impl From<Vec<u32>> for CrabPositions {
+    fn from(mut positions: Vec<u32>) -> Self {
+        positions.sort_unstable();
+
+        CrabPositions(positions)
+    }
+}
@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod cost_fn;
+pub mod crab_positions;
+pub mod fixtures;
+pub mod solver;
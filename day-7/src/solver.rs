@@ -0,0 +1,72 @@
+use crate::cost_fn::{ConstantCost, CostFn, TriangularCost};
+use crate::crab_positions::CrabPositions;
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::error::NoSolution;
+use aoc_common::solver::Solver;
+
+/// [Solver] for "The Treachery of Whales" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CrabAlignmentSolver;
+
+impl CrabAlignmentSolver {
+    /// Returns the least fuel needed to align the crabs at `crab_positions`
+    /// under `cost_fn`.
+    fn cheapest_fuel(crab_positions: &CrabPositions, cost_fn: &impl CostFn) -> Result<Answer> {
+        let alignment = crab_positions
+            .cheapest_alignment(cost_fn)
+            .context(NoSolution::new("There are no crabs to align"))?;
+
+        Ok(alignment.fuel.into())
+    }
+}
+
+impl Solver for CrabAlignmentSolver {
+    type Parsed = CrabPositions;
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input: &str) -> Result<CrabPositions> {
+        CrabPositions::deserialize(input)
+    }
+
+    fn part_one(&self, crab_positions: &CrabPositions) -> Result<Answer> {
+        CrabAlignmentSolver::cheapest_fuel(crab_positions, &ConstantCost)
+    }
+
+    fn part_two(&self, crab_positions: &CrabPositions) -> Result<Answer> {
+        CrabAlignmentSolver::cheapest_fuel(crab_positions, &TriangularCost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = CrabAlignmentSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(CrabAlignmentSolver.part_one(&parsed).unwrap(), "37");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = CrabAlignmentSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(CrabAlignmentSolver.part_two(&parsed).unwrap(), "168");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&CrabAlignmentSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_8::solver::SegmentSearchSolver;
+
+fn bench_segment_search_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &SegmentSearchSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_segment_search_solver);
+criterion_main!(benches);
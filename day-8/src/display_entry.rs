@@ -0,0 +1,85 @@
+use crate::signal_pattern::SignalPattern;
+
+use anyhow::{anyhow, Context, Result};
+use tracing::instrument;
+
+/// Number of unique signal patterns observed for each display.
+pub const UNIQUE_SIGNAL_PATTERN_COUNT: usize = 10;
+
+/// Number of digits shown by each four-digit output value.
+pub const OUTPUT_DIGIT_COUNT: usize = 4;
+
+/// Notes taken about a single scrambled four-digit seven-segment display.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisplayEntry {
+    /// Signal patterns of the four digits of the display's output value.
+    pub output_patterns: [SignalPattern; OUTPUT_DIGIT_COUNT],
+    /// Signal patterns of all ten digits, in no particular order.
+    pub unique_patterns: [SignalPattern; UNIQUE_SIGNAL_PATTERN_COUNT],
+}
+
+impl DisplayEntry {
+    /// Interprets the given [str] formatted like `<ten patterns> | <four
+    /// patterns>` as a [DisplayEntry].
+    pub fn deserialize(serialized_display_entry: &str) -> Result<DisplayEntry> {
+        let (serialized_unique_patterns, serialized_output_patterns) =
+            serialized_display_entry.split_once('|').ok_or_else(|| {
+                anyhow!(
+                    "\"{}\" is not a valid display entry (it lacks a \"|\")",
+                    serialized_display_entry
+                )
+            })?;
+
+        let unique_patterns = deserialize_signal_patterns(serialized_unique_patterns)
+            .with_context(|| {
+                format!(
+                    "\"{}\" lacks valid unique signal patterns",
+                    serialized_display_entry
+                )
+            })?;
+        let output_patterns = deserialize_signal_patterns(serialized_output_patterns)
+            .with_context(|| {
+                format!(
+                    "\"{}\" lacks valid output signal patterns",
+                    serialized_display_entry
+                )
+            })?;
+
+        Ok(DisplayEntry {
+            output_patterns,
+            unique_patterns,
+        })
+    }
+
+    /// Interprets a newline-delimited [str] of serialized display entries as a
+    /// [Vec] of [DisplayEntry].
+    #[instrument(skip_all)]
+    pub fn deserialize_all(serialized_display_entries: &str) -> Result<Vec<DisplayEntry>> {
+        serialized_display_entries
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(DisplayEntry::deserialize)
+            .collect()
+    }
+}
+
+/// Interprets the given [str] of whitespace-separated signal patterns as an
+/// array of exactly `N` [SignalPattern] instances.
+fn deserialize_signal_patterns<const N: usize>(
+    serialized_signal_patterns: &str,
+) -> Result<[SignalPattern; N]> {
+    let signal_patterns = serialized_signal_patterns
+        .split_whitespace()
+        .map(SignalPattern::deserialize)
+        .collect::<Result<Vec<SignalPattern>>>()?;
+    let signal_pattern_count = signal_patterns.len();
+
+    signal_patterns.try_into().map_err(|_| {
+        anyhow!(
+            "\"{}\" has {} signal patterns (not {})",
+            serialized_signal_patterns.trim(),
+            signal_pattern_count,
+            N
+        )
+    })
+}
@@ -0,0 +1,9 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod display_entry;
+pub mod fixtures;
+pub mod segment_decoder;
+pub mod signal_pattern;
+pub mod solver;
@@ -0,0 +1,311 @@
+use crate::signal_pattern::{letter_of, SignalPattern, SEGMENT_COUNT};
+
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashSet;
+
+/// Segments lit to show each digit on a correctly wired display, indexed by
+/// digit.
+const DIGIT_SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Deduces which segment of a seven-segment display each scrambled wire is
+/// connected to, and uses that wiring to decode signal patterns into digits.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SegmentDecoder {
+    /// Index of the segment connected to each wire, indexed by wire.
+    segments_by_wire: [usize; SEGMENT_COUNT],
+}
+
+impl SegmentDecoder {
+    /// Deduces the wiring of a display from the `unique_patterns` it shows
+    /// for all ten digits, returning [Err] if no wiring explains them.
+    ///
+    /// Every segment is lit by a distinctive number of the ten digits, except
+    /// that `a` and `c` are both lit by eight digits and `d` and `g` by seven;
+    /// those ties are broken by whether the wire is part of the only pattern
+    /// with two wires (a `1`) or four wires (a `4`).
+    pub fn deduce(unique_patterns: &[SignalPattern]) -> Result<SegmentDecoder> {
+        let distinct_patterns = unique_patterns.iter().collect::<HashSet<&SignalPattern>>();
+        if unique_patterns.len() != DIGIT_SEGMENTS.len()
+            || distinct_patterns.len() != DIGIT_SEGMENTS.len()
+        {
+            return Err(anyhow!(
+                "{:?} is not a set of {} distinct signal patterns",
+                unique_patterns,
+                DIGIT_SEGMENTS.len()
+            ));
+        }
+
+        let one = only_pattern_with(unique_patterns, 2)?;
+        let four = only_pattern_with(unique_patterns, 4)?;
+
+        let mut segments_by_wire = [0; SEGMENT_COUNT];
+        for (wire, segment) in segments_by_wire.iter_mut().enumerate() {
+            let appearances = unique_patterns
+                .iter()
+                .filter(|pattern| pattern.contains(wire))
+                .count();
+
+            let letter = match appearances {
+                4 => 'e',
+                6 => 'b',
+                7 if four.contains(wire) => 'd',
+                7 => 'g',
+                8 if one.contains(wire) => 'c',
+                8 => 'a',
+                9 => 'f',
+                _ => {
+                    return Err(anyhow!(
+                        "Wire \"{}\" appears in {} of {:?}, which matches no segment",
+                        letter_of(wire),
+                        appearances,
+                        unique_patterns
+                    ))
+                }
+            };
+
+            *segment = letter as usize - 'a' as usize;
+        }
+
+        let segment_decoder = SegmentDecoder { segments_by_wire };
+
+        // Tallies alone could be fooled, so make sure that every pattern
+        // really does decode to a different digit.
+        let digits = unique_patterns
+            .iter()
+            .map(|pattern| segment_decoder.decode(*pattern))
+            .collect::<Result<HashSet<u8>>>()
+            .with_context(|| format!("{:?} cannot all be decoded", unique_patterns))?;
+        if digits.len() != DIGIT_SEGMENTS.len() {
+            return Err(anyhow!(
+                "{:?} do not decode to {} different digits",
+                unique_patterns,
+                DIGIT_SEGMENTS.len()
+            ));
+        }
+
+        Ok(segment_decoder)
+    }
+
+    /// Returns the digit shown by the scrambled `pattern`, returning [Err] if
+    /// it does not show any digit.
+    pub fn decode(&self, pattern: SignalPattern) -> Result<u8> {
+        let segments = self.unscramble(pattern);
+
+        canonical_digit_patterns()
+            .position(|digit_pattern| digit_pattern == segments)
+            .map(|digit| digit as u8)
+            .ok_or_else(|| {
+                anyhow!(
+                    "\"{}\" (segments \"{}\") does not show a digit",
+                    pattern,
+                    segments
+                )
+            })
+    }
+
+    /// Returns the number shown by the scrambled `patterns`, interpreted as
+    /// decimal digits from most to least significant.
+    pub fn decode_number(&self, patterns: &[SignalPattern]) -> Result<u64> {
+        patterns.iter().try_fold(0, |number, pattern| {
+            Ok(number * 10 + self.decode(*pattern)? as u64)
+        })
+    }
+
+    /// Returns the index of the segment that the wire at index `wire` is
+    /// connected to.
+    pub fn segment_of(&self, wire: usize) -> usize {
+        self.segments_by_wire[wire]
+    }
+
+    /// Returns the segments lit by the scrambled `pattern`.
+    pub fn unscramble(&self, pattern: SignalPattern) -> SignalPattern {
+        pattern
+            .wires()
+            .fold(SignalPattern::default(), |segments, wire| {
+                segments.with(self.segment_of(wire))
+            })
+    }
+
+    /// Returns every `(wire, segment)` connection, labeled by letter, in
+    /// alphabetical order of wire.
+    pub fn wiring(&self) -> Vec<(char, char)> {
+        self.segments_by_wire
+            .iter()
+            .enumerate()
+            .map(|(wire, segment)| (letter_of(wire), letter_of(*segment)))
+            .collect()
+    }
+}
+
+/// Returns `true` if `pattern` has a number of wires that only one digit
+/// (`1`, `4`, `7`, or `8`) uses.
+pub fn is_easy_digit(pattern: &SignalPattern) -> bool {
+    matches!(pattern.len(), 2 | 3 | 4 | 7)
+}
+
+/// Returns an [Iterator] over the segments of every digit on a correctly
+/// wired display, in order of digit.
+fn canonical_digit_patterns() -> impl Iterator<Item = SignalPattern> {
+    DIGIT_SEGMENTS
+        .iter()
+        .map(|digit_segments| SignalPattern::deserialize(digit_segments).unwrap())
+}
+
+/// Returns the only pattern among `patterns` that has `wire_count` wires.
+fn only_pattern_with(patterns: &[SignalPattern], wire_count: usize) -> Result<SignalPattern> {
+    let mut matching_patterns = patterns
+        .iter()
+        .filter(|pattern| pattern.len() == wire_count);
+
+    match (matching_patterns.next(), matching_patterns.next()) {
+        (Some(pattern), None) => Ok(*pattern),
+        _ => Err(anyhow!(
+            "{:?} does not have exactly one pattern with {} wires",
+            patterns,
+            wire_count
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display_entry::DisplayEntry;
+
+    /// Entry worked through step by step in the puzzle description.
+    const EXAMPLE_ENTRY: &str =
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    fn pattern(serialized_signal_pattern: &str) -> SignalPattern {
+        SignalPattern::deserialize(serialized_signal_pattern).unwrap()
+    }
+
+    #[test]
+    fn deduces_the_example_wiring() {
+        let entry = DisplayEntry::deserialize(EXAMPLE_ENTRY).unwrap();
+
+        let segment_decoder = SegmentDecoder::deduce(&entry.unique_patterns).unwrap();
+
+        assert_eq!(
+            segment_decoder.wiring(),
+            vec![
+                ('a', 'c'),
+                ('b', 'f'),
+                ('c', 'g'),
+                ('d', 'a'),
+                ('e', 'b'),
+                ('f', 'd'),
+                ('g', 'e'),
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_every_example_digit() {
+        let entry = DisplayEntry::deserialize(EXAMPLE_ENTRY).unwrap();
+        let segment_decoder = SegmentDecoder::deduce(&entry.unique_patterns).unwrap();
+
+        let expected_digits = [
+            ("acedgfb", 8),
+            ("cdfbe", 5),
+            ("gcdfa", 2),
+            ("fbcad", 3),
+            ("dab", 7),
+            ("cefabd", 9),
+            ("cdfgeb", 6),
+            ("eafb", 4),
+            ("cagedb", 0),
+            ("ab", 1),
+        ];
+
+        for (serialized_signal_pattern, digit) in expected_digits {
+            assert_eq!(
+                segment_decoder
+                    .decode(pattern(serialized_signal_pattern))
+                    .unwrap(),
+                digit,
+                "{} should decode to {}",
+                serialized_signal_pattern,
+                digit
+            );
+        }
+        assert_eq!(
+            segment_decoder
+                .decode_number(&entry.output_patterns)
+                .unwrap(),
+            5353
+        );
+    }
+
+    #[test]
+    fn decodes_patterns_regardless_of_wire_order() {
+        let entry = DisplayEntry::deserialize(EXAMPLE_ENTRY).unwrap();
+        let segment_decoder = SegmentDecoder::deduce(&entry.unique_patterns).unwrap();
+
+        assert_eq!(segment_decoder.decode(pattern("ba")).unwrap(), 1);
+        assert_eq!(segment_decoder.decode(pattern("bfae")).unwrap(), 4);
+    }
+
+    #[test]
+    fn deduces_the_identity_wiring_from_correct_patterns() {
+        let unique_patterns = canonical_digit_patterns().collect::<Vec<SignalPattern>>();
+
+        let segment_decoder = SegmentDecoder::deduce(&unique_patterns).unwrap();
+
+        for wire in 0..SEGMENT_COUNT {
+            assert_eq!(segment_decoder.segment_of(wire), wire);
+        }
+    }
+
+    #[test]
+    fn rejects_patterns_that_cannot_show_a_digit() {
+        let entry = DisplayEntry::deserialize(EXAMPLE_ENTRY).unwrap();
+        let segment_decoder = SegmentDecoder::deduce(&entry.unique_patterns).unwrap();
+
+        assert!(segment_decoder.decode(pattern("a")).is_err());
+        assert!(segment_decoder.decode(pattern("abc")).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicated_patterns() {
+        let mut unique_patterns = canonical_digit_patterns().collect::<Vec<SignalPattern>>();
+        unique_patterns[9] = unique_patterns[8];
+
+        assert!(SegmentDecoder::deduce(&unique_patterns).is_err());
+    }
+
+    #[test]
+    fn rejects_too_few_patterns() {
+        let unique_patterns = canonical_digit_patterns()
+            .take(9)
+            .collect::<Vec<SignalPattern>>();
+
+        assert!(SegmentDecoder::deduce(&unique_patterns).is_err());
+    }
+
+    #[test]
+    fn rejects_patterns_that_no_wiring_explains() {
+        // Swapping the `0` for a pattern with the right number of wires keeps
+        // it distinct but makes the segment tallies inconsistent.
+        let mut unique_patterns = canonical_digit_patterns().collect::<Vec<SignalPattern>>();
+        unique_patterns[0] = pattern("abcdef");
+
+        assert!(SegmentDecoder::deduce(&unique_patterns).is_err());
+    }
+
+    #[test]
+    fn counts_easy_digits() {
+        let entry = DisplayEntry::deserialize(EXAMPLE_ENTRY).unwrap();
+
+        assert_eq!(
+            entry
+                .unique_patterns
+                .iter()
+                .filter(|pattern| is_easy_digit(pattern))
+                .count(),
+            4
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use anyhow::{anyhow, Result};
+use std::fmt::{Debug, Display, Formatter};
+
+/// Number of segments (and wires) in a seven-segment display.
+pub const SEGMENT_COUNT: usize = 7;
+
+/// Set of the wires, or segments, labeled `a` through `g` that are lit for a
+/// single digit of a seven-segment display.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct SignalPattern(u8);
+
+impl SignalPattern {
+    /// Interprets the given [str] of letters `a` through `g` as a
+    /// [SignalPattern], returning [Err] if any letter is repeated or out of
+    /// range.
+    pub fn deserialize(serialized_signal_pattern: &str) -> Result<SignalPattern> {
+        let mut signal_pattern = SignalPattern::default();
+
+        for letter in serialized_signal_pattern.trim().chars() {
+            let wire = wire_of(letter).ok_or_else(|| {
+                anyhow!(
+                    "\"{}\" is not a valid signal pattern (\"{}\" is not a wire)",
+                    serialized_signal_pattern,
+                    letter
+                )
+            })?;
+
+            if signal_pattern.contains(wire) {
+                return Err(anyhow!(
+                    "\"{}\" is not a valid signal pattern (\"{}\" is repeated)",
+                    serialized_signal_pattern,
+                    letter
+                ));
+            }
+
+            signal_pattern = signal_pattern.with(wire);
+        }
+
+        Ok(signal_pattern)
+    }
+
+    /// Returns `true` if the wire or segment at index `wire` (where `0` is
+    /// `a`) is part of this [SignalPattern].
+    pub fn contains(&self, wire: usize) -> bool {
+        self.0 & (1 << wire) != 0
+    }
+
+    /// Returns how many wires or segments are part of this [SignalPattern].
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` if no wire or segment is part of this [SignalPattern].
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an [Iterator] over the index of every wire or segment in this
+    /// [SignalPattern], in alphabetical order.
+    pub fn wires(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..SEGMENT_COUNT).filter(move |wire| self.contains(*wire))
+    }
+
+    /// Returns a copy of this [SignalPattern] that includes the wire or
+    /// segment at index `wire`.
+    pub fn with(&self, wire: usize) -> SignalPattern {
+        SignalPattern(self.0 | (1 << wire))
+    }
+}
+
+impl Debug for SignalPattern {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for SignalPattern {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let letters = self.wires().map(letter_of).collect::<String>();
+
+        write!(f, "{}", letters)
+    }
+}
+
+/// Returns the letter used to label the wire or segment at index `wire`.
+pub fn letter_of(wire: usize) -> char {
+    (b'a' + wire as u8) as char
+}
+
+/// Returns the index of the wire or segment labeled by `letter`, or
+/// [Option::None] if `letter` is not one of `a` through `g`.
+fn wire_of(letter: char) -> Option<usize> {
+    match letter {
+        'a'..='g' => Some(letter as usize - 'a' as usize),
+        _ => None,
+    }
+}
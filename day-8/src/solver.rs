@@ -0,0 +1,77 @@
+use crate::display_entry::DisplayEntry;
+use crate::segment_decoder::{is_easy_digit, SegmentDecoder};
+
+use anyhow::{Context, Result};
+use aoc_common::answer::Answer;
+use aoc_common::solver::Solver;
+
+/// [Solver] for the "Seven Segment Search" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SegmentSearchSolver;
+
+impl Solver for SegmentSearchSolver {
+    type Parsed = Vec<DisplayEntry>;
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<DisplayEntry>> {
+        DisplayEntry::deserialize_all(input)
+    }
+
+    fn part_one(&self, display_entries: &Vec<DisplayEntry>) -> Result<Answer> {
+        let easy_digit_count = display_entries
+            .iter()
+            .flat_map(|entry| entry.output_patterns.iter())
+            .filter(|pattern| is_easy_digit(pattern))
+            .count();
+
+        Ok(easy_digit_count.into())
+    }
+
+    fn part_two(&self, display_entries: &Vec<DisplayEntry>) -> Result<Answer> {
+        let output_value_sum = display_entries
+            .iter()
+            .enumerate()
+            .map(|(entry_index, entry)| {
+                SegmentDecoder::deduce(&entry.unique_patterns)
+                    .and_then(|segment_decoder| {
+                        segment_decoder.decode_number(&entry.output_patterns)
+                    })
+                    .with_context(|| format!("Failed to decode display entry {}", entry_index + 1))
+            })
+            .sum::<Result<u64>>()?;
+
+        Ok(output_value_sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = SegmentSearchSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SegmentSearchSolver.part_one(&parsed).unwrap(), "26");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = SegmentSearchSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SegmentSearchSolver.part_two(&parsed).unwrap(), "61229");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&SegmentSearchSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_common::bench;
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_9::solver::SmokeBasinSolver;
+
+fn bench_smoke_basin_solver(c: &mut Criterion) {
+    bench::bench_solver(
+        c,
+        &SmokeBasinSolver,
+        concat!(env!("CARGO_MANIFEST_DIR"), "/files"),
+    );
+}
+
+criterion_group!(benches, bench_smoke_basin_solver);
+criterion_main!(benches);
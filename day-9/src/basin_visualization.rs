@@ -0,0 +1,75 @@
+use crate::heightmap::{Heightmap, MAX_HEIGHT};
+
+use aoc_common::grid::Grid2D;
+use std::fmt::Write;
+
+/// ANSI escape sequence that resets all text styling.
+const RESET: &str = "\x1b[0m";
+
+/// ANSI escape sequence styling locations that belong to no basin.
+const RIDGE_STYLE: &str = "\x1b[2m";
+
+/// ANSI escape sequence that emboldens text, used to highlight low points.
+const BOLD: &str = "\x1b[1m";
+
+/// ANSI escape sequences of the background colors cycled through to tell
+/// neighboring basins apart.
+const BASIN_STYLES: [&str; 6] = [
+    "\x1b[30;41m",
+    "\x1b[30;42m",
+    "\x1b[30;43m",
+    "\x1b[30;44m",
+    "\x1b[30;45m",
+    "\x1b[30;46m",
+];
+
+/// Returns a rendering of `heightmap` for display in a terminal, where every
+/// basin has its own background color and every low point is bold.
+///
+/// Basins are colored in the order that they appear when reading the
+/// heightmap row by row, so the coloring is stable between renders.
+pub fn render_basins(heightmap: &Heightmap) -> String {
+    let heights = heightmap.heights();
+
+    let mut basin_indices = Grid2D::filled_with(None, heights.width(), heights.height());
+    let basins = heights.regions(|height| *height < MAX_HEIGHT);
+    for (basin_index, basin) in basins.iter().enumerate() {
+        for position in basin {
+            basin_indices[*position] = Some(basin_index);
+        }
+    }
+
+    let mut is_low_point = Grid2D::filled_with(false, heights.width(), heights.height());
+    for position in heightmap.low_points() {
+        is_low_point[position] = true;
+    }
+
+    let mut rendering = String::new();
+    for row in 0..heights.height() {
+        for column in 0..heights.width() {
+            let style = match basin_indices[(row, column)] {
+                Some(basin_index) => BASIN_STYLES[basin_index % BASIN_STYLES.len()],
+                None => RIDGE_STYLE,
+            };
+            let emphasis = if is_low_point[(row, column)] {
+                BOLD
+            } else {
+                ""
+            };
+
+            // Writing to a String cannot fail.
+            let _ = write!(
+                rendering,
+                "{}{}{}{}",
+                style,
+                emphasis,
+                heights[(row, column)],
+                RESET
+            );
+        }
+
+        rendering.push('\n');
+    }
+
+    rendering
+}
@@ -0,0 +1,86 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_common::grid::Grid2D;
+use tracing::instrument;
+
+/// Height of the tallest locations, which never belong to a basin.
+pub const MAX_HEIGHT: u8 = 9;
+
+/// Map of the height of every location on the cave floor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Heightmap(Grid2D<u8>);
+
+impl Heightmap {
+    /// Interprets a newline-delimited [str] of rows of single-digit heights as
+    /// a [Heightmap].
+    #[instrument(skip_all)]
+    pub fn deserialize(serialized_heightmap: &str) -> Result<Heightmap> {
+        let rows = serialized_heightmap
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.trim()
+                    .chars()
+                    .map(|character| {
+                        character
+                            .to_digit(10)
+                            .map(|digit| digit as u8)
+                            .ok_or_else(|| anyhow!("\"{}\" is not a valid height", character))
+                    })
+                    .collect::<Result<Vec<u8>>>()
+                    .with_context(|| format!("\"{}\" is not a valid row of heights", line))
+            })
+            .collect::<Result<Vec<Vec<u8>>>>()?;
+
+        let heights = Grid2D::from_rows(rows).context("Heightmap is not rectangular")?;
+
+        Ok(Heightmap(heights))
+    }
+
+    /// Returns every basin in this [Heightmap], each being the positions of
+    /// the connected locations that flow down to a single low point, largest
+    /// basin first.
+    pub fn basins(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut basins = self.0.regions(|height| *height < MAX_HEIGHT);
+
+        basins.sort_by_key(|basin| std::cmp::Reverse(basin.len()));
+
+        basins
+    }
+
+    /// Returns the [Grid2D] of heights underlying this [Heightmap].
+    pub fn heights(&self) -> &Grid2D<u8> {
+        &self.0
+    }
+
+    /// Returns the positions of every location in this [Heightmap] that is
+    /// lower than all of the locations directly up, down, left, or right of
+    /// it, row by row.
+    pub fn low_points(&self) -> Vec<(usize, usize)> {
+        self.0
+            .iter()
+            .filter(|((row, column), height)| {
+                self.adjacent_heights(*row, *column)
+                    .all(|adjacent_height| *height < adjacent_height)
+            })
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Returns the sum of the risk levels of every low point, where each risk
+    /// level is one more than the height of its low point.
+    pub fn total_risk_level(&self) -> u64 {
+        self.low_points()
+            .iter()
+            .map(|position| self.0[*position] as u64 + 1)
+            .sum()
+    }
+
+    /// Returns an [Iterator] over the heights of the locations directly up,
+    /// down, left, and right of the specified position, skipping any beyond
+    /// the edge of this [Heightmap].
+    fn adjacent_heights(&self, row: usize, column: usize) -> impl Iterator<Item = &u8> {
+        self.0
+            .neighbors4(row, column)
+            .map(move |neighbor| &self.0[neighbor])
+    }
+}
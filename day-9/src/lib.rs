@@ -0,0 +1,8 @@
+extern crate anyhow;
+extern crate aoc_common;
+extern crate tracing;
+
+pub mod basin_visualization;
+pub mod fixtures;
+pub mod heightmap;
+pub mod solver;
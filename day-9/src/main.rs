@@ -0,0 +1,55 @@
+use anyhow::{Context, Result};
+use aoc_common::args::ParseArgs;
+use aoc_common::error::InvalidInput;
+use aoc_common::{completions, input, solver};
+use args::Args;
+use day_9::basin_visualization;
+use day_9::heightmap::Heightmap;
+use day_9::solver::SmokeBasinSolver;
+use std::process::ExitCode;
+
+extern crate anyhow;
+extern crate aoc_common;
+extern crate clap;
+extern crate day_9;
+
+mod args;
+
+fn main() -> ExitCode {
+    input::set_crate_dir(env!("CARGO_MANIFEST_DIR"));
+    let args = Args::parse_args();
+
+    args.common.run(|| run(&args))
+}
+
+/// Solves the puzzle, or does whatever else `args` asks for instead.
+fn run(args: &Args) -> Result<()> {
+    if let Some(shell) = args.common.completions {
+        return completions::print::<Args>(shell);
+    }
+
+    let _tracing_guard = args.common.start_tracing()?;
+
+    if let Some(inputs) = &args.common.inputs {
+        return solver::write_answers_csv(&SmokeBasinSolver, inputs, &args.common);
+    }
+
+    if args.common.watch {
+        return solver::watch_answers(&SmokeBasinSolver, &args.common);
+    }
+
+    let serialized_heightmap = input::load(args.common.input_source())?;
+
+    if args.common.visualize {
+        print!(
+            "{}",
+            basin_visualization::render_basins(
+                &Heightmap::deserialize(&serialized_heightmap).context(InvalidInput)?
+            )
+        );
+
+        return Ok(());
+    }
+
+    solver::print_answers(&SmokeBasinSolver, &serialized_heightmap, &args.common)
+}
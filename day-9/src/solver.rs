@@ -0,0 +1,69 @@
+use crate::heightmap::Heightmap;
+
+use anyhow::Result;
+use aoc_common::answer::Answer;
+use aoc_common::solver::Solver;
+
+/// Number of the largest basins whose sizes are multiplied by the second part
+/// of the puzzle.
+const LARGEST_BASIN_COUNT: usize = 3;
+
+/// [Solver] for the "Smoke Basin" puzzle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SmokeBasinSolver;
+
+impl Solver for SmokeBasinSolver {
+    type Parsed = Heightmap;
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input: &str) -> Result<Heightmap> {
+        Heightmap::deserialize(input)
+    }
+
+    fn part_one(&self, heightmap: &Heightmap) -> Result<Answer> {
+        Ok(heightmap.total_risk_level().into())
+    }
+
+    fn part_two(&self, heightmap: &Heightmap) -> Result<Answer> {
+        let largest_basin_size_product = heightmap
+            .basins()
+            .iter()
+            .take(LARGEST_BASIN_COUNT)
+            .map(|basin| basin.len() as u64)
+            .product::<u64>();
+
+        Ok(largest_basin_size_product.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SAMPLE;
+    use aoc_common::solver::answers_table;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn solves_part_one_of_sample() {
+        let parsed = SmokeBasinSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SmokeBasinSolver.part_one(&parsed).unwrap(), "15");
+    }
+
+    #[test]
+    fn solves_part_two_of_sample() {
+        let parsed = SmokeBasinSolver.parse(SAMPLE).unwrap();
+
+        assert_eq!(SmokeBasinSolver.part_two(&parsed).unwrap(), "1134");
+    }
+
+    #[test]
+    fn prints_answers_to_sample() {
+        assert_snapshot!(answers_table(&SmokeBasinSolver, SAMPLE, None)
+            .unwrap()
+            .to_string());
+    }
+}
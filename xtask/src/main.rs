@@ -0,0 +1,52 @@
+//! Development tasks for the workspace, run from anywhere within it with
+//! `cargo xtask`.
+
+extern crate anyhow;
+extern crate clap;
+
+mod new_day;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+use std::path::Path;
+
+/// Root of the workspace that this program belongs to.
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Command-line arguments accepted by this program.
+#[derive(Debug, Parser)]
+#[clap(about = "Automates the chores of developing the workspace")]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+/// Enumerates what this program can be asked to do.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generates a `day-N` crate with a placeholder solver, sample input,
+    /// tests, and benchmark, and adds it to the workspace.
+    NewDay {
+        /// Year whose puzzle the crate solves; days of years other than 2021
+        /// live in a `YEAR/` directory.
+        #[clap(long, default_value_t = 2021)]
+        year: u16,
+
+        /// Day of December whose puzzle the crate solves.
+        #[clap(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Title of the puzzle, e.g. "Packet Decoder", which names its
+        /// solver; defaults to "Day N".
+        #[clap(long)]
+        title: Option<String>,
+    },
+}
+
+fn main() -> Result<()> {
+    match Args::parse().command {
+        Command::NewDay { year, day, title } => {
+            new_day::new_day(Path::new(ROOT), year, day, title.as_deref())
+        }
+    }
+}
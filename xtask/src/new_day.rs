@@ -0,0 +1,231 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// Year whose days live directly in the root of the workspace, as they do
+/// for `aoc --year`.
+const DEFAULT_YEAR: u16 = 2021;
+
+/// Path of every file of a new day's crate, relative to the crate, along with
+/// the template that it is rendered from.
+const TEMPLATES: [(&str, &str); 8] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/Cargo.toml.template"),
+    ),
+    (
+        "benches/solving.rs",
+        include_str!("../templates/solving.rs.template"),
+    ),
+    ("files/sample.txt", ""),
+    ("src/args.rs", include_str!("../templates/args.rs.template")),
+    (
+        "src/fixtures.rs",
+        include_str!("../templates/fixtures.rs.template"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs.template")),
+    ("src/main.rs", include_str!("../templates/main.rs.template")),
+    (
+        "src/solver.rs",
+        include_str!("../templates/solver.rs.template"),
+    ),
+];
+
+/// Generates the crate of `day` of `year` in the workspace at `root`,
+/// solving the puzzle titled `title` (or "Day N" if there is none), and adds
+/// it to the members of the workspace.
+///
+/// Like `aoc --year` expects, days of [DEFAULT_YEAR] live in `day-N` and are
+/// named that way, while days of every other year live in `YEAR/day-N` and
+/// are named `day-N-YEAR`.
+pub fn new_day(root: &Path, year: u16, day: u8, title: Option<&str>) -> Result<()> {
+    let (member_prefix, package, common_path) = match year {
+        DEFAULT_YEAR => (
+            String::from("day-"),
+            format!("day-{}", day),
+            "../aoc-common",
+        ),
+        _ => (
+            format!("{}/day-", year),
+            format!("day-{}-{}", day, year),
+            "../../aoc-common",
+        ),
+    };
+
+    let crate_dir = root.join(format!("{}{}", member_prefix, day));
+    if crate_dir.exists() {
+        bail!(
+            "Day {} of {} already exists at \"{}\"",
+            day,
+            year,
+            crate_dir.display()
+        );
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Failed to read \"{}\"", manifest_path.display()))?;
+    let manifest = with_member(&manifest, &member_prefix, day)?;
+
+    let title = title.map_or_else(|| format!("Day {}", day), String::from);
+    let placeholders = [
+        ("{{common}}", common_path.to_string()),
+        ("{{day}}", day.to_string()),
+        ("{{lib}}", package.replace('-', "_")),
+        ("{{package}}", package.clone()),
+        ("{{solver}}", solver_name_of(&title)),
+        ("{{title}}", title),
+    ];
+
+    for (relative_path, template) in TEMPLATES {
+        let path = crate_dir.join(relative_path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create \"{}\"", dir.display()))?;
+        }
+
+        fs::write(&path, render(template, &placeholders))
+            .with_context(|| format!("Failed to write \"{}\"", path.display()))?;
+    }
+
+    fs::write(&manifest_path, manifest)
+        .with_context(|| format!("Failed to write \"{}\"", manifest_path.display()))?;
+
+    println!("Created {} in \"{}{}\".", package, member_prefix, day);
+    println!(
+        "Paste the example from the puzzle into its files/sample.txt, and register its solver in aoc-solvers once it solves it."
+    );
+
+    Ok(())
+}
+
+/// Returns `template` with every placeholder in `placeholders` replaced by
+/// what it stands for.
+fn render(template: &str, placeholders: &[(&str, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(template.to_string(), |rendered, (placeholder, value)| {
+            rendered.replace(placeholder, value)
+        })
+}
+
+/// Returns the name of the solver of the puzzle titled `title`, e.g.
+/// `PacketDecoderSolver` for "Packet Decoder".
+fn solver_name_of(title: &str) -> String {
+    let mut solver = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect::<String>();
+    solver.push_str("Solver");
+
+    solver
+}
+
+/// Returns the workspace `manifest` with the crate of `day`, in the member
+/// directory starting with `member_prefix` (e.g. `"2020/day-"`), listed
+/// among its members right after the last earlier day of the same year, so
+/// that days stay in order.
+///
+/// The first day of a year is listed after every other member.
+fn with_member(manifest: &str, member_prefix: &str, day: u8) -> Result<String> {
+    let day_of = |line: &str| {
+        line.trim()
+            .strip_prefix('"')?
+            .strip_prefix(member_prefix)?
+            .strip_suffix("\",")?
+            .parse::<u8>()
+            .ok()
+    };
+
+    let lines = manifest.lines().collect::<Vec<&str>>();
+    let day_lines = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line).map(|member_day| (i, member_day)))
+        .collect::<Vec<(usize, u8)>>();
+
+    if day_lines.iter().any(|(_, member_day)| *member_day == day) {
+        bail!(
+            "{}{} is already a member of the workspace",
+            member_prefix,
+            day
+        );
+    }
+
+    let Some(first_member_index) = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("\"day-"))
+    else {
+        bail!("Workspace has no days among its members");
+    };
+    let insert_at = match day_lines.iter().rfind(|(_, member_day)| *member_day < day) {
+        Some((i, _)) => i + 1,
+        None => match day_lines.first() {
+            Some((i, _)) => *i,
+            None => lines[first_member_index..]
+                .iter()
+                .position(|line| line.trim() == "]")
+                .map(|i| first_member_index + i)
+                .context("Workspace members are never closed")?,
+        },
+    };
+    let first_member = lines[first_member_index];
+    let indentation = &first_member[..first_member.len() - first_member.trim_start().len()];
+
+    let mut manifest = lines[..insert_at].join("\n");
+    manifest.push_str(&format!("\n{}\"{}{}\",\n", indentation, member_prefix, day));
+    manifest.push_str(&lines[insert_at..].join("\n"));
+    manifest.push('\n');
+
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_solvers_after_titles() {
+        assert_eq!(solver_name_of("Packet Decoder"), "PacketDecoderSolver");
+        assert_eq!(solver_name_of("Day 16"), "Day16Solver");
+        assert_eq!(solver_name_of("Beacon scanner!"), "BeaconScannerSolver");
+    }
+
+    #[test]
+    fn keeps_members_in_order_of_day() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-1\",\n    \"day-15\",\n    \"day-17\",\n]\n";
+
+        assert_eq!(
+            with_member(manifest, "day-", 16).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-1\",\n    \"day-15\",\n    \"day-16\",\n    \"day-17\",\n]\n"
+        );
+        assert_eq!(
+            with_member(manifest, "day-", 25).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day-1\",\n    \"day-15\",\n    \"day-17\",\n    \"day-25\",\n]\n"
+        );
+        assert!(with_member(manifest, "day-", 15).is_err());
+    }
+
+    #[test]
+    fn lists_other_years_after_every_other_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"day-1\",\n    \"xtask\",\n]\n";
+        let manifest = with_member(manifest, "2020/day-", 3).unwrap();
+
+        assert_eq!(
+            manifest,
+            "[workspace]\nmembers = [\n    \"day-1\",\n    \"xtask\",\n    \"2020/day-3\",\n]\n"
+        );
+        assert_eq!(
+            with_member(&manifest, "2020/day-", 1).unwrap(),
+            "[workspace]\nmembers = [\n    \"day-1\",\n    \"xtask\",\n    \"2020/day-1\",\n    \"2020/day-3\",\n]\n"
+        );
+    }
+}